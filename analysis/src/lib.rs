@@ -0,0 +1,758 @@
+//! Statistical comparison of benchmark results.
+//!
+//! This crate contains the math used to decide whether the difference
+//! between two test results is significant and relevant: per-test-case
+//! significance thresholds derived from historical data, change
+//! magnitudes, and summaries over whole artifact comparisons. It is kept
+//! free of database and server dependencies so that the `site` crate, the
+//! collector's local comparison output, the GitHub bot, and external
+//! analysis scripts all share identical logic.
+//!
+//! The typical flow is to build one [`TestResultComparison`] per test
+//! case (optionally backed by [`HistoricalData`] for a noise-aware
+//! significance threshold) and then fold a group of them into an
+//! [`ArtifactComparisonSummary`].
+
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Serialize};
+use std::cmp;
+use std::str::FromStr;
+
+/// This enum contains all "known" metrics coming from rustc or profiling tools that we know
+/// (and care) about.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Metric {
+    #[serde(rename = "context-switches")]
+    ContextSwitches,
+    #[serde(rename = "cpu-clock")]
+    CpuClock,
+    #[serde(rename = "cpu-clock:u")]
+    CpuClockUser,
+    #[serde(rename = "cycles")]
+    Cycles,
+    #[serde(rename = "cycles:u")]
+    CyclesUser,
+    #[serde(rename = "faults")]
+    Faults,
+    #[serde(rename = "faults:u")]
+    FaultsUser,
+    #[serde(rename = "instructions:u")]
+    InstructionsUser,
+    #[serde(rename = "max-rss")]
+    MaxRSS,
+    #[serde(rename = "tree-max-rss")]
+    TreeMaxRSS,
+    #[serde(rename = "task-clock")]
+    TaskClock,
+    #[serde(rename = "task-clock:u")]
+    TaskClockUser,
+    #[serde(rename = "wall-time")]
+    WallTime,
+    /// Elapsed time until rustc's first diagnostic or artifact notification
+    /// during a check build; approximates IDE responsiveness.
+    #[serde(rename = "time-to-first-diagnostic")]
+    TimeToFirstDiagnostic,
+    #[serde(rename = "branch-misses")]
+    BranchMisses,
+    #[serde(rename = "cache-misses")]
+    CacheMisses,
+    /// Rustc guesses the codegen unit size by MIR count.
+    #[serde(rename = "size:codegen_unit_size_estimate")]
+    CodegenUnitSize,
+    /// The codegen unit size by llvm ir count, the real size of a cgu.
+    #[serde(rename = "size:cgu_instructions")]
+    CodegenUnitLlvmIrCount,
+    #[serde(rename = "size:dep_graph")]
+    DepGraphSize,
+    #[serde(rename = "size:linked_artifact")]
+    LinkedArtifactSize,
+    #[serde(rename = "size:object_file")]
+    ObjectFileSize,
+    #[serde(rename = "size:query_cache")]
+    QueryCacheSize,
+    #[serde(rename = "size:work_product_index")]
+    WorkProductIndexSize,
+    #[serde(rename = "size:crate_metadata")]
+    CrateMetadataSize,
+    #[serde(rename = "size:dwo_file")]
+    DwoFileSize,
+    #[serde(rename = "size:assembly_file")]
+    AssemblyFileSize,
+    #[serde(rename = "size:llvm_bitcode")]
+    LlvmBitcodeSize,
+    #[serde(rename = "size:llvm_ir")]
+    LlvmIrSize,
+    /// Total bytes of a generated documentation directory
+    #[serde(rename = "size:doc_bytes")]
+    DocByteSize,
+    /// Number of files inside a generated documentation directory.
+    #[serde(rename = "size:doc_files_count")]
+    DocFilesCount,
+    /// Total on-disk size of the incremental compilation cache directory.
+    #[serde(rename = "size:incr_cache_bytes")]
+    IncrCacheByteSize,
+    /// Number of files inside the incremental compilation cache directory.
+    #[serde(rename = "size:incr_cache_files_count")]
+    IncrCacheFilesCount,
+    /// On-disk size of the serialized dep-graph in the incremental cache.
+    #[serde(rename = "size:incr_dep_graph_bytes")]
+    IncrDepGraphByteSize,
+    /// Time cargo spent before invoking the final rustc (dependency
+    /// resolution, fingerprinting); recorded for full scenarios.
+    #[serde(rename = "cargo-overhead")]
+    CargoOverhead,
+    /// End-to-end duration of a benchmark run, including collector overhead.
+    #[serde(rename = "collector:run-duration")]
+    CollectorRunDuration,
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Metric::deserialize(s.into_deserializer())
+            .map_err(|e: serde::de::value::Error| format!("Unknown metric `{s}`: {e:?}"))
+    }
+}
+
+impl Metric {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Metric::ContextSwitches => "context-switches",
+            Metric::CpuClock => "cpu-clock",
+            Metric::CpuClockUser => "cpu-clock:u",
+            Metric::Cycles => "cycles",
+            Metric::CyclesUser => "cycles:u",
+            Metric::Faults => "faults",
+            Metric::FaultsUser => "faults:u",
+            Metric::InstructionsUser => "instructions:u",
+            Metric::MaxRSS => "max-rss",
+            Metric::TreeMaxRSS => "tree-max-rss",
+            Metric::TaskClock => "task-clock",
+            Metric::TaskClockUser => "task-clock:u",
+            Metric::WallTime => "wall-time",
+            Metric::TimeToFirstDiagnostic => "time-to-first-diagnostic",
+            Metric::BranchMisses => "branch-misses",
+            Metric::CacheMisses => "cache-misses",
+            Metric::CodegenUnitSize => "size:codegen_unit_size_estimate",
+            Metric::CodegenUnitLlvmIrCount => "size:cgu_instructions",
+            Metric::DepGraphSize => "size:dep_graph",
+            Metric::LinkedArtifactSize => "size:linked_artifact",
+            Metric::ObjectFileSize => "size:object_file",
+            Metric::QueryCacheSize => "size:query_cache",
+            Metric::WorkProductIndexSize => "size:work_product_index",
+            Metric::CrateMetadataSize => "size:crate_metadata",
+            Metric::DwoFileSize => "size:dwo_file",
+            Metric::AssemblyFileSize => "size:assembly_file",
+            Metric::LlvmBitcodeSize => "size:llvm_bitcode",
+            Metric::LlvmIrSize => "size:llvm_ir",
+            Metric::DocByteSize => "size:doc_bytes",
+            Metric::DocFilesCount => "size:doc_files_count",
+            Metric::IncrCacheByteSize => "size:incr_cache_bytes",
+            Metric::IncrCacheFilesCount => "size:incr_cache_files_count",
+            Metric::IncrDepGraphByteSize => "size:incr_dep_graph_bytes",
+            Metric::CargoOverhead => "cargo-overhead",
+            Metric::CollectorRunDuration => "collector:run-duration",
+        }
+    }
+
+    /// Determines the magnitude of a percent relative change for a given metric.
+    ///
+    /// Takes into account how noisy the stat is. For example, instruction
+    /// count which is normally not very noisy has smaller thresholds than
+    /// max-rss which can be noisy.
+    pub fn relative_change_magnitude(&self, change: f64) -> Magnitude {
+        let noise_factor = if self.is_typically_noisy() { 2.0 } else { 1.0 };
+        let change = change / noise_factor;
+        if change < 0.2 {
+            Magnitude::VerySmall
+        } else if change < 1.0 {
+            Magnitude::Small
+        } else if change < 2.0 {
+            Magnitude::Medium
+        } else if change < 5.0 {
+            Magnitude::Large
+        } else {
+            Magnitude::VeryLarge
+        }
+    }
+
+    /// Currently, we conservatively consider everything except instructions to be noisy.
+    pub fn is_typically_noisy(&self) -> bool {
+        !matches!(self, Self::InstructionsUser)
+    }
+}
+
+/// Historical results for a single test case, used to derive a noise-aware
+/// significance threshold for changes to that test case.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HistoricalData {
+    data: Vec<f64>,
+}
+
+impl HistoricalData {
+    /// The multiple of the IQR above Q3 that signifies significance
+    const IQR_MULTIPLIER: f64 = 3.0;
+
+    /// Appends a result to the history.
+    pub fn push(&mut self, value: f64) {
+        self.data.push(value);
+    }
+
+    /// The number of historical results.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The percent change of the deltas sorted from smallest delta to largest
+    fn percent_changes(&self) -> Vec<f64> {
+        let mut deltas = self
+            .deltas()
+            .zip(self.data.iter())
+            .map(|(d, &r)| d / r)
+            .collect::<Vec<_>>();
+        deltas.sort_by(|d1, d2| d1.partial_cmp(d2).unwrap_or(cmp::Ordering::Equal));
+        deltas
+    }
+
+    /// This is an absolute value indicating the noise barrier for changes on
+    /// this benchmark.
+    ///
+    /// A number line could be divided like this:
+    ///
+    /// ```text
+    /// ------o-------o----------
+    ///       ^   ^   ^
+    ///       |   |   |
+    ///       |   |   |
+    ///       |   |   ---- +significance_threshold
+    ///       |   |
+    ///       |   - not significant, includes zero
+    ///       |
+    ///       ---- -significance_threshold()
+    /// ```
+    pub fn significance_threshold(&self) -> f64 {
+        let (q1, q3) = self.quartiles();
+
+        // Changes that are IQR_MULTIPLIER away from the Q3 are considered
+        // outliers, and we judge those as significant.
+        q3 + (q3 - q1) * Self::IQR_MULTIPLIER
+    }
+
+    // (q1, q3)
+    fn quartiles(&self) -> (f64, f64) {
+        let pcs = self.percent_changes();
+        fn median(data: &[f64]) -> f64 {
+            if data.len() % 2 == 0 {
+                (data[(data.len() - 1) / 2] + data[data.len() / 2]) / 2.0
+            } else {
+                data[data.len() / 2]
+            }
+        }
+
+        let len = pcs.len();
+        let (h1_end, h2_begin) = if len % 2 == 0 {
+            (len / 2 - 2, len / 2 + 1)
+        } else {
+            (len / 2 - 1, len / 2 + 1)
+        };
+        let q1 = median(&pcs[..=h1_end]);
+        let q3 = median(&pcs[h2_begin..]);
+
+        (q1, q3)
+    }
+
+    // Absolute deltas between adjacent results
+    fn deltas(&self) -> impl Iterator<Item = f64> + '_ {
+        self.data
+            .windows(2)
+            .map(|window| (window[0] - window[1]).abs())
+    }
+}
+
+/// A single comparison between two test results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResultComparison {
+    metric: Metric,
+    historical_data: Option<HistoricalData>,
+    results: (f64, f64),
+    /// Whether a higher value of the metric is better for this test case
+    /// (e.g. a throughput counter), flipping the improvement/regression
+    /// classification. The reported relative change keeps its raw sign.
+    #[serde(default)]
+    higher_is_better: bool,
+}
+
+impl TestResultComparison {
+    /// The amount of relative change considered significant when
+    /// we cannot determine from historical data
+    const DEFAULT_SIGNIFICANCE_THRESHOLD: f64 = 0.002;
+
+    /// Creates a comparison of the results `(a, b)` of one test case,
+    /// where `b` is the newer result.
+    pub fn new(
+        metric: Metric,
+        historical_data: Option<HistoricalData>,
+        results: (f64, f64),
+    ) -> Self {
+        Self {
+            metric,
+            historical_data,
+            results,
+            higher_is_better: false,
+        }
+    }
+
+    /// Marks the compared metric as one where a higher value is better for
+    /// this test case, flipping the improvement/regression classification.
+    pub fn with_higher_is_better(mut self, higher_is_better: bool) -> Self {
+        self.higher_is_better = higher_is_better;
+        self
+    }
+
+    /// The metric the two results were measured with.
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// The two results being compared.
+    pub fn results(&self) -> (f64, f64) {
+        self.results
+    }
+
+    pub fn is_regression(&self) -> bool {
+        let (a, b) = self.results;
+        if self.higher_is_better {
+            b < a
+        } else {
+            b > a
+        }
+    }
+
+    pub fn is_improvement(&self) -> bool {
+        !self.is_regression()
+    }
+
+    /// Whether the comparison yielded a statistically significant result
+    pub fn is_significant(&self) -> bool {
+        self.relative_change().abs() >= self.significance_threshold()
+    }
+
+    /// Magnitude of change considered significant
+    pub fn significance_threshold(&self) -> f64 {
+        self.historical_data
+            .as_ref()
+            .map(|d| d.significance_threshold())
+            .unwrap_or(Self::DEFAULT_SIGNIFICANCE_THRESHOLD)
+    }
+
+    /// This is a numeric magnitude of a particular change.
+    pub fn significance_factor(&self) -> f64 {
+        let change = self.relative_change();
+        let threshold = self.significance_threshold();
+
+        // How many times the threshold this change is.
+        let factor = change.abs() / threshold;
+        if factor.is_finite() {
+            factor
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether the comparison is relevant or not.
+    ///
+    /// Relevance is a function of significance and magnitude.
+    pub fn is_relevant(&self) -> bool {
+        self.is_significant() && self.magnitude().is_small_or_above()
+    }
+
+    /// The magnitude of the change.
+    ///
+    /// This is the average of the absolute magnitude of the change
+    /// and the amount above the significance threshold.
+    pub fn magnitude(&self) -> Magnitude {
+        let change = self.relative_change().abs();
+
+        // When the significance threshold is very small, magnitude can become VeryLarge even though
+        // the change itself if incredibly small. So we deliberately return a VerySmall magnitude
+        // here to avoid marking such small result as being relevant.
+        if change < 0.0001 {
+            return Magnitude::VerySmall;
+        }
+
+        let threshold = self.significance_threshold();
+        let over_threshold = if change < threshold * 1.5 {
+            Magnitude::VerySmall
+        } else if change < threshold * 3.0 {
+            Magnitude::Small
+        } else if change < threshold * 6.0 {
+            Magnitude::Medium
+        } else if change < threshold * 12.0 {
+            Magnitude::Large
+        } else {
+            Magnitude::VeryLarge
+        };
+        let absolute_magnitude = self.metric.relative_change_magnitude(change * 100.0);
+        fn as_u8(m: Magnitude) -> u8 {
+            match m {
+                Magnitude::VerySmall => 1,
+                Magnitude::Small => 2,
+                Magnitude::Medium => 3,
+                Magnitude::Large => 4,
+                Magnitude::VeryLarge => 5,
+            }
+        }
+        fn from_u8(m: u8) -> Magnitude {
+            match m {
+                1 => Magnitude::VerySmall,
+                2 => Magnitude::Small,
+                3 => Magnitude::Medium,
+                4 => Magnitude::Large,
+                _ => Magnitude::VeryLarge,
+            }
+        }
+
+        // Take the average of the absolute magnitude and the magnitude
+        // above the significance threshold.
+        from_u8((as_u8(over_threshold) + as_u8(absolute_magnitude)) / 2)
+    }
+
+    /// The relative change from the first result to the second.
+    pub fn relative_change(&self) -> f64 {
+        let (a, b) = self.results;
+        (b - a) / a
+    }
+}
+
+/// A summary of a given comparison
+///
+/// This summary only includes changes that are significant and relevant (as determined by a change's magnitude).
+pub struct ArtifactComparisonSummary {
+    /// Relevant comparisons ordered from most negative to most positive
+    relevant_comparisons: Vec<TestResultComparison>,
+    /// The cached number of comparisons that are improvements
+    num_improvements: usize,
+    /// The cached number of comparisons that are regressions
+    num_regressions: usize,
+}
+
+impl ArtifactComparisonSummary {
+    /// Summarize a collection of `TestResultComparison`
+    pub fn summarize(comparisons: Vec<TestResultComparison>) -> Self {
+        let mut num_improvements = 0;
+        let mut num_regressions = 0;
+
+        let mut relevant_comparisons = comparisons
+            .into_iter()
+            .filter(|c| c.is_relevant())
+            .inspect(|c| {
+                if c.is_improvement() {
+                    num_improvements += 1;
+                } else {
+                    num_regressions += 1
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let cmp = |b1: &TestResultComparison, b2: &TestResultComparison| {
+            b1.relative_change()
+                .partial_cmp(&b2.relative_change())
+                .unwrap_or(cmp::Ordering::Equal)
+        };
+        relevant_comparisons.sort_by(cmp);
+
+        ArtifactComparisonSummary {
+            relevant_comparisons,
+            num_improvements,
+            num_regressions,
+        }
+    }
+
+    /// The relevant comparisons ordered from most negative to most positive
+    pub fn relevant_comparisons(&self) -> &[TestResultComparison] {
+        &self.relevant_comparisons
+    }
+
+    /// The number of relevant comparisons that are improvements
+    pub fn num_improvements(&self) -> usize {
+        self.num_improvements
+    }
+
+    /// The number of relevant comparisons that are regressions
+    pub fn num_regressions(&self) -> usize {
+        self.num_regressions
+    }
+
+    /// The direction of the changes
+    pub fn direction(&self) -> Direction {
+        if self.relevant_comparisons.is_empty() {
+            return Direction::None;
+        }
+
+        let (regressions, improvements): (Vec<&TestResultComparison>, _) = self
+            .relevant_comparisons
+            .iter()
+            .partition(|c| c.is_regression());
+
+        if regressions.is_empty() {
+            return Direction::Improvement;
+        }
+
+        if improvements.is_empty() {
+            return Direction::Regression;
+        }
+
+        let total_num = self.relevant_comparisons.len();
+        let regressions_ratio = regressions.len() as f64 / total_num as f64;
+
+        let has_medium_and_above_regressions = regressions
+            .iter()
+            .any(|c| c.magnitude().is_medium_or_above());
+        let has_medium_and_above_improvements = improvements
+            .iter()
+            .any(|c| c.magnitude().is_medium_or_above());
+        match (
+            has_medium_and_above_improvements,
+            has_medium_and_above_regressions,
+        ) {
+            (true, true) => Direction::Mixed,
+            (true, false) => {
+                if regressions_ratio >= 0.15 {
+                    Direction::Mixed
+                } else {
+                    Direction::Improvement
+                }
+            }
+            (false, true) => {
+                if regressions_ratio < 0.85 {
+                    Direction::Mixed
+                } else {
+                    Direction::Regression
+                }
+            }
+            (false, false) => {
+                if (0.1..=0.9).contains(&regressions_ratio) {
+                    Direction::Mixed
+                } else if regressions_ratio <= 0.1 {
+                    Direction::Improvement
+                } else {
+                    Direction::Regression
+                }
+            }
+        }
+    }
+
+    /// Arithmetic mean of all improvements as a percent
+    pub fn arithmetic_mean_of_improvements(&self) -> f64 {
+        self.arithmetic_mean(self.improvements())
+    }
+
+    /// Arithmetic mean of all regressions as a percent
+    pub fn arithmetic_mean_of_regressions(&self) -> f64 {
+        self.arithmetic_mean(self.regressions())
+    }
+
+    /// Arithmetic mean of all changes as a percent
+    pub fn arithmetic_mean_of_changes(&self) -> f64 {
+        self.arithmetic_mean(self.relevant_comparisons.iter())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relevant_comparisons.is_empty()
+    }
+
+    fn arithmetic_mean<'a>(
+        &'a self,
+        changes: impl Iterator<Item = &'a TestResultComparison>,
+    ) -> f64 {
+        let mut count = 0;
+        let mut sum = 0.0;
+        for r in changes {
+            sum += r.relative_change();
+            count += 1;
+        }
+
+        (sum / count as f64) * 100.0
+    }
+
+    fn improvements(&self) -> impl Iterator<Item = &TestResultComparison> {
+        self.relevant_comparisons
+            .iter()
+            .filter(|c| c.is_improvement())
+    }
+
+    fn regressions(&self) -> impl Iterator<Item = &TestResultComparison> {
+        self.relevant_comparisons
+            .iter()
+            .filter(|c| c.is_regression())
+    }
+
+    /// This is the most negative result.
+    pub fn largest_improvement(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons
+            .iter()
+            .find(|s| s.is_improvement())
+    }
+
+    /// This is the least negative result.
+    pub fn smallest_improvement(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons
+            .iter()
+            .rfind(|s| s.is_improvement())
+    }
+
+    /// This is the most positive result.
+    pub fn largest_regression(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons
+            .iter()
+            .rfind(|s| s.is_regression())
+    }
+
+    /// This is the least positive result.
+    pub fn smallest_regression(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons.iter().find(|s| s.is_regression())
+    }
+
+    /// This may be an improvement or a regression.
+    pub fn most_positive_change(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons.last()
+    }
+
+    /// This may be an improvement or a regression.
+    pub fn most_negative_change(&self) -> Option<&TestResultComparison> {
+        self.relevant_comparisons.first()
+    }
+
+    /// The relevance level of the entire comparison
+    pub fn is_relevant(&self) -> bool {
+        !self.is_empty()
+    }
+
+    pub fn num_changes(&self) -> usize {
+        self.relevant_comparisons.len()
+    }
+
+    pub fn largest_change(&self) -> Option<&TestResultComparison> {
+        if self.num_changes() == 0 {
+            None
+        } else {
+            let most_pos = self.most_positive_change().unwrap();
+            let most_neg = self.most_negative_change().unwrap();
+            let most_pos_abs = most_pos.relative_change().abs();
+            let most_neg_abs = most_neg.relative_change().abs();
+            if most_neg_abs.partial_cmp(&most_pos_abs) == Some(cmp::Ordering::Greater) {
+                Some(most_neg)
+            } else {
+                Some(most_pos)
+            }
+        }
+    }
+}
+
+/// Whether we are confident enough that an artifact comparison represents a real change and thus deserves to be looked at.
+///
+/// For example, this can be used to determine if artifact comparisons with regressions should be labeled with the
+/// `perf-regression` GitHub label or should be shown in the perf triage report.
+pub fn deserves_attention_icount(
+    primary: &ArtifactComparisonSummary,
+    secondary: &ArtifactComparisonSummary,
+) -> bool {
+    match (primary.largest_change(), secondary.largest_change()) {
+        (Some(c), _) if c.magnitude() >= Magnitude::Medium => true,
+        (_, Some(c)) if c.magnitude() >= Magnitude::Large => true,
+        _ => {
+            // How we determine whether a group of small changes deserves attention is and always will be arbitrary,
+            // but this feels good enough for now. We may choose in the future to become more sophisticated about it.
+            let primary_n = primary.num_changes();
+            let secondary_n = secondary.num_changes();
+            (primary_n * 3 + secondary_n) >= 9
+        }
+    }
+}
+
+/// The direction of a performance change
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    None,
+    Improvement,
+    Regression,
+    Mixed,
+}
+
+// The direction of a performance change. Forms a lattice:
+//
+//          Mixed
+//          /    \
+// Improvement  Regression
+//          \    /
+//           None
+//
+impl Direction {
+    /// Combines two directions. Also known as the "least upper bound".
+    pub fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::None, b) => b,
+            (a, Self::None) => a,
+            (Self::Improvement, Self::Improvement) => Self::Improvement,
+            (Self::Regression, Self::Regression) => Self::Regression,
+            _ => Self::Mixed,
+        }
+    }
+}
+
+/// The relative size of a performance change
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Ord, Eq)]
+pub enum Magnitude {
+    VerySmall,
+    Small,
+    Medium,
+    Large,
+    VeryLarge,
+}
+
+impl Magnitude {
+    pub fn is_small_or_above(&self) -> bool {
+        *self >= Self::Small
+    }
+
+    pub fn is_medium_or_above(&self) -> bool {
+        *self >= Self::Medium
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_metric_instructions() {
+        let metric: Metric = serde_json::from_str(r#""instructions:u""#).unwrap();
+        assert!(matches!(metric, Metric::InstructionsUser));
+    }
+
+    #[test]
+    fn parse_metric_cycles() {
+        let metric: Metric = serde_json::from_str(r#""cycles:u""#).unwrap();
+        assert!(matches!(metric, Metric::CyclesUser));
+    }
+
+    #[test]
+    fn parse_metric_max_rss() {
+        let metric: Metric = serde_json::from_str(r#""max-rss""#).unwrap();
+        assert!(matches!(metric, Metric::MaxRSS));
+    }
+
+    #[test]
+    fn higher_is_better_flips_classification() {
+        let comparison =
+            TestResultComparison::new(Metric::InstructionsUser, None, (100.0, 110.0));
+        assert!(comparison.is_regression());
+        let comparison = comparison.with_higher_is_better(true);
+        assert!(comparison.is_improvement());
+    }
+}
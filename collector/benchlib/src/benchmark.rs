@@ -1,5 +1,7 @@
 use crate::cli::{parse_cli, Args, BenchmarkArgs, ProfileArgs};
-use crate::comm::messages::{BenchmarkMessage, BenchmarkResult, BenchmarkStats};
+use crate::comm::messages::{
+    BenchmarkMessage, BenchmarkResult, BenchmarkStats, GroupInfo, PROTOCOL_VERSION,
+};
 use crate::comm::output_message;
 use crate::measure::benchmark_function;
 use crate::process::raise_process_priority;
@@ -73,6 +75,7 @@ impl<'a> BenchmarkGroup<'a> {
                 self.run_benchmarks(args)?;
             }
             Args::Profile(args) => self.profile_benchmark(args)?,
+            Args::Version => self.print_version()?,
             Args::List => self.list_benchmarks()?,
         }
 
@@ -127,6 +130,23 @@ impl<'a> BenchmarkGroup<'a> {
         Ok(())
     }
 
+    fn print_version(self) -> anyhow::Result<()> {
+        let mut benchmarks: Vec<String> = self
+            .benchmarks
+            .keys()
+            .map(|name| name.to_string())
+            .collect();
+        benchmarks.sort_unstable();
+        let info = GroupInfo {
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: vec!["run".to_string(), "profile".to_string()],
+            benchmarks,
+        };
+        serde_json::to_writer(std::io::stdout(), &info)?;
+
+        Ok(())
+    }
+
     fn list_benchmarks(self) -> anyhow::Result<()> {
         let benchmark_list: Vec<&str> = self.benchmarks.into_keys().collect();
         serde_json::to_writer(std::io::stdout(), &benchmark_list)?;
@@ -6,7 +6,14 @@ pub enum Args {
     Run(BenchmarkArgs),
     /// Profile a single benchmark execution.
     Profile(ProfileArgs),
+    /// Print the protocol version, the capabilities of this binary and the
+    /// benchmarks defined in the current group as JSON. This is the first
+    /// command the collector runs against a group binary; binaries that do
+    /// not understand it are rejected as stale.
+    Version,
     /// List benchmarks that are defined in the current group as a JSON array.
+    ///
+    /// Deprecated in favour of `version`, which also identifies the protocol.
     List,
 }
 
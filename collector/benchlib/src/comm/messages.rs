@@ -3,6 +3,24 @@
 
 use std::time::Duration;
 
+/// Version of the JSON protocol spoken between the collector and benchmark
+/// group binaries. Bump it whenever the handshake or message format changes
+/// incompatibly, so that stale compiled binaries in a cache are rejected
+/// instead of silently miscommunicating.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Reply to the `version` subcommand: the first message exchanged with a
+/// benchmark group binary, identifying the protocol it speaks, what it can
+/// do and which benchmarks it defines.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GroupInfo {
+    pub protocol_version: u32,
+    /// Subcommands the binary supports beyond the handshake.
+    pub capabilities: Vec<String>,
+    /// Names of the benchmarks defined in the group, sorted.
+    pub benchmarks: Vec<String>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum BenchmarkMessage {
     Result(BenchmarkResult),
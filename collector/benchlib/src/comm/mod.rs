@@ -7,6 +7,9 @@ pub mod messages;
 pub fn output_message<W: Write>(mut sink: W, message: BenchmarkMessage) -> anyhow::Result<()> {
     serde_json::to_writer(&mut sink, &message)?;
     sink.write_all(b"\n")?;
+    // Flush eagerly so that the collector sees each result as soon as it is
+    // produced, rather than when the buffer happens to fill up.
+    sink.flush()?;
     Ok(())
 }
 
@@ -28,6 +31,7 @@ impl<R: Read> Iterator for MessageReader<R> {
     type Item = anyhow::Result<BenchmarkMessage>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
         match self.inner.read_line(&mut self.line) {
             Ok(0) => None,
             Ok(_) => match serde_json::from_str(&self.line) {
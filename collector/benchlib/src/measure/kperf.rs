@@ -0,0 +1,173 @@
+//! A minimal dynamic binding to the private macOS `kperf` framework, which
+//! exposes the performance monitoring unit (PMU) of the CPU.
+//!
+//! Only the *fixed* counters of Apple Silicon CPUs are used: counter 0 counts
+//! CPU cycles and counter 1 counts retired instructions. Taking ownership of
+//! the PMU requires running as root.
+
+use std::ffi::c_void;
+
+/// Selects the fixed counters of the PMU.
+const KPC_CLASS_FIXED_MASK: u32 = 1;
+
+/// Indices of the fixed counters on Apple Silicon.
+const FIXED_CYCLES: usize = 0;
+const FIXED_INSTRUCTIONS: usize = 1;
+
+/// Upper bound on the number of counters per CPU, used to size read buffers.
+const MAX_COUNTERS: usize = 32;
+
+/// Values of the fixed counters at a single point in time.
+#[derive(Clone, Copy, Debug)]
+pub struct CounterValues {
+    pub cycles: u64,
+    pub instructions: u64,
+}
+
+impl CounterValues {
+    /// Returns the counter deltas between `earlier` and `self`.
+    pub fn delta_since(&self, earlier: &CounterValues) -> CounterValues {
+        CounterValues {
+            cycles: self.cycles.wrapping_sub(earlier.cycles),
+            instructions: self.instructions.wrapping_sub(earlier.instructions),
+        }
+    }
+}
+
+/// A loaded instance of the kperf framework with the PMU acquired.
+pub struct Kperf {
+    kpc_force_all_ctrs_set: unsafe extern "C" fn(i32) -> i32,
+    kpc_set_counting: unsafe extern "C" fn(u32) -> i32,
+    kpc_set_thread_counting: unsafe extern "C" fn(u32) -> i32,
+    kpc_get_thread_counters: unsafe extern "C" fn(u32, u32, *mut u64) -> i32,
+    kpc_get_cpu_counters: unsafe extern "C" fn(i32, u32, *mut i32, *mut u64) -> i32,
+    kpc_get_counter_count: unsafe extern "C" fn(u32) -> u32,
+}
+
+impl Kperf {
+    /// Loads the kperf framework and takes ownership of the PMU, enabling the
+    /// fixed counters system-wide. Requires running as root.
+    pub fn acquire() -> anyhow::Result<Self> {
+        let kperf = Self::load()?;
+        unsafe {
+            if (kperf.kpc_force_all_ctrs_set)(1) != 0 {
+                anyhow::bail!(
+                    "could not take ownership of the PMU counters; \
+                     accessing them requires running as root"
+                );
+            }
+            if (kperf.kpc_set_counting)(KPC_CLASS_FIXED_MASK) != 0 {
+                anyhow::bail!("could not enable the fixed PMU counters");
+            }
+        }
+        Ok(kperf)
+    }
+
+    fn load() -> anyhow::Result<Self> {
+        // The framework is private, so there are no headers to link against;
+        // resolve the symbols dynamically instead.
+        const KPERF_PATH: &[u8] =
+            b"/System/Library/PrivateFrameworks/kperf.framework/Versions/A/kperf\0";
+
+        unsafe {
+            let handle = libc::dlopen(KPERF_PATH.as_ptr() as *const libc::c_char, libc::RTLD_LAZY);
+            if handle.is_null() {
+                anyhow::bail!("could not load the kperf framework");
+            }
+
+            let symbol = |name: &'static [u8]| -> anyhow::Result<*mut c_void> {
+                let ptr = libc::dlsym(handle, name.as_ptr() as *const libc::c_char);
+                if ptr.is_null() {
+                    anyhow::bail!(
+                        "missing kperf symbol `{}`",
+                        String::from_utf8_lossy(&name[..name.len() - 1])
+                    );
+                }
+                Ok(ptr)
+            };
+
+            Ok(Kperf {
+                kpc_force_all_ctrs_set: std::mem::transmute(symbol(b"kpc_force_all_ctrs_set\0")?),
+                kpc_set_counting: std::mem::transmute(symbol(b"kpc_set_counting\0")?),
+                kpc_set_thread_counting: std::mem::transmute(symbol(
+                    b"kpc_set_thread_counting\0",
+                )?),
+                kpc_get_thread_counters: std::mem::transmute(symbol(
+                    b"kpc_get_thread_counters\0",
+                )?),
+                kpc_get_cpu_counters: std::mem::transmute(symbol(b"kpc_get_cpu_counters\0")?),
+                kpc_get_counter_count: std::mem::transmute(symbol(b"kpc_get_counter_count\0")?),
+            })
+        }
+    }
+
+    /// Additionally enables per-thread counting, so that
+    /// `read_thread_counters` reports values for the calling thread only.
+    pub fn enable_thread_counting(&self) -> anyhow::Result<()> {
+        unsafe {
+            if (self.kpc_set_thread_counting)(KPC_CLASS_FIXED_MASK) != 0 {
+                anyhow::bail!("could not enable per-thread PMU counting");
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the fixed counters of the calling thread. Requires
+    /// `enable_thread_counting` to have been called first.
+    pub fn read_thread_counters(&self) -> anyhow::Result<CounterValues> {
+        let mut buf = [0u64; MAX_COUNTERS];
+        unsafe {
+            if (self.kpc_get_thread_counters)(0, MAX_COUNTERS as u32, buf.as_mut_ptr()) != 0 {
+                anyhow::bail!("could not read the per-thread PMU counters");
+            }
+        }
+        Ok(CounterValues {
+            cycles: buf[FIXED_CYCLES],
+            instructions: buf[FIXED_INSTRUCTIONS],
+        })
+    }
+
+    /// Reads the fixed counters of every CPU and returns their sums. Note
+    /// that these counters are system-wide: they include all processes
+    /// running on the machine, not just the one of interest.
+    pub fn read_cpu_counters(&self) -> anyhow::Result<CounterValues> {
+        let stride = unsafe { (self.kpc_get_counter_count)(KPC_CLASS_FIXED_MASK) } as usize;
+        if stride < 2 {
+            anyhow::bail!("unexpected fixed PMU counter count {stride}");
+        }
+
+        let cpus = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) }.max(1) as usize;
+        let mut buf = vec![0u64; cpus * MAX_COUNTERS];
+        let mut read_cpus = 0i32;
+        unsafe {
+            if (self.kpc_get_cpu_counters)(
+                1,
+                KPC_CLASS_FIXED_MASK,
+                &mut read_cpus,
+                buf.as_mut_ptr(),
+            ) != 0
+            {
+                anyhow::bail!("could not read the per-CPU PMU counters");
+            }
+        }
+
+        let mut values = CounterValues {
+            cycles: 0,
+            instructions: 0,
+        };
+        for cpu in 0..read_cpus.max(0) as usize {
+            values.cycles += buf[cpu * stride + FIXED_CYCLES];
+            values.instructions += buf[cpu * stride + FIXED_INSTRUCTIONS];
+        }
+        Ok(values)
+    }
+}
+
+impl Drop for Kperf {
+    fn drop(&mut self) {
+        // Best-effort release of the PMU so that other tools can use it.
+        unsafe {
+            (self.kpc_force_all_ctrs_set)(0);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(target_os = "macos")]
+pub mod kperf;
 pub mod perf_counter;
 
 pub use perf_counter::benchmark_function;
@@ -0,0 +1,77 @@
+use crate::benchmark::black_box;
+use crate::comm::messages::BenchmarkStats;
+use crate::measure::kperf::Kperf;
+use std::time::Instant;
+
+/// Benchmarks a single function generated by `benchmark_constructor`.
+/// The function is executed twice, once to gather wall-time measurement and the second time to
+/// gather the fixed PMU counters (cycles and instructions) via the kperf framework.
+///
+/// Accessing the counters requires running as root. If they cannot be enabled, only wall-time
+/// is recorded.
+pub fn benchmark_function<F: Fn() -> Bench, R, Bench: FnOnce() -> R>(
+    benchmark_constructor: &F,
+) -> anyhow::Result<BenchmarkStats> {
+    let kperf = prepare_counters();
+
+    let mut cycles = None;
+    let mut instructions = None;
+
+    // Measure perf. counters.
+    if let Some(kperf) = &kperf {
+        let func = benchmark_constructor();
+
+        // Do not act on the return value to avoid including the branch in the measurement
+        let start = kperf.read_thread_counters();
+        let output = func();
+        let end = kperf.read_thread_counters()?;
+
+        // Try to avoid optimizing the result out.
+        black_box(output);
+
+        // Check if we have succeeded before
+        let start = start?;
+
+        let delta = end.delta_since(&start);
+        cycles = Some(delta.cycles);
+        instructions = Some(delta.instructions);
+    }
+
+    // Measure wall time.
+    let func = benchmark_constructor();
+
+    let start = Instant::now();
+    let output = func();
+    let duration = start.elapsed();
+
+    // Try to avoid optimizing the result out.
+    black_box(output);
+
+    let result = BenchmarkStats {
+        cycles,
+        instructions,
+        // The fixed counters do not cover these events.
+        branch_misses: None,
+        cache_misses: None,
+        cache_references: None,
+        wall_time: duration,
+    };
+    Ok(result)
+}
+
+fn prepare_counters() -> Option<Kperf> {
+    let kperf = Kperf::acquire().and_then(|kperf| {
+        kperf.enable_thread_counting()?;
+        Ok(kperf)
+    });
+    match kperf {
+        Ok(kperf) => Some(kperf),
+        Err(error) => {
+            log::warn!(
+                "Could not enable the kperf counters: {:?}. Only wall-time will be recorded.",
+                error
+            );
+            None
+        }
+    }
+}
@@ -1,11 +1,17 @@
 #[cfg(target_os = "linux")]
 mod linux;
 
-#[cfg(not(target_os = "linux"))]
-mod non_linux;
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod unsupported;
 
 #[cfg(target_os = "linux")]
 pub use linux::benchmark_function;
 
-#[cfg(not(target_os = "linux"))]
-pub use non_linux::benchmark_function;
+#[cfg(target_os = "macos")]
+pub use macos::benchmark_function;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub use unsupported::benchmark_function;
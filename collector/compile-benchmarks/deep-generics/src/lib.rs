@@ -0,0 +1,82 @@
+//! Stresses trait resolution and type checking with deeply nested generic
+//! types. Each wrapping layer forces the compiler to re-prove the trait
+//! bounds of everything below it, so the obligation trees grow quickly with
+//! depth.
+
+pub trait Depth {
+    const DEPTH: usize;
+    fn depth(&self) -> usize {
+        Self::DEPTH
+    }
+}
+
+pub struct Leaf;
+
+impl Depth for Leaf {
+    const DEPTH: usize = 0;
+}
+
+pub struct Wrap<T>(pub T);
+
+impl<T: Depth> Depth for Wrap<T> {
+    const DEPTH: usize = T::DEPTH + 1;
+}
+
+pub struct Pair<A, B>(pub A, pub B);
+
+impl<A: Depth, B: Depth> Depth for Pair<A, B> {
+    const DEPTH: usize = A::DEPTH + B::DEPTH + 1;
+}
+
+macro_rules! wrap {
+    (0, $t:ty) => { $t };
+    (1, $t:ty) => { Wrap<wrap!(0, $t)> };
+    (2, $t:ty) => { Wrap<wrap!(1, $t)> };
+    (3, $t:ty) => { Wrap<wrap!(2, $t)> };
+    (4, $t:ty) => { Wrap<wrap!(3, $t)> };
+    (5, $t:ty) => { Wrap<wrap!(4, $t)> };
+    (6, $t:ty) => { Wrap<wrap!(5, $t)> };
+    (7, $t:ty) => { Wrap<wrap!(6, $t)> };
+    (8, $t:ty) => { Wrap<wrap!(7, $t)> };
+    (9, $t:ty) => { Wrap<wrap!(8, $t)> };
+    (10, $t:ty) => { Wrap<wrap!(9, $t)> };
+    (11, $t:ty) => { Wrap<wrap!(10, $t)> };
+    (12, $t:ty) => { Wrap<wrap!(11, $t)> };
+    (13, $t:ty) => { Wrap<wrap!(12, $t)> };
+    (14, $t:ty) => { Wrap<wrap!(13, $t)> };
+    (15, $t:ty) => { Wrap<wrap!(14, $t)> };
+    (16, $t:ty) => { Wrap<wrap!(15, $t)> };
+}
+
+// Each level doubles the size of the type by pairing two deeply wrapped
+// subtrees, producing a type tree with 2^16 leaves.
+macro_rules! tree {
+    (0) => { Leaf };
+    (1) => { Pair<tree!(0), tree!(0)> };
+    (2) => { Pair<tree!(1), tree!(1)> };
+    (3) => { Pair<tree!(2), tree!(2)> };
+    (4) => { Pair<tree!(3), tree!(3)> };
+    (5) => { Pair<tree!(4), tree!(4)> };
+    (6) => { Pair<tree!(5), tree!(5)> };
+    (7) => { Pair<tree!(6), tree!(6)> };
+    (8) => { Pair<tree!(7), tree!(7)> };
+    (9) => { Pair<tree!(8), tree!(8)> };
+    (10) => { Pair<tree!(9), tree!(9)> };
+    (11) => { Pair<tree!(10), tree!(10)> };
+    (12) => { Pair<tree!(11), tree!(11)> };
+    (13) => { Pair<tree!(12), tree!(12)> };
+    (14) => { Pair<tree!(13), tree!(13)> };
+    (15) => { Pair<tree!(14), tree!(14)> };
+    (16) => { Pair<tree!(15), tree!(15)> };
+}
+
+pub type DeepWrap = wrap!(16, tree!(8));
+pub type DeepTree = tree!(12);
+
+pub fn depth_of<T: Depth>() -> usize {
+    T::DEPTH
+}
+
+pub fn depths() -> (usize, usize) {
+    (depth_of::<DeepWrap>(), depth_of::<DeepTree>())
+}
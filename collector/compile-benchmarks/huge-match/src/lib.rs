@@ -0,0 +1,8725 @@
+//! Stresses exhaustiveness checking and MIR match lowering with a single
+//! enormous `match`. Unlike `match-stress`, which gets its matches from
+//! `PartialEq` derives, the match here is written out directly, including
+//! multi-pattern arms over pairs of variants.
+//!
+//! This file is generated; the enum has 4096 variants.
+
+pub enum Huge {
+    V0,
+    V1,
+    V2,
+    V3,
+    V4,
+    V5,
+    V6,
+    V7,
+    V8,
+    V9,
+    V10,
+    V11,
+    V12,
+    V13,
+    V14,
+    V15,
+    V16,
+    V17,
+    V18,
+    V19,
+    V20,
+    V21,
+    V22,
+    V23,
+    V24,
+    V25,
+    V26,
+    V27,
+    V28,
+    V29,
+    V30,
+    V31,
+    V32,
+    V33,
+    V34,
+    V35,
+    V36,
+    V37,
+    V38,
+    V39,
+    V40,
+    V41,
+    V42,
+    V43,
+    V44,
+    V45,
+    V46,
+    V47,
+    V48,
+    V49,
+    V50,
+    V51,
+    V52,
+    V53,
+    V54,
+    V55,
+    V56,
+    V57,
+    V58,
+    V59,
+    V60,
+    V61,
+    V62,
+    V63,
+    V64,
+    V65,
+    V66,
+    V67,
+    V68,
+    V69,
+    V70,
+    V71,
+    V72,
+    V73,
+    V74,
+    V75,
+    V76,
+    V77,
+    V78,
+    V79,
+    V80,
+    V81,
+    V82,
+    V83,
+    V84,
+    V85,
+    V86,
+    V87,
+    V88,
+    V89,
+    V90,
+    V91,
+    V92,
+    V93,
+    V94,
+    V95,
+    V96,
+    V97,
+    V98,
+    V99,
+    V100,
+    V101,
+    V102,
+    V103,
+    V104,
+    V105,
+    V106,
+    V107,
+    V108,
+    V109,
+    V110,
+    V111,
+    V112,
+    V113,
+    V114,
+    V115,
+    V116,
+    V117,
+    V118,
+    V119,
+    V120,
+    V121,
+    V122,
+    V123,
+    V124,
+    V125,
+    V126,
+    V127,
+    V128,
+    V129,
+    V130,
+    V131,
+    V132,
+    V133,
+    V134,
+    V135,
+    V136,
+    V137,
+    V138,
+    V139,
+    V140,
+    V141,
+    V142,
+    V143,
+    V144,
+    V145,
+    V146,
+    V147,
+    V148,
+    V149,
+    V150,
+    V151,
+    V152,
+    V153,
+    V154,
+    V155,
+    V156,
+    V157,
+    V158,
+    V159,
+    V160,
+    V161,
+    V162,
+    V163,
+    V164,
+    V165,
+    V166,
+    V167,
+    V168,
+    V169,
+    V170,
+    V171,
+    V172,
+    V173,
+    V174,
+    V175,
+    V176,
+    V177,
+    V178,
+    V179,
+    V180,
+    V181,
+    V182,
+    V183,
+    V184,
+    V185,
+    V186,
+    V187,
+    V188,
+    V189,
+    V190,
+    V191,
+    V192,
+    V193,
+    V194,
+    V195,
+    V196,
+    V197,
+    V198,
+    V199,
+    V200,
+    V201,
+    V202,
+    V203,
+    V204,
+    V205,
+    V206,
+    V207,
+    V208,
+    V209,
+    V210,
+    V211,
+    V212,
+    V213,
+    V214,
+    V215,
+    V216,
+    V217,
+    V218,
+    V219,
+    V220,
+    V221,
+    V222,
+    V223,
+    V224,
+    V225,
+    V226,
+    V227,
+    V228,
+    V229,
+    V230,
+    V231,
+    V232,
+    V233,
+    V234,
+    V235,
+    V236,
+    V237,
+    V238,
+    V239,
+    V240,
+    V241,
+    V242,
+    V243,
+    V244,
+    V245,
+    V246,
+    V247,
+    V248,
+    V249,
+    V250,
+    V251,
+    V252,
+    V253,
+    V254,
+    V255,
+    V256,
+    V257,
+    V258,
+    V259,
+    V260,
+    V261,
+    V262,
+    V263,
+    V264,
+    V265,
+    V266,
+    V267,
+    V268,
+    V269,
+    V270,
+    V271,
+    V272,
+    V273,
+    V274,
+    V275,
+    V276,
+    V277,
+    V278,
+    V279,
+    V280,
+    V281,
+    V282,
+    V283,
+    V284,
+    V285,
+    V286,
+    V287,
+    V288,
+    V289,
+    V290,
+    V291,
+    V292,
+    V293,
+    V294,
+    V295,
+    V296,
+    V297,
+    V298,
+    V299,
+    V300,
+    V301,
+    V302,
+    V303,
+    V304,
+    V305,
+    V306,
+    V307,
+    V308,
+    V309,
+    V310,
+    V311,
+    V312,
+    V313,
+    V314,
+    V315,
+    V316,
+    V317,
+    V318,
+    V319,
+    V320,
+    V321,
+    V322,
+    V323,
+    V324,
+    V325,
+    V326,
+    V327,
+    V328,
+    V329,
+    V330,
+    V331,
+    V332,
+    V333,
+    V334,
+    V335,
+    V336,
+    V337,
+    V338,
+    V339,
+    V340,
+    V341,
+    V342,
+    V343,
+    V344,
+    V345,
+    V346,
+    V347,
+    V348,
+    V349,
+    V350,
+    V351,
+    V352,
+    V353,
+    V354,
+    V355,
+    V356,
+    V357,
+    V358,
+    V359,
+    V360,
+    V361,
+    V362,
+    V363,
+    V364,
+    V365,
+    V366,
+    V367,
+    V368,
+    V369,
+    V370,
+    V371,
+    V372,
+    V373,
+    V374,
+    V375,
+    V376,
+    V377,
+    V378,
+    V379,
+    V380,
+    V381,
+    V382,
+    V383,
+    V384,
+    V385,
+    V386,
+    V387,
+    V388,
+    V389,
+    V390,
+    V391,
+    V392,
+    V393,
+    V394,
+    V395,
+    V396,
+    V397,
+    V398,
+    V399,
+    V400,
+    V401,
+    V402,
+    V403,
+    V404,
+    V405,
+    V406,
+    V407,
+    V408,
+    V409,
+    V410,
+    V411,
+    V412,
+    V413,
+    V414,
+    V415,
+    V416,
+    V417,
+    V418,
+    V419,
+    V420,
+    V421,
+    V422,
+    V423,
+    V424,
+    V425,
+    V426,
+    V427,
+    V428,
+    V429,
+    V430,
+    V431,
+    V432,
+    V433,
+    V434,
+    V435,
+    V436,
+    V437,
+    V438,
+    V439,
+    V440,
+    V441,
+    V442,
+    V443,
+    V444,
+    V445,
+    V446,
+    V447,
+    V448,
+    V449,
+    V450,
+    V451,
+    V452,
+    V453,
+    V454,
+    V455,
+    V456,
+    V457,
+    V458,
+    V459,
+    V460,
+    V461,
+    V462,
+    V463,
+    V464,
+    V465,
+    V466,
+    V467,
+    V468,
+    V469,
+    V470,
+    V471,
+    V472,
+    V473,
+    V474,
+    V475,
+    V476,
+    V477,
+    V478,
+    V479,
+    V480,
+    V481,
+    V482,
+    V483,
+    V484,
+    V485,
+    V486,
+    V487,
+    V488,
+    V489,
+    V490,
+    V491,
+    V492,
+    V493,
+    V494,
+    V495,
+    V496,
+    V497,
+    V498,
+    V499,
+    V500,
+    V501,
+    V502,
+    V503,
+    V504,
+    V505,
+    V506,
+    V507,
+    V508,
+    V509,
+    V510,
+    V511,
+    V512,
+    V513,
+    V514,
+    V515,
+    V516,
+    V517,
+    V518,
+    V519,
+    V520,
+    V521,
+    V522,
+    V523,
+    V524,
+    V525,
+    V526,
+    V527,
+    V528,
+    V529,
+    V530,
+    V531,
+    V532,
+    V533,
+    V534,
+    V535,
+    V536,
+    V537,
+    V538,
+    V539,
+    V540,
+    V541,
+    V542,
+    V543,
+    V544,
+    V545,
+    V546,
+    V547,
+    V548,
+    V549,
+    V550,
+    V551,
+    V552,
+    V553,
+    V554,
+    V555,
+    V556,
+    V557,
+    V558,
+    V559,
+    V560,
+    V561,
+    V562,
+    V563,
+    V564,
+    V565,
+    V566,
+    V567,
+    V568,
+    V569,
+    V570,
+    V571,
+    V572,
+    V573,
+    V574,
+    V575,
+    V576,
+    V577,
+    V578,
+    V579,
+    V580,
+    V581,
+    V582,
+    V583,
+    V584,
+    V585,
+    V586,
+    V587,
+    V588,
+    V589,
+    V590,
+    V591,
+    V592,
+    V593,
+    V594,
+    V595,
+    V596,
+    V597,
+    V598,
+    V599,
+    V600,
+    V601,
+    V602,
+    V603,
+    V604,
+    V605,
+    V606,
+    V607,
+    V608,
+    V609,
+    V610,
+    V611,
+    V612,
+    V613,
+    V614,
+    V615,
+    V616,
+    V617,
+    V618,
+    V619,
+    V620,
+    V621,
+    V622,
+    V623,
+    V624,
+    V625,
+    V626,
+    V627,
+    V628,
+    V629,
+    V630,
+    V631,
+    V632,
+    V633,
+    V634,
+    V635,
+    V636,
+    V637,
+    V638,
+    V639,
+    V640,
+    V641,
+    V642,
+    V643,
+    V644,
+    V645,
+    V646,
+    V647,
+    V648,
+    V649,
+    V650,
+    V651,
+    V652,
+    V653,
+    V654,
+    V655,
+    V656,
+    V657,
+    V658,
+    V659,
+    V660,
+    V661,
+    V662,
+    V663,
+    V664,
+    V665,
+    V666,
+    V667,
+    V668,
+    V669,
+    V670,
+    V671,
+    V672,
+    V673,
+    V674,
+    V675,
+    V676,
+    V677,
+    V678,
+    V679,
+    V680,
+    V681,
+    V682,
+    V683,
+    V684,
+    V685,
+    V686,
+    V687,
+    V688,
+    V689,
+    V690,
+    V691,
+    V692,
+    V693,
+    V694,
+    V695,
+    V696,
+    V697,
+    V698,
+    V699,
+    V700,
+    V701,
+    V702,
+    V703,
+    V704,
+    V705,
+    V706,
+    V707,
+    V708,
+    V709,
+    V710,
+    V711,
+    V712,
+    V713,
+    V714,
+    V715,
+    V716,
+    V717,
+    V718,
+    V719,
+    V720,
+    V721,
+    V722,
+    V723,
+    V724,
+    V725,
+    V726,
+    V727,
+    V728,
+    V729,
+    V730,
+    V731,
+    V732,
+    V733,
+    V734,
+    V735,
+    V736,
+    V737,
+    V738,
+    V739,
+    V740,
+    V741,
+    V742,
+    V743,
+    V744,
+    V745,
+    V746,
+    V747,
+    V748,
+    V749,
+    V750,
+    V751,
+    V752,
+    V753,
+    V754,
+    V755,
+    V756,
+    V757,
+    V758,
+    V759,
+    V760,
+    V761,
+    V762,
+    V763,
+    V764,
+    V765,
+    V766,
+    V767,
+    V768,
+    V769,
+    V770,
+    V771,
+    V772,
+    V773,
+    V774,
+    V775,
+    V776,
+    V777,
+    V778,
+    V779,
+    V780,
+    V781,
+    V782,
+    V783,
+    V784,
+    V785,
+    V786,
+    V787,
+    V788,
+    V789,
+    V790,
+    V791,
+    V792,
+    V793,
+    V794,
+    V795,
+    V796,
+    V797,
+    V798,
+    V799,
+    V800,
+    V801,
+    V802,
+    V803,
+    V804,
+    V805,
+    V806,
+    V807,
+    V808,
+    V809,
+    V810,
+    V811,
+    V812,
+    V813,
+    V814,
+    V815,
+    V816,
+    V817,
+    V818,
+    V819,
+    V820,
+    V821,
+    V822,
+    V823,
+    V824,
+    V825,
+    V826,
+    V827,
+    V828,
+    V829,
+    V830,
+    V831,
+    V832,
+    V833,
+    V834,
+    V835,
+    V836,
+    V837,
+    V838,
+    V839,
+    V840,
+    V841,
+    V842,
+    V843,
+    V844,
+    V845,
+    V846,
+    V847,
+    V848,
+    V849,
+    V850,
+    V851,
+    V852,
+    V853,
+    V854,
+    V855,
+    V856,
+    V857,
+    V858,
+    V859,
+    V860,
+    V861,
+    V862,
+    V863,
+    V864,
+    V865,
+    V866,
+    V867,
+    V868,
+    V869,
+    V870,
+    V871,
+    V872,
+    V873,
+    V874,
+    V875,
+    V876,
+    V877,
+    V878,
+    V879,
+    V880,
+    V881,
+    V882,
+    V883,
+    V884,
+    V885,
+    V886,
+    V887,
+    V888,
+    V889,
+    V890,
+    V891,
+    V892,
+    V893,
+    V894,
+    V895,
+    V896,
+    V897,
+    V898,
+    V899,
+    V900,
+    V901,
+    V902,
+    V903,
+    V904,
+    V905,
+    V906,
+    V907,
+    V908,
+    V909,
+    V910,
+    V911,
+    V912,
+    V913,
+    V914,
+    V915,
+    V916,
+    V917,
+    V918,
+    V919,
+    V920,
+    V921,
+    V922,
+    V923,
+    V924,
+    V925,
+    V926,
+    V927,
+    V928,
+    V929,
+    V930,
+    V931,
+    V932,
+    V933,
+    V934,
+    V935,
+    V936,
+    V937,
+    V938,
+    V939,
+    V940,
+    V941,
+    V942,
+    V943,
+    V944,
+    V945,
+    V946,
+    V947,
+    V948,
+    V949,
+    V950,
+    V951,
+    V952,
+    V953,
+    V954,
+    V955,
+    V956,
+    V957,
+    V958,
+    V959,
+    V960,
+    V961,
+    V962,
+    V963,
+    V964,
+    V965,
+    V966,
+    V967,
+    V968,
+    V969,
+    V970,
+    V971,
+    V972,
+    V973,
+    V974,
+    V975,
+    V976,
+    V977,
+    V978,
+    V979,
+    V980,
+    V981,
+    V982,
+    V983,
+    V984,
+    V985,
+    V986,
+    V987,
+    V988,
+    V989,
+    V990,
+    V991,
+    V992,
+    V993,
+    V994,
+    V995,
+    V996,
+    V997,
+    V998,
+    V999,
+    V1000,
+    V1001,
+    V1002,
+    V1003,
+    V1004,
+    V1005,
+    V1006,
+    V1007,
+    V1008,
+    V1009,
+    V1010,
+    V1011,
+    V1012,
+    V1013,
+    V1014,
+    V1015,
+    V1016,
+    V1017,
+    V1018,
+    V1019,
+    V1020,
+    V1021,
+    V1022,
+    V1023,
+    V1024,
+    V1025,
+    V1026,
+    V1027,
+    V1028,
+    V1029,
+    V1030,
+    V1031,
+    V1032,
+    V1033,
+    V1034,
+    V1035,
+    V1036,
+    V1037,
+    V1038,
+    V1039,
+    V1040,
+    V1041,
+    V1042,
+    V1043,
+    V1044,
+    V1045,
+    V1046,
+    V1047,
+    V1048,
+    V1049,
+    V1050,
+    V1051,
+    V1052,
+    V1053,
+    V1054,
+    V1055,
+    V1056,
+    V1057,
+    V1058,
+    V1059,
+    V1060,
+    V1061,
+    V1062,
+    V1063,
+    V1064,
+    V1065,
+    V1066,
+    V1067,
+    V1068,
+    V1069,
+    V1070,
+    V1071,
+    V1072,
+    V1073,
+    V1074,
+    V1075,
+    V1076,
+    V1077,
+    V1078,
+    V1079,
+    V1080,
+    V1081,
+    V1082,
+    V1083,
+    V1084,
+    V1085,
+    V1086,
+    V1087,
+    V1088,
+    V1089,
+    V1090,
+    V1091,
+    V1092,
+    V1093,
+    V1094,
+    V1095,
+    V1096,
+    V1097,
+    V1098,
+    V1099,
+    V1100,
+    V1101,
+    V1102,
+    V1103,
+    V1104,
+    V1105,
+    V1106,
+    V1107,
+    V1108,
+    V1109,
+    V1110,
+    V1111,
+    V1112,
+    V1113,
+    V1114,
+    V1115,
+    V1116,
+    V1117,
+    V1118,
+    V1119,
+    V1120,
+    V1121,
+    V1122,
+    V1123,
+    V1124,
+    V1125,
+    V1126,
+    V1127,
+    V1128,
+    V1129,
+    V1130,
+    V1131,
+    V1132,
+    V1133,
+    V1134,
+    V1135,
+    V1136,
+    V1137,
+    V1138,
+    V1139,
+    V1140,
+    V1141,
+    V1142,
+    V1143,
+    V1144,
+    V1145,
+    V1146,
+    V1147,
+    V1148,
+    V1149,
+    V1150,
+    V1151,
+    V1152,
+    V1153,
+    V1154,
+    V1155,
+    V1156,
+    V1157,
+    V1158,
+    V1159,
+    V1160,
+    V1161,
+    V1162,
+    V1163,
+    V1164,
+    V1165,
+    V1166,
+    V1167,
+    V1168,
+    V1169,
+    V1170,
+    V1171,
+    V1172,
+    V1173,
+    V1174,
+    V1175,
+    V1176,
+    V1177,
+    V1178,
+    V1179,
+    V1180,
+    V1181,
+    V1182,
+    V1183,
+    V1184,
+    V1185,
+    V1186,
+    V1187,
+    V1188,
+    V1189,
+    V1190,
+    V1191,
+    V1192,
+    V1193,
+    V1194,
+    V1195,
+    V1196,
+    V1197,
+    V1198,
+    V1199,
+    V1200,
+    V1201,
+    V1202,
+    V1203,
+    V1204,
+    V1205,
+    V1206,
+    V1207,
+    V1208,
+    V1209,
+    V1210,
+    V1211,
+    V1212,
+    V1213,
+    V1214,
+    V1215,
+    V1216,
+    V1217,
+    V1218,
+    V1219,
+    V1220,
+    V1221,
+    V1222,
+    V1223,
+    V1224,
+    V1225,
+    V1226,
+    V1227,
+    V1228,
+    V1229,
+    V1230,
+    V1231,
+    V1232,
+    V1233,
+    V1234,
+    V1235,
+    V1236,
+    V1237,
+    V1238,
+    V1239,
+    V1240,
+    V1241,
+    V1242,
+    V1243,
+    V1244,
+    V1245,
+    V1246,
+    V1247,
+    V1248,
+    V1249,
+    V1250,
+    V1251,
+    V1252,
+    V1253,
+    V1254,
+    V1255,
+    V1256,
+    V1257,
+    V1258,
+    V1259,
+    V1260,
+    V1261,
+    V1262,
+    V1263,
+    V1264,
+    V1265,
+    V1266,
+    V1267,
+    V1268,
+    V1269,
+    V1270,
+    V1271,
+    V1272,
+    V1273,
+    V1274,
+    V1275,
+    V1276,
+    V1277,
+    V1278,
+    V1279,
+    V1280,
+    V1281,
+    V1282,
+    V1283,
+    V1284,
+    V1285,
+    V1286,
+    V1287,
+    V1288,
+    V1289,
+    V1290,
+    V1291,
+    V1292,
+    V1293,
+    V1294,
+    V1295,
+    V1296,
+    V1297,
+    V1298,
+    V1299,
+    V1300,
+    V1301,
+    V1302,
+    V1303,
+    V1304,
+    V1305,
+    V1306,
+    V1307,
+    V1308,
+    V1309,
+    V1310,
+    V1311,
+    V1312,
+    V1313,
+    V1314,
+    V1315,
+    V1316,
+    V1317,
+    V1318,
+    V1319,
+    V1320,
+    V1321,
+    V1322,
+    V1323,
+    V1324,
+    V1325,
+    V1326,
+    V1327,
+    V1328,
+    V1329,
+    V1330,
+    V1331,
+    V1332,
+    V1333,
+    V1334,
+    V1335,
+    V1336,
+    V1337,
+    V1338,
+    V1339,
+    V1340,
+    V1341,
+    V1342,
+    V1343,
+    V1344,
+    V1345,
+    V1346,
+    V1347,
+    V1348,
+    V1349,
+    V1350,
+    V1351,
+    V1352,
+    V1353,
+    V1354,
+    V1355,
+    V1356,
+    V1357,
+    V1358,
+    V1359,
+    V1360,
+    V1361,
+    V1362,
+    V1363,
+    V1364,
+    V1365,
+    V1366,
+    V1367,
+    V1368,
+    V1369,
+    V1370,
+    V1371,
+    V1372,
+    V1373,
+    V1374,
+    V1375,
+    V1376,
+    V1377,
+    V1378,
+    V1379,
+    V1380,
+    V1381,
+    V1382,
+    V1383,
+    V1384,
+    V1385,
+    V1386,
+    V1387,
+    V1388,
+    V1389,
+    V1390,
+    V1391,
+    V1392,
+    V1393,
+    V1394,
+    V1395,
+    V1396,
+    V1397,
+    V1398,
+    V1399,
+    V1400,
+    V1401,
+    V1402,
+    V1403,
+    V1404,
+    V1405,
+    V1406,
+    V1407,
+    V1408,
+    V1409,
+    V1410,
+    V1411,
+    V1412,
+    V1413,
+    V1414,
+    V1415,
+    V1416,
+    V1417,
+    V1418,
+    V1419,
+    V1420,
+    V1421,
+    V1422,
+    V1423,
+    V1424,
+    V1425,
+    V1426,
+    V1427,
+    V1428,
+    V1429,
+    V1430,
+    V1431,
+    V1432,
+    V1433,
+    V1434,
+    V1435,
+    V1436,
+    V1437,
+    V1438,
+    V1439,
+    V1440,
+    V1441,
+    V1442,
+    V1443,
+    V1444,
+    V1445,
+    V1446,
+    V1447,
+    V1448,
+    V1449,
+    V1450,
+    V1451,
+    V1452,
+    V1453,
+    V1454,
+    V1455,
+    V1456,
+    V1457,
+    V1458,
+    V1459,
+    V1460,
+    V1461,
+    V1462,
+    V1463,
+    V1464,
+    V1465,
+    V1466,
+    V1467,
+    V1468,
+    V1469,
+    V1470,
+    V1471,
+    V1472,
+    V1473,
+    V1474,
+    V1475,
+    V1476,
+    V1477,
+    V1478,
+    V1479,
+    V1480,
+    V1481,
+    V1482,
+    V1483,
+    V1484,
+    V1485,
+    V1486,
+    V1487,
+    V1488,
+    V1489,
+    V1490,
+    V1491,
+    V1492,
+    V1493,
+    V1494,
+    V1495,
+    V1496,
+    V1497,
+    V1498,
+    V1499,
+    V1500,
+    V1501,
+    V1502,
+    V1503,
+    V1504,
+    V1505,
+    V1506,
+    V1507,
+    V1508,
+    V1509,
+    V1510,
+    V1511,
+    V1512,
+    V1513,
+    V1514,
+    V1515,
+    V1516,
+    V1517,
+    V1518,
+    V1519,
+    V1520,
+    V1521,
+    V1522,
+    V1523,
+    V1524,
+    V1525,
+    V1526,
+    V1527,
+    V1528,
+    V1529,
+    V1530,
+    V1531,
+    V1532,
+    V1533,
+    V1534,
+    V1535,
+    V1536,
+    V1537,
+    V1538,
+    V1539,
+    V1540,
+    V1541,
+    V1542,
+    V1543,
+    V1544,
+    V1545,
+    V1546,
+    V1547,
+    V1548,
+    V1549,
+    V1550,
+    V1551,
+    V1552,
+    V1553,
+    V1554,
+    V1555,
+    V1556,
+    V1557,
+    V1558,
+    V1559,
+    V1560,
+    V1561,
+    V1562,
+    V1563,
+    V1564,
+    V1565,
+    V1566,
+    V1567,
+    V1568,
+    V1569,
+    V1570,
+    V1571,
+    V1572,
+    V1573,
+    V1574,
+    V1575,
+    V1576,
+    V1577,
+    V1578,
+    V1579,
+    V1580,
+    V1581,
+    V1582,
+    V1583,
+    V1584,
+    V1585,
+    V1586,
+    V1587,
+    V1588,
+    V1589,
+    V1590,
+    V1591,
+    V1592,
+    V1593,
+    V1594,
+    V1595,
+    V1596,
+    V1597,
+    V1598,
+    V1599,
+    V1600,
+    V1601,
+    V1602,
+    V1603,
+    V1604,
+    V1605,
+    V1606,
+    V1607,
+    V1608,
+    V1609,
+    V1610,
+    V1611,
+    V1612,
+    V1613,
+    V1614,
+    V1615,
+    V1616,
+    V1617,
+    V1618,
+    V1619,
+    V1620,
+    V1621,
+    V1622,
+    V1623,
+    V1624,
+    V1625,
+    V1626,
+    V1627,
+    V1628,
+    V1629,
+    V1630,
+    V1631,
+    V1632,
+    V1633,
+    V1634,
+    V1635,
+    V1636,
+    V1637,
+    V1638,
+    V1639,
+    V1640,
+    V1641,
+    V1642,
+    V1643,
+    V1644,
+    V1645,
+    V1646,
+    V1647,
+    V1648,
+    V1649,
+    V1650,
+    V1651,
+    V1652,
+    V1653,
+    V1654,
+    V1655,
+    V1656,
+    V1657,
+    V1658,
+    V1659,
+    V1660,
+    V1661,
+    V1662,
+    V1663,
+    V1664,
+    V1665,
+    V1666,
+    V1667,
+    V1668,
+    V1669,
+    V1670,
+    V1671,
+    V1672,
+    V1673,
+    V1674,
+    V1675,
+    V1676,
+    V1677,
+    V1678,
+    V1679,
+    V1680,
+    V1681,
+    V1682,
+    V1683,
+    V1684,
+    V1685,
+    V1686,
+    V1687,
+    V1688,
+    V1689,
+    V1690,
+    V1691,
+    V1692,
+    V1693,
+    V1694,
+    V1695,
+    V1696,
+    V1697,
+    V1698,
+    V1699,
+    V1700,
+    V1701,
+    V1702,
+    V1703,
+    V1704,
+    V1705,
+    V1706,
+    V1707,
+    V1708,
+    V1709,
+    V1710,
+    V1711,
+    V1712,
+    V1713,
+    V1714,
+    V1715,
+    V1716,
+    V1717,
+    V1718,
+    V1719,
+    V1720,
+    V1721,
+    V1722,
+    V1723,
+    V1724,
+    V1725,
+    V1726,
+    V1727,
+    V1728,
+    V1729,
+    V1730,
+    V1731,
+    V1732,
+    V1733,
+    V1734,
+    V1735,
+    V1736,
+    V1737,
+    V1738,
+    V1739,
+    V1740,
+    V1741,
+    V1742,
+    V1743,
+    V1744,
+    V1745,
+    V1746,
+    V1747,
+    V1748,
+    V1749,
+    V1750,
+    V1751,
+    V1752,
+    V1753,
+    V1754,
+    V1755,
+    V1756,
+    V1757,
+    V1758,
+    V1759,
+    V1760,
+    V1761,
+    V1762,
+    V1763,
+    V1764,
+    V1765,
+    V1766,
+    V1767,
+    V1768,
+    V1769,
+    V1770,
+    V1771,
+    V1772,
+    V1773,
+    V1774,
+    V1775,
+    V1776,
+    V1777,
+    V1778,
+    V1779,
+    V1780,
+    V1781,
+    V1782,
+    V1783,
+    V1784,
+    V1785,
+    V1786,
+    V1787,
+    V1788,
+    V1789,
+    V1790,
+    V1791,
+    V1792,
+    V1793,
+    V1794,
+    V1795,
+    V1796,
+    V1797,
+    V1798,
+    V1799,
+    V1800,
+    V1801,
+    V1802,
+    V1803,
+    V1804,
+    V1805,
+    V1806,
+    V1807,
+    V1808,
+    V1809,
+    V1810,
+    V1811,
+    V1812,
+    V1813,
+    V1814,
+    V1815,
+    V1816,
+    V1817,
+    V1818,
+    V1819,
+    V1820,
+    V1821,
+    V1822,
+    V1823,
+    V1824,
+    V1825,
+    V1826,
+    V1827,
+    V1828,
+    V1829,
+    V1830,
+    V1831,
+    V1832,
+    V1833,
+    V1834,
+    V1835,
+    V1836,
+    V1837,
+    V1838,
+    V1839,
+    V1840,
+    V1841,
+    V1842,
+    V1843,
+    V1844,
+    V1845,
+    V1846,
+    V1847,
+    V1848,
+    V1849,
+    V1850,
+    V1851,
+    V1852,
+    V1853,
+    V1854,
+    V1855,
+    V1856,
+    V1857,
+    V1858,
+    V1859,
+    V1860,
+    V1861,
+    V1862,
+    V1863,
+    V1864,
+    V1865,
+    V1866,
+    V1867,
+    V1868,
+    V1869,
+    V1870,
+    V1871,
+    V1872,
+    V1873,
+    V1874,
+    V1875,
+    V1876,
+    V1877,
+    V1878,
+    V1879,
+    V1880,
+    V1881,
+    V1882,
+    V1883,
+    V1884,
+    V1885,
+    V1886,
+    V1887,
+    V1888,
+    V1889,
+    V1890,
+    V1891,
+    V1892,
+    V1893,
+    V1894,
+    V1895,
+    V1896,
+    V1897,
+    V1898,
+    V1899,
+    V1900,
+    V1901,
+    V1902,
+    V1903,
+    V1904,
+    V1905,
+    V1906,
+    V1907,
+    V1908,
+    V1909,
+    V1910,
+    V1911,
+    V1912,
+    V1913,
+    V1914,
+    V1915,
+    V1916,
+    V1917,
+    V1918,
+    V1919,
+    V1920,
+    V1921,
+    V1922,
+    V1923,
+    V1924,
+    V1925,
+    V1926,
+    V1927,
+    V1928,
+    V1929,
+    V1930,
+    V1931,
+    V1932,
+    V1933,
+    V1934,
+    V1935,
+    V1936,
+    V1937,
+    V1938,
+    V1939,
+    V1940,
+    V1941,
+    V1942,
+    V1943,
+    V1944,
+    V1945,
+    V1946,
+    V1947,
+    V1948,
+    V1949,
+    V1950,
+    V1951,
+    V1952,
+    V1953,
+    V1954,
+    V1955,
+    V1956,
+    V1957,
+    V1958,
+    V1959,
+    V1960,
+    V1961,
+    V1962,
+    V1963,
+    V1964,
+    V1965,
+    V1966,
+    V1967,
+    V1968,
+    V1969,
+    V1970,
+    V1971,
+    V1972,
+    V1973,
+    V1974,
+    V1975,
+    V1976,
+    V1977,
+    V1978,
+    V1979,
+    V1980,
+    V1981,
+    V1982,
+    V1983,
+    V1984,
+    V1985,
+    V1986,
+    V1987,
+    V1988,
+    V1989,
+    V1990,
+    V1991,
+    V1992,
+    V1993,
+    V1994,
+    V1995,
+    V1996,
+    V1997,
+    V1998,
+    V1999,
+    V2000,
+    V2001,
+    V2002,
+    V2003,
+    V2004,
+    V2005,
+    V2006,
+    V2007,
+    V2008,
+    V2009,
+    V2010,
+    V2011,
+    V2012,
+    V2013,
+    V2014,
+    V2015,
+    V2016,
+    V2017,
+    V2018,
+    V2019,
+    V2020,
+    V2021,
+    V2022,
+    V2023,
+    V2024,
+    V2025,
+    V2026,
+    V2027,
+    V2028,
+    V2029,
+    V2030,
+    V2031,
+    V2032,
+    V2033,
+    V2034,
+    V2035,
+    V2036,
+    V2037,
+    V2038,
+    V2039,
+    V2040,
+    V2041,
+    V2042,
+    V2043,
+    V2044,
+    V2045,
+    V2046,
+    V2047,
+    V2048,
+    V2049,
+    V2050,
+    V2051,
+    V2052,
+    V2053,
+    V2054,
+    V2055,
+    V2056,
+    V2057,
+    V2058,
+    V2059,
+    V2060,
+    V2061,
+    V2062,
+    V2063,
+    V2064,
+    V2065,
+    V2066,
+    V2067,
+    V2068,
+    V2069,
+    V2070,
+    V2071,
+    V2072,
+    V2073,
+    V2074,
+    V2075,
+    V2076,
+    V2077,
+    V2078,
+    V2079,
+    V2080,
+    V2081,
+    V2082,
+    V2083,
+    V2084,
+    V2085,
+    V2086,
+    V2087,
+    V2088,
+    V2089,
+    V2090,
+    V2091,
+    V2092,
+    V2093,
+    V2094,
+    V2095,
+    V2096,
+    V2097,
+    V2098,
+    V2099,
+    V2100,
+    V2101,
+    V2102,
+    V2103,
+    V2104,
+    V2105,
+    V2106,
+    V2107,
+    V2108,
+    V2109,
+    V2110,
+    V2111,
+    V2112,
+    V2113,
+    V2114,
+    V2115,
+    V2116,
+    V2117,
+    V2118,
+    V2119,
+    V2120,
+    V2121,
+    V2122,
+    V2123,
+    V2124,
+    V2125,
+    V2126,
+    V2127,
+    V2128,
+    V2129,
+    V2130,
+    V2131,
+    V2132,
+    V2133,
+    V2134,
+    V2135,
+    V2136,
+    V2137,
+    V2138,
+    V2139,
+    V2140,
+    V2141,
+    V2142,
+    V2143,
+    V2144,
+    V2145,
+    V2146,
+    V2147,
+    V2148,
+    V2149,
+    V2150,
+    V2151,
+    V2152,
+    V2153,
+    V2154,
+    V2155,
+    V2156,
+    V2157,
+    V2158,
+    V2159,
+    V2160,
+    V2161,
+    V2162,
+    V2163,
+    V2164,
+    V2165,
+    V2166,
+    V2167,
+    V2168,
+    V2169,
+    V2170,
+    V2171,
+    V2172,
+    V2173,
+    V2174,
+    V2175,
+    V2176,
+    V2177,
+    V2178,
+    V2179,
+    V2180,
+    V2181,
+    V2182,
+    V2183,
+    V2184,
+    V2185,
+    V2186,
+    V2187,
+    V2188,
+    V2189,
+    V2190,
+    V2191,
+    V2192,
+    V2193,
+    V2194,
+    V2195,
+    V2196,
+    V2197,
+    V2198,
+    V2199,
+    V2200,
+    V2201,
+    V2202,
+    V2203,
+    V2204,
+    V2205,
+    V2206,
+    V2207,
+    V2208,
+    V2209,
+    V2210,
+    V2211,
+    V2212,
+    V2213,
+    V2214,
+    V2215,
+    V2216,
+    V2217,
+    V2218,
+    V2219,
+    V2220,
+    V2221,
+    V2222,
+    V2223,
+    V2224,
+    V2225,
+    V2226,
+    V2227,
+    V2228,
+    V2229,
+    V2230,
+    V2231,
+    V2232,
+    V2233,
+    V2234,
+    V2235,
+    V2236,
+    V2237,
+    V2238,
+    V2239,
+    V2240,
+    V2241,
+    V2242,
+    V2243,
+    V2244,
+    V2245,
+    V2246,
+    V2247,
+    V2248,
+    V2249,
+    V2250,
+    V2251,
+    V2252,
+    V2253,
+    V2254,
+    V2255,
+    V2256,
+    V2257,
+    V2258,
+    V2259,
+    V2260,
+    V2261,
+    V2262,
+    V2263,
+    V2264,
+    V2265,
+    V2266,
+    V2267,
+    V2268,
+    V2269,
+    V2270,
+    V2271,
+    V2272,
+    V2273,
+    V2274,
+    V2275,
+    V2276,
+    V2277,
+    V2278,
+    V2279,
+    V2280,
+    V2281,
+    V2282,
+    V2283,
+    V2284,
+    V2285,
+    V2286,
+    V2287,
+    V2288,
+    V2289,
+    V2290,
+    V2291,
+    V2292,
+    V2293,
+    V2294,
+    V2295,
+    V2296,
+    V2297,
+    V2298,
+    V2299,
+    V2300,
+    V2301,
+    V2302,
+    V2303,
+    V2304,
+    V2305,
+    V2306,
+    V2307,
+    V2308,
+    V2309,
+    V2310,
+    V2311,
+    V2312,
+    V2313,
+    V2314,
+    V2315,
+    V2316,
+    V2317,
+    V2318,
+    V2319,
+    V2320,
+    V2321,
+    V2322,
+    V2323,
+    V2324,
+    V2325,
+    V2326,
+    V2327,
+    V2328,
+    V2329,
+    V2330,
+    V2331,
+    V2332,
+    V2333,
+    V2334,
+    V2335,
+    V2336,
+    V2337,
+    V2338,
+    V2339,
+    V2340,
+    V2341,
+    V2342,
+    V2343,
+    V2344,
+    V2345,
+    V2346,
+    V2347,
+    V2348,
+    V2349,
+    V2350,
+    V2351,
+    V2352,
+    V2353,
+    V2354,
+    V2355,
+    V2356,
+    V2357,
+    V2358,
+    V2359,
+    V2360,
+    V2361,
+    V2362,
+    V2363,
+    V2364,
+    V2365,
+    V2366,
+    V2367,
+    V2368,
+    V2369,
+    V2370,
+    V2371,
+    V2372,
+    V2373,
+    V2374,
+    V2375,
+    V2376,
+    V2377,
+    V2378,
+    V2379,
+    V2380,
+    V2381,
+    V2382,
+    V2383,
+    V2384,
+    V2385,
+    V2386,
+    V2387,
+    V2388,
+    V2389,
+    V2390,
+    V2391,
+    V2392,
+    V2393,
+    V2394,
+    V2395,
+    V2396,
+    V2397,
+    V2398,
+    V2399,
+    V2400,
+    V2401,
+    V2402,
+    V2403,
+    V2404,
+    V2405,
+    V2406,
+    V2407,
+    V2408,
+    V2409,
+    V2410,
+    V2411,
+    V2412,
+    V2413,
+    V2414,
+    V2415,
+    V2416,
+    V2417,
+    V2418,
+    V2419,
+    V2420,
+    V2421,
+    V2422,
+    V2423,
+    V2424,
+    V2425,
+    V2426,
+    V2427,
+    V2428,
+    V2429,
+    V2430,
+    V2431,
+    V2432,
+    V2433,
+    V2434,
+    V2435,
+    V2436,
+    V2437,
+    V2438,
+    V2439,
+    V2440,
+    V2441,
+    V2442,
+    V2443,
+    V2444,
+    V2445,
+    V2446,
+    V2447,
+    V2448,
+    V2449,
+    V2450,
+    V2451,
+    V2452,
+    V2453,
+    V2454,
+    V2455,
+    V2456,
+    V2457,
+    V2458,
+    V2459,
+    V2460,
+    V2461,
+    V2462,
+    V2463,
+    V2464,
+    V2465,
+    V2466,
+    V2467,
+    V2468,
+    V2469,
+    V2470,
+    V2471,
+    V2472,
+    V2473,
+    V2474,
+    V2475,
+    V2476,
+    V2477,
+    V2478,
+    V2479,
+    V2480,
+    V2481,
+    V2482,
+    V2483,
+    V2484,
+    V2485,
+    V2486,
+    V2487,
+    V2488,
+    V2489,
+    V2490,
+    V2491,
+    V2492,
+    V2493,
+    V2494,
+    V2495,
+    V2496,
+    V2497,
+    V2498,
+    V2499,
+    V2500,
+    V2501,
+    V2502,
+    V2503,
+    V2504,
+    V2505,
+    V2506,
+    V2507,
+    V2508,
+    V2509,
+    V2510,
+    V2511,
+    V2512,
+    V2513,
+    V2514,
+    V2515,
+    V2516,
+    V2517,
+    V2518,
+    V2519,
+    V2520,
+    V2521,
+    V2522,
+    V2523,
+    V2524,
+    V2525,
+    V2526,
+    V2527,
+    V2528,
+    V2529,
+    V2530,
+    V2531,
+    V2532,
+    V2533,
+    V2534,
+    V2535,
+    V2536,
+    V2537,
+    V2538,
+    V2539,
+    V2540,
+    V2541,
+    V2542,
+    V2543,
+    V2544,
+    V2545,
+    V2546,
+    V2547,
+    V2548,
+    V2549,
+    V2550,
+    V2551,
+    V2552,
+    V2553,
+    V2554,
+    V2555,
+    V2556,
+    V2557,
+    V2558,
+    V2559,
+    V2560,
+    V2561,
+    V2562,
+    V2563,
+    V2564,
+    V2565,
+    V2566,
+    V2567,
+    V2568,
+    V2569,
+    V2570,
+    V2571,
+    V2572,
+    V2573,
+    V2574,
+    V2575,
+    V2576,
+    V2577,
+    V2578,
+    V2579,
+    V2580,
+    V2581,
+    V2582,
+    V2583,
+    V2584,
+    V2585,
+    V2586,
+    V2587,
+    V2588,
+    V2589,
+    V2590,
+    V2591,
+    V2592,
+    V2593,
+    V2594,
+    V2595,
+    V2596,
+    V2597,
+    V2598,
+    V2599,
+    V2600,
+    V2601,
+    V2602,
+    V2603,
+    V2604,
+    V2605,
+    V2606,
+    V2607,
+    V2608,
+    V2609,
+    V2610,
+    V2611,
+    V2612,
+    V2613,
+    V2614,
+    V2615,
+    V2616,
+    V2617,
+    V2618,
+    V2619,
+    V2620,
+    V2621,
+    V2622,
+    V2623,
+    V2624,
+    V2625,
+    V2626,
+    V2627,
+    V2628,
+    V2629,
+    V2630,
+    V2631,
+    V2632,
+    V2633,
+    V2634,
+    V2635,
+    V2636,
+    V2637,
+    V2638,
+    V2639,
+    V2640,
+    V2641,
+    V2642,
+    V2643,
+    V2644,
+    V2645,
+    V2646,
+    V2647,
+    V2648,
+    V2649,
+    V2650,
+    V2651,
+    V2652,
+    V2653,
+    V2654,
+    V2655,
+    V2656,
+    V2657,
+    V2658,
+    V2659,
+    V2660,
+    V2661,
+    V2662,
+    V2663,
+    V2664,
+    V2665,
+    V2666,
+    V2667,
+    V2668,
+    V2669,
+    V2670,
+    V2671,
+    V2672,
+    V2673,
+    V2674,
+    V2675,
+    V2676,
+    V2677,
+    V2678,
+    V2679,
+    V2680,
+    V2681,
+    V2682,
+    V2683,
+    V2684,
+    V2685,
+    V2686,
+    V2687,
+    V2688,
+    V2689,
+    V2690,
+    V2691,
+    V2692,
+    V2693,
+    V2694,
+    V2695,
+    V2696,
+    V2697,
+    V2698,
+    V2699,
+    V2700,
+    V2701,
+    V2702,
+    V2703,
+    V2704,
+    V2705,
+    V2706,
+    V2707,
+    V2708,
+    V2709,
+    V2710,
+    V2711,
+    V2712,
+    V2713,
+    V2714,
+    V2715,
+    V2716,
+    V2717,
+    V2718,
+    V2719,
+    V2720,
+    V2721,
+    V2722,
+    V2723,
+    V2724,
+    V2725,
+    V2726,
+    V2727,
+    V2728,
+    V2729,
+    V2730,
+    V2731,
+    V2732,
+    V2733,
+    V2734,
+    V2735,
+    V2736,
+    V2737,
+    V2738,
+    V2739,
+    V2740,
+    V2741,
+    V2742,
+    V2743,
+    V2744,
+    V2745,
+    V2746,
+    V2747,
+    V2748,
+    V2749,
+    V2750,
+    V2751,
+    V2752,
+    V2753,
+    V2754,
+    V2755,
+    V2756,
+    V2757,
+    V2758,
+    V2759,
+    V2760,
+    V2761,
+    V2762,
+    V2763,
+    V2764,
+    V2765,
+    V2766,
+    V2767,
+    V2768,
+    V2769,
+    V2770,
+    V2771,
+    V2772,
+    V2773,
+    V2774,
+    V2775,
+    V2776,
+    V2777,
+    V2778,
+    V2779,
+    V2780,
+    V2781,
+    V2782,
+    V2783,
+    V2784,
+    V2785,
+    V2786,
+    V2787,
+    V2788,
+    V2789,
+    V2790,
+    V2791,
+    V2792,
+    V2793,
+    V2794,
+    V2795,
+    V2796,
+    V2797,
+    V2798,
+    V2799,
+    V2800,
+    V2801,
+    V2802,
+    V2803,
+    V2804,
+    V2805,
+    V2806,
+    V2807,
+    V2808,
+    V2809,
+    V2810,
+    V2811,
+    V2812,
+    V2813,
+    V2814,
+    V2815,
+    V2816,
+    V2817,
+    V2818,
+    V2819,
+    V2820,
+    V2821,
+    V2822,
+    V2823,
+    V2824,
+    V2825,
+    V2826,
+    V2827,
+    V2828,
+    V2829,
+    V2830,
+    V2831,
+    V2832,
+    V2833,
+    V2834,
+    V2835,
+    V2836,
+    V2837,
+    V2838,
+    V2839,
+    V2840,
+    V2841,
+    V2842,
+    V2843,
+    V2844,
+    V2845,
+    V2846,
+    V2847,
+    V2848,
+    V2849,
+    V2850,
+    V2851,
+    V2852,
+    V2853,
+    V2854,
+    V2855,
+    V2856,
+    V2857,
+    V2858,
+    V2859,
+    V2860,
+    V2861,
+    V2862,
+    V2863,
+    V2864,
+    V2865,
+    V2866,
+    V2867,
+    V2868,
+    V2869,
+    V2870,
+    V2871,
+    V2872,
+    V2873,
+    V2874,
+    V2875,
+    V2876,
+    V2877,
+    V2878,
+    V2879,
+    V2880,
+    V2881,
+    V2882,
+    V2883,
+    V2884,
+    V2885,
+    V2886,
+    V2887,
+    V2888,
+    V2889,
+    V2890,
+    V2891,
+    V2892,
+    V2893,
+    V2894,
+    V2895,
+    V2896,
+    V2897,
+    V2898,
+    V2899,
+    V2900,
+    V2901,
+    V2902,
+    V2903,
+    V2904,
+    V2905,
+    V2906,
+    V2907,
+    V2908,
+    V2909,
+    V2910,
+    V2911,
+    V2912,
+    V2913,
+    V2914,
+    V2915,
+    V2916,
+    V2917,
+    V2918,
+    V2919,
+    V2920,
+    V2921,
+    V2922,
+    V2923,
+    V2924,
+    V2925,
+    V2926,
+    V2927,
+    V2928,
+    V2929,
+    V2930,
+    V2931,
+    V2932,
+    V2933,
+    V2934,
+    V2935,
+    V2936,
+    V2937,
+    V2938,
+    V2939,
+    V2940,
+    V2941,
+    V2942,
+    V2943,
+    V2944,
+    V2945,
+    V2946,
+    V2947,
+    V2948,
+    V2949,
+    V2950,
+    V2951,
+    V2952,
+    V2953,
+    V2954,
+    V2955,
+    V2956,
+    V2957,
+    V2958,
+    V2959,
+    V2960,
+    V2961,
+    V2962,
+    V2963,
+    V2964,
+    V2965,
+    V2966,
+    V2967,
+    V2968,
+    V2969,
+    V2970,
+    V2971,
+    V2972,
+    V2973,
+    V2974,
+    V2975,
+    V2976,
+    V2977,
+    V2978,
+    V2979,
+    V2980,
+    V2981,
+    V2982,
+    V2983,
+    V2984,
+    V2985,
+    V2986,
+    V2987,
+    V2988,
+    V2989,
+    V2990,
+    V2991,
+    V2992,
+    V2993,
+    V2994,
+    V2995,
+    V2996,
+    V2997,
+    V2998,
+    V2999,
+    V3000,
+    V3001,
+    V3002,
+    V3003,
+    V3004,
+    V3005,
+    V3006,
+    V3007,
+    V3008,
+    V3009,
+    V3010,
+    V3011,
+    V3012,
+    V3013,
+    V3014,
+    V3015,
+    V3016,
+    V3017,
+    V3018,
+    V3019,
+    V3020,
+    V3021,
+    V3022,
+    V3023,
+    V3024,
+    V3025,
+    V3026,
+    V3027,
+    V3028,
+    V3029,
+    V3030,
+    V3031,
+    V3032,
+    V3033,
+    V3034,
+    V3035,
+    V3036,
+    V3037,
+    V3038,
+    V3039,
+    V3040,
+    V3041,
+    V3042,
+    V3043,
+    V3044,
+    V3045,
+    V3046,
+    V3047,
+    V3048,
+    V3049,
+    V3050,
+    V3051,
+    V3052,
+    V3053,
+    V3054,
+    V3055,
+    V3056,
+    V3057,
+    V3058,
+    V3059,
+    V3060,
+    V3061,
+    V3062,
+    V3063,
+    V3064,
+    V3065,
+    V3066,
+    V3067,
+    V3068,
+    V3069,
+    V3070,
+    V3071,
+    V3072,
+    V3073,
+    V3074,
+    V3075,
+    V3076,
+    V3077,
+    V3078,
+    V3079,
+    V3080,
+    V3081,
+    V3082,
+    V3083,
+    V3084,
+    V3085,
+    V3086,
+    V3087,
+    V3088,
+    V3089,
+    V3090,
+    V3091,
+    V3092,
+    V3093,
+    V3094,
+    V3095,
+    V3096,
+    V3097,
+    V3098,
+    V3099,
+    V3100,
+    V3101,
+    V3102,
+    V3103,
+    V3104,
+    V3105,
+    V3106,
+    V3107,
+    V3108,
+    V3109,
+    V3110,
+    V3111,
+    V3112,
+    V3113,
+    V3114,
+    V3115,
+    V3116,
+    V3117,
+    V3118,
+    V3119,
+    V3120,
+    V3121,
+    V3122,
+    V3123,
+    V3124,
+    V3125,
+    V3126,
+    V3127,
+    V3128,
+    V3129,
+    V3130,
+    V3131,
+    V3132,
+    V3133,
+    V3134,
+    V3135,
+    V3136,
+    V3137,
+    V3138,
+    V3139,
+    V3140,
+    V3141,
+    V3142,
+    V3143,
+    V3144,
+    V3145,
+    V3146,
+    V3147,
+    V3148,
+    V3149,
+    V3150,
+    V3151,
+    V3152,
+    V3153,
+    V3154,
+    V3155,
+    V3156,
+    V3157,
+    V3158,
+    V3159,
+    V3160,
+    V3161,
+    V3162,
+    V3163,
+    V3164,
+    V3165,
+    V3166,
+    V3167,
+    V3168,
+    V3169,
+    V3170,
+    V3171,
+    V3172,
+    V3173,
+    V3174,
+    V3175,
+    V3176,
+    V3177,
+    V3178,
+    V3179,
+    V3180,
+    V3181,
+    V3182,
+    V3183,
+    V3184,
+    V3185,
+    V3186,
+    V3187,
+    V3188,
+    V3189,
+    V3190,
+    V3191,
+    V3192,
+    V3193,
+    V3194,
+    V3195,
+    V3196,
+    V3197,
+    V3198,
+    V3199,
+    V3200,
+    V3201,
+    V3202,
+    V3203,
+    V3204,
+    V3205,
+    V3206,
+    V3207,
+    V3208,
+    V3209,
+    V3210,
+    V3211,
+    V3212,
+    V3213,
+    V3214,
+    V3215,
+    V3216,
+    V3217,
+    V3218,
+    V3219,
+    V3220,
+    V3221,
+    V3222,
+    V3223,
+    V3224,
+    V3225,
+    V3226,
+    V3227,
+    V3228,
+    V3229,
+    V3230,
+    V3231,
+    V3232,
+    V3233,
+    V3234,
+    V3235,
+    V3236,
+    V3237,
+    V3238,
+    V3239,
+    V3240,
+    V3241,
+    V3242,
+    V3243,
+    V3244,
+    V3245,
+    V3246,
+    V3247,
+    V3248,
+    V3249,
+    V3250,
+    V3251,
+    V3252,
+    V3253,
+    V3254,
+    V3255,
+    V3256,
+    V3257,
+    V3258,
+    V3259,
+    V3260,
+    V3261,
+    V3262,
+    V3263,
+    V3264,
+    V3265,
+    V3266,
+    V3267,
+    V3268,
+    V3269,
+    V3270,
+    V3271,
+    V3272,
+    V3273,
+    V3274,
+    V3275,
+    V3276,
+    V3277,
+    V3278,
+    V3279,
+    V3280,
+    V3281,
+    V3282,
+    V3283,
+    V3284,
+    V3285,
+    V3286,
+    V3287,
+    V3288,
+    V3289,
+    V3290,
+    V3291,
+    V3292,
+    V3293,
+    V3294,
+    V3295,
+    V3296,
+    V3297,
+    V3298,
+    V3299,
+    V3300,
+    V3301,
+    V3302,
+    V3303,
+    V3304,
+    V3305,
+    V3306,
+    V3307,
+    V3308,
+    V3309,
+    V3310,
+    V3311,
+    V3312,
+    V3313,
+    V3314,
+    V3315,
+    V3316,
+    V3317,
+    V3318,
+    V3319,
+    V3320,
+    V3321,
+    V3322,
+    V3323,
+    V3324,
+    V3325,
+    V3326,
+    V3327,
+    V3328,
+    V3329,
+    V3330,
+    V3331,
+    V3332,
+    V3333,
+    V3334,
+    V3335,
+    V3336,
+    V3337,
+    V3338,
+    V3339,
+    V3340,
+    V3341,
+    V3342,
+    V3343,
+    V3344,
+    V3345,
+    V3346,
+    V3347,
+    V3348,
+    V3349,
+    V3350,
+    V3351,
+    V3352,
+    V3353,
+    V3354,
+    V3355,
+    V3356,
+    V3357,
+    V3358,
+    V3359,
+    V3360,
+    V3361,
+    V3362,
+    V3363,
+    V3364,
+    V3365,
+    V3366,
+    V3367,
+    V3368,
+    V3369,
+    V3370,
+    V3371,
+    V3372,
+    V3373,
+    V3374,
+    V3375,
+    V3376,
+    V3377,
+    V3378,
+    V3379,
+    V3380,
+    V3381,
+    V3382,
+    V3383,
+    V3384,
+    V3385,
+    V3386,
+    V3387,
+    V3388,
+    V3389,
+    V3390,
+    V3391,
+    V3392,
+    V3393,
+    V3394,
+    V3395,
+    V3396,
+    V3397,
+    V3398,
+    V3399,
+    V3400,
+    V3401,
+    V3402,
+    V3403,
+    V3404,
+    V3405,
+    V3406,
+    V3407,
+    V3408,
+    V3409,
+    V3410,
+    V3411,
+    V3412,
+    V3413,
+    V3414,
+    V3415,
+    V3416,
+    V3417,
+    V3418,
+    V3419,
+    V3420,
+    V3421,
+    V3422,
+    V3423,
+    V3424,
+    V3425,
+    V3426,
+    V3427,
+    V3428,
+    V3429,
+    V3430,
+    V3431,
+    V3432,
+    V3433,
+    V3434,
+    V3435,
+    V3436,
+    V3437,
+    V3438,
+    V3439,
+    V3440,
+    V3441,
+    V3442,
+    V3443,
+    V3444,
+    V3445,
+    V3446,
+    V3447,
+    V3448,
+    V3449,
+    V3450,
+    V3451,
+    V3452,
+    V3453,
+    V3454,
+    V3455,
+    V3456,
+    V3457,
+    V3458,
+    V3459,
+    V3460,
+    V3461,
+    V3462,
+    V3463,
+    V3464,
+    V3465,
+    V3466,
+    V3467,
+    V3468,
+    V3469,
+    V3470,
+    V3471,
+    V3472,
+    V3473,
+    V3474,
+    V3475,
+    V3476,
+    V3477,
+    V3478,
+    V3479,
+    V3480,
+    V3481,
+    V3482,
+    V3483,
+    V3484,
+    V3485,
+    V3486,
+    V3487,
+    V3488,
+    V3489,
+    V3490,
+    V3491,
+    V3492,
+    V3493,
+    V3494,
+    V3495,
+    V3496,
+    V3497,
+    V3498,
+    V3499,
+    V3500,
+    V3501,
+    V3502,
+    V3503,
+    V3504,
+    V3505,
+    V3506,
+    V3507,
+    V3508,
+    V3509,
+    V3510,
+    V3511,
+    V3512,
+    V3513,
+    V3514,
+    V3515,
+    V3516,
+    V3517,
+    V3518,
+    V3519,
+    V3520,
+    V3521,
+    V3522,
+    V3523,
+    V3524,
+    V3525,
+    V3526,
+    V3527,
+    V3528,
+    V3529,
+    V3530,
+    V3531,
+    V3532,
+    V3533,
+    V3534,
+    V3535,
+    V3536,
+    V3537,
+    V3538,
+    V3539,
+    V3540,
+    V3541,
+    V3542,
+    V3543,
+    V3544,
+    V3545,
+    V3546,
+    V3547,
+    V3548,
+    V3549,
+    V3550,
+    V3551,
+    V3552,
+    V3553,
+    V3554,
+    V3555,
+    V3556,
+    V3557,
+    V3558,
+    V3559,
+    V3560,
+    V3561,
+    V3562,
+    V3563,
+    V3564,
+    V3565,
+    V3566,
+    V3567,
+    V3568,
+    V3569,
+    V3570,
+    V3571,
+    V3572,
+    V3573,
+    V3574,
+    V3575,
+    V3576,
+    V3577,
+    V3578,
+    V3579,
+    V3580,
+    V3581,
+    V3582,
+    V3583,
+    V3584,
+    V3585,
+    V3586,
+    V3587,
+    V3588,
+    V3589,
+    V3590,
+    V3591,
+    V3592,
+    V3593,
+    V3594,
+    V3595,
+    V3596,
+    V3597,
+    V3598,
+    V3599,
+    V3600,
+    V3601,
+    V3602,
+    V3603,
+    V3604,
+    V3605,
+    V3606,
+    V3607,
+    V3608,
+    V3609,
+    V3610,
+    V3611,
+    V3612,
+    V3613,
+    V3614,
+    V3615,
+    V3616,
+    V3617,
+    V3618,
+    V3619,
+    V3620,
+    V3621,
+    V3622,
+    V3623,
+    V3624,
+    V3625,
+    V3626,
+    V3627,
+    V3628,
+    V3629,
+    V3630,
+    V3631,
+    V3632,
+    V3633,
+    V3634,
+    V3635,
+    V3636,
+    V3637,
+    V3638,
+    V3639,
+    V3640,
+    V3641,
+    V3642,
+    V3643,
+    V3644,
+    V3645,
+    V3646,
+    V3647,
+    V3648,
+    V3649,
+    V3650,
+    V3651,
+    V3652,
+    V3653,
+    V3654,
+    V3655,
+    V3656,
+    V3657,
+    V3658,
+    V3659,
+    V3660,
+    V3661,
+    V3662,
+    V3663,
+    V3664,
+    V3665,
+    V3666,
+    V3667,
+    V3668,
+    V3669,
+    V3670,
+    V3671,
+    V3672,
+    V3673,
+    V3674,
+    V3675,
+    V3676,
+    V3677,
+    V3678,
+    V3679,
+    V3680,
+    V3681,
+    V3682,
+    V3683,
+    V3684,
+    V3685,
+    V3686,
+    V3687,
+    V3688,
+    V3689,
+    V3690,
+    V3691,
+    V3692,
+    V3693,
+    V3694,
+    V3695,
+    V3696,
+    V3697,
+    V3698,
+    V3699,
+    V3700,
+    V3701,
+    V3702,
+    V3703,
+    V3704,
+    V3705,
+    V3706,
+    V3707,
+    V3708,
+    V3709,
+    V3710,
+    V3711,
+    V3712,
+    V3713,
+    V3714,
+    V3715,
+    V3716,
+    V3717,
+    V3718,
+    V3719,
+    V3720,
+    V3721,
+    V3722,
+    V3723,
+    V3724,
+    V3725,
+    V3726,
+    V3727,
+    V3728,
+    V3729,
+    V3730,
+    V3731,
+    V3732,
+    V3733,
+    V3734,
+    V3735,
+    V3736,
+    V3737,
+    V3738,
+    V3739,
+    V3740,
+    V3741,
+    V3742,
+    V3743,
+    V3744,
+    V3745,
+    V3746,
+    V3747,
+    V3748,
+    V3749,
+    V3750,
+    V3751,
+    V3752,
+    V3753,
+    V3754,
+    V3755,
+    V3756,
+    V3757,
+    V3758,
+    V3759,
+    V3760,
+    V3761,
+    V3762,
+    V3763,
+    V3764,
+    V3765,
+    V3766,
+    V3767,
+    V3768,
+    V3769,
+    V3770,
+    V3771,
+    V3772,
+    V3773,
+    V3774,
+    V3775,
+    V3776,
+    V3777,
+    V3778,
+    V3779,
+    V3780,
+    V3781,
+    V3782,
+    V3783,
+    V3784,
+    V3785,
+    V3786,
+    V3787,
+    V3788,
+    V3789,
+    V3790,
+    V3791,
+    V3792,
+    V3793,
+    V3794,
+    V3795,
+    V3796,
+    V3797,
+    V3798,
+    V3799,
+    V3800,
+    V3801,
+    V3802,
+    V3803,
+    V3804,
+    V3805,
+    V3806,
+    V3807,
+    V3808,
+    V3809,
+    V3810,
+    V3811,
+    V3812,
+    V3813,
+    V3814,
+    V3815,
+    V3816,
+    V3817,
+    V3818,
+    V3819,
+    V3820,
+    V3821,
+    V3822,
+    V3823,
+    V3824,
+    V3825,
+    V3826,
+    V3827,
+    V3828,
+    V3829,
+    V3830,
+    V3831,
+    V3832,
+    V3833,
+    V3834,
+    V3835,
+    V3836,
+    V3837,
+    V3838,
+    V3839,
+    V3840,
+    V3841,
+    V3842,
+    V3843,
+    V3844,
+    V3845,
+    V3846,
+    V3847,
+    V3848,
+    V3849,
+    V3850,
+    V3851,
+    V3852,
+    V3853,
+    V3854,
+    V3855,
+    V3856,
+    V3857,
+    V3858,
+    V3859,
+    V3860,
+    V3861,
+    V3862,
+    V3863,
+    V3864,
+    V3865,
+    V3866,
+    V3867,
+    V3868,
+    V3869,
+    V3870,
+    V3871,
+    V3872,
+    V3873,
+    V3874,
+    V3875,
+    V3876,
+    V3877,
+    V3878,
+    V3879,
+    V3880,
+    V3881,
+    V3882,
+    V3883,
+    V3884,
+    V3885,
+    V3886,
+    V3887,
+    V3888,
+    V3889,
+    V3890,
+    V3891,
+    V3892,
+    V3893,
+    V3894,
+    V3895,
+    V3896,
+    V3897,
+    V3898,
+    V3899,
+    V3900,
+    V3901,
+    V3902,
+    V3903,
+    V3904,
+    V3905,
+    V3906,
+    V3907,
+    V3908,
+    V3909,
+    V3910,
+    V3911,
+    V3912,
+    V3913,
+    V3914,
+    V3915,
+    V3916,
+    V3917,
+    V3918,
+    V3919,
+    V3920,
+    V3921,
+    V3922,
+    V3923,
+    V3924,
+    V3925,
+    V3926,
+    V3927,
+    V3928,
+    V3929,
+    V3930,
+    V3931,
+    V3932,
+    V3933,
+    V3934,
+    V3935,
+    V3936,
+    V3937,
+    V3938,
+    V3939,
+    V3940,
+    V3941,
+    V3942,
+    V3943,
+    V3944,
+    V3945,
+    V3946,
+    V3947,
+    V3948,
+    V3949,
+    V3950,
+    V3951,
+    V3952,
+    V3953,
+    V3954,
+    V3955,
+    V3956,
+    V3957,
+    V3958,
+    V3959,
+    V3960,
+    V3961,
+    V3962,
+    V3963,
+    V3964,
+    V3965,
+    V3966,
+    V3967,
+    V3968,
+    V3969,
+    V3970,
+    V3971,
+    V3972,
+    V3973,
+    V3974,
+    V3975,
+    V3976,
+    V3977,
+    V3978,
+    V3979,
+    V3980,
+    V3981,
+    V3982,
+    V3983,
+    V3984,
+    V3985,
+    V3986,
+    V3987,
+    V3988,
+    V3989,
+    V3990,
+    V3991,
+    V3992,
+    V3993,
+    V3994,
+    V3995,
+    V3996,
+    V3997,
+    V3998,
+    V3999,
+    V4000,
+    V4001,
+    V4002,
+    V4003,
+    V4004,
+    V4005,
+    V4006,
+    V4007,
+    V4008,
+    V4009,
+    V4010,
+    V4011,
+    V4012,
+    V4013,
+    V4014,
+    V4015,
+    V4016,
+    V4017,
+    V4018,
+    V4019,
+    V4020,
+    V4021,
+    V4022,
+    V4023,
+    V4024,
+    V4025,
+    V4026,
+    V4027,
+    V4028,
+    V4029,
+    V4030,
+    V4031,
+    V4032,
+    V4033,
+    V4034,
+    V4035,
+    V4036,
+    V4037,
+    V4038,
+    V4039,
+    V4040,
+    V4041,
+    V4042,
+    V4043,
+    V4044,
+    V4045,
+    V4046,
+    V4047,
+    V4048,
+    V4049,
+    V4050,
+    V4051,
+    V4052,
+    V4053,
+    V4054,
+    V4055,
+    V4056,
+    V4057,
+    V4058,
+    V4059,
+    V4060,
+    V4061,
+    V4062,
+    V4063,
+    V4064,
+    V4065,
+    V4066,
+    V4067,
+    V4068,
+    V4069,
+    V4070,
+    V4071,
+    V4072,
+    V4073,
+    V4074,
+    V4075,
+    V4076,
+    V4077,
+    V4078,
+    V4079,
+    V4080,
+    V4081,
+    V4082,
+    V4083,
+    V4084,
+    V4085,
+    V4086,
+    V4087,
+    V4088,
+    V4089,
+    V4090,
+    V4091,
+    V4092,
+    V4093,
+    V4094,
+    V4095,
+}
+
+pub fn classify(h: &Huge) -> u32 {
+    match h {
+        Huge::V0 => 0,
+        Huge::V1 => 1,
+        Huge::V2 => 2,
+        Huge::V3 => 3,
+        Huge::V4 => 4,
+        Huge::V5 => 5,
+        Huge::V6 => 6,
+        Huge::V7 => 7,
+        Huge::V8 => 9,
+        Huge::V9 => 8,
+        Huge::V10 => 11,
+        Huge::V11 => 10,
+        Huge::V12 => 13,
+        Huge::V13 => 12,
+        Huge::V14 => 15,
+        Huge::V15 => 14,
+        Huge::V16 => 18,
+        Huge::V17 => 19,
+        Huge::V18 => 16,
+        Huge::V19 => 17,
+        Huge::V20 => 22,
+        Huge::V21 => 23,
+        Huge::V22 => 20,
+        Huge::V23 => 21,
+        Huge::V24 => 27,
+        Huge::V25 => 26,
+        Huge::V26 => 25,
+        Huge::V27 => 24,
+        Huge::V28 => 31,
+        Huge::V29 => 30,
+        Huge::V30 => 29,
+        Huge::V31 => 28,
+        Huge::V32 => 36,
+        Huge::V33 => 37,
+        Huge::V34 => 38,
+        Huge::V35 => 39,
+        Huge::V36 => 32,
+        Huge::V37 => 33,
+        Huge::V38 => 34,
+        Huge::V39 => 35,
+        Huge::V40 => 45,
+        Huge::V41 => 44,
+        Huge::V42 => 47,
+        Huge::V43 => 46,
+        Huge::V44 => 41,
+        Huge::V45 => 40,
+        Huge::V46 => 43,
+        Huge::V47 => 42,
+        Huge::V48 => 54,
+        Huge::V49 => 55,
+        Huge::V50 => 52,
+        Huge::V51 => 53,
+        Huge::V52 => 50,
+        Huge::V53 => 51,
+        Huge::V54 => 48,
+        Huge::V55 => 49,
+        Huge::V56 => 63,
+        Huge::V57 => 62,
+        Huge::V58 => 61,
+        Huge::V59 => 60,
+        Huge::V60 => 59,
+        Huge::V61 => 58,
+        Huge::V62 => 57,
+        Huge::V63 => 56,
+        Huge::V64 => 72,
+        Huge::V65 => 73,
+        Huge::V66 => 74,
+        Huge::V67 => 75,
+        Huge::V68 => 76,
+        Huge::V69 => 77,
+        Huge::V70 => 78,
+        Huge::V71 => 79,
+        Huge::V72 => 65,
+        Huge::V73 => 64,
+        Huge::V74 => 67,
+        Huge::V75 => 66,
+        Huge::V76 => 69,
+        Huge::V77 => 68,
+        Huge::V78 => 71,
+        Huge::V79 => 70,
+        Huge::V80 => 90,
+        Huge::V81 => 91,
+        Huge::V82 => 88,
+        Huge::V83 => 89,
+        Huge::V84 => 94,
+        Huge::V85 => 95,
+        Huge::V86 => 92,
+        Huge::V87 => 93,
+        Huge::V88 => 83,
+        Huge::V89 => 82,
+        Huge::V90 => 81,
+        Huge::V91 => 80,
+        Huge::V92 => 87,
+        Huge::V93 => 86,
+        Huge::V94 => 85,
+        Huge::V95 => 84,
+        Huge::V96 => 108,
+        Huge::V97 => 109,
+        Huge::V98 => 110,
+        Huge::V99 => 111,
+        Huge::V100 => 104,
+        Huge::V101 => 105,
+        Huge::V102 => 106,
+        Huge::V103 => 107,
+        Huge::V104 => 101,
+        Huge::V105 => 100,
+        Huge::V106 => 103,
+        Huge::V107 => 102,
+        Huge::V108 => 97,
+        Huge::V109 => 96,
+        Huge::V110 => 99,
+        Huge::V111 => 98,
+        Huge::V112 => 126,
+        Huge::V113 => 127,
+        Huge::V114 => 124,
+        Huge::V115 => 125,
+        Huge::V116 => 122,
+        Huge::V117 => 123,
+        Huge::V118 => 120,
+        Huge::V119 => 121,
+        Huge::V120 => 119,
+        Huge::V121 => 118,
+        Huge::V122 => 117,
+        Huge::V123 => 116,
+        Huge::V124 => 115,
+        Huge::V125 => 114,
+        Huge::V126 => 113,
+        Huge::V127 => 112,
+        Huge::V128 => 144,
+        Huge::V129 => 145,
+        Huge::V130 => 146,
+        Huge::V131 => 147,
+        Huge::V132 => 148,
+        Huge::V133 => 149,
+        Huge::V134 => 150,
+        Huge::V135 => 151,
+        Huge::V136 => 153,
+        Huge::V137 => 152,
+        Huge::V138 => 155,
+        Huge::V139 => 154,
+        Huge::V140 => 157,
+        Huge::V141 => 156,
+        Huge::V142 => 159,
+        Huge::V143 => 158,
+        Huge::V144 => 130,
+        Huge::V145 => 131,
+        Huge::V146 => 128,
+        Huge::V147 => 129,
+        Huge::V148 => 134,
+        Huge::V149 => 135,
+        Huge::V150 => 132,
+        Huge::V151 => 133,
+        Huge::V152 => 139,
+        Huge::V153 => 138,
+        Huge::V154 => 137,
+        Huge::V155 => 136,
+        Huge::V156 => 143,
+        Huge::V157 => 142,
+        Huge::V158 => 141,
+        Huge::V159 => 140,
+        Huge::V160 => 180,
+        Huge::V161 => 181,
+        Huge::V162 => 182,
+        Huge::V163 => 183,
+        Huge::V164 => 176,
+        Huge::V165 => 177,
+        Huge::V166 => 178,
+        Huge::V167 => 179,
+        Huge::V168 => 189,
+        Huge::V169 => 188,
+        Huge::V170 => 191,
+        Huge::V171 => 190,
+        Huge::V172 => 185,
+        Huge::V173 => 184,
+        Huge::V174 => 187,
+        Huge::V175 => 186,
+        Huge::V176 => 166,
+        Huge::V177 => 167,
+        Huge::V178 => 164,
+        Huge::V179 => 165,
+        Huge::V180 => 162,
+        Huge::V181 => 163,
+        Huge::V182 => 160,
+        Huge::V183 => 161,
+        Huge::V184 => 175,
+        Huge::V185 => 174,
+        Huge::V186 => 173,
+        Huge::V187 => 172,
+        Huge::V188 => 171,
+        Huge::V189 => 170,
+        Huge::V190 => 169,
+        Huge::V191 => 168,
+        Huge::V192 => 216,
+        Huge::V193 => 217,
+        Huge::V194 => 218,
+        Huge::V195 => 219,
+        Huge::V196 => 220,
+        Huge::V197 => 221,
+        Huge::V198 => 222,
+        Huge::V199 => 223,
+        Huge::V200 => 209,
+        Huge::V201 => 208,
+        Huge::V202 => 211,
+        Huge::V203 => 210,
+        Huge::V204 => 213,
+        Huge::V205 => 212,
+        Huge::V206 => 215,
+        Huge::V207 => 214,
+        Huge::V208 => 202,
+        Huge::V209 => 203,
+        Huge::V210 => 200,
+        Huge::V211 => 201,
+        Huge::V212 => 206,
+        Huge::V213 => 207,
+        Huge::V214 => 204,
+        Huge::V215 => 205,
+        Huge::V216 => 195,
+        Huge::V217 => 194,
+        Huge::V218 => 193,
+        Huge::V219 => 192,
+        Huge::V220 => 199,
+        Huge::V221 => 198,
+        Huge::V222 => 197,
+        Huge::V223 => 196,
+        Huge::V224 => 252,
+        Huge::V225 => 253,
+        Huge::V226 => 254,
+        Huge::V227 => 255,
+        Huge::V228 => 248,
+        Huge::V229 => 249,
+        Huge::V230 => 250,
+        Huge::V231 => 251,
+        Huge::V232 => 245,
+        Huge::V233 => 244,
+        Huge::V234 => 247,
+        Huge::V235 => 246,
+        Huge::V236 => 241,
+        Huge::V237 => 240,
+        Huge::V238 => 243,
+        Huge::V239 => 242,
+        Huge::V240 => 238,
+        Huge::V241 => 239,
+        Huge::V242 => 236,
+        Huge::V243 => 237,
+        Huge::V244 => 234,
+        Huge::V245 => 235,
+        Huge::V246 => 232,
+        Huge::V247 => 233,
+        Huge::V248 => 231,
+        Huge::V249 => 230,
+        Huge::V250 => 229,
+        Huge::V251 => 228,
+        Huge::V252 => 227,
+        Huge::V253 => 226,
+        Huge::V254 => 225,
+        Huge::V255 => 224,
+        Huge::V256 => 288,
+        Huge::V257 => 289,
+        Huge::V258 => 290,
+        Huge::V259 => 291,
+        Huge::V260 => 292,
+        Huge::V261 => 293,
+        Huge::V262 => 294,
+        Huge::V263 => 295,
+        Huge::V264 => 297,
+        Huge::V265 => 296,
+        Huge::V266 => 299,
+        Huge::V267 => 298,
+        Huge::V268 => 301,
+        Huge::V269 => 300,
+        Huge::V270 => 303,
+        Huge::V271 => 302,
+        Huge::V272 => 306,
+        Huge::V273 => 307,
+        Huge::V274 => 304,
+        Huge::V275 => 305,
+        Huge::V276 => 310,
+        Huge::V277 => 311,
+        Huge::V278 => 308,
+        Huge::V279 => 309,
+        Huge::V280 => 315,
+        Huge::V281 => 314,
+        Huge::V282 => 313,
+        Huge::V283 => 312,
+        Huge::V284 => 319,
+        Huge::V285 => 318,
+        Huge::V286 => 317,
+        Huge::V287 => 316,
+        Huge::V288 => 260,
+        Huge::V289 => 261,
+        Huge::V290 => 262,
+        Huge::V291 => 263,
+        Huge::V292 => 256,
+        Huge::V293 => 257,
+        Huge::V294 => 258,
+        Huge::V295 => 259,
+        Huge::V296 => 269,
+        Huge::V297 => 268,
+        Huge::V298 => 271,
+        Huge::V299 => 270,
+        Huge::V300 => 265,
+        Huge::V301 => 264,
+        Huge::V302 => 267,
+        Huge::V303 => 266,
+        Huge::V304 => 278,
+        Huge::V305 => 279,
+        Huge::V306 => 276,
+        Huge::V307 => 277,
+        Huge::V308 => 274,
+        Huge::V309 => 275,
+        Huge::V310 => 272,
+        Huge::V311 => 273,
+        Huge::V312 => 287,
+        Huge::V313 => 286,
+        Huge::V314 => 285,
+        Huge::V315 => 284,
+        Huge::V316 => 283,
+        Huge::V317 => 282,
+        Huge::V318 => 281,
+        Huge::V319 => 280,
+        Huge::V320 => 360,
+        Huge::V321 => 361,
+        Huge::V322 => 362,
+        Huge::V323 => 363,
+        Huge::V324 => 364,
+        Huge::V325 => 365,
+        Huge::V326 => 366,
+        Huge::V327 => 367,
+        Huge::V328 => 353,
+        Huge::V329 => 352,
+        Huge::V330 => 355,
+        Huge::V331 => 354,
+        Huge::V332 => 357,
+        Huge::V333 => 356,
+        Huge::V334 => 359,
+        Huge::V335 => 358,
+        Huge::V336 => 378,
+        Huge::V337 => 379,
+        Huge::V338 => 376,
+        Huge::V339 => 377,
+        Huge::V340 => 382,
+        Huge::V341 => 383,
+        Huge::V342 => 380,
+        Huge::V343 => 381,
+        Huge::V344 => 371,
+        Huge::V345 => 370,
+        Huge::V346 => 369,
+        Huge::V347 => 368,
+        Huge::V348 => 375,
+        Huge::V349 => 374,
+        Huge::V350 => 373,
+        Huge::V351 => 372,
+        Huge::V352 => 332,
+        Huge::V353 => 333,
+        Huge::V354 => 334,
+        Huge::V355 => 335,
+        Huge::V356 => 328,
+        Huge::V357 => 329,
+        Huge::V358 => 330,
+        Huge::V359 => 331,
+        Huge::V360 => 325,
+        Huge::V361 => 324,
+        Huge::V362 => 327,
+        Huge::V363 => 326,
+        Huge::V364 => 321,
+        Huge::V365 => 320,
+        Huge::V366 => 323,
+        Huge::V367 => 322,
+        Huge::V368 => 350,
+        Huge::V369 => 351,
+        Huge::V370 => 348,
+        Huge::V371 => 349,
+        Huge::V372 => 346,
+        Huge::V373 => 347,
+        Huge::V374 => 344,
+        Huge::V375 => 345,
+        Huge::V376 => 343,
+        Huge::V377 => 342,
+        Huge::V378 => 341,
+        Huge::V379 => 340,
+        Huge::V380 => 339,
+        Huge::V381 => 338,
+        Huge::V382 => 337,
+        Huge::V383 => 336,
+        Huge::V384 => 432,
+        Huge::V385 => 433,
+        Huge::V386 => 434,
+        Huge::V387 => 435,
+        Huge::V388 => 436,
+        Huge::V389 => 437,
+        Huge::V390 => 438,
+        Huge::V391 => 439,
+        Huge::V392 => 441,
+        Huge::V393 => 440,
+        Huge::V394 => 443,
+        Huge::V395 => 442,
+        Huge::V396 => 445,
+        Huge::V397 => 444,
+        Huge::V398 => 447,
+        Huge::V399 => 446,
+        Huge::V400 => 418,
+        Huge::V401 => 419,
+        Huge::V402 => 416,
+        Huge::V403 => 417,
+        Huge::V404 => 422,
+        Huge::V405 => 423,
+        Huge::V406 => 420,
+        Huge::V407 => 421,
+        Huge::V408 => 427,
+        Huge::V409 => 426,
+        Huge::V410 => 425,
+        Huge::V411 => 424,
+        Huge::V412 => 431,
+        Huge::V413 => 430,
+        Huge::V414 => 429,
+        Huge::V415 => 428,
+        Huge::V416 => 404,
+        Huge::V417 => 405,
+        Huge::V418 => 406,
+        Huge::V419 => 407,
+        Huge::V420 => 400,
+        Huge::V421 => 401,
+        Huge::V422 => 402,
+        Huge::V423 => 403,
+        Huge::V424 => 413,
+        Huge::V425 => 412,
+        Huge::V426 => 415,
+        Huge::V427 => 414,
+        Huge::V428 => 409,
+        Huge::V429 => 408,
+        Huge::V430 => 411,
+        Huge::V431 => 410,
+        Huge::V432 => 390,
+        Huge::V433 => 391,
+        Huge::V434 => 388,
+        Huge::V435 => 389,
+        Huge::V436 => 386,
+        Huge::V437 => 387,
+        Huge::V438 => 384,
+        Huge::V439 => 385,
+        Huge::V440 => 399,
+        Huge::V441 => 398,
+        Huge::V442 => 397,
+        Huge::V443 => 396,
+        Huge::V444 => 395,
+        Huge::V445 => 394,
+        Huge::V446 => 393,
+        Huge::V447 => 392,
+        Huge::V448 => 504,
+        Huge::V449 => 505,
+        Huge::V450 => 506,
+        Huge::V451 => 507,
+        Huge::V452 => 508,
+        Huge::V453 => 509,
+        Huge::V454 => 510,
+        Huge::V455 => 511,
+        Huge::V456 => 497,
+        Huge::V457 => 496,
+        Huge::V458 => 499,
+        Huge::V459 => 498,
+        Huge::V460 => 501,
+        Huge::V461 => 500,
+        Huge::V462 => 503,
+        Huge::V463 => 502,
+        Huge::V464 => 490,
+        Huge::V465 => 491,
+        Huge::V466 => 488,
+        Huge::V467 => 489,
+        Huge::V468 => 494,
+        Huge::V469 => 495,
+        Huge::V470 => 492,
+        Huge::V471 => 493,
+        Huge::V472 => 483,
+        Huge::V473 => 482,
+        Huge::V474 => 481,
+        Huge::V475 => 480,
+        Huge::V476 => 487,
+        Huge::V477 => 486,
+        Huge::V478 => 485,
+        Huge::V479 => 484,
+        Huge::V480 => 476,
+        Huge::V481 => 477,
+        Huge::V482 => 478,
+        Huge::V483 => 479,
+        Huge::V484 => 472,
+        Huge::V485 => 473,
+        Huge::V486 => 474,
+        Huge::V487 => 475,
+        Huge::V488 => 469,
+        Huge::V489 => 468,
+        Huge::V490 => 471,
+        Huge::V491 => 470,
+        Huge::V492 => 465,
+        Huge::V493 => 464,
+        Huge::V494 => 467,
+        Huge::V495 => 466,
+        Huge::V496 => 462,
+        Huge::V497 => 463,
+        Huge::V498 => 460,
+        Huge::V499 => 461,
+        Huge::V500 => 458,
+        Huge::V501 => 459,
+        Huge::V502 => 456,
+        Huge::V503 => 457,
+        Huge::V504 => 455,
+        Huge::V505 => 454,
+        Huge::V506 => 453,
+        Huge::V507 => 452,
+        Huge::V508 => 451,
+        Huge::V509 => 450,
+        Huge::V510 => 449,
+        Huge::V511 => 448,
+        Huge::V512 => 576,
+        Huge::V513 => 577,
+        Huge::V514 => 578,
+        Huge::V515 => 579,
+        Huge::V516 => 580,
+        Huge::V517 => 581,
+        Huge::V518 => 582,
+        Huge::V519 => 583,
+        Huge::V520 => 585,
+        Huge::V521 => 584,
+        Huge::V522 => 587,
+        Huge::V523 => 586,
+        Huge::V524 => 589,
+        Huge::V525 => 588,
+        Huge::V526 => 591,
+        Huge::V527 => 590,
+        Huge::V528 => 594,
+        Huge::V529 => 595,
+        Huge::V530 => 592,
+        Huge::V531 => 593,
+        Huge::V532 => 598,
+        Huge::V533 => 599,
+        Huge::V534 => 596,
+        Huge::V535 => 597,
+        Huge::V536 => 603,
+        Huge::V537 => 602,
+        Huge::V538 => 601,
+        Huge::V539 => 600,
+        Huge::V540 => 607,
+        Huge::V541 => 606,
+        Huge::V542 => 605,
+        Huge::V543 => 604,
+        Huge::V544 => 612,
+        Huge::V545 => 613,
+        Huge::V546 => 614,
+        Huge::V547 => 615,
+        Huge::V548 => 608,
+        Huge::V549 => 609,
+        Huge::V550 => 610,
+        Huge::V551 => 611,
+        Huge::V552 => 621,
+        Huge::V553 => 620,
+        Huge::V554 => 623,
+        Huge::V555 => 622,
+        Huge::V556 => 617,
+        Huge::V557 => 616,
+        Huge::V558 => 619,
+        Huge::V559 => 618,
+        Huge::V560 => 630,
+        Huge::V561 => 631,
+        Huge::V562 => 628,
+        Huge::V563 => 629,
+        Huge::V564 => 626,
+        Huge::V565 => 627,
+        Huge::V566 => 624,
+        Huge::V567 => 625,
+        Huge::V568 => 639,
+        Huge::V569 => 638,
+        Huge::V570 => 637,
+        Huge::V571 => 636,
+        Huge::V572 => 635,
+        Huge::V573 => 634,
+        Huge::V574 => 633,
+        Huge::V575 => 632,
+        Huge::V576 => 520,
+        Huge::V577 => 521,
+        Huge::V578 => 522,
+        Huge::V579 => 523,
+        Huge::V580 => 524,
+        Huge::V581 => 525,
+        Huge::V582 => 526,
+        Huge::V583 => 527,
+        Huge::V584 => 513,
+        Huge::V585 => 512,
+        Huge::V586 => 515,
+        Huge::V587 => 514,
+        Huge::V588 => 517,
+        Huge::V589 => 516,
+        Huge::V590 => 519,
+        Huge::V591 => 518,
+        Huge::V592 => 538,
+        Huge::V593 => 539,
+        Huge::V594 => 536,
+        Huge::V595 => 537,
+        Huge::V596 => 542,
+        Huge::V597 => 543,
+        Huge::V598 => 540,
+        Huge::V599 => 541,
+        Huge::V600 => 531,
+        Huge::V601 => 530,
+        Huge::V602 => 529,
+        Huge::V603 => 528,
+        Huge::V604 => 535,
+        Huge::V605 => 534,
+        Huge::V606 => 533,
+        Huge::V607 => 532,
+        Huge::V608 => 556,
+        Huge::V609 => 557,
+        Huge::V610 => 558,
+        Huge::V611 => 559,
+        Huge::V612 => 552,
+        Huge::V613 => 553,
+        Huge::V614 => 554,
+        Huge::V615 => 555,
+        Huge::V616 => 549,
+        Huge::V617 => 548,
+        Huge::V618 => 551,
+        Huge::V619 => 550,
+        Huge::V620 => 545,
+        Huge::V621 => 544,
+        Huge::V622 => 547,
+        Huge::V623 => 546,
+        Huge::V624 => 574,
+        Huge::V625 => 575,
+        Huge::V626 => 572,
+        Huge::V627 => 573,
+        Huge::V628 => 570,
+        Huge::V629 => 571,
+        Huge::V630 => 568,
+        Huge::V631 => 569,
+        Huge::V632 => 567,
+        Huge::V633 => 566,
+        Huge::V634 => 565,
+        Huge::V635 => 564,
+        Huge::V636 => 563,
+        Huge::V637 => 562,
+        Huge::V638 => 561,
+        Huge::V639 => 560,
+        Huge::V640 => 720,
+        Huge::V641 => 721,
+        Huge::V642 => 722,
+        Huge::V643 => 723,
+        Huge::V644 => 724,
+        Huge::V645 => 725,
+        Huge::V646 => 726,
+        Huge::V647 => 727,
+        Huge::V648 => 729,
+        Huge::V649 => 728,
+        Huge::V650 => 731,
+        Huge::V651 => 730,
+        Huge::V652 => 733,
+        Huge::V653 => 732,
+        Huge::V654 => 735,
+        Huge::V655 => 734,
+        Huge::V656 => 706,
+        Huge::V657 => 707,
+        Huge::V658 => 704,
+        Huge::V659 => 705,
+        Huge::V660 => 710,
+        Huge::V661 => 711,
+        Huge::V662 => 708,
+        Huge::V663 => 709,
+        Huge::V664 => 715,
+        Huge::V665 => 714,
+        Huge::V666 => 713,
+        Huge::V667 => 712,
+        Huge::V668 => 719,
+        Huge::V669 => 718,
+        Huge::V670 => 717,
+        Huge::V671 => 716,
+        Huge::V672 => 756,
+        Huge::V673 => 757,
+        Huge::V674 => 758,
+        Huge::V675 => 759,
+        Huge::V676 => 752,
+        Huge::V677 => 753,
+        Huge::V678 => 754,
+        Huge::V679 => 755,
+        Huge::V680 => 765,
+        Huge::V681 => 764,
+        Huge::V682 => 767,
+        Huge::V683 => 766,
+        Huge::V684 => 761,
+        Huge::V685 => 760,
+        Huge::V686 => 763,
+        Huge::V687 => 762,
+        Huge::V688 => 742,
+        Huge::V689 => 743,
+        Huge::V690 => 740,
+        Huge::V691 => 741,
+        Huge::V692 => 738,
+        Huge::V693 => 739,
+        Huge::V694 => 736,
+        Huge::V695 => 737,
+        Huge::V696 => 751,
+        Huge::V697 => 750,
+        Huge::V698 => 749,
+        Huge::V699 => 748,
+        Huge::V700 => 747,
+        Huge::V701 => 746,
+        Huge::V702 => 745,
+        Huge::V703 => 744,
+        Huge::V704 => 664,
+        Huge::V705 => 665,
+        Huge::V706 => 666,
+        Huge::V707 => 667,
+        Huge::V708 => 668,
+        Huge::V709 => 669,
+        Huge::V710 => 670,
+        Huge::V711 => 671,
+        Huge::V712 => 657,
+        Huge::V713 => 656,
+        Huge::V714 => 659,
+        Huge::V715 => 658,
+        Huge::V716 => 661,
+        Huge::V717 => 660,
+        Huge::V718 => 663,
+        Huge::V719 => 662,
+        Huge::V720 => 650,
+        Huge::V721 => 651,
+        Huge::V722 => 648,
+        Huge::V723 => 649,
+        Huge::V724 => 654,
+        Huge::V725 => 655,
+        Huge::V726 => 652,
+        Huge::V727 => 653,
+        Huge::V728 => 643,
+        Huge::V729 => 642,
+        Huge::V730 => 641,
+        Huge::V731 => 640,
+        Huge::V732 => 647,
+        Huge::V733 => 646,
+        Huge::V734 => 645,
+        Huge::V735 => 644,
+        Huge::V736 => 700,
+        Huge::V737 => 701,
+        Huge::V738 => 702,
+        Huge::V739 => 703,
+        Huge::V740 => 696,
+        Huge::V741 => 697,
+        Huge::V742 => 698,
+        Huge::V743 => 699,
+        Huge::V744 => 693,
+        Huge::V745 => 692,
+        Huge::V746 => 695,
+        Huge::V747 => 694,
+        Huge::V748 => 689,
+        Huge::V749 => 688,
+        Huge::V750 => 691,
+        Huge::V751 => 690,
+        Huge::V752 => 686,
+        Huge::V753 => 687,
+        Huge::V754 => 684,
+        Huge::V755 => 685,
+        Huge::V756 => 682,
+        Huge::V757 => 683,
+        Huge::V758 => 680,
+        Huge::V759 => 681,
+        Huge::V760 => 679,
+        Huge::V761 => 678,
+        Huge::V762 => 677,
+        Huge::V763 => 676,
+        Huge::V764 => 675,
+        Huge::V765 => 674,
+        Huge::V766 => 673,
+        Huge::V767 => 672,
+        Huge::V768 => 864,
+        Huge::V769 => 865,
+        Huge::V770 => 866,
+        Huge::V771 => 867,
+        Huge::V772 => 868,
+        Huge::V773 => 869,
+        Huge::V774 => 870,
+        Huge::V775 => 871,
+        Huge::V776 => 873,
+        Huge::V777 => 872,
+        Huge::V778 => 875,
+        Huge::V779 => 874,
+        Huge::V780 => 877,
+        Huge::V781 => 876,
+        Huge::V782 => 879,
+        Huge::V783 => 878,
+        Huge::V784 => 882,
+        Huge::V785 => 883,
+        Huge::V786 => 880,
+        Huge::V787 => 881,
+        Huge::V788 => 886,
+        Huge::V789 => 887,
+        Huge::V790 => 884,
+        Huge::V791 => 885,
+        Huge::V792 => 891,
+        Huge::V793 => 890,
+        Huge::V794 => 889,
+        Huge::V795 => 888,
+        Huge::V796 => 895,
+        Huge::V797 => 894,
+        Huge::V798 => 893,
+        Huge::V799 => 892,
+        Huge::V800 => 836,
+        Huge::V801 => 837,
+        Huge::V802 => 838,
+        Huge::V803 => 839,
+        Huge::V804 => 832,
+        Huge::V805 => 833,
+        Huge::V806 => 834,
+        Huge::V807 => 835,
+        Huge::V808 => 845,
+        Huge::V809 => 844,
+        Huge::V810 => 847,
+        Huge::V811 => 846,
+        Huge::V812 => 841,
+        Huge::V813 => 840,
+        Huge::V814 => 843,
+        Huge::V815 => 842,
+        Huge::V816 => 854,
+        Huge::V817 => 855,
+        Huge::V818 => 852,
+        Huge::V819 => 853,
+        Huge::V820 => 850,
+        Huge::V821 => 851,
+        Huge::V822 => 848,
+        Huge::V823 => 849,
+        Huge::V824 => 863,
+        Huge::V825 => 862,
+        Huge::V826 => 861,
+        Huge::V827 => 860,
+        Huge::V828 => 859,
+        Huge::V829 => 858,
+        Huge::V830 => 857,
+        Huge::V831 => 856,
+        Huge::V832 => 808,
+        Huge::V833 => 809,
+        Huge::V834 => 810,
+        Huge::V835 => 811,
+        Huge::V836 => 812,
+        Huge::V837 => 813,
+        Huge::V838 => 814,
+        Huge::V839 => 815,
+        Huge::V840 => 801,
+        Huge::V841 => 800,
+        Huge::V842 => 803,
+        Huge::V843 => 802,
+        Huge::V844 => 805,
+        Huge::V845 => 804,
+        Huge::V846 => 807,
+        Huge::V847 => 806,
+        Huge::V848 => 826,
+        Huge::V849 => 827,
+        Huge::V850 => 824,
+        Huge::V851 => 825,
+        Huge::V852 => 830,
+        Huge::V853 => 831,
+        Huge::V854 => 828,
+        Huge::V855 => 829,
+        Huge::V856 => 819,
+        Huge::V857 => 818,
+        Huge::V858 => 817,
+        Huge::V859 => 816,
+        Huge::V860 => 823,
+        Huge::V861 => 822,
+        Huge::V862 => 821,
+        Huge::V863 => 820,
+        Huge::V864 => 780,
+        Huge::V865 => 781,
+        Huge::V866 => 782,
+        Huge::V867 => 783,
+        Huge::V868 => 776,
+        Huge::V869 => 777,
+        Huge::V870 => 778,
+        Huge::V871 => 779,
+        Huge::V872 => 773,
+        Huge::V873 => 772,
+        Huge::V874 => 775,
+        Huge::V875 => 774,
+        Huge::V876 => 769,
+        Huge::V877 => 768,
+        Huge::V878 => 771,
+        Huge::V879 => 770,
+        Huge::V880 => 798,
+        Huge::V881 => 799,
+        Huge::V882 => 796,
+        Huge::V883 => 797,
+        Huge::V884 => 794,
+        Huge::V885 => 795,
+        Huge::V886 => 792,
+        Huge::V887 => 793,
+        Huge::V888 => 791,
+        Huge::V889 => 790,
+        Huge::V890 => 789,
+        Huge::V891 => 788,
+        Huge::V892 => 787,
+        Huge::V893 => 786,
+        Huge::V894 => 785,
+        Huge::V895 => 784,
+        Huge::V896 => 1008,
+        Huge::V897 => 1009,
+        Huge::V898 => 1010,
+        Huge::V899 => 1011,
+        Huge::V900 => 1012,
+        Huge::V901 => 1013,
+        Huge::V902 => 1014,
+        Huge::V903 => 1015,
+        Huge::V904 => 1017,
+        Huge::V905 => 1016,
+        Huge::V906 => 1019,
+        Huge::V907 => 1018,
+        Huge::V908 => 1021,
+        Huge::V909 => 1020,
+        Huge::V910 => 1023,
+        Huge::V911 => 1022,
+        Huge::V912 => 994,
+        Huge::V913 => 995,
+        Huge::V914 => 992,
+        Huge::V915 => 993,
+        Huge::V916 => 998,
+        Huge::V917 => 999,
+        Huge::V918 => 996,
+        Huge::V919 => 997,
+        Huge::V920 => 1003,
+        Huge::V921 => 1002,
+        Huge::V922 => 1001,
+        Huge::V923 => 1000,
+        Huge::V924 => 1007,
+        Huge::V925 => 1006,
+        Huge::V926 => 1005,
+        Huge::V927 => 1004,
+        Huge::V928 => 980,
+        Huge::V929 => 981,
+        Huge::V930 => 982,
+        Huge::V931 => 983,
+        Huge::V932 => 976,
+        Huge::V933 => 977,
+        Huge::V934 => 978,
+        Huge::V935 => 979,
+        Huge::V936 => 989,
+        Huge::V937 => 988,
+        Huge::V938 => 991,
+        Huge::V939 => 990,
+        Huge::V940 => 985,
+        Huge::V941 => 984,
+        Huge::V942 => 987,
+        Huge::V943 => 986,
+        Huge::V944 => 966,
+        Huge::V945 => 967,
+        Huge::V946 => 964,
+        Huge::V947 => 965,
+        Huge::V948 => 962,
+        Huge::V949 => 963,
+        Huge::V950 => 960,
+        Huge::V951 => 961,
+        Huge::V952 => 975,
+        Huge::V953 => 974,
+        Huge::V954 => 973,
+        Huge::V955 => 972,
+        Huge::V956 => 971,
+        Huge::V957 => 970,
+        Huge::V958 => 969,
+        Huge::V959 => 968,
+        Huge::V960 => 952,
+        Huge::V961 => 953,
+        Huge::V962 => 954,
+        Huge::V963 => 955,
+        Huge::V964 => 956,
+        Huge::V965 => 957,
+        Huge::V966 => 958,
+        Huge::V967 => 959,
+        Huge::V968 => 945,
+        Huge::V969 => 944,
+        Huge::V970 => 947,
+        Huge::V971 => 946,
+        Huge::V972 => 949,
+        Huge::V973 => 948,
+        Huge::V974 => 951,
+        Huge::V975 => 950,
+        Huge::V976 => 938,
+        Huge::V977 => 939,
+        Huge::V978 => 936,
+        Huge::V979 => 937,
+        Huge::V980 => 942,
+        Huge::V981 => 943,
+        Huge::V982 => 940,
+        Huge::V983 => 941,
+        Huge::V984 => 931,
+        Huge::V985 => 930,
+        Huge::V986 => 929,
+        Huge::V987 => 928,
+        Huge::V988 => 935,
+        Huge::V989 => 934,
+        Huge::V990 => 933,
+        Huge::V991 => 932,
+        Huge::V992 => 924,
+        Huge::V993 => 925,
+        Huge::V994 => 926,
+        Huge::V995 => 927,
+        Huge::V996 => 920,
+        Huge::V997 => 921,
+        Huge::V998 => 922,
+        Huge::V999 => 923,
+        Huge::V1000 => 917,
+        Huge::V1001 => 916,
+        Huge::V1002 => 919,
+        Huge::V1003 => 918,
+        Huge::V1004 => 913,
+        Huge::V1005 => 912,
+        Huge::V1006 => 915,
+        Huge::V1007 => 914,
+        Huge::V1008 => 910,
+        Huge::V1009 => 911,
+        Huge::V1010 => 908,
+        Huge::V1011 => 909,
+        Huge::V1012 => 906,
+        Huge::V1013 => 907,
+        Huge::V1014 => 904,
+        Huge::V1015 => 905,
+        Huge::V1016 => 903,
+        Huge::V1017 => 902,
+        Huge::V1018 => 901,
+        Huge::V1019 => 900,
+        Huge::V1020 => 899,
+        Huge::V1021 => 898,
+        Huge::V1022 => 897,
+        Huge::V1023 => 896,
+        Huge::V1024 => 1152,
+        Huge::V1025 => 1153,
+        Huge::V1026 => 1154,
+        Huge::V1027 => 1155,
+        Huge::V1028 => 1156,
+        Huge::V1029 => 1157,
+        Huge::V1030 => 1158,
+        Huge::V1031 => 1159,
+        Huge::V1032 => 1161,
+        Huge::V1033 => 1160,
+        Huge::V1034 => 1163,
+        Huge::V1035 => 1162,
+        Huge::V1036 => 1165,
+        Huge::V1037 => 1164,
+        Huge::V1038 => 1167,
+        Huge::V1039 => 1166,
+        Huge::V1040 => 1170,
+        Huge::V1041 => 1171,
+        Huge::V1042 => 1168,
+        Huge::V1043 => 1169,
+        Huge::V1044 => 1174,
+        Huge::V1045 => 1175,
+        Huge::V1046 => 1172,
+        Huge::V1047 => 1173,
+        Huge::V1048 => 1179,
+        Huge::V1049 => 1178,
+        Huge::V1050 => 1177,
+        Huge::V1051 => 1176,
+        Huge::V1052 => 1183,
+        Huge::V1053 => 1182,
+        Huge::V1054 => 1181,
+        Huge::V1055 => 1180,
+        Huge::V1056 => 1188,
+        Huge::V1057 => 1189,
+        Huge::V1058 => 1190,
+        Huge::V1059 => 1191,
+        Huge::V1060 => 1184,
+        Huge::V1061 => 1185,
+        Huge::V1062 => 1186,
+        Huge::V1063 => 1187,
+        Huge::V1064 => 1197,
+        Huge::V1065 => 1196,
+        Huge::V1066 => 1199,
+        Huge::V1067 => 1198,
+        Huge::V1068 => 1193,
+        Huge::V1069 => 1192,
+        Huge::V1070 => 1195,
+        Huge::V1071 => 1194,
+        Huge::V1072 => 1206,
+        Huge::V1073 => 1207,
+        Huge::V1074 => 1204,
+        Huge::V1075 => 1205,
+        Huge::V1076 => 1202,
+        Huge::V1077 => 1203,
+        Huge::V1078 => 1200,
+        Huge::V1079 => 1201,
+        Huge::V1080 => 1215,
+        Huge::V1081 => 1214,
+        Huge::V1082 => 1213,
+        Huge::V1083 => 1212,
+        Huge::V1084 => 1211,
+        Huge::V1085 => 1210,
+        Huge::V1086 => 1209,
+        Huge::V1087 => 1208,
+        Huge::V1088 => 1224,
+        Huge::V1089 => 1225,
+        Huge::V1090 => 1226,
+        Huge::V1091 => 1227,
+        Huge::V1092 => 1228,
+        Huge::V1093 => 1229,
+        Huge::V1094 => 1230,
+        Huge::V1095 => 1231,
+        Huge::V1096 => 1217,
+        Huge::V1097 => 1216,
+        Huge::V1098 => 1219,
+        Huge::V1099 => 1218,
+        Huge::V1100 => 1221,
+        Huge::V1101 => 1220,
+        Huge::V1102 => 1223,
+        Huge::V1103 => 1222,
+        Huge::V1104 => 1242,
+        Huge::V1105 => 1243,
+        Huge::V1106 => 1240,
+        Huge::V1107 => 1241,
+        Huge::V1108 => 1246,
+        Huge::V1109 => 1247,
+        Huge::V1110 => 1244,
+        Huge::V1111 => 1245,
+        Huge::V1112 => 1235,
+        Huge::V1113 => 1234,
+        Huge::V1114 => 1233,
+        Huge::V1115 => 1232,
+        Huge::V1116 => 1239,
+        Huge::V1117 => 1238,
+        Huge::V1118 => 1237,
+        Huge::V1119 => 1236,
+        Huge::V1120 => 1260,
+        Huge::V1121 => 1261,
+        Huge::V1122 => 1262,
+        Huge::V1123 => 1263,
+        Huge::V1124 => 1256,
+        Huge::V1125 => 1257,
+        Huge::V1126 => 1258,
+        Huge::V1127 => 1259,
+        Huge::V1128 => 1253,
+        Huge::V1129 => 1252,
+        Huge::V1130 => 1255,
+        Huge::V1131 => 1254,
+        Huge::V1132 => 1249,
+        Huge::V1133 => 1248,
+        Huge::V1134 => 1251,
+        Huge::V1135 => 1250,
+        Huge::V1136 => 1278,
+        Huge::V1137 => 1279,
+        Huge::V1138 => 1276,
+        Huge::V1139 => 1277,
+        Huge::V1140 => 1274,
+        Huge::V1141 => 1275,
+        Huge::V1142 => 1272,
+        Huge::V1143 => 1273,
+        Huge::V1144 => 1271,
+        Huge::V1145 => 1270,
+        Huge::V1146 => 1269,
+        Huge::V1147 => 1268,
+        Huge::V1148 => 1267,
+        Huge::V1149 => 1266,
+        Huge::V1150 => 1265,
+        Huge::V1151 => 1264,
+        Huge::V1152 => 1040,
+        Huge::V1153 => 1041,
+        Huge::V1154 => 1042,
+        Huge::V1155 => 1043,
+        Huge::V1156 => 1044,
+        Huge::V1157 => 1045,
+        Huge::V1158 => 1046,
+        Huge::V1159 => 1047,
+        Huge::V1160 => 1049,
+        Huge::V1161 => 1048,
+        Huge::V1162 => 1051,
+        Huge::V1163 => 1050,
+        Huge::V1164 => 1053,
+        Huge::V1165 => 1052,
+        Huge::V1166 => 1055,
+        Huge::V1167 => 1054,
+        Huge::V1168 => 1026,
+        Huge::V1169 => 1027,
+        Huge::V1170 => 1024,
+        Huge::V1171 => 1025,
+        Huge::V1172 => 1030,
+        Huge::V1173 => 1031,
+        Huge::V1174 => 1028,
+        Huge::V1175 => 1029,
+        Huge::V1176 => 1035,
+        Huge::V1177 => 1034,
+        Huge::V1178 => 1033,
+        Huge::V1179 => 1032,
+        Huge::V1180 => 1039,
+        Huge::V1181 => 1038,
+        Huge::V1182 => 1037,
+        Huge::V1183 => 1036,
+        Huge::V1184 => 1076,
+        Huge::V1185 => 1077,
+        Huge::V1186 => 1078,
+        Huge::V1187 => 1079,
+        Huge::V1188 => 1072,
+        Huge::V1189 => 1073,
+        Huge::V1190 => 1074,
+        Huge::V1191 => 1075,
+        Huge::V1192 => 1085,
+        Huge::V1193 => 1084,
+        Huge::V1194 => 1087,
+        Huge::V1195 => 1086,
+        Huge::V1196 => 1081,
+        Huge::V1197 => 1080,
+        Huge::V1198 => 1083,
+        Huge::V1199 => 1082,
+        Huge::V1200 => 1062,
+        Huge::V1201 => 1063,
+        Huge::V1202 => 1060,
+        Huge::V1203 => 1061,
+        Huge::V1204 => 1058,
+        Huge::V1205 => 1059,
+        Huge::V1206 => 1056,
+        Huge::V1207 => 1057,
+        Huge::V1208 => 1071,
+        Huge::V1209 => 1070,
+        Huge::V1210 => 1069,
+        Huge::V1211 => 1068,
+        Huge::V1212 => 1067,
+        Huge::V1213 => 1066,
+        Huge::V1214 => 1065,
+        Huge::V1215 => 1064,
+        Huge::V1216 => 1112,
+        Huge::V1217 => 1113,
+        Huge::V1218 => 1114,
+        Huge::V1219 => 1115,
+        Huge::V1220 => 1116,
+        Huge::V1221 => 1117,
+        Huge::V1222 => 1118,
+        Huge::V1223 => 1119,
+        Huge::V1224 => 1105,
+        Huge::V1225 => 1104,
+        Huge::V1226 => 1107,
+        Huge::V1227 => 1106,
+        Huge::V1228 => 1109,
+        Huge::V1229 => 1108,
+        Huge::V1230 => 1111,
+        Huge::V1231 => 1110,
+        Huge::V1232 => 1098,
+        Huge::V1233 => 1099,
+        Huge::V1234 => 1096,
+        Huge::V1235 => 1097,
+        Huge::V1236 => 1102,
+        Huge::V1237 => 1103,
+        Huge::V1238 => 1100,
+        Huge::V1239 => 1101,
+        Huge::V1240 => 1091,
+        Huge::V1241 => 1090,
+        Huge::V1242 => 1089,
+        Huge::V1243 => 1088,
+        Huge::V1244 => 1095,
+        Huge::V1245 => 1094,
+        Huge::V1246 => 1093,
+        Huge::V1247 => 1092,
+        Huge::V1248 => 1148,
+        Huge::V1249 => 1149,
+        Huge::V1250 => 1150,
+        Huge::V1251 => 1151,
+        Huge::V1252 => 1144,
+        Huge::V1253 => 1145,
+        Huge::V1254 => 1146,
+        Huge::V1255 => 1147,
+        Huge::V1256 => 1141,
+        Huge::V1257 => 1140,
+        Huge::V1258 => 1143,
+        Huge::V1259 => 1142,
+        Huge::V1260 => 1137,
+        Huge::V1261 => 1136,
+        Huge::V1262 => 1139,
+        Huge::V1263 => 1138,
+        Huge::V1264 => 1134,
+        Huge::V1265 => 1135,
+        Huge::V1266 => 1132,
+        Huge::V1267 => 1133,
+        Huge::V1268 => 1130,
+        Huge::V1269 => 1131,
+        Huge::V1270 => 1128,
+        Huge::V1271 => 1129,
+        Huge::V1272 => 1127,
+        Huge::V1273 => 1126,
+        Huge::V1274 => 1125,
+        Huge::V1275 => 1124,
+        Huge::V1276 => 1123,
+        Huge::V1277 => 1122,
+        Huge::V1278 => 1121,
+        Huge::V1279 => 1120,
+        Huge::V1280 => 1440,
+        Huge::V1281 => 1441,
+        Huge::V1282 => 1442,
+        Huge::V1283 => 1443,
+        Huge::V1284 => 1444,
+        Huge::V1285 => 1445,
+        Huge::V1286 => 1446,
+        Huge::V1287 => 1447,
+        Huge::V1288 => 1449,
+        Huge::V1289 => 1448,
+        Huge::V1290 => 1451,
+        Huge::V1291 => 1450,
+        Huge::V1292 => 1453,
+        Huge::V1293 => 1452,
+        Huge::V1294 => 1455,
+        Huge::V1295 => 1454,
+        Huge::V1296 => 1458,
+        Huge::V1297 => 1459,
+        Huge::V1298 => 1456,
+        Huge::V1299 => 1457,
+        Huge::V1300 => 1462,
+        Huge::V1301 => 1463,
+        Huge::V1302 => 1460,
+        Huge::V1303 => 1461,
+        Huge::V1304 => 1467,
+        Huge::V1305 => 1466,
+        Huge::V1306 => 1465,
+        Huge::V1307 => 1464,
+        Huge::V1308 => 1471,
+        Huge::V1309 => 1470,
+        Huge::V1310 => 1469,
+        Huge::V1311 => 1468,
+        Huge::V1312 => 1412,
+        Huge::V1313 => 1413,
+        Huge::V1314 => 1414,
+        Huge::V1315 => 1415,
+        Huge::V1316 => 1408,
+        Huge::V1317 => 1409,
+        Huge::V1318 => 1410,
+        Huge::V1319 => 1411,
+        Huge::V1320 => 1421,
+        Huge::V1321 => 1420,
+        Huge::V1322 => 1423,
+        Huge::V1323 => 1422,
+        Huge::V1324 => 1417,
+        Huge::V1325 => 1416,
+        Huge::V1326 => 1419,
+        Huge::V1327 => 1418,
+        Huge::V1328 => 1430,
+        Huge::V1329 => 1431,
+        Huge::V1330 => 1428,
+        Huge::V1331 => 1429,
+        Huge::V1332 => 1426,
+        Huge::V1333 => 1427,
+        Huge::V1334 => 1424,
+        Huge::V1335 => 1425,
+        Huge::V1336 => 1439,
+        Huge::V1337 => 1438,
+        Huge::V1338 => 1437,
+        Huge::V1339 => 1436,
+        Huge::V1340 => 1435,
+        Huge::V1341 => 1434,
+        Huge::V1342 => 1433,
+        Huge::V1343 => 1432,
+        Huge::V1344 => 1512,
+        Huge::V1345 => 1513,
+        Huge::V1346 => 1514,
+        Huge::V1347 => 1515,
+        Huge::V1348 => 1516,
+        Huge::V1349 => 1517,
+        Huge::V1350 => 1518,
+        Huge::V1351 => 1519,
+        Huge::V1352 => 1505,
+        Huge::V1353 => 1504,
+        Huge::V1354 => 1507,
+        Huge::V1355 => 1506,
+        Huge::V1356 => 1509,
+        Huge::V1357 => 1508,
+        Huge::V1358 => 1511,
+        Huge::V1359 => 1510,
+        Huge::V1360 => 1530,
+        Huge::V1361 => 1531,
+        Huge::V1362 => 1528,
+        Huge::V1363 => 1529,
+        Huge::V1364 => 1534,
+        Huge::V1365 => 1535,
+        Huge::V1366 => 1532,
+        Huge::V1367 => 1533,
+        Huge::V1368 => 1523,
+        Huge::V1369 => 1522,
+        Huge::V1370 => 1521,
+        Huge::V1371 => 1520,
+        Huge::V1372 => 1527,
+        Huge::V1373 => 1526,
+        Huge::V1374 => 1525,
+        Huge::V1375 => 1524,
+        Huge::V1376 => 1484,
+        Huge::V1377 => 1485,
+        Huge::V1378 => 1486,
+        Huge::V1379 => 1487,
+        Huge::V1380 => 1480,
+        Huge::V1381 => 1481,
+        Huge::V1382 => 1482,
+        Huge::V1383 => 1483,
+        Huge::V1384 => 1477,
+        Huge::V1385 => 1476,
+        Huge::V1386 => 1479,
+        Huge::V1387 => 1478,
+        Huge::V1388 => 1473,
+        Huge::V1389 => 1472,
+        Huge::V1390 => 1475,
+        Huge::V1391 => 1474,
+        Huge::V1392 => 1502,
+        Huge::V1393 => 1503,
+        Huge::V1394 => 1500,
+        Huge::V1395 => 1501,
+        Huge::V1396 => 1498,
+        Huge::V1397 => 1499,
+        Huge::V1398 => 1496,
+        Huge::V1399 => 1497,
+        Huge::V1400 => 1495,
+        Huge::V1401 => 1494,
+        Huge::V1402 => 1493,
+        Huge::V1403 => 1492,
+        Huge::V1404 => 1491,
+        Huge::V1405 => 1490,
+        Huge::V1406 => 1489,
+        Huge::V1407 => 1488,
+        Huge::V1408 => 1328,
+        Huge::V1409 => 1329,
+        Huge::V1410 => 1330,
+        Huge::V1411 => 1331,
+        Huge::V1412 => 1332,
+        Huge::V1413 => 1333,
+        Huge::V1414 => 1334,
+        Huge::V1415 => 1335,
+        Huge::V1416 => 1337,
+        Huge::V1417 => 1336,
+        Huge::V1418 => 1339,
+        Huge::V1419 => 1338,
+        Huge::V1420 => 1341,
+        Huge::V1421 => 1340,
+        Huge::V1422 => 1343,
+        Huge::V1423 => 1342,
+        Huge::V1424 => 1314,
+        Huge::V1425 => 1315,
+        Huge::V1426 => 1312,
+        Huge::V1427 => 1313,
+        Huge::V1428 => 1318,
+        Huge::V1429 => 1319,
+        Huge::V1430 => 1316,
+        Huge::V1431 => 1317,
+        Huge::V1432 => 1323,
+        Huge::V1433 => 1322,
+        Huge::V1434 => 1321,
+        Huge::V1435 => 1320,
+        Huge::V1436 => 1327,
+        Huge::V1437 => 1326,
+        Huge::V1438 => 1325,
+        Huge::V1439 => 1324,
+        Huge::V1440 => 1300,
+        Huge::V1441 => 1301,
+        Huge::V1442 => 1302,
+        Huge::V1443 => 1303,
+        Huge::V1444 => 1296,
+        Huge::V1445 => 1297,
+        Huge::V1446 => 1298,
+        Huge::V1447 => 1299,
+        Huge::V1448 => 1309,
+        Huge::V1449 => 1308,
+        Huge::V1450 => 1311,
+        Huge::V1451 => 1310,
+        Huge::V1452 => 1305,
+        Huge::V1453 => 1304,
+        Huge::V1454 => 1307,
+        Huge::V1455 => 1306,
+        Huge::V1456 => 1286,
+        Huge::V1457 => 1287,
+        Huge::V1458 => 1284,
+        Huge::V1459 => 1285,
+        Huge::V1460 => 1282,
+        Huge::V1461 => 1283,
+        Huge::V1462 => 1280,
+        Huge::V1463 => 1281,
+        Huge::V1464 => 1295,
+        Huge::V1465 => 1294,
+        Huge::V1466 => 1293,
+        Huge::V1467 => 1292,
+        Huge::V1468 => 1291,
+        Huge::V1469 => 1290,
+        Huge::V1470 => 1289,
+        Huge::V1471 => 1288,
+        Huge::V1472 => 1400,
+        Huge::V1473 => 1401,
+        Huge::V1474 => 1402,
+        Huge::V1475 => 1403,
+        Huge::V1476 => 1404,
+        Huge::V1477 => 1405,
+        Huge::V1478 => 1406,
+        Huge::V1479 => 1407,
+        Huge::V1480 => 1393,
+        Huge::V1481 => 1392,
+        Huge::V1482 => 1395,
+        Huge::V1483 => 1394,
+        Huge::V1484 => 1397,
+        Huge::V1485 => 1396,
+        Huge::V1486 => 1399,
+        Huge::V1487 => 1398,
+        Huge::V1488 => 1386,
+        Huge::V1489 => 1387,
+        Huge::V1490 => 1384,
+        Huge::V1491 => 1385,
+        Huge::V1492 => 1390,
+        Huge::V1493 => 1391,
+        Huge::V1494 => 1388,
+        Huge::V1495 => 1389,
+        Huge::V1496 => 1379,
+        Huge::V1497 => 1378,
+        Huge::V1498 => 1377,
+        Huge::V1499 => 1376,
+        Huge::V1500 => 1383,
+        Huge::V1501 => 1382,
+        Huge::V1502 => 1381,
+        Huge::V1503 => 1380,
+        Huge::V1504 => 1372,
+        Huge::V1505 => 1373,
+        Huge::V1506 => 1374,
+        Huge::V1507 => 1375,
+        Huge::V1508 => 1368,
+        Huge::V1509 => 1369,
+        Huge::V1510 => 1370,
+        Huge::V1511 => 1371,
+        Huge::V1512 => 1365,
+        Huge::V1513 => 1364,
+        Huge::V1514 => 1367,
+        Huge::V1515 => 1366,
+        Huge::V1516 => 1361,
+        Huge::V1517 => 1360,
+        Huge::V1518 => 1363,
+        Huge::V1519 => 1362,
+        Huge::V1520 => 1358,
+        Huge::V1521 => 1359,
+        Huge::V1522 => 1356,
+        Huge::V1523 => 1357,
+        Huge::V1524 => 1354,
+        Huge::V1525 => 1355,
+        Huge::V1526 => 1352,
+        Huge::V1527 => 1353,
+        Huge::V1528 => 1351,
+        Huge::V1529 => 1350,
+        Huge::V1530 => 1349,
+        Huge::V1531 => 1348,
+        Huge::V1532 => 1347,
+        Huge::V1533 => 1346,
+        Huge::V1534 => 1345,
+        Huge::V1535 => 1344,
+        Huge::V1536 => 1728,
+        Huge::V1537 => 1729,
+        Huge::V1538 => 1730,
+        Huge::V1539 => 1731,
+        Huge::V1540 => 1732,
+        Huge::V1541 => 1733,
+        Huge::V1542 => 1734,
+        Huge::V1543 => 1735,
+        Huge::V1544 => 1737,
+        Huge::V1545 => 1736,
+        Huge::V1546 => 1739,
+        Huge::V1547 => 1738,
+        Huge::V1548 => 1741,
+        Huge::V1549 => 1740,
+        Huge::V1550 => 1743,
+        Huge::V1551 => 1742,
+        Huge::V1552 => 1746,
+        Huge::V1553 => 1747,
+        Huge::V1554 => 1744,
+        Huge::V1555 => 1745,
+        Huge::V1556 => 1750,
+        Huge::V1557 => 1751,
+        Huge::V1558 => 1748,
+        Huge::V1559 => 1749,
+        Huge::V1560 => 1755,
+        Huge::V1561 => 1754,
+        Huge::V1562 => 1753,
+        Huge::V1563 => 1752,
+        Huge::V1564 => 1759,
+        Huge::V1565 => 1758,
+        Huge::V1566 => 1757,
+        Huge::V1567 => 1756,
+        Huge::V1568 => 1764,
+        Huge::V1569 => 1765,
+        Huge::V1570 => 1766,
+        Huge::V1571 => 1767,
+        Huge::V1572 => 1760,
+        Huge::V1573 => 1761,
+        Huge::V1574 => 1762,
+        Huge::V1575 => 1763,
+        Huge::V1576 => 1773,
+        Huge::V1577 => 1772,
+        Huge::V1578 => 1775,
+        Huge::V1579 => 1774,
+        Huge::V1580 => 1769,
+        Huge::V1581 => 1768,
+        Huge::V1582 => 1771,
+        Huge::V1583 => 1770,
+        Huge::V1584 => 1782,
+        Huge::V1585 => 1783,
+        Huge::V1586 => 1780,
+        Huge::V1587 => 1781,
+        Huge::V1588 => 1778,
+        Huge::V1589 => 1779,
+        Huge::V1590 => 1776,
+        Huge::V1591 => 1777,
+        Huge::V1592 => 1791,
+        Huge::V1593 => 1790,
+        Huge::V1594 => 1789,
+        Huge::V1595 => 1788,
+        Huge::V1596 => 1787,
+        Huge::V1597 => 1786,
+        Huge::V1598 => 1785,
+        Huge::V1599 => 1784,
+        Huge::V1600 => 1672,
+        Huge::V1601 => 1673,
+        Huge::V1602 => 1674,
+        Huge::V1603 => 1675,
+        Huge::V1604 => 1676,
+        Huge::V1605 => 1677,
+        Huge::V1606 => 1678,
+        Huge::V1607 => 1679,
+        Huge::V1608 => 1665,
+        Huge::V1609 => 1664,
+        Huge::V1610 => 1667,
+        Huge::V1611 => 1666,
+        Huge::V1612 => 1669,
+        Huge::V1613 => 1668,
+        Huge::V1614 => 1671,
+        Huge::V1615 => 1670,
+        Huge::V1616 => 1690,
+        Huge::V1617 => 1691,
+        Huge::V1618 => 1688,
+        Huge::V1619 => 1689,
+        Huge::V1620 => 1694,
+        Huge::V1621 => 1695,
+        Huge::V1622 => 1692,
+        Huge::V1623 => 1693,
+        Huge::V1624 => 1683,
+        Huge::V1625 => 1682,
+        Huge::V1626 => 1681,
+        Huge::V1627 => 1680,
+        Huge::V1628 => 1687,
+        Huge::V1629 => 1686,
+        Huge::V1630 => 1685,
+        Huge::V1631 => 1684,
+        Huge::V1632 => 1708,
+        Huge::V1633 => 1709,
+        Huge::V1634 => 1710,
+        Huge::V1635 => 1711,
+        Huge::V1636 => 1704,
+        Huge::V1637 => 1705,
+        Huge::V1638 => 1706,
+        Huge::V1639 => 1707,
+        Huge::V1640 => 1701,
+        Huge::V1641 => 1700,
+        Huge::V1642 => 1703,
+        Huge::V1643 => 1702,
+        Huge::V1644 => 1697,
+        Huge::V1645 => 1696,
+        Huge::V1646 => 1699,
+        Huge::V1647 => 1698,
+        Huge::V1648 => 1726,
+        Huge::V1649 => 1727,
+        Huge::V1650 => 1724,
+        Huge::V1651 => 1725,
+        Huge::V1652 => 1722,
+        Huge::V1653 => 1723,
+        Huge::V1654 => 1720,
+        Huge::V1655 => 1721,
+        Huge::V1656 => 1719,
+        Huge::V1657 => 1718,
+        Huge::V1658 => 1717,
+        Huge::V1659 => 1716,
+        Huge::V1660 => 1715,
+        Huge::V1661 => 1714,
+        Huge::V1662 => 1713,
+        Huge::V1663 => 1712,
+        Huge::V1664 => 1616,
+        Huge::V1665 => 1617,
+        Huge::V1666 => 1618,
+        Huge::V1667 => 1619,
+        Huge::V1668 => 1620,
+        Huge::V1669 => 1621,
+        Huge::V1670 => 1622,
+        Huge::V1671 => 1623,
+        Huge::V1672 => 1625,
+        Huge::V1673 => 1624,
+        Huge::V1674 => 1627,
+        Huge::V1675 => 1626,
+        Huge::V1676 => 1629,
+        Huge::V1677 => 1628,
+        Huge::V1678 => 1631,
+        Huge::V1679 => 1630,
+        Huge::V1680 => 1602,
+        Huge::V1681 => 1603,
+        Huge::V1682 => 1600,
+        Huge::V1683 => 1601,
+        Huge::V1684 => 1606,
+        Huge::V1685 => 1607,
+        Huge::V1686 => 1604,
+        Huge::V1687 => 1605,
+        Huge::V1688 => 1611,
+        Huge::V1689 => 1610,
+        Huge::V1690 => 1609,
+        Huge::V1691 => 1608,
+        Huge::V1692 => 1615,
+        Huge::V1693 => 1614,
+        Huge::V1694 => 1613,
+        Huge::V1695 => 1612,
+        Huge::V1696 => 1652,
+        Huge::V1697 => 1653,
+        Huge::V1698 => 1654,
+        Huge::V1699 => 1655,
+        Huge::V1700 => 1648,
+        Huge::V1701 => 1649,
+        Huge::V1702 => 1650,
+        Huge::V1703 => 1651,
+        Huge::V1704 => 1661,
+        Huge::V1705 => 1660,
+        Huge::V1706 => 1663,
+        Huge::V1707 => 1662,
+        Huge::V1708 => 1657,
+        Huge::V1709 => 1656,
+        Huge::V1710 => 1659,
+        Huge::V1711 => 1658,
+        Huge::V1712 => 1638,
+        Huge::V1713 => 1639,
+        Huge::V1714 => 1636,
+        Huge::V1715 => 1637,
+        Huge::V1716 => 1634,
+        Huge::V1717 => 1635,
+        Huge::V1718 => 1632,
+        Huge::V1719 => 1633,
+        Huge::V1720 => 1647,
+        Huge::V1721 => 1646,
+        Huge::V1722 => 1645,
+        Huge::V1723 => 1644,
+        Huge::V1724 => 1643,
+        Huge::V1725 => 1642,
+        Huge::V1726 => 1641,
+        Huge::V1727 => 1640,
+        Huge::V1728 => 1560,
+        Huge::V1729 => 1561,
+        Huge::V1730 => 1562,
+        Huge::V1731 => 1563,
+        Huge::V1732 => 1564,
+        Huge::V1733 => 1565,
+        Huge::V1734 => 1566,
+        Huge::V1735 => 1567,
+        Huge::V1736 => 1553,
+        Huge::V1737 => 1552,
+        Huge::V1738 => 1555,
+        Huge::V1739 => 1554,
+        Huge::V1740 => 1557,
+        Huge::V1741 => 1556,
+        Huge::V1742 => 1559,
+        Huge::V1743 => 1558,
+        Huge::V1744 => 1546,
+        Huge::V1745 => 1547,
+        Huge::V1746 => 1544,
+        Huge::V1747 => 1545,
+        Huge::V1748 => 1550,
+        Huge::V1749 => 1551,
+        Huge::V1750 => 1548,
+        Huge::V1751 => 1549,
+        Huge::V1752 => 1539,
+        Huge::V1753 => 1538,
+        Huge::V1754 => 1537,
+        Huge::V1755 => 1536,
+        Huge::V1756 => 1543,
+        Huge::V1757 => 1542,
+        Huge::V1758 => 1541,
+        Huge::V1759 => 1540,
+        Huge::V1760 => 1596,
+        Huge::V1761 => 1597,
+        Huge::V1762 => 1598,
+        Huge::V1763 => 1599,
+        Huge::V1764 => 1592,
+        Huge::V1765 => 1593,
+        Huge::V1766 => 1594,
+        Huge::V1767 => 1595,
+        Huge::V1768 => 1589,
+        Huge::V1769 => 1588,
+        Huge::V1770 => 1591,
+        Huge::V1771 => 1590,
+        Huge::V1772 => 1585,
+        Huge::V1773 => 1584,
+        Huge::V1774 => 1587,
+        Huge::V1775 => 1586,
+        Huge::V1776 => 1582,
+        Huge::V1777 => 1583,
+        Huge::V1778 => 1580,
+        Huge::V1779 => 1581,
+        Huge::V1780 => 1578,
+        Huge::V1781 => 1579,
+        Huge::V1782 => 1576,
+        Huge::V1783 => 1577,
+        Huge::V1784 => 1575,
+        Huge::V1785 => 1574,
+        Huge::V1786 => 1573,
+        Huge::V1787 => 1572,
+        Huge::V1788 => 1571,
+        Huge::V1789 => 1570,
+        Huge::V1790 => 1569,
+        Huge::V1791 => 1568,
+        Huge::V1792 => 2016,
+        Huge::V1793 => 2017,
+        Huge::V1794 => 2018,
+        Huge::V1795 => 2019,
+        Huge::V1796 => 2020,
+        Huge::V1797 => 2021,
+        Huge::V1798 => 2022,
+        Huge::V1799 => 2023,
+        Huge::V1800 => 2025,
+        Huge::V1801 => 2024,
+        Huge::V1802 => 2027,
+        Huge::V1803 => 2026,
+        Huge::V1804 => 2029,
+        Huge::V1805 => 2028,
+        Huge::V1806 => 2031,
+        Huge::V1807 => 2030,
+        Huge::V1808 => 2034,
+        Huge::V1809 => 2035,
+        Huge::V1810 => 2032,
+        Huge::V1811 => 2033,
+        Huge::V1812 => 2038,
+        Huge::V1813 => 2039,
+        Huge::V1814 => 2036,
+        Huge::V1815 => 2037,
+        Huge::V1816 => 2043,
+        Huge::V1817 => 2042,
+        Huge::V1818 => 2041,
+        Huge::V1819 => 2040,
+        Huge::V1820 => 2047,
+        Huge::V1821 => 2046,
+        Huge::V1822 => 2045,
+        Huge::V1823 => 2044,
+        Huge::V1824 => 1988,
+        Huge::V1825 => 1989,
+        Huge::V1826 => 1990,
+        Huge::V1827 => 1991,
+        Huge::V1828 => 1984,
+        Huge::V1829 => 1985,
+        Huge::V1830 => 1986,
+        Huge::V1831 => 1987,
+        Huge::V1832 => 1997,
+        Huge::V1833 => 1996,
+        Huge::V1834 => 1999,
+        Huge::V1835 => 1998,
+        Huge::V1836 => 1993,
+        Huge::V1837 => 1992,
+        Huge::V1838 => 1995,
+        Huge::V1839 => 1994,
+        Huge::V1840 => 2006,
+        Huge::V1841 => 2007,
+        Huge::V1842 => 2004,
+        Huge::V1843 => 2005,
+        Huge::V1844 => 2002,
+        Huge::V1845 => 2003,
+        Huge::V1846 => 2000,
+        Huge::V1847 => 2001,
+        Huge::V1848 => 2015,
+        Huge::V1849 => 2014,
+        Huge::V1850 => 2013,
+        Huge::V1851 => 2012,
+        Huge::V1852 => 2011,
+        Huge::V1853 => 2010,
+        Huge::V1854 => 2009,
+        Huge::V1855 => 2008,
+        Huge::V1856 => 1960,
+        Huge::V1857 => 1961,
+        Huge::V1858 => 1962,
+        Huge::V1859 => 1963,
+        Huge::V1860 => 1964,
+        Huge::V1861 => 1965,
+        Huge::V1862 => 1966,
+        Huge::V1863 => 1967,
+        Huge::V1864 => 1953,
+        Huge::V1865 => 1952,
+        Huge::V1866 => 1955,
+        Huge::V1867 => 1954,
+        Huge::V1868 => 1957,
+        Huge::V1869 => 1956,
+        Huge::V1870 => 1959,
+        Huge::V1871 => 1958,
+        Huge::V1872 => 1978,
+        Huge::V1873 => 1979,
+        Huge::V1874 => 1976,
+        Huge::V1875 => 1977,
+        Huge::V1876 => 1982,
+        Huge::V1877 => 1983,
+        Huge::V1878 => 1980,
+        Huge::V1879 => 1981,
+        Huge::V1880 => 1971,
+        Huge::V1881 => 1970,
+        Huge::V1882 => 1969,
+        Huge::V1883 => 1968,
+        Huge::V1884 => 1975,
+        Huge::V1885 => 1974,
+        Huge::V1886 => 1973,
+        Huge::V1887 => 1972,
+        Huge::V1888 => 1932,
+        Huge::V1889 => 1933,
+        Huge::V1890 => 1934,
+        Huge::V1891 => 1935,
+        Huge::V1892 => 1928,
+        Huge::V1893 => 1929,
+        Huge::V1894 => 1930,
+        Huge::V1895 => 1931,
+        Huge::V1896 => 1925,
+        Huge::V1897 => 1924,
+        Huge::V1898 => 1927,
+        Huge::V1899 => 1926,
+        Huge::V1900 => 1921,
+        Huge::V1901 => 1920,
+        Huge::V1902 => 1923,
+        Huge::V1903 => 1922,
+        Huge::V1904 => 1950,
+        Huge::V1905 => 1951,
+        Huge::V1906 => 1948,
+        Huge::V1907 => 1949,
+        Huge::V1908 => 1946,
+        Huge::V1909 => 1947,
+        Huge::V1910 => 1944,
+        Huge::V1911 => 1945,
+        Huge::V1912 => 1943,
+        Huge::V1913 => 1942,
+        Huge::V1914 => 1941,
+        Huge::V1915 => 1940,
+        Huge::V1916 => 1939,
+        Huge::V1917 => 1938,
+        Huge::V1918 => 1937,
+        Huge::V1919 => 1936,
+        Huge::V1920 => 1904,
+        Huge::V1921 => 1905,
+        Huge::V1922 => 1906,
+        Huge::V1923 => 1907,
+        Huge::V1924 => 1908,
+        Huge::V1925 => 1909,
+        Huge::V1926 => 1910,
+        Huge::V1927 => 1911,
+        Huge::V1928 => 1913,
+        Huge::V1929 => 1912,
+        Huge::V1930 => 1915,
+        Huge::V1931 => 1914,
+        Huge::V1932 => 1917,
+        Huge::V1933 => 1916,
+        Huge::V1934 => 1919,
+        Huge::V1935 => 1918,
+        Huge::V1936 => 1890,
+        Huge::V1937 => 1891,
+        Huge::V1938 => 1888,
+        Huge::V1939 => 1889,
+        Huge::V1940 => 1894,
+        Huge::V1941 => 1895,
+        Huge::V1942 => 1892,
+        Huge::V1943 => 1893,
+        Huge::V1944 => 1899,
+        Huge::V1945 => 1898,
+        Huge::V1946 => 1897,
+        Huge::V1947 => 1896,
+        Huge::V1948 => 1903,
+        Huge::V1949 => 1902,
+        Huge::V1950 => 1901,
+        Huge::V1951 => 1900,
+        Huge::V1952 => 1876,
+        Huge::V1953 => 1877,
+        Huge::V1954 => 1878,
+        Huge::V1955 => 1879,
+        Huge::V1956 => 1872,
+        Huge::V1957 => 1873,
+        Huge::V1958 => 1874,
+        Huge::V1959 => 1875,
+        Huge::V1960 => 1885,
+        Huge::V1961 => 1884,
+        Huge::V1962 => 1887,
+        Huge::V1963 => 1886,
+        Huge::V1964 => 1881,
+        Huge::V1965 => 1880,
+        Huge::V1966 => 1883,
+        Huge::V1967 => 1882,
+        Huge::V1968 => 1862,
+        Huge::V1969 => 1863,
+        Huge::V1970 => 1860,
+        Huge::V1971 => 1861,
+        Huge::V1972 => 1858,
+        Huge::V1973 => 1859,
+        Huge::V1974 => 1856,
+        Huge::V1975 => 1857,
+        Huge::V1976 => 1871,
+        Huge::V1977 => 1870,
+        Huge::V1978 => 1869,
+        Huge::V1979 => 1868,
+        Huge::V1980 => 1867,
+        Huge::V1981 => 1866,
+        Huge::V1982 => 1865,
+        Huge::V1983 => 1864,
+        Huge::V1984 => 1848,
+        Huge::V1985 => 1849,
+        Huge::V1986 => 1850,
+        Huge::V1987 => 1851,
+        Huge::V1988 => 1852,
+        Huge::V1989 => 1853,
+        Huge::V1990 => 1854,
+        Huge::V1991 => 1855,
+        Huge::V1992 => 1841,
+        Huge::V1993 => 1840,
+        Huge::V1994 => 1843,
+        Huge::V1995 => 1842,
+        Huge::V1996 => 1845,
+        Huge::V1997 => 1844,
+        Huge::V1998 => 1847,
+        Huge::V1999 => 1846,
+        Huge::V2000 => 1834,
+        Huge::V2001 => 1835,
+        Huge::V2002 => 1832,
+        Huge::V2003 => 1833,
+        Huge::V2004 => 1838,
+        Huge::V2005 => 1839,
+        Huge::V2006 => 1836,
+        Huge::V2007 => 1837,
+        Huge::V2008 => 1827,
+        Huge::V2009 => 1826,
+        Huge::V2010 => 1825,
+        Huge::V2011 => 1824,
+        Huge::V2012 => 1831,
+        Huge::V2013 => 1830,
+        Huge::V2014 => 1829,
+        Huge::V2015 => 1828,
+        Huge::V2016 => 1820,
+        Huge::V2017 => 1821,
+        Huge::V2018 => 1822,
+        Huge::V2019 => 1823,
+        Huge::V2020 => 1816,
+        Huge::V2021 => 1817,
+        Huge::V2022 => 1818,
+        Huge::V2023 => 1819,
+        Huge::V2024 => 1813,
+        Huge::V2025 => 1812,
+        Huge::V2026 => 1815,
+        Huge::V2027 => 1814,
+        Huge::V2028 => 1809,
+        Huge::V2029 => 1808,
+        Huge::V2030 => 1811,
+        Huge::V2031 => 1810,
+        Huge::V2032 => 1806,
+        Huge::V2033 => 1807,
+        Huge::V2034 => 1804,
+        Huge::V2035 => 1805,
+        Huge::V2036 => 1802,
+        Huge::V2037 => 1803,
+        Huge::V2038 => 1800,
+        Huge::V2039 => 1801,
+        Huge::V2040 => 1799,
+        Huge::V2041 => 1798,
+        Huge::V2042 => 1797,
+        Huge::V2043 => 1796,
+        Huge::V2044 => 1795,
+        Huge::V2045 => 1794,
+        Huge::V2046 => 1793,
+        Huge::V2047 => 1792,
+        Huge::V2048 => 2304,
+        Huge::V2049 => 2305,
+        Huge::V2050 => 2306,
+        Huge::V2051 => 2307,
+        Huge::V2052 => 2308,
+        Huge::V2053 => 2309,
+        Huge::V2054 => 2310,
+        Huge::V2055 => 2311,
+        Huge::V2056 => 2313,
+        Huge::V2057 => 2312,
+        Huge::V2058 => 2315,
+        Huge::V2059 => 2314,
+        Huge::V2060 => 2317,
+        Huge::V2061 => 2316,
+        Huge::V2062 => 2319,
+        Huge::V2063 => 2318,
+        Huge::V2064 => 2322,
+        Huge::V2065 => 2323,
+        Huge::V2066 => 2320,
+        Huge::V2067 => 2321,
+        Huge::V2068 => 2326,
+        Huge::V2069 => 2327,
+        Huge::V2070 => 2324,
+        Huge::V2071 => 2325,
+        Huge::V2072 => 2331,
+        Huge::V2073 => 2330,
+        Huge::V2074 => 2329,
+        Huge::V2075 => 2328,
+        Huge::V2076 => 2335,
+        Huge::V2077 => 2334,
+        Huge::V2078 => 2333,
+        Huge::V2079 => 2332,
+        Huge::V2080 => 2340,
+        Huge::V2081 => 2341,
+        Huge::V2082 => 2342,
+        Huge::V2083 => 2343,
+        Huge::V2084 => 2336,
+        Huge::V2085 => 2337,
+        Huge::V2086 => 2338,
+        Huge::V2087 => 2339,
+        Huge::V2088 => 2349,
+        Huge::V2089 => 2348,
+        Huge::V2090 => 2351,
+        Huge::V2091 => 2350,
+        Huge::V2092 => 2345,
+        Huge::V2093 => 2344,
+        Huge::V2094 => 2347,
+        Huge::V2095 => 2346,
+        Huge::V2096 => 2358,
+        Huge::V2097 => 2359,
+        Huge::V2098 => 2356,
+        Huge::V2099 => 2357,
+        Huge::V2100 => 2354,
+        Huge::V2101 => 2355,
+        Huge::V2102 => 2352,
+        Huge::V2103 => 2353,
+        Huge::V2104 => 2367,
+        Huge::V2105 => 2366,
+        Huge::V2106 => 2365,
+        Huge::V2107 => 2364,
+        Huge::V2108 => 2363,
+        Huge::V2109 => 2362,
+        Huge::V2110 => 2361,
+        Huge::V2111 => 2360,
+        Huge::V2112 => 2376,
+        Huge::V2113 => 2377,
+        Huge::V2114 => 2378,
+        Huge::V2115 => 2379,
+        Huge::V2116 => 2380,
+        Huge::V2117 => 2381,
+        Huge::V2118 => 2382,
+        Huge::V2119 => 2383,
+        Huge::V2120 => 2369,
+        Huge::V2121 => 2368,
+        Huge::V2122 => 2371,
+        Huge::V2123 => 2370,
+        Huge::V2124 => 2373,
+        Huge::V2125 => 2372,
+        Huge::V2126 => 2375,
+        Huge::V2127 => 2374,
+        Huge::V2128 => 2394,
+        Huge::V2129 => 2395,
+        Huge::V2130 => 2392,
+        Huge::V2131 => 2393,
+        Huge::V2132 => 2398,
+        Huge::V2133 => 2399,
+        Huge::V2134 => 2396,
+        Huge::V2135 => 2397,
+        Huge::V2136 => 2387,
+        Huge::V2137 => 2386,
+        Huge::V2138 => 2385,
+        Huge::V2139 => 2384,
+        Huge::V2140 => 2391,
+        Huge::V2141 => 2390,
+        Huge::V2142 => 2389,
+        Huge::V2143 => 2388,
+        Huge::V2144 => 2412,
+        Huge::V2145 => 2413,
+        Huge::V2146 => 2414,
+        Huge::V2147 => 2415,
+        Huge::V2148 => 2408,
+        Huge::V2149 => 2409,
+        Huge::V2150 => 2410,
+        Huge::V2151 => 2411,
+        Huge::V2152 => 2405,
+        Huge::V2153 => 2404,
+        Huge::V2154 => 2407,
+        Huge::V2155 => 2406,
+        Huge::V2156 => 2401,
+        Huge::V2157 => 2400,
+        Huge::V2158 => 2403,
+        Huge::V2159 => 2402,
+        Huge::V2160 => 2430,
+        Huge::V2161 => 2431,
+        Huge::V2162 => 2428,
+        Huge::V2163 => 2429,
+        Huge::V2164 => 2426,
+        Huge::V2165 => 2427,
+        Huge::V2166 => 2424,
+        Huge::V2167 => 2425,
+        Huge::V2168 => 2423,
+        Huge::V2169 => 2422,
+        Huge::V2170 => 2421,
+        Huge::V2171 => 2420,
+        Huge::V2172 => 2419,
+        Huge::V2173 => 2418,
+        Huge::V2174 => 2417,
+        Huge::V2175 => 2416,
+        Huge::V2176 => 2448,
+        Huge::V2177 => 2449,
+        Huge::V2178 => 2450,
+        Huge::V2179 => 2451,
+        Huge::V2180 => 2452,
+        Huge::V2181 => 2453,
+        Huge::V2182 => 2454,
+        Huge::V2183 => 2455,
+        Huge::V2184 => 2457,
+        Huge::V2185 => 2456,
+        Huge::V2186 => 2459,
+        Huge::V2187 => 2458,
+        Huge::V2188 => 2461,
+        Huge::V2189 => 2460,
+        Huge::V2190 => 2463,
+        Huge::V2191 => 2462,
+        Huge::V2192 => 2434,
+        Huge::V2193 => 2435,
+        Huge::V2194 => 2432,
+        Huge::V2195 => 2433,
+        Huge::V2196 => 2438,
+        Huge::V2197 => 2439,
+        Huge::V2198 => 2436,
+        Huge::V2199 => 2437,
+        Huge::V2200 => 2443,
+        Huge::V2201 => 2442,
+        Huge::V2202 => 2441,
+        Huge::V2203 => 2440,
+        Huge::V2204 => 2447,
+        Huge::V2205 => 2446,
+        Huge::V2206 => 2445,
+        Huge::V2207 => 2444,
+        Huge::V2208 => 2484,
+        Huge::V2209 => 2485,
+        Huge::V2210 => 2486,
+        Huge::V2211 => 2487,
+        Huge::V2212 => 2480,
+        Huge::V2213 => 2481,
+        Huge::V2214 => 2482,
+        Huge::V2215 => 2483,
+        Huge::V2216 => 2493,
+        Huge::V2217 => 2492,
+        Huge::V2218 => 2495,
+        Huge::V2219 => 2494,
+        Huge::V2220 => 2489,
+        Huge::V2221 => 2488,
+        Huge::V2222 => 2491,
+        Huge::V2223 => 2490,
+        Huge::V2224 => 2470,
+        Huge::V2225 => 2471,
+        Huge::V2226 => 2468,
+        Huge::V2227 => 2469,
+        Huge::V2228 => 2466,
+        Huge::V2229 => 2467,
+        Huge::V2230 => 2464,
+        Huge::V2231 => 2465,
+        Huge::V2232 => 2479,
+        Huge::V2233 => 2478,
+        Huge::V2234 => 2477,
+        Huge::V2235 => 2476,
+        Huge::V2236 => 2475,
+        Huge::V2237 => 2474,
+        Huge::V2238 => 2473,
+        Huge::V2239 => 2472,
+        Huge::V2240 => 2520,
+        Huge::V2241 => 2521,
+        Huge::V2242 => 2522,
+        Huge::V2243 => 2523,
+        Huge::V2244 => 2524,
+        Huge::V2245 => 2525,
+        Huge::V2246 => 2526,
+        Huge::V2247 => 2527,
+        Huge::V2248 => 2513,
+        Huge::V2249 => 2512,
+        Huge::V2250 => 2515,
+        Huge::V2251 => 2514,
+        Huge::V2252 => 2517,
+        Huge::V2253 => 2516,
+        Huge::V2254 => 2519,
+        Huge::V2255 => 2518,
+        Huge::V2256 => 2506,
+        Huge::V2257 => 2507,
+        Huge::V2258 => 2504,
+        Huge::V2259 => 2505,
+        Huge::V2260 => 2510,
+        Huge::V2261 => 2511,
+        Huge::V2262 => 2508,
+        Huge::V2263 => 2509,
+        Huge::V2264 => 2499,
+        Huge::V2265 => 2498,
+        Huge::V2266 => 2497,
+        Huge::V2267 => 2496,
+        Huge::V2268 => 2503,
+        Huge::V2269 => 2502,
+        Huge::V2270 => 2501,
+        Huge::V2271 => 2500,
+        Huge::V2272 => 2556,
+        Huge::V2273 => 2557,
+        Huge::V2274 => 2558,
+        Huge::V2275 => 2559,
+        Huge::V2276 => 2552,
+        Huge::V2277 => 2553,
+        Huge::V2278 => 2554,
+        Huge::V2279 => 2555,
+        Huge::V2280 => 2549,
+        Huge::V2281 => 2548,
+        Huge::V2282 => 2551,
+        Huge::V2283 => 2550,
+        Huge::V2284 => 2545,
+        Huge::V2285 => 2544,
+        Huge::V2286 => 2547,
+        Huge::V2287 => 2546,
+        Huge::V2288 => 2542,
+        Huge::V2289 => 2543,
+        Huge::V2290 => 2540,
+        Huge::V2291 => 2541,
+        Huge::V2292 => 2538,
+        Huge::V2293 => 2539,
+        Huge::V2294 => 2536,
+        Huge::V2295 => 2537,
+        Huge::V2296 => 2535,
+        Huge::V2297 => 2534,
+        Huge::V2298 => 2533,
+        Huge::V2299 => 2532,
+        Huge::V2300 => 2531,
+        Huge::V2301 => 2530,
+        Huge::V2302 => 2529,
+        Huge::V2303 => 2528,
+        Huge::V2304 => 2080,
+        Huge::V2305 => 2081,
+        Huge::V2306 => 2082,
+        Huge::V2307 => 2083,
+        Huge::V2308 => 2084,
+        Huge::V2309 => 2085,
+        Huge::V2310 => 2086,
+        Huge::V2311 => 2087,
+        Huge::V2312 => 2089,
+        Huge::V2313 => 2088,
+        Huge::V2314 => 2091,
+        Huge::V2315 => 2090,
+        Huge::V2316 => 2093,
+        Huge::V2317 => 2092,
+        Huge::V2318 => 2095,
+        Huge::V2319 => 2094,
+        Huge::V2320 => 2098,
+        Huge::V2321 => 2099,
+        Huge::V2322 => 2096,
+        Huge::V2323 => 2097,
+        Huge::V2324 => 2102,
+        Huge::V2325 => 2103,
+        Huge::V2326 => 2100,
+        Huge::V2327 => 2101,
+        Huge::V2328 => 2107,
+        Huge::V2329 => 2106,
+        Huge::V2330 => 2105,
+        Huge::V2331 => 2104,
+        Huge::V2332 => 2111,
+        Huge::V2333 => 2110,
+        Huge::V2334 => 2109,
+        Huge::V2335 => 2108,
+        Huge::V2336 => 2052,
+        Huge::V2337 => 2053,
+        Huge::V2338 => 2054,
+        Huge::V2339 => 2055,
+        Huge::V2340 => 2048,
+        Huge::V2341 => 2049,
+        Huge::V2342 => 2050,
+        Huge::V2343 => 2051,
+        Huge::V2344 => 2061,
+        Huge::V2345 => 2060,
+        Huge::V2346 => 2063,
+        Huge::V2347 => 2062,
+        Huge::V2348 => 2057,
+        Huge::V2349 => 2056,
+        Huge::V2350 => 2059,
+        Huge::V2351 => 2058,
+        Huge::V2352 => 2070,
+        Huge::V2353 => 2071,
+        Huge::V2354 => 2068,
+        Huge::V2355 => 2069,
+        Huge::V2356 => 2066,
+        Huge::V2357 => 2067,
+        Huge::V2358 => 2064,
+        Huge::V2359 => 2065,
+        Huge::V2360 => 2079,
+        Huge::V2361 => 2078,
+        Huge::V2362 => 2077,
+        Huge::V2363 => 2076,
+        Huge::V2364 => 2075,
+        Huge::V2365 => 2074,
+        Huge::V2366 => 2073,
+        Huge::V2367 => 2072,
+        Huge::V2368 => 2152,
+        Huge::V2369 => 2153,
+        Huge::V2370 => 2154,
+        Huge::V2371 => 2155,
+        Huge::V2372 => 2156,
+        Huge::V2373 => 2157,
+        Huge::V2374 => 2158,
+        Huge::V2375 => 2159,
+        Huge::V2376 => 2145,
+        Huge::V2377 => 2144,
+        Huge::V2378 => 2147,
+        Huge::V2379 => 2146,
+        Huge::V2380 => 2149,
+        Huge::V2381 => 2148,
+        Huge::V2382 => 2151,
+        Huge::V2383 => 2150,
+        Huge::V2384 => 2170,
+        Huge::V2385 => 2171,
+        Huge::V2386 => 2168,
+        Huge::V2387 => 2169,
+        Huge::V2388 => 2174,
+        Huge::V2389 => 2175,
+        Huge::V2390 => 2172,
+        Huge::V2391 => 2173,
+        Huge::V2392 => 2163,
+        Huge::V2393 => 2162,
+        Huge::V2394 => 2161,
+        Huge::V2395 => 2160,
+        Huge::V2396 => 2167,
+        Huge::V2397 => 2166,
+        Huge::V2398 => 2165,
+        Huge::V2399 => 2164,
+        Huge::V2400 => 2124,
+        Huge::V2401 => 2125,
+        Huge::V2402 => 2126,
+        Huge::V2403 => 2127,
+        Huge::V2404 => 2120,
+        Huge::V2405 => 2121,
+        Huge::V2406 => 2122,
+        Huge::V2407 => 2123,
+        Huge::V2408 => 2117,
+        Huge::V2409 => 2116,
+        Huge::V2410 => 2119,
+        Huge::V2411 => 2118,
+        Huge::V2412 => 2113,
+        Huge::V2413 => 2112,
+        Huge::V2414 => 2115,
+        Huge::V2415 => 2114,
+        Huge::V2416 => 2142,
+        Huge::V2417 => 2143,
+        Huge::V2418 => 2140,
+        Huge::V2419 => 2141,
+        Huge::V2420 => 2138,
+        Huge::V2421 => 2139,
+        Huge::V2422 => 2136,
+        Huge::V2423 => 2137,
+        Huge::V2424 => 2135,
+        Huge::V2425 => 2134,
+        Huge::V2426 => 2133,
+        Huge::V2427 => 2132,
+        Huge::V2428 => 2131,
+        Huge::V2429 => 2130,
+        Huge::V2430 => 2129,
+        Huge::V2431 => 2128,
+        Huge::V2432 => 2224,
+        Huge::V2433 => 2225,
+        Huge::V2434 => 2226,
+        Huge::V2435 => 2227,
+        Huge::V2436 => 2228,
+        Huge::V2437 => 2229,
+        Huge::V2438 => 2230,
+        Huge::V2439 => 2231,
+        Huge::V2440 => 2233,
+        Huge::V2441 => 2232,
+        Huge::V2442 => 2235,
+        Huge::V2443 => 2234,
+        Huge::V2444 => 2237,
+        Huge::V2445 => 2236,
+        Huge::V2446 => 2239,
+        Huge::V2447 => 2238,
+        Huge::V2448 => 2210,
+        Huge::V2449 => 2211,
+        Huge::V2450 => 2208,
+        Huge::V2451 => 2209,
+        Huge::V2452 => 2214,
+        Huge::V2453 => 2215,
+        Huge::V2454 => 2212,
+        Huge::V2455 => 2213,
+        Huge::V2456 => 2219,
+        Huge::V2457 => 2218,
+        Huge::V2458 => 2217,
+        Huge::V2459 => 2216,
+        Huge::V2460 => 2223,
+        Huge::V2461 => 2222,
+        Huge::V2462 => 2221,
+        Huge::V2463 => 2220,
+        Huge::V2464 => 2196,
+        Huge::V2465 => 2197,
+        Huge::V2466 => 2198,
+        Huge::V2467 => 2199,
+        Huge::V2468 => 2192,
+        Huge::V2469 => 2193,
+        Huge::V2470 => 2194,
+        Huge::V2471 => 2195,
+        Huge::V2472 => 2205,
+        Huge::V2473 => 2204,
+        Huge::V2474 => 2207,
+        Huge::V2475 => 2206,
+        Huge::V2476 => 2201,
+        Huge::V2477 => 2200,
+        Huge::V2478 => 2203,
+        Huge::V2479 => 2202,
+        Huge::V2480 => 2182,
+        Huge::V2481 => 2183,
+        Huge::V2482 => 2180,
+        Huge::V2483 => 2181,
+        Huge::V2484 => 2178,
+        Huge::V2485 => 2179,
+        Huge::V2486 => 2176,
+        Huge::V2487 => 2177,
+        Huge::V2488 => 2191,
+        Huge::V2489 => 2190,
+        Huge::V2490 => 2189,
+        Huge::V2491 => 2188,
+        Huge::V2492 => 2187,
+        Huge::V2493 => 2186,
+        Huge::V2494 => 2185,
+        Huge::V2495 => 2184,
+        Huge::V2496 => 2296,
+        Huge::V2497 => 2297,
+        Huge::V2498 => 2298,
+        Huge::V2499 => 2299,
+        Huge::V2500 => 2300,
+        Huge::V2501 => 2301,
+        Huge::V2502 => 2302,
+        Huge::V2503 => 2303,
+        Huge::V2504 => 2289,
+        Huge::V2505 => 2288,
+        Huge::V2506 => 2291,
+        Huge::V2507 => 2290,
+        Huge::V2508 => 2293,
+        Huge::V2509 => 2292,
+        Huge::V2510 => 2295,
+        Huge::V2511 => 2294,
+        Huge::V2512 => 2282,
+        Huge::V2513 => 2283,
+        Huge::V2514 => 2280,
+        Huge::V2515 => 2281,
+        Huge::V2516 => 2286,
+        Huge::V2517 => 2287,
+        Huge::V2518 => 2284,
+        Huge::V2519 => 2285,
+        Huge::V2520 => 2275,
+        Huge::V2521 => 2274,
+        Huge::V2522 => 2273,
+        Huge::V2523 => 2272,
+        Huge::V2524 => 2279,
+        Huge::V2525 => 2278,
+        Huge::V2526 => 2277,
+        Huge::V2527 => 2276,
+        Huge::V2528 => 2268,
+        Huge::V2529 => 2269,
+        Huge::V2530 => 2270,
+        Huge::V2531 => 2271,
+        Huge::V2532 => 2264,
+        Huge::V2533 => 2265,
+        Huge::V2534 => 2266,
+        Huge::V2535 => 2267,
+        Huge::V2536 => 2261,
+        Huge::V2537 => 2260,
+        Huge::V2538 => 2263,
+        Huge::V2539 => 2262,
+        Huge::V2540 => 2257,
+        Huge::V2541 => 2256,
+        Huge::V2542 => 2259,
+        Huge::V2543 => 2258,
+        Huge::V2544 => 2254,
+        Huge::V2545 => 2255,
+        Huge::V2546 => 2252,
+        Huge::V2547 => 2253,
+        Huge::V2548 => 2250,
+        Huge::V2549 => 2251,
+        Huge::V2550 => 2248,
+        Huge::V2551 => 2249,
+        Huge::V2552 => 2247,
+        Huge::V2553 => 2246,
+        Huge::V2554 => 2245,
+        Huge::V2555 => 2244,
+        Huge::V2556 => 2243,
+        Huge::V2557 => 2242,
+        Huge::V2558 => 2241,
+        Huge::V2559 => 2240,
+        Huge::V2560 => 2880,
+        Huge::V2561 => 2881,
+        Huge::V2562 => 2882,
+        Huge::V2563 => 2883,
+        Huge::V2564 => 2884,
+        Huge::V2565 => 2885,
+        Huge::V2566 => 2886,
+        Huge::V2567 => 2887,
+        Huge::V2568 => 2889,
+        Huge::V2569 => 2888,
+        Huge::V2570 => 2891,
+        Huge::V2571 => 2890,
+        Huge::V2572 => 2893,
+        Huge::V2573 => 2892,
+        Huge::V2574 => 2895,
+        Huge::V2575 => 2894,
+        Huge::V2576 => 2898,
+        Huge::V2577 => 2899,
+        Huge::V2578 => 2896,
+        Huge::V2579 => 2897,
+        Huge::V2580 => 2902,
+        Huge::V2581 => 2903,
+        Huge::V2582 => 2900,
+        Huge::V2583 => 2901,
+        Huge::V2584 => 2907,
+        Huge::V2585 => 2906,
+        Huge::V2586 => 2905,
+        Huge::V2587 => 2904,
+        Huge::V2588 => 2911,
+        Huge::V2589 => 2910,
+        Huge::V2590 => 2909,
+        Huge::V2591 => 2908,
+        Huge::V2592 => 2916,
+        Huge::V2593 => 2917,
+        Huge::V2594 => 2918,
+        Huge::V2595 => 2919,
+        Huge::V2596 => 2912,
+        Huge::V2597 => 2913,
+        Huge::V2598 => 2914,
+        Huge::V2599 => 2915,
+        Huge::V2600 => 2925,
+        Huge::V2601 => 2924,
+        Huge::V2602 => 2927,
+        Huge::V2603 => 2926,
+        Huge::V2604 => 2921,
+        Huge::V2605 => 2920,
+        Huge::V2606 => 2923,
+        Huge::V2607 => 2922,
+        Huge::V2608 => 2934,
+        Huge::V2609 => 2935,
+        Huge::V2610 => 2932,
+        Huge::V2611 => 2933,
+        Huge::V2612 => 2930,
+        Huge::V2613 => 2931,
+        Huge::V2614 => 2928,
+        Huge::V2615 => 2929,
+        Huge::V2616 => 2943,
+        Huge::V2617 => 2942,
+        Huge::V2618 => 2941,
+        Huge::V2619 => 2940,
+        Huge::V2620 => 2939,
+        Huge::V2621 => 2938,
+        Huge::V2622 => 2937,
+        Huge::V2623 => 2936,
+        Huge::V2624 => 2824,
+        Huge::V2625 => 2825,
+        Huge::V2626 => 2826,
+        Huge::V2627 => 2827,
+        Huge::V2628 => 2828,
+        Huge::V2629 => 2829,
+        Huge::V2630 => 2830,
+        Huge::V2631 => 2831,
+        Huge::V2632 => 2817,
+        Huge::V2633 => 2816,
+        Huge::V2634 => 2819,
+        Huge::V2635 => 2818,
+        Huge::V2636 => 2821,
+        Huge::V2637 => 2820,
+        Huge::V2638 => 2823,
+        Huge::V2639 => 2822,
+        Huge::V2640 => 2842,
+        Huge::V2641 => 2843,
+        Huge::V2642 => 2840,
+        Huge::V2643 => 2841,
+        Huge::V2644 => 2846,
+        Huge::V2645 => 2847,
+        Huge::V2646 => 2844,
+        Huge::V2647 => 2845,
+        Huge::V2648 => 2835,
+        Huge::V2649 => 2834,
+        Huge::V2650 => 2833,
+        Huge::V2651 => 2832,
+        Huge::V2652 => 2839,
+        Huge::V2653 => 2838,
+        Huge::V2654 => 2837,
+        Huge::V2655 => 2836,
+        Huge::V2656 => 2860,
+        Huge::V2657 => 2861,
+        Huge::V2658 => 2862,
+        Huge::V2659 => 2863,
+        Huge::V2660 => 2856,
+        Huge::V2661 => 2857,
+        Huge::V2662 => 2858,
+        Huge::V2663 => 2859,
+        Huge::V2664 => 2853,
+        Huge::V2665 => 2852,
+        Huge::V2666 => 2855,
+        Huge::V2667 => 2854,
+        Huge::V2668 => 2849,
+        Huge::V2669 => 2848,
+        Huge::V2670 => 2851,
+        Huge::V2671 => 2850,
+        Huge::V2672 => 2878,
+        Huge::V2673 => 2879,
+        Huge::V2674 => 2876,
+        Huge::V2675 => 2877,
+        Huge::V2676 => 2874,
+        Huge::V2677 => 2875,
+        Huge::V2678 => 2872,
+        Huge::V2679 => 2873,
+        Huge::V2680 => 2871,
+        Huge::V2681 => 2870,
+        Huge::V2682 => 2869,
+        Huge::V2683 => 2868,
+        Huge::V2684 => 2867,
+        Huge::V2685 => 2866,
+        Huge::V2686 => 2865,
+        Huge::V2687 => 2864,
+        Huge::V2688 => 3024,
+        Huge::V2689 => 3025,
+        Huge::V2690 => 3026,
+        Huge::V2691 => 3027,
+        Huge::V2692 => 3028,
+        Huge::V2693 => 3029,
+        Huge::V2694 => 3030,
+        Huge::V2695 => 3031,
+        Huge::V2696 => 3033,
+        Huge::V2697 => 3032,
+        Huge::V2698 => 3035,
+        Huge::V2699 => 3034,
+        Huge::V2700 => 3037,
+        Huge::V2701 => 3036,
+        Huge::V2702 => 3039,
+        Huge::V2703 => 3038,
+        Huge::V2704 => 3010,
+        Huge::V2705 => 3011,
+        Huge::V2706 => 3008,
+        Huge::V2707 => 3009,
+        Huge::V2708 => 3014,
+        Huge::V2709 => 3015,
+        Huge::V2710 => 3012,
+        Huge::V2711 => 3013,
+        Huge::V2712 => 3019,
+        Huge::V2713 => 3018,
+        Huge::V2714 => 3017,
+        Huge::V2715 => 3016,
+        Huge::V2716 => 3023,
+        Huge::V2717 => 3022,
+        Huge::V2718 => 3021,
+        Huge::V2719 => 3020,
+        Huge::V2720 => 3060,
+        Huge::V2721 => 3061,
+        Huge::V2722 => 3062,
+        Huge::V2723 => 3063,
+        Huge::V2724 => 3056,
+        Huge::V2725 => 3057,
+        Huge::V2726 => 3058,
+        Huge::V2727 => 3059,
+        Huge::V2728 => 3069,
+        Huge::V2729 => 3068,
+        Huge::V2730 => 3071,
+        Huge::V2731 => 3070,
+        Huge::V2732 => 3065,
+        Huge::V2733 => 3064,
+        Huge::V2734 => 3067,
+        Huge::V2735 => 3066,
+        Huge::V2736 => 3046,
+        Huge::V2737 => 3047,
+        Huge::V2738 => 3044,
+        Huge::V2739 => 3045,
+        Huge::V2740 => 3042,
+        Huge::V2741 => 3043,
+        Huge::V2742 => 3040,
+        Huge::V2743 => 3041,
+        Huge::V2744 => 3055,
+        Huge::V2745 => 3054,
+        Huge::V2746 => 3053,
+        Huge::V2747 => 3052,
+        Huge::V2748 => 3051,
+        Huge::V2749 => 3050,
+        Huge::V2750 => 3049,
+        Huge::V2751 => 3048,
+        Huge::V2752 => 2968,
+        Huge::V2753 => 2969,
+        Huge::V2754 => 2970,
+        Huge::V2755 => 2971,
+        Huge::V2756 => 2972,
+        Huge::V2757 => 2973,
+        Huge::V2758 => 2974,
+        Huge::V2759 => 2975,
+        Huge::V2760 => 2961,
+        Huge::V2761 => 2960,
+        Huge::V2762 => 2963,
+        Huge::V2763 => 2962,
+        Huge::V2764 => 2965,
+        Huge::V2765 => 2964,
+        Huge::V2766 => 2967,
+        Huge::V2767 => 2966,
+        Huge::V2768 => 2954,
+        Huge::V2769 => 2955,
+        Huge::V2770 => 2952,
+        Huge::V2771 => 2953,
+        Huge::V2772 => 2958,
+        Huge::V2773 => 2959,
+        Huge::V2774 => 2956,
+        Huge::V2775 => 2957,
+        Huge::V2776 => 2947,
+        Huge::V2777 => 2946,
+        Huge::V2778 => 2945,
+        Huge::V2779 => 2944,
+        Huge::V2780 => 2951,
+        Huge::V2781 => 2950,
+        Huge::V2782 => 2949,
+        Huge::V2783 => 2948,
+        Huge::V2784 => 3004,
+        Huge::V2785 => 3005,
+        Huge::V2786 => 3006,
+        Huge::V2787 => 3007,
+        Huge::V2788 => 3000,
+        Huge::V2789 => 3001,
+        Huge::V2790 => 3002,
+        Huge::V2791 => 3003,
+        Huge::V2792 => 2997,
+        Huge::V2793 => 2996,
+        Huge::V2794 => 2999,
+        Huge::V2795 => 2998,
+        Huge::V2796 => 2993,
+        Huge::V2797 => 2992,
+        Huge::V2798 => 2995,
+        Huge::V2799 => 2994,
+        Huge::V2800 => 2990,
+        Huge::V2801 => 2991,
+        Huge::V2802 => 2988,
+        Huge::V2803 => 2989,
+        Huge::V2804 => 2986,
+        Huge::V2805 => 2987,
+        Huge::V2806 => 2984,
+        Huge::V2807 => 2985,
+        Huge::V2808 => 2983,
+        Huge::V2809 => 2982,
+        Huge::V2810 => 2981,
+        Huge::V2811 => 2980,
+        Huge::V2812 => 2979,
+        Huge::V2813 => 2978,
+        Huge::V2814 => 2977,
+        Huge::V2815 => 2976,
+        Huge::V2816 => 2656,
+        Huge::V2817 => 2657,
+        Huge::V2818 => 2658,
+        Huge::V2819 => 2659,
+        Huge::V2820 => 2660,
+        Huge::V2821 => 2661,
+        Huge::V2822 => 2662,
+        Huge::V2823 => 2663,
+        Huge::V2824 => 2665,
+        Huge::V2825 => 2664,
+        Huge::V2826 => 2667,
+        Huge::V2827 => 2666,
+        Huge::V2828 => 2669,
+        Huge::V2829 => 2668,
+        Huge::V2830 => 2671,
+        Huge::V2831 => 2670,
+        Huge::V2832 => 2674,
+        Huge::V2833 => 2675,
+        Huge::V2834 => 2672,
+        Huge::V2835 => 2673,
+        Huge::V2836 => 2678,
+        Huge::V2837 => 2679,
+        Huge::V2838 => 2676,
+        Huge::V2839 => 2677,
+        Huge::V2840 => 2683,
+        Huge::V2841 => 2682,
+        Huge::V2842 => 2681,
+        Huge::V2843 => 2680,
+        Huge::V2844 => 2687,
+        Huge::V2845 => 2686,
+        Huge::V2846 => 2685,
+        Huge::V2847 => 2684,
+        Huge::V2848 => 2628,
+        Huge::V2849 => 2629,
+        Huge::V2850 => 2630,
+        Huge::V2851 => 2631,
+        Huge::V2852 => 2624,
+        Huge::V2853 => 2625,
+        Huge::V2854 => 2626,
+        Huge::V2855 => 2627,
+        Huge::V2856 => 2637,
+        Huge::V2857 => 2636,
+        Huge::V2858 => 2639,
+        Huge::V2859 => 2638,
+        Huge::V2860 => 2633,
+        Huge::V2861 => 2632,
+        Huge::V2862 => 2635,
+        Huge::V2863 => 2634,
+        Huge::V2864 => 2646,
+        Huge::V2865 => 2647,
+        Huge::V2866 => 2644,
+        Huge::V2867 => 2645,
+        Huge::V2868 => 2642,
+        Huge::V2869 => 2643,
+        Huge::V2870 => 2640,
+        Huge::V2871 => 2641,
+        Huge::V2872 => 2655,
+        Huge::V2873 => 2654,
+        Huge::V2874 => 2653,
+        Huge::V2875 => 2652,
+        Huge::V2876 => 2651,
+        Huge::V2877 => 2650,
+        Huge::V2878 => 2649,
+        Huge::V2879 => 2648,
+        Huge::V2880 => 2600,
+        Huge::V2881 => 2601,
+        Huge::V2882 => 2602,
+        Huge::V2883 => 2603,
+        Huge::V2884 => 2604,
+        Huge::V2885 => 2605,
+        Huge::V2886 => 2606,
+        Huge::V2887 => 2607,
+        Huge::V2888 => 2593,
+        Huge::V2889 => 2592,
+        Huge::V2890 => 2595,
+        Huge::V2891 => 2594,
+        Huge::V2892 => 2597,
+        Huge::V2893 => 2596,
+        Huge::V2894 => 2599,
+        Huge::V2895 => 2598,
+        Huge::V2896 => 2618,
+        Huge::V2897 => 2619,
+        Huge::V2898 => 2616,
+        Huge::V2899 => 2617,
+        Huge::V2900 => 2622,
+        Huge::V2901 => 2623,
+        Huge::V2902 => 2620,
+        Huge::V2903 => 2621,
+        Huge::V2904 => 2611,
+        Huge::V2905 => 2610,
+        Huge::V2906 => 2609,
+        Huge::V2907 => 2608,
+        Huge::V2908 => 2615,
+        Huge::V2909 => 2614,
+        Huge::V2910 => 2613,
+        Huge::V2911 => 2612,
+        Huge::V2912 => 2572,
+        Huge::V2913 => 2573,
+        Huge::V2914 => 2574,
+        Huge::V2915 => 2575,
+        Huge::V2916 => 2568,
+        Huge::V2917 => 2569,
+        Huge::V2918 => 2570,
+        Huge::V2919 => 2571,
+        Huge::V2920 => 2565,
+        Huge::V2921 => 2564,
+        Huge::V2922 => 2567,
+        Huge::V2923 => 2566,
+        Huge::V2924 => 2561,
+        Huge::V2925 => 2560,
+        Huge::V2926 => 2563,
+        Huge::V2927 => 2562,
+        Huge::V2928 => 2590,
+        Huge::V2929 => 2591,
+        Huge::V2930 => 2588,
+        Huge::V2931 => 2589,
+        Huge::V2932 => 2586,
+        Huge::V2933 => 2587,
+        Huge::V2934 => 2584,
+        Huge::V2935 => 2585,
+        Huge::V2936 => 2583,
+        Huge::V2937 => 2582,
+        Huge::V2938 => 2581,
+        Huge::V2939 => 2580,
+        Huge::V2940 => 2579,
+        Huge::V2941 => 2578,
+        Huge::V2942 => 2577,
+        Huge::V2943 => 2576,
+        Huge::V2944 => 2800,
+        Huge::V2945 => 2801,
+        Huge::V2946 => 2802,
+        Huge::V2947 => 2803,
+        Huge::V2948 => 2804,
+        Huge::V2949 => 2805,
+        Huge::V2950 => 2806,
+        Huge::V2951 => 2807,
+        Huge::V2952 => 2809,
+        Huge::V2953 => 2808,
+        Huge::V2954 => 2811,
+        Huge::V2955 => 2810,
+        Huge::V2956 => 2813,
+        Huge::V2957 => 2812,
+        Huge::V2958 => 2815,
+        Huge::V2959 => 2814,
+        Huge::V2960 => 2786,
+        Huge::V2961 => 2787,
+        Huge::V2962 => 2784,
+        Huge::V2963 => 2785,
+        Huge::V2964 => 2790,
+        Huge::V2965 => 2791,
+        Huge::V2966 => 2788,
+        Huge::V2967 => 2789,
+        Huge::V2968 => 2795,
+        Huge::V2969 => 2794,
+        Huge::V2970 => 2793,
+        Huge::V2971 => 2792,
+        Huge::V2972 => 2799,
+        Huge::V2973 => 2798,
+        Huge::V2974 => 2797,
+        Huge::V2975 => 2796,
+        Huge::V2976 => 2772,
+        Huge::V2977 => 2773,
+        Huge::V2978 => 2774,
+        Huge::V2979 => 2775,
+        Huge::V2980 => 2768,
+        Huge::V2981 => 2769,
+        Huge::V2982 => 2770,
+        Huge::V2983 => 2771,
+        Huge::V2984 => 2781,
+        Huge::V2985 => 2780,
+        Huge::V2986 => 2783,
+        Huge::V2987 => 2782,
+        Huge::V2988 => 2777,
+        Huge::V2989 => 2776,
+        Huge::V2990 => 2779,
+        Huge::V2991 => 2778,
+        Huge::V2992 => 2758,
+        Huge::V2993 => 2759,
+        Huge::V2994 => 2756,
+        Huge::V2995 => 2757,
+        Huge::V2996 => 2754,
+        Huge::V2997 => 2755,
+        Huge::V2998 => 2752,
+        Huge::V2999 => 2753,
+        Huge::V3000 => 2767,
+        Huge::V3001 => 2766,
+        Huge::V3002 => 2765,
+        Huge::V3003 => 2764,
+        Huge::V3004 => 2763,
+        Huge::V3005 => 2762,
+        Huge::V3006 => 2761,
+        Huge::V3007 => 2760,
+        Huge::V3008 => 2744,
+        Huge::V3009 => 2745,
+        Huge::V3010 => 2746,
+        Huge::V3011 => 2747,
+        Huge::V3012 => 2748,
+        Huge::V3013 => 2749,
+        Huge::V3014 => 2750,
+        Huge::V3015 => 2751,
+        Huge::V3016 => 2737,
+        Huge::V3017 => 2736,
+        Huge::V3018 => 2739,
+        Huge::V3019 => 2738,
+        Huge::V3020 => 2741,
+        Huge::V3021 => 2740,
+        Huge::V3022 => 2743,
+        Huge::V3023 => 2742,
+        Huge::V3024 => 2730,
+        Huge::V3025 => 2731,
+        Huge::V3026 => 2728,
+        Huge::V3027 => 2729,
+        Huge::V3028 => 2734,
+        Huge::V3029 => 2735,
+        Huge::V3030 => 2732,
+        Huge::V3031 => 2733,
+        Huge::V3032 => 2723,
+        Huge::V3033 => 2722,
+        Huge::V3034 => 2721,
+        Huge::V3035 => 2720,
+        Huge::V3036 => 2727,
+        Huge::V3037 => 2726,
+        Huge::V3038 => 2725,
+        Huge::V3039 => 2724,
+        Huge::V3040 => 2716,
+        Huge::V3041 => 2717,
+        Huge::V3042 => 2718,
+        Huge::V3043 => 2719,
+        Huge::V3044 => 2712,
+        Huge::V3045 => 2713,
+        Huge::V3046 => 2714,
+        Huge::V3047 => 2715,
+        Huge::V3048 => 2709,
+        Huge::V3049 => 2708,
+        Huge::V3050 => 2711,
+        Huge::V3051 => 2710,
+        Huge::V3052 => 2705,
+        Huge::V3053 => 2704,
+        Huge::V3054 => 2707,
+        Huge::V3055 => 2706,
+        Huge::V3056 => 2702,
+        Huge::V3057 => 2703,
+        Huge::V3058 => 2700,
+        Huge::V3059 => 2701,
+        Huge::V3060 => 2698,
+        Huge::V3061 => 2699,
+        Huge::V3062 => 2696,
+        Huge::V3063 => 2697,
+        Huge::V3064 => 2695,
+        Huge::V3065 => 2694,
+        Huge::V3066 => 2693,
+        Huge::V3067 => 2692,
+        Huge::V3068 => 2691,
+        Huge::V3069 => 2690,
+        Huge::V3070 => 2689,
+        Huge::V3071 => 2688,
+        Huge::V3072 => 3456,
+        Huge::V3073 => 3457,
+        Huge::V3074 => 3458,
+        Huge::V3075 => 3459,
+        Huge::V3076 => 3460,
+        Huge::V3077 => 3461,
+        Huge::V3078 => 3462,
+        Huge::V3079 => 3463,
+        Huge::V3080 => 3465,
+        Huge::V3081 => 3464,
+        Huge::V3082 => 3467,
+        Huge::V3083 => 3466,
+        Huge::V3084 => 3469,
+        Huge::V3085 => 3468,
+        Huge::V3086 => 3471,
+        Huge::V3087 => 3470,
+        Huge::V3088 => 3474,
+        Huge::V3089 => 3475,
+        Huge::V3090 => 3472,
+        Huge::V3091 => 3473,
+        Huge::V3092 => 3478,
+        Huge::V3093 => 3479,
+        Huge::V3094 => 3476,
+        Huge::V3095 => 3477,
+        Huge::V3096 => 3483,
+        Huge::V3097 => 3482,
+        Huge::V3098 => 3481,
+        Huge::V3099 => 3480,
+        Huge::V3100 => 3487,
+        Huge::V3101 => 3486,
+        Huge::V3102 => 3485,
+        Huge::V3103 => 3484,
+        Huge::V3104 => 3492,
+        Huge::V3105 => 3493,
+        Huge::V3106 => 3494,
+        Huge::V3107 => 3495,
+        Huge::V3108 => 3488,
+        Huge::V3109 => 3489,
+        Huge::V3110 => 3490,
+        Huge::V3111 => 3491,
+        Huge::V3112 => 3501,
+        Huge::V3113 => 3500,
+        Huge::V3114 => 3503,
+        Huge::V3115 => 3502,
+        Huge::V3116 => 3497,
+        Huge::V3117 => 3496,
+        Huge::V3118 => 3499,
+        Huge::V3119 => 3498,
+        Huge::V3120 => 3510,
+        Huge::V3121 => 3511,
+        Huge::V3122 => 3508,
+        Huge::V3123 => 3509,
+        Huge::V3124 => 3506,
+        Huge::V3125 => 3507,
+        Huge::V3126 => 3504,
+        Huge::V3127 => 3505,
+        Huge::V3128 => 3519,
+        Huge::V3129 => 3518,
+        Huge::V3130 => 3517,
+        Huge::V3131 => 3516,
+        Huge::V3132 => 3515,
+        Huge::V3133 => 3514,
+        Huge::V3134 => 3513,
+        Huge::V3135 => 3512,
+        Huge::V3136 => 3528,
+        Huge::V3137 => 3529,
+        Huge::V3138 => 3530,
+        Huge::V3139 => 3531,
+        Huge::V3140 => 3532,
+        Huge::V3141 => 3533,
+        Huge::V3142 => 3534,
+        Huge::V3143 => 3535,
+        Huge::V3144 => 3521,
+        Huge::V3145 => 3520,
+        Huge::V3146 => 3523,
+        Huge::V3147 => 3522,
+        Huge::V3148 => 3525,
+        Huge::V3149 => 3524,
+        Huge::V3150 => 3527,
+        Huge::V3151 => 3526,
+        Huge::V3152 => 3546,
+        Huge::V3153 => 3547,
+        Huge::V3154 => 3544,
+        Huge::V3155 => 3545,
+        Huge::V3156 => 3550,
+        Huge::V3157 => 3551,
+        Huge::V3158 => 3548,
+        Huge::V3159 => 3549,
+        Huge::V3160 => 3539,
+        Huge::V3161 => 3538,
+        Huge::V3162 => 3537,
+        Huge::V3163 => 3536,
+        Huge::V3164 => 3543,
+        Huge::V3165 => 3542,
+        Huge::V3166 => 3541,
+        Huge::V3167 => 3540,
+        Huge::V3168 => 3564,
+        Huge::V3169 => 3565,
+        Huge::V3170 => 3566,
+        Huge::V3171 => 3567,
+        Huge::V3172 => 3560,
+        Huge::V3173 => 3561,
+        Huge::V3174 => 3562,
+        Huge::V3175 => 3563,
+        Huge::V3176 => 3557,
+        Huge::V3177 => 3556,
+        Huge::V3178 => 3559,
+        Huge::V3179 => 3558,
+        Huge::V3180 => 3553,
+        Huge::V3181 => 3552,
+        Huge::V3182 => 3555,
+        Huge::V3183 => 3554,
+        Huge::V3184 => 3582,
+        Huge::V3185 => 3583,
+        Huge::V3186 => 3580,
+        Huge::V3187 => 3581,
+        Huge::V3188 => 3578,
+        Huge::V3189 => 3579,
+        Huge::V3190 => 3576,
+        Huge::V3191 => 3577,
+        Huge::V3192 => 3575,
+        Huge::V3193 => 3574,
+        Huge::V3194 => 3573,
+        Huge::V3195 => 3572,
+        Huge::V3196 => 3571,
+        Huge::V3197 => 3570,
+        Huge::V3198 => 3569,
+        Huge::V3199 => 3568,
+        Huge::V3200 => 3344,
+        Huge::V3201 => 3345,
+        Huge::V3202 => 3346,
+        Huge::V3203 => 3347,
+        Huge::V3204 => 3348,
+        Huge::V3205 => 3349,
+        Huge::V3206 => 3350,
+        Huge::V3207 => 3351,
+        Huge::V3208 => 3353,
+        Huge::V3209 => 3352,
+        Huge::V3210 => 3355,
+        Huge::V3211 => 3354,
+        Huge::V3212 => 3357,
+        Huge::V3213 => 3356,
+        Huge::V3214 => 3359,
+        Huge::V3215 => 3358,
+        Huge::V3216 => 3330,
+        Huge::V3217 => 3331,
+        Huge::V3218 => 3328,
+        Huge::V3219 => 3329,
+        Huge::V3220 => 3334,
+        Huge::V3221 => 3335,
+        Huge::V3222 => 3332,
+        Huge::V3223 => 3333,
+        Huge::V3224 => 3339,
+        Huge::V3225 => 3338,
+        Huge::V3226 => 3337,
+        Huge::V3227 => 3336,
+        Huge::V3228 => 3343,
+        Huge::V3229 => 3342,
+        Huge::V3230 => 3341,
+        Huge::V3231 => 3340,
+        Huge::V3232 => 3380,
+        Huge::V3233 => 3381,
+        Huge::V3234 => 3382,
+        Huge::V3235 => 3383,
+        Huge::V3236 => 3376,
+        Huge::V3237 => 3377,
+        Huge::V3238 => 3378,
+        Huge::V3239 => 3379,
+        Huge::V3240 => 3389,
+        Huge::V3241 => 3388,
+        Huge::V3242 => 3391,
+        Huge::V3243 => 3390,
+        Huge::V3244 => 3385,
+        Huge::V3245 => 3384,
+        Huge::V3246 => 3387,
+        Huge::V3247 => 3386,
+        Huge::V3248 => 3366,
+        Huge::V3249 => 3367,
+        Huge::V3250 => 3364,
+        Huge::V3251 => 3365,
+        Huge::V3252 => 3362,
+        Huge::V3253 => 3363,
+        Huge::V3254 => 3360,
+        Huge::V3255 => 3361,
+        Huge::V3256 => 3375,
+        Huge::V3257 => 3374,
+        Huge::V3258 => 3373,
+        Huge::V3259 => 3372,
+        Huge::V3260 => 3371,
+        Huge::V3261 => 3370,
+        Huge::V3262 => 3369,
+        Huge::V3263 => 3368,
+        Huge::V3264 => 3416,
+        Huge::V3265 => 3417,
+        Huge::V3266 => 3418,
+        Huge::V3267 => 3419,
+        Huge::V3268 => 3420,
+        Huge::V3269 => 3421,
+        Huge::V3270 => 3422,
+        Huge::V3271 => 3423,
+        Huge::V3272 => 3409,
+        Huge::V3273 => 3408,
+        Huge::V3274 => 3411,
+        Huge::V3275 => 3410,
+        Huge::V3276 => 3413,
+        Huge::V3277 => 3412,
+        Huge::V3278 => 3415,
+        Huge::V3279 => 3414,
+        Huge::V3280 => 3402,
+        Huge::V3281 => 3403,
+        Huge::V3282 => 3400,
+        Huge::V3283 => 3401,
+        Huge::V3284 => 3406,
+        Huge::V3285 => 3407,
+        Huge::V3286 => 3404,
+        Huge::V3287 => 3405,
+        Huge::V3288 => 3395,
+        Huge::V3289 => 3394,
+        Huge::V3290 => 3393,
+        Huge::V3291 => 3392,
+        Huge::V3292 => 3399,
+        Huge::V3293 => 3398,
+        Huge::V3294 => 3397,
+        Huge::V3295 => 3396,
+        Huge::V3296 => 3452,
+        Huge::V3297 => 3453,
+        Huge::V3298 => 3454,
+        Huge::V3299 => 3455,
+        Huge::V3300 => 3448,
+        Huge::V3301 => 3449,
+        Huge::V3302 => 3450,
+        Huge::V3303 => 3451,
+        Huge::V3304 => 3445,
+        Huge::V3305 => 3444,
+        Huge::V3306 => 3447,
+        Huge::V3307 => 3446,
+        Huge::V3308 => 3441,
+        Huge::V3309 => 3440,
+        Huge::V3310 => 3443,
+        Huge::V3311 => 3442,
+        Huge::V3312 => 3438,
+        Huge::V3313 => 3439,
+        Huge::V3314 => 3436,
+        Huge::V3315 => 3437,
+        Huge::V3316 => 3434,
+        Huge::V3317 => 3435,
+        Huge::V3318 => 3432,
+        Huge::V3319 => 3433,
+        Huge::V3320 => 3431,
+        Huge::V3321 => 3430,
+        Huge::V3322 => 3429,
+        Huge::V3323 => 3428,
+        Huge::V3324 => 3427,
+        Huge::V3325 => 3426,
+        Huge::V3326 => 3425,
+        Huge::V3327 => 3424,
+        Huge::V3328 => 3232,
+        Huge::V3329 => 3233,
+        Huge::V3330 => 3234,
+        Huge::V3331 => 3235,
+        Huge::V3332 => 3236,
+        Huge::V3333 => 3237,
+        Huge::V3334 => 3238,
+        Huge::V3335 => 3239,
+        Huge::V3336 => 3241,
+        Huge::V3337 => 3240,
+        Huge::V3338 => 3243,
+        Huge::V3339 => 3242,
+        Huge::V3340 => 3245,
+        Huge::V3341 => 3244,
+        Huge::V3342 => 3247,
+        Huge::V3343 => 3246,
+        Huge::V3344 => 3250,
+        Huge::V3345 => 3251,
+        Huge::V3346 => 3248,
+        Huge::V3347 => 3249,
+        Huge::V3348 => 3254,
+        Huge::V3349 => 3255,
+        Huge::V3350 => 3252,
+        Huge::V3351 => 3253,
+        Huge::V3352 => 3259,
+        Huge::V3353 => 3258,
+        Huge::V3354 => 3257,
+        Huge::V3355 => 3256,
+        Huge::V3356 => 3263,
+        Huge::V3357 => 3262,
+        Huge::V3358 => 3261,
+        Huge::V3359 => 3260,
+        Huge::V3360 => 3204,
+        Huge::V3361 => 3205,
+        Huge::V3362 => 3206,
+        Huge::V3363 => 3207,
+        Huge::V3364 => 3200,
+        Huge::V3365 => 3201,
+        Huge::V3366 => 3202,
+        Huge::V3367 => 3203,
+        Huge::V3368 => 3213,
+        Huge::V3369 => 3212,
+        Huge::V3370 => 3215,
+        Huge::V3371 => 3214,
+        Huge::V3372 => 3209,
+        Huge::V3373 => 3208,
+        Huge::V3374 => 3211,
+        Huge::V3375 => 3210,
+        Huge::V3376 => 3222,
+        Huge::V3377 => 3223,
+        Huge::V3378 => 3220,
+        Huge::V3379 => 3221,
+        Huge::V3380 => 3218,
+        Huge::V3381 => 3219,
+        Huge::V3382 => 3216,
+        Huge::V3383 => 3217,
+        Huge::V3384 => 3231,
+        Huge::V3385 => 3230,
+        Huge::V3386 => 3229,
+        Huge::V3387 => 3228,
+        Huge::V3388 => 3227,
+        Huge::V3389 => 3226,
+        Huge::V3390 => 3225,
+        Huge::V3391 => 3224,
+        Huge::V3392 => 3304,
+        Huge::V3393 => 3305,
+        Huge::V3394 => 3306,
+        Huge::V3395 => 3307,
+        Huge::V3396 => 3308,
+        Huge::V3397 => 3309,
+        Huge::V3398 => 3310,
+        Huge::V3399 => 3311,
+        Huge::V3400 => 3297,
+        Huge::V3401 => 3296,
+        Huge::V3402 => 3299,
+        Huge::V3403 => 3298,
+        Huge::V3404 => 3301,
+        Huge::V3405 => 3300,
+        Huge::V3406 => 3303,
+        Huge::V3407 => 3302,
+        Huge::V3408 => 3322,
+        Huge::V3409 => 3323,
+        Huge::V3410 => 3320,
+        Huge::V3411 => 3321,
+        Huge::V3412 => 3326,
+        Huge::V3413 => 3327,
+        Huge::V3414 => 3324,
+        Huge::V3415 => 3325,
+        Huge::V3416 => 3315,
+        Huge::V3417 => 3314,
+        Huge::V3418 => 3313,
+        Huge::V3419 => 3312,
+        Huge::V3420 => 3319,
+        Huge::V3421 => 3318,
+        Huge::V3422 => 3317,
+        Huge::V3423 => 3316,
+        Huge::V3424 => 3276,
+        Huge::V3425 => 3277,
+        Huge::V3426 => 3278,
+        Huge::V3427 => 3279,
+        Huge::V3428 => 3272,
+        Huge::V3429 => 3273,
+        Huge::V3430 => 3274,
+        Huge::V3431 => 3275,
+        Huge::V3432 => 3269,
+        Huge::V3433 => 3268,
+        Huge::V3434 => 3271,
+        Huge::V3435 => 3270,
+        Huge::V3436 => 3265,
+        Huge::V3437 => 3264,
+        Huge::V3438 => 3267,
+        Huge::V3439 => 3266,
+        Huge::V3440 => 3294,
+        Huge::V3441 => 3295,
+        Huge::V3442 => 3292,
+        Huge::V3443 => 3293,
+        Huge::V3444 => 3290,
+        Huge::V3445 => 3291,
+        Huge::V3446 => 3288,
+        Huge::V3447 => 3289,
+        Huge::V3448 => 3287,
+        Huge::V3449 => 3286,
+        Huge::V3450 => 3285,
+        Huge::V3451 => 3284,
+        Huge::V3452 => 3283,
+        Huge::V3453 => 3282,
+        Huge::V3454 => 3281,
+        Huge::V3455 => 3280,
+        Huge::V3456 => 3120,
+        Huge::V3457 => 3121,
+        Huge::V3458 => 3122,
+        Huge::V3459 => 3123,
+        Huge::V3460 => 3124,
+        Huge::V3461 => 3125,
+        Huge::V3462 => 3126,
+        Huge::V3463 => 3127,
+        Huge::V3464 => 3129,
+        Huge::V3465 => 3128,
+        Huge::V3466 => 3131,
+        Huge::V3467 => 3130,
+        Huge::V3468 => 3133,
+        Huge::V3469 => 3132,
+        Huge::V3470 => 3135,
+        Huge::V3471 => 3134,
+        Huge::V3472 => 3106,
+        Huge::V3473 => 3107,
+        Huge::V3474 => 3104,
+        Huge::V3475 => 3105,
+        Huge::V3476 => 3110,
+        Huge::V3477 => 3111,
+        Huge::V3478 => 3108,
+        Huge::V3479 => 3109,
+        Huge::V3480 => 3115,
+        Huge::V3481 => 3114,
+        Huge::V3482 => 3113,
+        Huge::V3483 => 3112,
+        Huge::V3484 => 3119,
+        Huge::V3485 => 3118,
+        Huge::V3486 => 3117,
+        Huge::V3487 => 3116,
+        Huge::V3488 => 3092,
+        Huge::V3489 => 3093,
+        Huge::V3490 => 3094,
+        Huge::V3491 => 3095,
+        Huge::V3492 => 3088,
+        Huge::V3493 => 3089,
+        Huge::V3494 => 3090,
+        Huge::V3495 => 3091,
+        Huge::V3496 => 3101,
+        Huge::V3497 => 3100,
+        Huge::V3498 => 3103,
+        Huge::V3499 => 3102,
+        Huge::V3500 => 3097,
+        Huge::V3501 => 3096,
+        Huge::V3502 => 3099,
+        Huge::V3503 => 3098,
+        Huge::V3504 => 3078,
+        Huge::V3505 => 3079,
+        Huge::V3506 => 3076,
+        Huge::V3507 => 3077,
+        Huge::V3508 => 3074,
+        Huge::V3509 => 3075,
+        Huge::V3510 => 3072,
+        Huge::V3511 => 3073,
+        Huge::V3512 => 3087,
+        Huge::V3513 => 3086,
+        Huge::V3514 => 3085,
+        Huge::V3515 => 3084,
+        Huge::V3516 => 3083,
+        Huge::V3517 => 3082,
+        Huge::V3518 => 3081,
+        Huge::V3519 => 3080,
+        Huge::V3520 => 3192,
+        Huge::V3521 => 3193,
+        Huge::V3522 => 3194,
+        Huge::V3523 => 3195,
+        Huge::V3524 => 3196,
+        Huge::V3525 => 3197,
+        Huge::V3526 => 3198,
+        Huge::V3527 => 3199,
+        Huge::V3528 => 3185,
+        Huge::V3529 => 3184,
+        Huge::V3530 => 3187,
+        Huge::V3531 => 3186,
+        Huge::V3532 => 3189,
+        Huge::V3533 => 3188,
+        Huge::V3534 => 3191,
+        Huge::V3535 => 3190,
+        Huge::V3536 => 3178,
+        Huge::V3537 => 3179,
+        Huge::V3538 => 3176,
+        Huge::V3539 => 3177,
+        Huge::V3540 => 3182,
+        Huge::V3541 => 3183,
+        Huge::V3542 => 3180,
+        Huge::V3543 => 3181,
+        Huge::V3544 => 3171,
+        Huge::V3545 => 3170,
+        Huge::V3546 => 3169,
+        Huge::V3547 => 3168,
+        Huge::V3548 => 3175,
+        Huge::V3549 => 3174,
+        Huge::V3550 => 3173,
+        Huge::V3551 => 3172,
+        Huge::V3552 => 3164,
+        Huge::V3553 => 3165,
+        Huge::V3554 => 3166,
+        Huge::V3555 => 3167,
+        Huge::V3556 => 3160,
+        Huge::V3557 => 3161,
+        Huge::V3558 => 3162,
+        Huge::V3559 => 3163,
+        Huge::V3560 => 3157,
+        Huge::V3561 => 3156,
+        Huge::V3562 => 3159,
+        Huge::V3563 => 3158,
+        Huge::V3564 => 3153,
+        Huge::V3565 => 3152,
+        Huge::V3566 => 3155,
+        Huge::V3567 => 3154,
+        Huge::V3568 => 3150,
+        Huge::V3569 => 3151,
+        Huge::V3570 => 3148,
+        Huge::V3571 => 3149,
+        Huge::V3572 => 3146,
+        Huge::V3573 => 3147,
+        Huge::V3574 => 3144,
+        Huge::V3575 => 3145,
+        Huge::V3576 => 3143,
+        Huge::V3577 => 3142,
+        Huge::V3578 => 3141,
+        Huge::V3579 => 3140,
+        Huge::V3580 => 3139,
+        Huge::V3581 => 3138,
+        Huge::V3582 => 3137,
+        Huge::V3583 => 3136,
+        Huge::V3584 => 4032,
+        Huge::V3585 => 4033,
+        Huge::V3586 => 4034,
+        Huge::V3587 => 4035,
+        Huge::V3588 => 4036,
+        Huge::V3589 => 4037,
+        Huge::V3590 => 4038,
+        Huge::V3591 => 4039,
+        Huge::V3592 => 4041,
+        Huge::V3593 => 4040,
+        Huge::V3594 => 4043,
+        Huge::V3595 => 4042,
+        Huge::V3596 => 4045,
+        Huge::V3597 => 4044,
+        Huge::V3598 => 4047,
+        Huge::V3599 => 4046,
+        Huge::V3600 => 4050,
+        Huge::V3601 => 4051,
+        Huge::V3602 => 4048,
+        Huge::V3603 => 4049,
+        Huge::V3604 => 4054,
+        Huge::V3605 => 4055,
+        Huge::V3606 => 4052,
+        Huge::V3607 => 4053,
+        Huge::V3608 => 4059,
+        Huge::V3609 => 4058,
+        Huge::V3610 => 4057,
+        Huge::V3611 => 4056,
+        Huge::V3612 => 4063,
+        Huge::V3613 => 4062,
+        Huge::V3614 => 4061,
+        Huge::V3615 => 4060,
+        Huge::V3616 => 4068,
+        Huge::V3617 => 4069,
+        Huge::V3618 => 4070,
+        Huge::V3619 => 4071,
+        Huge::V3620 => 4064,
+        Huge::V3621 => 4065,
+        Huge::V3622 => 4066,
+        Huge::V3623 => 4067,
+        Huge::V3624 => 4077,
+        Huge::V3625 => 4076,
+        Huge::V3626 => 4079,
+        Huge::V3627 => 4078,
+        Huge::V3628 => 4073,
+        Huge::V3629 => 4072,
+        Huge::V3630 => 4075,
+        Huge::V3631 => 4074,
+        Huge::V3632 => 4086,
+        Huge::V3633 => 4087,
+        Huge::V3634 => 4084,
+        Huge::V3635 => 4085,
+        Huge::V3636 => 4082,
+        Huge::V3637 => 4083,
+        Huge::V3638 => 4080,
+        Huge::V3639 => 4081,
+        Huge::V3640 => 4095,
+        Huge::V3641 => 4094,
+        Huge::V3642 => 4093,
+        Huge::V3643 => 4092,
+        Huge::V3644 => 4091,
+        Huge::V3645 => 4090,
+        Huge::V3646 => 4089,
+        Huge::V3647 => 4088,
+        Huge::V3648 => 3976,
+        Huge::V3649 => 3977,
+        Huge::V3650 => 3978,
+        Huge::V3651 => 3979,
+        Huge::V3652 => 3980,
+        Huge::V3653 => 3981,
+        Huge::V3654 => 3982,
+        Huge::V3655 => 3983,
+        Huge::V3656 => 3969,
+        Huge::V3657 => 3968,
+        Huge::V3658 => 3971,
+        Huge::V3659 => 3970,
+        Huge::V3660 => 3973,
+        Huge::V3661 => 3972,
+        Huge::V3662 => 3975,
+        Huge::V3663 => 3974,
+        Huge::V3664 => 3994,
+        Huge::V3665 => 3995,
+        Huge::V3666 => 3992,
+        Huge::V3667 => 3993,
+        Huge::V3668 => 3998,
+        Huge::V3669 => 3999,
+        Huge::V3670 => 3996,
+        Huge::V3671 => 3997,
+        Huge::V3672 => 3987,
+        Huge::V3673 => 3986,
+        Huge::V3674 => 3985,
+        Huge::V3675 => 3984,
+        Huge::V3676 => 3991,
+        Huge::V3677 => 3990,
+        Huge::V3678 => 3989,
+        Huge::V3679 => 3988,
+        Huge::V3680 => 4012,
+        Huge::V3681 => 4013,
+        Huge::V3682 => 4014,
+        Huge::V3683 => 4015,
+        Huge::V3684 => 4008,
+        Huge::V3685 => 4009,
+        Huge::V3686 => 4010,
+        Huge::V3687 => 4011,
+        Huge::V3688 => 4005,
+        Huge::V3689 => 4004,
+        Huge::V3690 => 4007,
+        Huge::V3691 => 4006,
+        Huge::V3692 => 4001,
+        Huge::V3693 => 4000,
+        Huge::V3694 => 4003,
+        Huge::V3695 => 4002,
+        Huge::V3696 => 4030,
+        Huge::V3697 => 4031,
+        Huge::V3698 => 4028,
+        Huge::V3699 => 4029,
+        Huge::V3700 => 4026,
+        Huge::V3701 => 4027,
+        Huge::V3702 => 4024,
+        Huge::V3703 => 4025,
+        Huge::V3704 => 4023,
+        Huge::V3705 => 4022,
+        Huge::V3706 => 4021,
+        Huge::V3707 => 4020,
+        Huge::V3708 => 4019,
+        Huge::V3709 => 4018,
+        Huge::V3710 => 4017,
+        Huge::V3711 => 4016,
+        Huge::V3712 => 3920,
+        Huge::V3713 => 3921,
+        Huge::V3714 => 3922,
+        Huge::V3715 => 3923,
+        Huge::V3716 => 3924,
+        Huge::V3717 => 3925,
+        Huge::V3718 => 3926,
+        Huge::V3719 => 3927,
+        Huge::V3720 => 3929,
+        Huge::V3721 => 3928,
+        Huge::V3722 => 3931,
+        Huge::V3723 => 3930,
+        Huge::V3724 => 3933,
+        Huge::V3725 => 3932,
+        Huge::V3726 => 3935,
+        Huge::V3727 => 3934,
+        Huge::V3728 => 3906,
+        Huge::V3729 => 3907,
+        Huge::V3730 => 3904,
+        Huge::V3731 => 3905,
+        Huge::V3732 => 3910,
+        Huge::V3733 => 3911,
+        Huge::V3734 => 3908,
+        Huge::V3735 => 3909,
+        Huge::V3736 => 3915,
+        Huge::V3737 => 3914,
+        Huge::V3738 => 3913,
+        Huge::V3739 => 3912,
+        Huge::V3740 => 3919,
+        Huge::V3741 => 3918,
+        Huge::V3742 => 3917,
+        Huge::V3743 => 3916,
+        Huge::V3744 => 3956,
+        Huge::V3745 => 3957,
+        Huge::V3746 => 3958,
+        Huge::V3747 => 3959,
+        Huge::V3748 => 3952,
+        Huge::V3749 => 3953,
+        Huge::V3750 => 3954,
+        Huge::V3751 => 3955,
+        Huge::V3752 => 3965,
+        Huge::V3753 => 3964,
+        Huge::V3754 => 3967,
+        Huge::V3755 => 3966,
+        Huge::V3756 => 3961,
+        Huge::V3757 => 3960,
+        Huge::V3758 => 3963,
+        Huge::V3759 => 3962,
+        Huge::V3760 => 3942,
+        Huge::V3761 => 3943,
+        Huge::V3762 => 3940,
+        Huge::V3763 => 3941,
+        Huge::V3764 => 3938,
+        Huge::V3765 => 3939,
+        Huge::V3766 => 3936,
+        Huge::V3767 => 3937,
+        Huge::V3768 => 3951,
+        Huge::V3769 => 3950,
+        Huge::V3770 => 3949,
+        Huge::V3771 => 3948,
+        Huge::V3772 => 3947,
+        Huge::V3773 => 3946,
+        Huge::V3774 => 3945,
+        Huge::V3775 => 3944,
+        Huge::V3776 => 3864,
+        Huge::V3777 => 3865,
+        Huge::V3778 => 3866,
+        Huge::V3779 => 3867,
+        Huge::V3780 => 3868,
+        Huge::V3781 => 3869,
+        Huge::V3782 => 3870,
+        Huge::V3783 => 3871,
+        Huge::V3784 => 3857,
+        Huge::V3785 => 3856,
+        Huge::V3786 => 3859,
+        Huge::V3787 => 3858,
+        Huge::V3788 => 3861,
+        Huge::V3789 => 3860,
+        Huge::V3790 => 3863,
+        Huge::V3791 => 3862,
+        Huge::V3792 => 3850,
+        Huge::V3793 => 3851,
+        Huge::V3794 => 3848,
+        Huge::V3795 => 3849,
+        Huge::V3796 => 3854,
+        Huge::V3797 => 3855,
+        Huge::V3798 => 3852,
+        Huge::V3799 => 3853,
+        Huge::V3800 => 3843,
+        Huge::V3801 => 3842,
+        Huge::V3802 => 3841,
+        Huge::V3803 => 3840,
+        Huge::V3804 => 3847,
+        Huge::V3805 => 3846,
+        Huge::V3806 => 3845,
+        Huge::V3807 => 3844,
+        Huge::V3808 => 3900,
+        Huge::V3809 => 3901,
+        Huge::V3810 => 3902,
+        Huge::V3811 => 3903,
+        Huge::V3812 => 3896,
+        Huge::V3813 => 3897,
+        Huge::V3814 => 3898,
+        Huge::V3815 => 3899,
+        Huge::V3816 => 3893,
+        Huge::V3817 => 3892,
+        Huge::V3818 => 3895,
+        Huge::V3819 => 3894,
+        Huge::V3820 => 3889,
+        Huge::V3821 => 3888,
+        Huge::V3822 => 3891,
+        Huge::V3823 => 3890,
+        Huge::V3824 => 3886,
+        Huge::V3825 => 3887,
+        Huge::V3826 => 3884,
+        Huge::V3827 => 3885,
+        Huge::V3828 => 3882,
+        Huge::V3829 => 3883,
+        Huge::V3830 => 3880,
+        Huge::V3831 => 3881,
+        Huge::V3832 => 3879,
+        Huge::V3833 => 3878,
+        Huge::V3834 => 3877,
+        Huge::V3835 => 3876,
+        Huge::V3836 => 3875,
+        Huge::V3837 => 3874,
+        Huge::V3838 => 3873,
+        Huge::V3839 => 3872,
+        Huge::V3840 => 3808,
+        Huge::V3841 => 3809,
+        Huge::V3842 => 3810,
+        Huge::V3843 => 3811,
+        Huge::V3844 => 3812,
+        Huge::V3845 => 3813,
+        Huge::V3846 => 3814,
+        Huge::V3847 => 3815,
+        Huge::V3848 => 3817,
+        Huge::V3849 => 3816,
+        Huge::V3850 => 3819,
+        Huge::V3851 => 3818,
+        Huge::V3852 => 3821,
+        Huge::V3853 => 3820,
+        Huge::V3854 => 3823,
+        Huge::V3855 => 3822,
+        Huge::V3856 => 3826,
+        Huge::V3857 => 3827,
+        Huge::V3858 => 3824,
+        Huge::V3859 => 3825,
+        Huge::V3860 => 3830,
+        Huge::V3861 => 3831,
+        Huge::V3862 => 3828,
+        Huge::V3863 => 3829,
+        Huge::V3864 => 3835,
+        Huge::V3865 => 3834,
+        Huge::V3866 => 3833,
+        Huge::V3867 => 3832,
+        Huge::V3868 => 3839,
+        Huge::V3869 => 3838,
+        Huge::V3870 => 3837,
+        Huge::V3871 => 3836,
+        Huge::V3872 => 3780,
+        Huge::V3873 => 3781,
+        Huge::V3874 => 3782,
+        Huge::V3875 => 3783,
+        Huge::V3876 => 3776,
+        Huge::V3877 => 3777,
+        Huge::V3878 => 3778,
+        Huge::V3879 => 3779,
+        Huge::V3880 => 3789,
+        Huge::V3881 => 3788,
+        Huge::V3882 => 3791,
+        Huge::V3883 => 3790,
+        Huge::V3884 => 3785,
+        Huge::V3885 => 3784,
+        Huge::V3886 => 3787,
+        Huge::V3887 => 3786,
+        Huge::V3888 => 3798,
+        Huge::V3889 => 3799,
+        Huge::V3890 => 3796,
+        Huge::V3891 => 3797,
+        Huge::V3892 => 3794,
+        Huge::V3893 => 3795,
+        Huge::V3894 => 3792,
+        Huge::V3895 => 3793,
+        Huge::V3896 => 3807,
+        Huge::V3897 => 3806,
+        Huge::V3898 => 3805,
+        Huge::V3899 => 3804,
+        Huge::V3900 => 3803,
+        Huge::V3901 => 3802,
+        Huge::V3902 => 3801,
+        Huge::V3903 => 3800,
+        Huge::V3904 => 3752,
+        Huge::V3905 => 3753,
+        Huge::V3906 => 3754,
+        Huge::V3907 => 3755,
+        Huge::V3908 => 3756,
+        Huge::V3909 => 3757,
+        Huge::V3910 => 3758,
+        Huge::V3911 => 3759,
+        Huge::V3912 => 3745,
+        Huge::V3913 => 3744,
+        Huge::V3914 => 3747,
+        Huge::V3915 => 3746,
+        Huge::V3916 => 3749,
+        Huge::V3917 => 3748,
+        Huge::V3918 => 3751,
+        Huge::V3919 => 3750,
+        Huge::V3920 => 3770,
+        Huge::V3921 => 3771,
+        Huge::V3922 => 3768,
+        Huge::V3923 => 3769,
+        Huge::V3924 => 3774,
+        Huge::V3925 => 3775,
+        Huge::V3926 => 3772,
+        Huge::V3927 => 3773,
+        Huge::V3928 => 3763,
+        Huge::V3929 => 3762,
+        Huge::V3930 => 3761,
+        Huge::V3931 => 3760,
+        Huge::V3932 => 3767,
+        Huge::V3933 => 3766,
+        Huge::V3934 => 3765,
+        Huge::V3935 => 3764,
+        Huge::V3936 => 3724,
+        Huge::V3937 => 3725,
+        Huge::V3938 => 3726,
+        Huge::V3939 => 3727,
+        Huge::V3940 => 3720,
+        Huge::V3941 => 3721,
+        Huge::V3942 => 3722,
+        Huge::V3943 => 3723,
+        Huge::V3944 => 3717,
+        Huge::V3945 => 3716,
+        Huge::V3946 => 3719,
+        Huge::V3947 => 3718,
+        Huge::V3948 => 3713,
+        Huge::V3949 => 3712,
+        Huge::V3950 => 3715,
+        Huge::V3951 => 3714,
+        Huge::V3952 => 3742,
+        Huge::V3953 => 3743,
+        Huge::V3954 => 3740,
+        Huge::V3955 => 3741,
+        Huge::V3956 => 3738,
+        Huge::V3957 => 3739,
+        Huge::V3958 => 3736,
+        Huge::V3959 => 3737,
+        Huge::V3960 => 3735,
+        Huge::V3961 => 3734,
+        Huge::V3962 => 3733,
+        Huge::V3963 => 3732,
+        Huge::V3964 => 3731,
+        Huge::V3965 => 3730,
+        Huge::V3966 => 3729,
+        Huge::V3967 => 3728,
+        Huge::V3968 => 3696,
+        Huge::V3969 => 3697,
+        Huge::V3970 => 3698,
+        Huge::V3971 => 3699,
+        Huge::V3972 => 3700,
+        Huge::V3973 => 3701,
+        Huge::V3974 => 3702,
+        Huge::V3975 => 3703,
+        Huge::V3976 => 3705,
+        Huge::V3977 => 3704,
+        Huge::V3978 => 3707,
+        Huge::V3979 => 3706,
+        Huge::V3980 => 3709,
+        Huge::V3981 => 3708,
+        Huge::V3982 => 3711,
+        Huge::V3983 => 3710,
+        Huge::V3984 => 3682,
+        Huge::V3985 => 3683,
+        Huge::V3986 => 3680,
+        Huge::V3987 => 3681,
+        Huge::V3988 => 3686,
+        Huge::V3989 => 3687,
+        Huge::V3990 => 3684,
+        Huge::V3991 => 3685,
+        Huge::V3992 => 3691,
+        Huge::V3993 => 3690,
+        Huge::V3994 => 3689,
+        Huge::V3995 => 3688,
+        Huge::V3996 => 3695,
+        Huge::V3997 => 3694,
+        Huge::V3998 => 3693,
+        Huge::V3999 => 3692,
+        Huge::V4000 => 3668,
+        Huge::V4001 => 3669,
+        Huge::V4002 => 3670,
+        Huge::V4003 => 3671,
+        Huge::V4004 => 3664,
+        Huge::V4005 => 3665,
+        Huge::V4006 => 3666,
+        Huge::V4007 => 3667,
+        Huge::V4008 => 3677,
+        Huge::V4009 => 3676,
+        Huge::V4010 => 3679,
+        Huge::V4011 => 3678,
+        Huge::V4012 => 3673,
+        Huge::V4013 => 3672,
+        Huge::V4014 => 3675,
+        Huge::V4015 => 3674,
+        Huge::V4016 => 3654,
+        Huge::V4017 => 3655,
+        Huge::V4018 => 3652,
+        Huge::V4019 => 3653,
+        Huge::V4020 => 3650,
+        Huge::V4021 => 3651,
+        Huge::V4022 => 3648,
+        Huge::V4023 => 3649,
+        Huge::V4024 => 3663,
+        Huge::V4025 => 3662,
+        Huge::V4026 => 3661,
+        Huge::V4027 => 3660,
+        Huge::V4028 => 3659,
+        Huge::V4029 => 3658,
+        Huge::V4030 => 3657,
+        Huge::V4031 => 3656,
+        Huge::V4032 => 3640,
+        Huge::V4033 => 3641,
+        Huge::V4034 => 3642,
+        Huge::V4035 => 3643,
+        Huge::V4036 => 3644,
+        Huge::V4037 => 3645,
+        Huge::V4038 => 3646,
+        Huge::V4039 => 3647,
+        Huge::V4040 => 3633,
+        Huge::V4041 => 3632,
+        Huge::V4042 => 3635,
+        Huge::V4043 => 3634,
+        Huge::V4044 => 3637,
+        Huge::V4045 => 3636,
+        Huge::V4046 => 3639,
+        Huge::V4047 => 3638,
+        Huge::V4048 => 3626,
+        Huge::V4049 => 3627,
+        Huge::V4050 => 3624,
+        Huge::V4051 => 3625,
+        Huge::V4052 => 3630,
+        Huge::V4053 => 3631,
+        Huge::V4054 => 3628,
+        Huge::V4055 => 3629,
+        Huge::V4056 => 3619,
+        Huge::V4057 => 3618,
+        Huge::V4058 => 3617,
+        Huge::V4059 => 3616,
+        Huge::V4060 => 3623,
+        Huge::V4061 => 3622,
+        Huge::V4062 => 3621,
+        Huge::V4063 => 3620,
+        Huge::V4064 => 3612,
+        Huge::V4065 => 3613,
+        Huge::V4066 => 3614,
+        Huge::V4067 => 3615,
+        Huge::V4068 => 3608,
+        Huge::V4069 => 3609,
+        Huge::V4070 => 3610,
+        Huge::V4071 => 3611,
+        Huge::V4072 => 3605,
+        Huge::V4073 => 3604,
+        Huge::V4074 => 3607,
+        Huge::V4075 => 3606,
+        Huge::V4076 => 3601,
+        Huge::V4077 => 3600,
+        Huge::V4078 => 3603,
+        Huge::V4079 => 3602,
+        Huge::V4080 => 3598,
+        Huge::V4081 => 3599,
+        Huge::V4082 => 3596,
+        Huge::V4083 => 3597,
+        Huge::V4084 => 3594,
+        Huge::V4085 => 3595,
+        Huge::V4086 => 3592,
+        Huge::V4087 => 3593,
+        Huge::V4088 => 3591,
+        Huge::V4089 => 3590,
+        Huge::V4090 => 3589,
+        Huge::V4091 => 3588,
+        Huge::V4092 => 3587,
+        Huge::V4093 => 3586,
+        Huge::V4094 => 3585,
+        Huge::V4095 => 3584,
+    }
+}
+
+pub fn adjacent(a: &Huge, b: &Huge) -> bool {
+    matches!(
+        (a, b),
+        (Huge::V0, Huge::V1) | (Huge::V1, Huge::V0) |
+        (Huge::V8, Huge::V9) | (Huge::V9, Huge::V8) |
+        (Huge::V16, Huge::V17) | (Huge::V17, Huge::V16) |
+        (Huge::V24, Huge::V25) | (Huge::V25, Huge::V24) |
+        (Huge::V32, Huge::V33) | (Huge::V33, Huge::V32) |
+        (Huge::V40, Huge::V41) | (Huge::V41, Huge::V40) |
+        (Huge::V48, Huge::V49) | (Huge::V49, Huge::V48) |
+        (Huge::V56, Huge::V57) | (Huge::V57, Huge::V56) |
+        (Huge::V64, Huge::V65) | (Huge::V65, Huge::V64) |
+        (Huge::V72, Huge::V73) | (Huge::V73, Huge::V72) |
+        (Huge::V80, Huge::V81) | (Huge::V81, Huge::V80) |
+        (Huge::V88, Huge::V89) | (Huge::V89, Huge::V88) |
+        (Huge::V96, Huge::V97) | (Huge::V97, Huge::V96) |
+        (Huge::V104, Huge::V105) | (Huge::V105, Huge::V104) |
+        (Huge::V112, Huge::V113) | (Huge::V113, Huge::V112) |
+        (Huge::V120, Huge::V121) | (Huge::V121, Huge::V120) |
+        (Huge::V128, Huge::V129) | (Huge::V129, Huge::V128) |
+        (Huge::V136, Huge::V137) | (Huge::V137, Huge::V136) |
+        (Huge::V144, Huge::V145) | (Huge::V145, Huge::V144) |
+        (Huge::V152, Huge::V153) | (Huge::V153, Huge::V152) |
+        (Huge::V160, Huge::V161) | (Huge::V161, Huge::V160) |
+        (Huge::V168, Huge::V169) | (Huge::V169, Huge::V168) |
+        (Huge::V176, Huge::V177) | (Huge::V177, Huge::V176) |
+        (Huge::V184, Huge::V185) | (Huge::V185, Huge::V184) |
+        (Huge::V192, Huge::V193) | (Huge::V193, Huge::V192) |
+        (Huge::V200, Huge::V201) | (Huge::V201, Huge::V200) |
+        (Huge::V208, Huge::V209) | (Huge::V209, Huge::V208) |
+        (Huge::V216, Huge::V217) | (Huge::V217, Huge::V216) |
+        (Huge::V224, Huge::V225) | (Huge::V225, Huge::V224) |
+        (Huge::V232, Huge::V233) | (Huge::V233, Huge::V232) |
+        (Huge::V240, Huge::V241) | (Huge::V241, Huge::V240) |
+        (Huge::V248, Huge::V249) | (Huge::V249, Huge::V248) |
+        (Huge::V256, Huge::V257) | (Huge::V257, Huge::V256) |
+        (Huge::V264, Huge::V265) | (Huge::V265, Huge::V264) |
+        (Huge::V272, Huge::V273) | (Huge::V273, Huge::V272) |
+        (Huge::V280, Huge::V281) | (Huge::V281, Huge::V280) |
+        (Huge::V288, Huge::V289) | (Huge::V289, Huge::V288) |
+        (Huge::V296, Huge::V297) | (Huge::V297, Huge::V296) |
+        (Huge::V304, Huge::V305) | (Huge::V305, Huge::V304) |
+        (Huge::V312, Huge::V313) | (Huge::V313, Huge::V312) |
+        (Huge::V320, Huge::V321) | (Huge::V321, Huge::V320) |
+        (Huge::V328, Huge::V329) | (Huge::V329, Huge::V328) |
+        (Huge::V336, Huge::V337) | (Huge::V337, Huge::V336) |
+        (Huge::V344, Huge::V345) | (Huge::V345, Huge::V344) |
+        (Huge::V352, Huge::V353) | (Huge::V353, Huge::V352) |
+        (Huge::V360, Huge::V361) | (Huge::V361, Huge::V360) |
+        (Huge::V368, Huge::V369) | (Huge::V369, Huge::V368) |
+        (Huge::V376, Huge::V377) | (Huge::V377, Huge::V376) |
+        (Huge::V384, Huge::V385) | (Huge::V385, Huge::V384) |
+        (Huge::V392, Huge::V393) | (Huge::V393, Huge::V392) |
+        (Huge::V400, Huge::V401) | (Huge::V401, Huge::V400) |
+        (Huge::V408, Huge::V409) | (Huge::V409, Huge::V408) |
+        (Huge::V416, Huge::V417) | (Huge::V417, Huge::V416) |
+        (Huge::V424, Huge::V425) | (Huge::V425, Huge::V424) |
+        (Huge::V432, Huge::V433) | (Huge::V433, Huge::V432) |
+        (Huge::V440, Huge::V441) | (Huge::V441, Huge::V440) |
+        (Huge::V448, Huge::V449) | (Huge::V449, Huge::V448) |
+        (Huge::V456, Huge::V457) | (Huge::V457, Huge::V456) |
+        (Huge::V464, Huge::V465) | (Huge::V465, Huge::V464) |
+        (Huge::V472, Huge::V473) | (Huge::V473, Huge::V472) |
+        (Huge::V480, Huge::V481) | (Huge::V481, Huge::V480) |
+        (Huge::V488, Huge::V489) | (Huge::V489, Huge::V488) |
+        (Huge::V496, Huge::V497) | (Huge::V497, Huge::V496) |
+        (Huge::V504, Huge::V505) | (Huge::V505, Huge::V504) |
+        (Huge::V512, Huge::V513) | (Huge::V513, Huge::V512) |
+        (Huge::V520, Huge::V521) | (Huge::V521, Huge::V520) |
+        (Huge::V528, Huge::V529) | (Huge::V529, Huge::V528) |
+        (Huge::V536, Huge::V537) | (Huge::V537, Huge::V536) |
+        (Huge::V544, Huge::V545) | (Huge::V545, Huge::V544) |
+        (Huge::V552, Huge::V553) | (Huge::V553, Huge::V552) |
+        (Huge::V560, Huge::V561) | (Huge::V561, Huge::V560) |
+        (Huge::V568, Huge::V569) | (Huge::V569, Huge::V568) |
+        (Huge::V576, Huge::V577) | (Huge::V577, Huge::V576) |
+        (Huge::V584, Huge::V585) | (Huge::V585, Huge::V584) |
+        (Huge::V592, Huge::V593) | (Huge::V593, Huge::V592) |
+        (Huge::V600, Huge::V601) | (Huge::V601, Huge::V600) |
+        (Huge::V608, Huge::V609) | (Huge::V609, Huge::V608) |
+        (Huge::V616, Huge::V617) | (Huge::V617, Huge::V616) |
+        (Huge::V624, Huge::V625) | (Huge::V625, Huge::V624) |
+        (Huge::V632, Huge::V633) | (Huge::V633, Huge::V632) |
+        (Huge::V640, Huge::V641) | (Huge::V641, Huge::V640) |
+        (Huge::V648, Huge::V649) | (Huge::V649, Huge::V648) |
+        (Huge::V656, Huge::V657) | (Huge::V657, Huge::V656) |
+        (Huge::V664, Huge::V665) | (Huge::V665, Huge::V664) |
+        (Huge::V672, Huge::V673) | (Huge::V673, Huge::V672) |
+        (Huge::V680, Huge::V681) | (Huge::V681, Huge::V680) |
+        (Huge::V688, Huge::V689) | (Huge::V689, Huge::V688) |
+        (Huge::V696, Huge::V697) | (Huge::V697, Huge::V696) |
+        (Huge::V704, Huge::V705) | (Huge::V705, Huge::V704) |
+        (Huge::V712, Huge::V713) | (Huge::V713, Huge::V712) |
+        (Huge::V720, Huge::V721) | (Huge::V721, Huge::V720) |
+        (Huge::V728, Huge::V729) | (Huge::V729, Huge::V728) |
+        (Huge::V736, Huge::V737) | (Huge::V737, Huge::V736) |
+        (Huge::V744, Huge::V745) | (Huge::V745, Huge::V744) |
+        (Huge::V752, Huge::V753) | (Huge::V753, Huge::V752) |
+        (Huge::V760, Huge::V761) | (Huge::V761, Huge::V760) |
+        (Huge::V768, Huge::V769) | (Huge::V769, Huge::V768) |
+        (Huge::V776, Huge::V777) | (Huge::V777, Huge::V776) |
+        (Huge::V784, Huge::V785) | (Huge::V785, Huge::V784) |
+        (Huge::V792, Huge::V793) | (Huge::V793, Huge::V792) |
+        (Huge::V800, Huge::V801) | (Huge::V801, Huge::V800) |
+        (Huge::V808, Huge::V809) | (Huge::V809, Huge::V808) |
+        (Huge::V816, Huge::V817) | (Huge::V817, Huge::V816) |
+        (Huge::V824, Huge::V825) | (Huge::V825, Huge::V824) |
+        (Huge::V832, Huge::V833) | (Huge::V833, Huge::V832) |
+        (Huge::V840, Huge::V841) | (Huge::V841, Huge::V840) |
+        (Huge::V848, Huge::V849) | (Huge::V849, Huge::V848) |
+        (Huge::V856, Huge::V857) | (Huge::V857, Huge::V856) |
+        (Huge::V864, Huge::V865) | (Huge::V865, Huge::V864) |
+        (Huge::V872, Huge::V873) | (Huge::V873, Huge::V872) |
+        (Huge::V880, Huge::V881) | (Huge::V881, Huge::V880) |
+        (Huge::V888, Huge::V889) | (Huge::V889, Huge::V888) |
+        (Huge::V896, Huge::V897) | (Huge::V897, Huge::V896) |
+        (Huge::V904, Huge::V905) | (Huge::V905, Huge::V904) |
+        (Huge::V912, Huge::V913) | (Huge::V913, Huge::V912) |
+        (Huge::V920, Huge::V921) | (Huge::V921, Huge::V920) |
+        (Huge::V928, Huge::V929) | (Huge::V929, Huge::V928) |
+        (Huge::V936, Huge::V937) | (Huge::V937, Huge::V936) |
+        (Huge::V944, Huge::V945) | (Huge::V945, Huge::V944) |
+        (Huge::V952, Huge::V953) | (Huge::V953, Huge::V952) |
+        (Huge::V960, Huge::V961) | (Huge::V961, Huge::V960) |
+        (Huge::V968, Huge::V969) | (Huge::V969, Huge::V968) |
+        (Huge::V976, Huge::V977) | (Huge::V977, Huge::V976) |
+        (Huge::V984, Huge::V985) | (Huge::V985, Huge::V984) |
+        (Huge::V992, Huge::V993) | (Huge::V993, Huge::V992) |
+        (Huge::V1000, Huge::V1001) | (Huge::V1001, Huge::V1000) |
+        (Huge::V1008, Huge::V1009) | (Huge::V1009, Huge::V1008) |
+        (Huge::V1016, Huge::V1017) | (Huge::V1017, Huge::V1016) |
+        (Huge::V1024, Huge::V1025) | (Huge::V1025, Huge::V1024) |
+        (Huge::V1032, Huge::V1033) | (Huge::V1033, Huge::V1032) |
+        (Huge::V1040, Huge::V1041) | (Huge::V1041, Huge::V1040) |
+        (Huge::V1048, Huge::V1049) | (Huge::V1049, Huge::V1048) |
+        (Huge::V1056, Huge::V1057) | (Huge::V1057, Huge::V1056) |
+        (Huge::V1064, Huge::V1065) | (Huge::V1065, Huge::V1064) |
+        (Huge::V1072, Huge::V1073) | (Huge::V1073, Huge::V1072) |
+        (Huge::V1080, Huge::V1081) | (Huge::V1081, Huge::V1080) |
+        (Huge::V1088, Huge::V1089) | (Huge::V1089, Huge::V1088) |
+        (Huge::V1096, Huge::V1097) | (Huge::V1097, Huge::V1096) |
+        (Huge::V1104, Huge::V1105) | (Huge::V1105, Huge::V1104) |
+        (Huge::V1112, Huge::V1113) | (Huge::V1113, Huge::V1112) |
+        (Huge::V1120, Huge::V1121) | (Huge::V1121, Huge::V1120) |
+        (Huge::V1128, Huge::V1129) | (Huge::V1129, Huge::V1128) |
+        (Huge::V1136, Huge::V1137) | (Huge::V1137, Huge::V1136) |
+        (Huge::V1144, Huge::V1145) | (Huge::V1145, Huge::V1144) |
+        (Huge::V1152, Huge::V1153) | (Huge::V1153, Huge::V1152) |
+        (Huge::V1160, Huge::V1161) | (Huge::V1161, Huge::V1160) |
+        (Huge::V1168, Huge::V1169) | (Huge::V1169, Huge::V1168) |
+        (Huge::V1176, Huge::V1177) | (Huge::V1177, Huge::V1176) |
+        (Huge::V1184, Huge::V1185) | (Huge::V1185, Huge::V1184) |
+        (Huge::V1192, Huge::V1193) | (Huge::V1193, Huge::V1192) |
+        (Huge::V1200, Huge::V1201) | (Huge::V1201, Huge::V1200) |
+        (Huge::V1208, Huge::V1209) | (Huge::V1209, Huge::V1208) |
+        (Huge::V1216, Huge::V1217) | (Huge::V1217, Huge::V1216) |
+        (Huge::V1224, Huge::V1225) | (Huge::V1225, Huge::V1224) |
+        (Huge::V1232, Huge::V1233) | (Huge::V1233, Huge::V1232) |
+        (Huge::V1240, Huge::V1241) | (Huge::V1241, Huge::V1240) |
+        (Huge::V1248, Huge::V1249) | (Huge::V1249, Huge::V1248) |
+        (Huge::V1256, Huge::V1257) | (Huge::V1257, Huge::V1256) |
+        (Huge::V1264, Huge::V1265) | (Huge::V1265, Huge::V1264) |
+        (Huge::V1272, Huge::V1273) | (Huge::V1273, Huge::V1272) |
+        (Huge::V1280, Huge::V1281) | (Huge::V1281, Huge::V1280) |
+        (Huge::V1288, Huge::V1289) | (Huge::V1289, Huge::V1288) |
+        (Huge::V1296, Huge::V1297) | (Huge::V1297, Huge::V1296) |
+        (Huge::V1304, Huge::V1305) | (Huge::V1305, Huge::V1304) |
+        (Huge::V1312, Huge::V1313) | (Huge::V1313, Huge::V1312) |
+        (Huge::V1320, Huge::V1321) | (Huge::V1321, Huge::V1320) |
+        (Huge::V1328, Huge::V1329) | (Huge::V1329, Huge::V1328) |
+        (Huge::V1336, Huge::V1337) | (Huge::V1337, Huge::V1336) |
+        (Huge::V1344, Huge::V1345) | (Huge::V1345, Huge::V1344) |
+        (Huge::V1352, Huge::V1353) | (Huge::V1353, Huge::V1352) |
+        (Huge::V1360, Huge::V1361) | (Huge::V1361, Huge::V1360) |
+        (Huge::V1368, Huge::V1369) | (Huge::V1369, Huge::V1368) |
+        (Huge::V1376, Huge::V1377) | (Huge::V1377, Huge::V1376) |
+        (Huge::V1384, Huge::V1385) | (Huge::V1385, Huge::V1384) |
+        (Huge::V1392, Huge::V1393) | (Huge::V1393, Huge::V1392) |
+        (Huge::V1400, Huge::V1401) | (Huge::V1401, Huge::V1400) |
+        (Huge::V1408, Huge::V1409) | (Huge::V1409, Huge::V1408) |
+        (Huge::V1416, Huge::V1417) | (Huge::V1417, Huge::V1416) |
+        (Huge::V1424, Huge::V1425) | (Huge::V1425, Huge::V1424) |
+        (Huge::V1432, Huge::V1433) | (Huge::V1433, Huge::V1432) |
+        (Huge::V1440, Huge::V1441) | (Huge::V1441, Huge::V1440) |
+        (Huge::V1448, Huge::V1449) | (Huge::V1449, Huge::V1448) |
+        (Huge::V1456, Huge::V1457) | (Huge::V1457, Huge::V1456) |
+        (Huge::V1464, Huge::V1465) | (Huge::V1465, Huge::V1464) |
+        (Huge::V1472, Huge::V1473) | (Huge::V1473, Huge::V1472) |
+        (Huge::V1480, Huge::V1481) | (Huge::V1481, Huge::V1480) |
+        (Huge::V1488, Huge::V1489) | (Huge::V1489, Huge::V1488) |
+        (Huge::V1496, Huge::V1497) | (Huge::V1497, Huge::V1496) |
+        (Huge::V1504, Huge::V1505) | (Huge::V1505, Huge::V1504) |
+        (Huge::V1512, Huge::V1513) | (Huge::V1513, Huge::V1512) |
+        (Huge::V1520, Huge::V1521) | (Huge::V1521, Huge::V1520) |
+        (Huge::V1528, Huge::V1529) | (Huge::V1529, Huge::V1528) |
+        (Huge::V1536, Huge::V1537) | (Huge::V1537, Huge::V1536) |
+        (Huge::V1544, Huge::V1545) | (Huge::V1545, Huge::V1544) |
+        (Huge::V1552, Huge::V1553) | (Huge::V1553, Huge::V1552) |
+        (Huge::V1560, Huge::V1561) | (Huge::V1561, Huge::V1560) |
+        (Huge::V1568, Huge::V1569) | (Huge::V1569, Huge::V1568) |
+        (Huge::V1576, Huge::V1577) | (Huge::V1577, Huge::V1576) |
+        (Huge::V1584, Huge::V1585) | (Huge::V1585, Huge::V1584) |
+        (Huge::V1592, Huge::V1593) | (Huge::V1593, Huge::V1592) |
+        (Huge::V1600, Huge::V1601) | (Huge::V1601, Huge::V1600) |
+        (Huge::V1608, Huge::V1609) | (Huge::V1609, Huge::V1608) |
+        (Huge::V1616, Huge::V1617) | (Huge::V1617, Huge::V1616) |
+        (Huge::V1624, Huge::V1625) | (Huge::V1625, Huge::V1624) |
+        (Huge::V1632, Huge::V1633) | (Huge::V1633, Huge::V1632) |
+        (Huge::V1640, Huge::V1641) | (Huge::V1641, Huge::V1640) |
+        (Huge::V1648, Huge::V1649) | (Huge::V1649, Huge::V1648) |
+        (Huge::V1656, Huge::V1657) | (Huge::V1657, Huge::V1656) |
+        (Huge::V1664, Huge::V1665) | (Huge::V1665, Huge::V1664) |
+        (Huge::V1672, Huge::V1673) | (Huge::V1673, Huge::V1672) |
+        (Huge::V1680, Huge::V1681) | (Huge::V1681, Huge::V1680) |
+        (Huge::V1688, Huge::V1689) | (Huge::V1689, Huge::V1688) |
+        (Huge::V1696, Huge::V1697) | (Huge::V1697, Huge::V1696) |
+        (Huge::V1704, Huge::V1705) | (Huge::V1705, Huge::V1704) |
+        (Huge::V1712, Huge::V1713) | (Huge::V1713, Huge::V1712) |
+        (Huge::V1720, Huge::V1721) | (Huge::V1721, Huge::V1720) |
+        (Huge::V1728, Huge::V1729) | (Huge::V1729, Huge::V1728) |
+        (Huge::V1736, Huge::V1737) | (Huge::V1737, Huge::V1736) |
+        (Huge::V1744, Huge::V1745) | (Huge::V1745, Huge::V1744) |
+        (Huge::V1752, Huge::V1753) | (Huge::V1753, Huge::V1752) |
+        (Huge::V1760, Huge::V1761) | (Huge::V1761, Huge::V1760) |
+        (Huge::V1768, Huge::V1769) | (Huge::V1769, Huge::V1768) |
+        (Huge::V1776, Huge::V1777) | (Huge::V1777, Huge::V1776) |
+        (Huge::V1784, Huge::V1785) | (Huge::V1785, Huge::V1784) |
+        (Huge::V1792, Huge::V1793) | (Huge::V1793, Huge::V1792) |
+        (Huge::V1800, Huge::V1801) | (Huge::V1801, Huge::V1800) |
+        (Huge::V1808, Huge::V1809) | (Huge::V1809, Huge::V1808) |
+        (Huge::V1816, Huge::V1817) | (Huge::V1817, Huge::V1816) |
+        (Huge::V1824, Huge::V1825) | (Huge::V1825, Huge::V1824) |
+        (Huge::V1832, Huge::V1833) | (Huge::V1833, Huge::V1832) |
+        (Huge::V1840, Huge::V1841) | (Huge::V1841, Huge::V1840) |
+        (Huge::V1848, Huge::V1849) | (Huge::V1849, Huge::V1848) |
+        (Huge::V1856, Huge::V1857) | (Huge::V1857, Huge::V1856) |
+        (Huge::V1864, Huge::V1865) | (Huge::V1865, Huge::V1864) |
+        (Huge::V1872, Huge::V1873) | (Huge::V1873, Huge::V1872) |
+        (Huge::V1880, Huge::V1881) | (Huge::V1881, Huge::V1880) |
+        (Huge::V1888, Huge::V1889) | (Huge::V1889, Huge::V1888) |
+        (Huge::V1896, Huge::V1897) | (Huge::V1897, Huge::V1896) |
+        (Huge::V1904, Huge::V1905) | (Huge::V1905, Huge::V1904) |
+        (Huge::V1912, Huge::V1913) | (Huge::V1913, Huge::V1912) |
+        (Huge::V1920, Huge::V1921) | (Huge::V1921, Huge::V1920) |
+        (Huge::V1928, Huge::V1929) | (Huge::V1929, Huge::V1928) |
+        (Huge::V1936, Huge::V1937) | (Huge::V1937, Huge::V1936) |
+        (Huge::V1944, Huge::V1945) | (Huge::V1945, Huge::V1944) |
+        (Huge::V1952, Huge::V1953) | (Huge::V1953, Huge::V1952) |
+        (Huge::V1960, Huge::V1961) | (Huge::V1961, Huge::V1960) |
+        (Huge::V1968, Huge::V1969) | (Huge::V1969, Huge::V1968) |
+        (Huge::V1976, Huge::V1977) | (Huge::V1977, Huge::V1976) |
+        (Huge::V1984, Huge::V1985) | (Huge::V1985, Huge::V1984) |
+        (Huge::V1992, Huge::V1993) | (Huge::V1993, Huge::V1992) |
+        (Huge::V2000, Huge::V2001) | (Huge::V2001, Huge::V2000) |
+        (Huge::V2008, Huge::V2009) | (Huge::V2009, Huge::V2008) |
+        (Huge::V2016, Huge::V2017) | (Huge::V2017, Huge::V2016) |
+        (Huge::V2024, Huge::V2025) | (Huge::V2025, Huge::V2024) |
+        (Huge::V2032, Huge::V2033) | (Huge::V2033, Huge::V2032) |
+        (Huge::V2040, Huge::V2041) | (Huge::V2041, Huge::V2040) |
+        (Huge::V2048, Huge::V2049) | (Huge::V2049, Huge::V2048) |
+        (Huge::V2056, Huge::V2057) | (Huge::V2057, Huge::V2056) |
+        (Huge::V2064, Huge::V2065) | (Huge::V2065, Huge::V2064) |
+        (Huge::V2072, Huge::V2073) | (Huge::V2073, Huge::V2072) |
+        (Huge::V2080, Huge::V2081) | (Huge::V2081, Huge::V2080) |
+        (Huge::V2088, Huge::V2089) | (Huge::V2089, Huge::V2088) |
+        (Huge::V2096, Huge::V2097) | (Huge::V2097, Huge::V2096) |
+        (Huge::V2104, Huge::V2105) | (Huge::V2105, Huge::V2104) |
+        (Huge::V2112, Huge::V2113) | (Huge::V2113, Huge::V2112) |
+        (Huge::V2120, Huge::V2121) | (Huge::V2121, Huge::V2120) |
+        (Huge::V2128, Huge::V2129) | (Huge::V2129, Huge::V2128) |
+        (Huge::V2136, Huge::V2137) | (Huge::V2137, Huge::V2136) |
+        (Huge::V2144, Huge::V2145) | (Huge::V2145, Huge::V2144) |
+        (Huge::V2152, Huge::V2153) | (Huge::V2153, Huge::V2152) |
+        (Huge::V2160, Huge::V2161) | (Huge::V2161, Huge::V2160) |
+        (Huge::V2168, Huge::V2169) | (Huge::V2169, Huge::V2168) |
+        (Huge::V2176, Huge::V2177) | (Huge::V2177, Huge::V2176) |
+        (Huge::V2184, Huge::V2185) | (Huge::V2185, Huge::V2184) |
+        (Huge::V2192, Huge::V2193) | (Huge::V2193, Huge::V2192) |
+        (Huge::V2200, Huge::V2201) | (Huge::V2201, Huge::V2200) |
+        (Huge::V2208, Huge::V2209) | (Huge::V2209, Huge::V2208) |
+        (Huge::V2216, Huge::V2217) | (Huge::V2217, Huge::V2216) |
+        (Huge::V2224, Huge::V2225) | (Huge::V2225, Huge::V2224) |
+        (Huge::V2232, Huge::V2233) | (Huge::V2233, Huge::V2232) |
+        (Huge::V2240, Huge::V2241) | (Huge::V2241, Huge::V2240) |
+        (Huge::V2248, Huge::V2249) | (Huge::V2249, Huge::V2248) |
+        (Huge::V2256, Huge::V2257) | (Huge::V2257, Huge::V2256) |
+        (Huge::V2264, Huge::V2265) | (Huge::V2265, Huge::V2264) |
+        (Huge::V2272, Huge::V2273) | (Huge::V2273, Huge::V2272) |
+        (Huge::V2280, Huge::V2281) | (Huge::V2281, Huge::V2280) |
+        (Huge::V2288, Huge::V2289) | (Huge::V2289, Huge::V2288) |
+        (Huge::V2296, Huge::V2297) | (Huge::V2297, Huge::V2296) |
+        (Huge::V2304, Huge::V2305) | (Huge::V2305, Huge::V2304) |
+        (Huge::V2312, Huge::V2313) | (Huge::V2313, Huge::V2312) |
+        (Huge::V2320, Huge::V2321) | (Huge::V2321, Huge::V2320) |
+        (Huge::V2328, Huge::V2329) | (Huge::V2329, Huge::V2328) |
+        (Huge::V2336, Huge::V2337) | (Huge::V2337, Huge::V2336) |
+        (Huge::V2344, Huge::V2345) | (Huge::V2345, Huge::V2344) |
+        (Huge::V2352, Huge::V2353) | (Huge::V2353, Huge::V2352) |
+        (Huge::V2360, Huge::V2361) | (Huge::V2361, Huge::V2360) |
+        (Huge::V2368, Huge::V2369) | (Huge::V2369, Huge::V2368) |
+        (Huge::V2376, Huge::V2377) | (Huge::V2377, Huge::V2376) |
+        (Huge::V2384, Huge::V2385) | (Huge::V2385, Huge::V2384) |
+        (Huge::V2392, Huge::V2393) | (Huge::V2393, Huge::V2392) |
+        (Huge::V2400, Huge::V2401) | (Huge::V2401, Huge::V2400) |
+        (Huge::V2408, Huge::V2409) | (Huge::V2409, Huge::V2408) |
+        (Huge::V2416, Huge::V2417) | (Huge::V2417, Huge::V2416) |
+        (Huge::V2424, Huge::V2425) | (Huge::V2425, Huge::V2424) |
+        (Huge::V2432, Huge::V2433) | (Huge::V2433, Huge::V2432) |
+        (Huge::V2440, Huge::V2441) | (Huge::V2441, Huge::V2440) |
+        (Huge::V2448, Huge::V2449) | (Huge::V2449, Huge::V2448) |
+        (Huge::V2456, Huge::V2457) | (Huge::V2457, Huge::V2456) |
+        (Huge::V2464, Huge::V2465) | (Huge::V2465, Huge::V2464) |
+        (Huge::V2472, Huge::V2473) | (Huge::V2473, Huge::V2472) |
+        (Huge::V2480, Huge::V2481) | (Huge::V2481, Huge::V2480) |
+        (Huge::V2488, Huge::V2489) | (Huge::V2489, Huge::V2488) |
+        (Huge::V2496, Huge::V2497) | (Huge::V2497, Huge::V2496) |
+        (Huge::V2504, Huge::V2505) | (Huge::V2505, Huge::V2504) |
+        (Huge::V2512, Huge::V2513) | (Huge::V2513, Huge::V2512) |
+        (Huge::V2520, Huge::V2521) | (Huge::V2521, Huge::V2520) |
+        (Huge::V2528, Huge::V2529) | (Huge::V2529, Huge::V2528) |
+        (Huge::V2536, Huge::V2537) | (Huge::V2537, Huge::V2536) |
+        (Huge::V2544, Huge::V2545) | (Huge::V2545, Huge::V2544) |
+        (Huge::V2552, Huge::V2553) | (Huge::V2553, Huge::V2552) |
+        (Huge::V2560, Huge::V2561) | (Huge::V2561, Huge::V2560) |
+        (Huge::V2568, Huge::V2569) | (Huge::V2569, Huge::V2568) |
+        (Huge::V2576, Huge::V2577) | (Huge::V2577, Huge::V2576) |
+        (Huge::V2584, Huge::V2585) | (Huge::V2585, Huge::V2584) |
+        (Huge::V2592, Huge::V2593) | (Huge::V2593, Huge::V2592) |
+        (Huge::V2600, Huge::V2601) | (Huge::V2601, Huge::V2600) |
+        (Huge::V2608, Huge::V2609) | (Huge::V2609, Huge::V2608) |
+        (Huge::V2616, Huge::V2617) | (Huge::V2617, Huge::V2616) |
+        (Huge::V2624, Huge::V2625) | (Huge::V2625, Huge::V2624) |
+        (Huge::V2632, Huge::V2633) | (Huge::V2633, Huge::V2632) |
+        (Huge::V2640, Huge::V2641) | (Huge::V2641, Huge::V2640) |
+        (Huge::V2648, Huge::V2649) | (Huge::V2649, Huge::V2648) |
+        (Huge::V2656, Huge::V2657) | (Huge::V2657, Huge::V2656) |
+        (Huge::V2664, Huge::V2665) | (Huge::V2665, Huge::V2664) |
+        (Huge::V2672, Huge::V2673) | (Huge::V2673, Huge::V2672) |
+        (Huge::V2680, Huge::V2681) | (Huge::V2681, Huge::V2680) |
+        (Huge::V2688, Huge::V2689) | (Huge::V2689, Huge::V2688) |
+        (Huge::V2696, Huge::V2697) | (Huge::V2697, Huge::V2696) |
+        (Huge::V2704, Huge::V2705) | (Huge::V2705, Huge::V2704) |
+        (Huge::V2712, Huge::V2713) | (Huge::V2713, Huge::V2712) |
+        (Huge::V2720, Huge::V2721) | (Huge::V2721, Huge::V2720) |
+        (Huge::V2728, Huge::V2729) | (Huge::V2729, Huge::V2728) |
+        (Huge::V2736, Huge::V2737) | (Huge::V2737, Huge::V2736) |
+        (Huge::V2744, Huge::V2745) | (Huge::V2745, Huge::V2744) |
+        (Huge::V2752, Huge::V2753) | (Huge::V2753, Huge::V2752) |
+        (Huge::V2760, Huge::V2761) | (Huge::V2761, Huge::V2760) |
+        (Huge::V2768, Huge::V2769) | (Huge::V2769, Huge::V2768) |
+        (Huge::V2776, Huge::V2777) | (Huge::V2777, Huge::V2776) |
+        (Huge::V2784, Huge::V2785) | (Huge::V2785, Huge::V2784) |
+        (Huge::V2792, Huge::V2793) | (Huge::V2793, Huge::V2792) |
+        (Huge::V2800, Huge::V2801) | (Huge::V2801, Huge::V2800) |
+        (Huge::V2808, Huge::V2809) | (Huge::V2809, Huge::V2808) |
+        (Huge::V2816, Huge::V2817) | (Huge::V2817, Huge::V2816) |
+        (Huge::V2824, Huge::V2825) | (Huge::V2825, Huge::V2824) |
+        (Huge::V2832, Huge::V2833) | (Huge::V2833, Huge::V2832) |
+        (Huge::V2840, Huge::V2841) | (Huge::V2841, Huge::V2840) |
+        (Huge::V2848, Huge::V2849) | (Huge::V2849, Huge::V2848) |
+        (Huge::V2856, Huge::V2857) | (Huge::V2857, Huge::V2856) |
+        (Huge::V2864, Huge::V2865) | (Huge::V2865, Huge::V2864) |
+        (Huge::V2872, Huge::V2873) | (Huge::V2873, Huge::V2872) |
+        (Huge::V2880, Huge::V2881) | (Huge::V2881, Huge::V2880) |
+        (Huge::V2888, Huge::V2889) | (Huge::V2889, Huge::V2888) |
+        (Huge::V2896, Huge::V2897) | (Huge::V2897, Huge::V2896) |
+        (Huge::V2904, Huge::V2905) | (Huge::V2905, Huge::V2904) |
+        (Huge::V2912, Huge::V2913) | (Huge::V2913, Huge::V2912) |
+        (Huge::V2920, Huge::V2921) | (Huge::V2921, Huge::V2920) |
+        (Huge::V2928, Huge::V2929) | (Huge::V2929, Huge::V2928) |
+        (Huge::V2936, Huge::V2937) | (Huge::V2937, Huge::V2936) |
+        (Huge::V2944, Huge::V2945) | (Huge::V2945, Huge::V2944) |
+        (Huge::V2952, Huge::V2953) | (Huge::V2953, Huge::V2952) |
+        (Huge::V2960, Huge::V2961) | (Huge::V2961, Huge::V2960) |
+        (Huge::V2968, Huge::V2969) | (Huge::V2969, Huge::V2968) |
+        (Huge::V2976, Huge::V2977) | (Huge::V2977, Huge::V2976) |
+        (Huge::V2984, Huge::V2985) | (Huge::V2985, Huge::V2984) |
+        (Huge::V2992, Huge::V2993) | (Huge::V2993, Huge::V2992) |
+        (Huge::V3000, Huge::V3001) | (Huge::V3001, Huge::V3000) |
+        (Huge::V3008, Huge::V3009) | (Huge::V3009, Huge::V3008) |
+        (Huge::V3016, Huge::V3017) | (Huge::V3017, Huge::V3016) |
+        (Huge::V3024, Huge::V3025) | (Huge::V3025, Huge::V3024) |
+        (Huge::V3032, Huge::V3033) | (Huge::V3033, Huge::V3032) |
+        (Huge::V3040, Huge::V3041) | (Huge::V3041, Huge::V3040) |
+        (Huge::V3048, Huge::V3049) | (Huge::V3049, Huge::V3048) |
+        (Huge::V3056, Huge::V3057) | (Huge::V3057, Huge::V3056) |
+        (Huge::V3064, Huge::V3065) | (Huge::V3065, Huge::V3064) |
+        (Huge::V3072, Huge::V3073) | (Huge::V3073, Huge::V3072) |
+        (Huge::V3080, Huge::V3081) | (Huge::V3081, Huge::V3080) |
+        (Huge::V3088, Huge::V3089) | (Huge::V3089, Huge::V3088) |
+        (Huge::V3096, Huge::V3097) | (Huge::V3097, Huge::V3096) |
+        (Huge::V3104, Huge::V3105) | (Huge::V3105, Huge::V3104) |
+        (Huge::V3112, Huge::V3113) | (Huge::V3113, Huge::V3112) |
+        (Huge::V3120, Huge::V3121) | (Huge::V3121, Huge::V3120) |
+        (Huge::V3128, Huge::V3129) | (Huge::V3129, Huge::V3128) |
+        (Huge::V3136, Huge::V3137) | (Huge::V3137, Huge::V3136) |
+        (Huge::V3144, Huge::V3145) | (Huge::V3145, Huge::V3144) |
+        (Huge::V3152, Huge::V3153) | (Huge::V3153, Huge::V3152) |
+        (Huge::V3160, Huge::V3161) | (Huge::V3161, Huge::V3160) |
+        (Huge::V3168, Huge::V3169) | (Huge::V3169, Huge::V3168) |
+        (Huge::V3176, Huge::V3177) | (Huge::V3177, Huge::V3176) |
+        (Huge::V3184, Huge::V3185) | (Huge::V3185, Huge::V3184) |
+        (Huge::V3192, Huge::V3193) | (Huge::V3193, Huge::V3192) |
+        (Huge::V3200, Huge::V3201) | (Huge::V3201, Huge::V3200) |
+        (Huge::V3208, Huge::V3209) | (Huge::V3209, Huge::V3208) |
+        (Huge::V3216, Huge::V3217) | (Huge::V3217, Huge::V3216) |
+        (Huge::V3224, Huge::V3225) | (Huge::V3225, Huge::V3224) |
+        (Huge::V3232, Huge::V3233) | (Huge::V3233, Huge::V3232) |
+        (Huge::V3240, Huge::V3241) | (Huge::V3241, Huge::V3240) |
+        (Huge::V3248, Huge::V3249) | (Huge::V3249, Huge::V3248) |
+        (Huge::V3256, Huge::V3257) | (Huge::V3257, Huge::V3256) |
+        (Huge::V3264, Huge::V3265) | (Huge::V3265, Huge::V3264) |
+        (Huge::V3272, Huge::V3273) | (Huge::V3273, Huge::V3272) |
+        (Huge::V3280, Huge::V3281) | (Huge::V3281, Huge::V3280) |
+        (Huge::V3288, Huge::V3289) | (Huge::V3289, Huge::V3288) |
+        (Huge::V3296, Huge::V3297) | (Huge::V3297, Huge::V3296) |
+        (Huge::V3304, Huge::V3305) | (Huge::V3305, Huge::V3304) |
+        (Huge::V3312, Huge::V3313) | (Huge::V3313, Huge::V3312) |
+        (Huge::V3320, Huge::V3321) | (Huge::V3321, Huge::V3320) |
+        (Huge::V3328, Huge::V3329) | (Huge::V3329, Huge::V3328) |
+        (Huge::V3336, Huge::V3337) | (Huge::V3337, Huge::V3336) |
+        (Huge::V3344, Huge::V3345) | (Huge::V3345, Huge::V3344) |
+        (Huge::V3352, Huge::V3353) | (Huge::V3353, Huge::V3352) |
+        (Huge::V3360, Huge::V3361) | (Huge::V3361, Huge::V3360) |
+        (Huge::V3368, Huge::V3369) | (Huge::V3369, Huge::V3368) |
+        (Huge::V3376, Huge::V3377) | (Huge::V3377, Huge::V3376) |
+        (Huge::V3384, Huge::V3385) | (Huge::V3385, Huge::V3384) |
+        (Huge::V3392, Huge::V3393) | (Huge::V3393, Huge::V3392) |
+        (Huge::V3400, Huge::V3401) | (Huge::V3401, Huge::V3400) |
+        (Huge::V3408, Huge::V3409) | (Huge::V3409, Huge::V3408) |
+        (Huge::V3416, Huge::V3417) | (Huge::V3417, Huge::V3416) |
+        (Huge::V3424, Huge::V3425) | (Huge::V3425, Huge::V3424) |
+        (Huge::V3432, Huge::V3433) | (Huge::V3433, Huge::V3432) |
+        (Huge::V3440, Huge::V3441) | (Huge::V3441, Huge::V3440) |
+        (Huge::V3448, Huge::V3449) | (Huge::V3449, Huge::V3448) |
+        (Huge::V3456, Huge::V3457) | (Huge::V3457, Huge::V3456) |
+        (Huge::V3464, Huge::V3465) | (Huge::V3465, Huge::V3464) |
+        (Huge::V3472, Huge::V3473) | (Huge::V3473, Huge::V3472) |
+        (Huge::V3480, Huge::V3481) | (Huge::V3481, Huge::V3480) |
+        (Huge::V3488, Huge::V3489) | (Huge::V3489, Huge::V3488) |
+        (Huge::V3496, Huge::V3497) | (Huge::V3497, Huge::V3496) |
+        (Huge::V3504, Huge::V3505) | (Huge::V3505, Huge::V3504) |
+        (Huge::V3512, Huge::V3513) | (Huge::V3513, Huge::V3512) |
+        (Huge::V3520, Huge::V3521) | (Huge::V3521, Huge::V3520) |
+        (Huge::V3528, Huge::V3529) | (Huge::V3529, Huge::V3528) |
+        (Huge::V3536, Huge::V3537) | (Huge::V3537, Huge::V3536) |
+        (Huge::V3544, Huge::V3545) | (Huge::V3545, Huge::V3544) |
+        (Huge::V3552, Huge::V3553) | (Huge::V3553, Huge::V3552) |
+        (Huge::V3560, Huge::V3561) | (Huge::V3561, Huge::V3560) |
+        (Huge::V3568, Huge::V3569) | (Huge::V3569, Huge::V3568) |
+        (Huge::V3576, Huge::V3577) | (Huge::V3577, Huge::V3576) |
+        (Huge::V3584, Huge::V3585) | (Huge::V3585, Huge::V3584) |
+        (Huge::V3592, Huge::V3593) | (Huge::V3593, Huge::V3592) |
+        (Huge::V3600, Huge::V3601) | (Huge::V3601, Huge::V3600) |
+        (Huge::V3608, Huge::V3609) | (Huge::V3609, Huge::V3608) |
+        (Huge::V3616, Huge::V3617) | (Huge::V3617, Huge::V3616) |
+        (Huge::V3624, Huge::V3625) | (Huge::V3625, Huge::V3624) |
+        (Huge::V3632, Huge::V3633) | (Huge::V3633, Huge::V3632) |
+        (Huge::V3640, Huge::V3641) | (Huge::V3641, Huge::V3640) |
+        (Huge::V3648, Huge::V3649) | (Huge::V3649, Huge::V3648) |
+        (Huge::V3656, Huge::V3657) | (Huge::V3657, Huge::V3656) |
+        (Huge::V3664, Huge::V3665) | (Huge::V3665, Huge::V3664) |
+        (Huge::V3672, Huge::V3673) | (Huge::V3673, Huge::V3672) |
+        (Huge::V3680, Huge::V3681) | (Huge::V3681, Huge::V3680) |
+        (Huge::V3688, Huge::V3689) | (Huge::V3689, Huge::V3688) |
+        (Huge::V3696, Huge::V3697) | (Huge::V3697, Huge::V3696) |
+        (Huge::V3704, Huge::V3705) | (Huge::V3705, Huge::V3704) |
+        (Huge::V3712, Huge::V3713) | (Huge::V3713, Huge::V3712) |
+        (Huge::V3720, Huge::V3721) | (Huge::V3721, Huge::V3720) |
+        (Huge::V3728, Huge::V3729) | (Huge::V3729, Huge::V3728) |
+        (Huge::V3736, Huge::V3737) | (Huge::V3737, Huge::V3736) |
+        (Huge::V3744, Huge::V3745) | (Huge::V3745, Huge::V3744) |
+        (Huge::V3752, Huge::V3753) | (Huge::V3753, Huge::V3752) |
+        (Huge::V3760, Huge::V3761) | (Huge::V3761, Huge::V3760) |
+        (Huge::V3768, Huge::V3769) | (Huge::V3769, Huge::V3768) |
+        (Huge::V3776, Huge::V3777) | (Huge::V3777, Huge::V3776) |
+        (Huge::V3784, Huge::V3785) | (Huge::V3785, Huge::V3784) |
+        (Huge::V3792, Huge::V3793) | (Huge::V3793, Huge::V3792) |
+        (Huge::V3800, Huge::V3801) | (Huge::V3801, Huge::V3800) |
+        (Huge::V3808, Huge::V3809) | (Huge::V3809, Huge::V3808) |
+        (Huge::V3816, Huge::V3817) | (Huge::V3817, Huge::V3816) |
+        (Huge::V3824, Huge::V3825) | (Huge::V3825, Huge::V3824) |
+        (Huge::V3832, Huge::V3833) | (Huge::V3833, Huge::V3832) |
+        (Huge::V3840, Huge::V3841) | (Huge::V3841, Huge::V3840) |
+        (Huge::V3848, Huge::V3849) | (Huge::V3849, Huge::V3848) |
+        (Huge::V3856, Huge::V3857) | (Huge::V3857, Huge::V3856) |
+        (Huge::V3864, Huge::V3865) | (Huge::V3865, Huge::V3864) |
+        (Huge::V3872, Huge::V3873) | (Huge::V3873, Huge::V3872) |
+        (Huge::V3880, Huge::V3881) | (Huge::V3881, Huge::V3880) |
+        (Huge::V3888, Huge::V3889) | (Huge::V3889, Huge::V3888) |
+        (Huge::V3896, Huge::V3897) | (Huge::V3897, Huge::V3896) |
+        (Huge::V3904, Huge::V3905) | (Huge::V3905, Huge::V3904) |
+        (Huge::V3912, Huge::V3913) | (Huge::V3913, Huge::V3912) |
+        (Huge::V3920, Huge::V3921) | (Huge::V3921, Huge::V3920) |
+        (Huge::V3928, Huge::V3929) | (Huge::V3929, Huge::V3928) |
+        (Huge::V3936, Huge::V3937) | (Huge::V3937, Huge::V3936) |
+        (Huge::V3944, Huge::V3945) | (Huge::V3945, Huge::V3944) |
+        (Huge::V3952, Huge::V3953) | (Huge::V3953, Huge::V3952) |
+        (Huge::V3960, Huge::V3961) | (Huge::V3961, Huge::V3960) |
+        (Huge::V3968, Huge::V3969) | (Huge::V3969, Huge::V3968) |
+        (Huge::V3976, Huge::V3977) | (Huge::V3977, Huge::V3976) |
+        (Huge::V3984, Huge::V3985) | (Huge::V3985, Huge::V3984) |
+        (Huge::V3992, Huge::V3993) | (Huge::V3993, Huge::V3992) |
+        (Huge::V4000, Huge::V4001) | (Huge::V4001, Huge::V4000) |
+        (Huge::V4008, Huge::V4009) | (Huge::V4009, Huge::V4008) |
+        (Huge::V4016, Huge::V4017) | (Huge::V4017, Huge::V4016) |
+        (Huge::V4024, Huge::V4025) | (Huge::V4025, Huge::V4024) |
+        (Huge::V4032, Huge::V4033) | (Huge::V4033, Huge::V4032) |
+        (Huge::V4040, Huge::V4041) | (Huge::V4041, Huge::V4040) |
+        (Huge::V4048, Huge::V4049) | (Huge::V4049, Huge::V4048) |
+        (Huge::V4056, Huge::V4057) | (Huge::V4057, Huge::V4056) |
+        (Huge::V4064, Huge::V4065) | (Huge::V4065, Huge::V4064) |
+        (Huge::V4072, Huge::V4073) | (Huge::V4073, Huge::V4072) |
+        (Huge::V4080, Huge::V4081) | (Huge::V4081, Huge::V4080) |
+        (Huge::V4088, Huge::V4089) | (Huge::V4089, Huge::V4088) |
+        (Huge::V0, Huge::V0)
+    )
+}
@@ -0,0 +1,41 @@
+//! Stresses macro expansion by doubling a token stream at every recursion
+//! level, so a short input expands into hundreds of thousands of tokens. The
+//! cost is concentrated in the expansion and parsing passes rather than in
+//! type checking or codegen.
+
+macro_rules! double {
+    (0, $($t:tt)*) => {
+        [$($t)*]
+    };
+    ($n:tt, $($t:tt)*) => {
+        dec!($n, $($t)* $($t)*)
+    };
+}
+
+macro_rules! dec {
+    (18, $($t:tt)*) => { double!(17, $($t)*) };
+    (17, $($t:tt)*) => { double!(16, $($t)*) };
+    (16, $($t:tt)*) => { double!(15, $($t)*) };
+    (15, $($t:tt)*) => { double!(14, $($t)*) };
+    (14, $($t:tt)*) => { double!(13, $($t)*) };
+    (13, $($t:tt)*) => { double!(12, $($t)*) };
+    (12, $($t:tt)*) => { double!(11, $($t)*) };
+    (11, $($t:tt)*) => { double!(10, $($t)*) };
+    (10, $($t:tt)*) => { double!(9, $($t)*) };
+    (9, $($t:tt)*) => { double!(8, $($t)*) };
+    (8, $($t:tt)*) => { double!(7, $($t)*) };
+    (7, $($t:tt)*) => { double!(6, $($t)*) };
+    (6, $($t:tt)*) => { double!(5, $($t)*) };
+    (5, $($t:tt)*) => { double!(4, $($t)*) };
+    (4, $($t:tt)*) => { double!(3, $($t)*) };
+    (3, $($t:tt)*) => { double!(2, $($t)*) };
+    (2, $($t:tt)*) => { double!(1, $($t)*) };
+    (1, $($t:tt)*) => { double!(0, $($t)*) };
+}
+
+// 2^18 = 262144 elements.
+pub static DATA: [u8; 262144] = double!(18, 0u8,);
+
+pub fn checksum() -> u64 {
+    DATA.iter().map(|&b| b as u64).sum()
+}
@@ -10,6 +10,14 @@ pub mod next_artifact {
             exclude: Option<String>,
             runs: Option<i32>,
         },
+        /// Re-run a single test case of an already benchmarked commit with
+        /// self-profiling enabled and upload the resulting trace.
+        SelfProfile {
+            commit: Commit,
+            benchmark: String,
+            profile: String,
+            scenario: String,
+        },
     }
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
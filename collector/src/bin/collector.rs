@@ -9,13 +9,15 @@ use collector::compile::benchmark::category::Category;
 use collector::compile::benchmark::profile::Profile;
 use collector::compile::benchmark::scenario::Scenario;
 use collector::compile::benchmark::{
-    compile_benchmark_dir, get_compile_benchmarks, ArtifactType, Benchmark, BenchmarkName,
+    compile_benchmark_dir, get_compile_benchmarks, validate_benchmark_dir, ArtifactType, Benchmark,
+    BenchmarkName,
 };
+use collector::preset::Preset;
 use collector::{utils, CollectorCtx, CollectorStepBuilder};
-use database::{ArtifactId, ArtifactIdNumber, Commit, CommitType, Connection, Pool};
+use database::{ArtifactId, ArtifactIdNumber, Commit, CommitType, Connection, Lookup, Pool};
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::fs;
 use std::fs::File;
@@ -31,16 +33,19 @@ use tokio::runtime::Runtime;
 
 use collector::compile::execute::bencher::BenchProcessor;
 use collector::compile::execute::profiler::{ProfileProcessor, Profiler};
+use collector::compile::execute::tool::{ToolBenchmark, ToolConfig};
 use collector::runtime::{
     bench_runtime, get_runtime_benchmark_groups, prepare_runtime_benchmark_suite,
-    runtime_benchmark_dir, BenchmarkFilter, BenchmarkSuite, BenchmarkSuiteCompilation,
-    CargoIsolationMode, RuntimeProfiler, DEFAULT_RUNTIME_ITERATIONS,
+    runtime_benchmark_dir, test_runtime_benchmark_suite, BenchmarkFilter, BenchmarkOutput,
+    BenchmarkSuite, BenchmarkSuiteCompilation, CargoIsolationMode, RuntimeProfiler,
+    DEFAULT_RUNTIME_ITERATIONS,
 };
 use collector::runtime::{profile_runtime, RuntimeCompilationOpts};
 use collector::toolchain::{
-    create_toolchain_from_published_version, get_local_toolchain, Sysroot, Toolchain,
+    create_toolchain_from_published_version, get_local_toolchain, CargoVariant, Sysroot, Toolchain,
 };
 use collector::utils::cachegrind::cachegrind_diff;
+use collector::utils::llvm_lines::llvm_lines_diff;
 use collector::utils::{is_installed, wait_for_future};
 
 fn n_normal_benchmarks_remaining(n: usize) -> String {
@@ -81,23 +86,119 @@ struct CompileBenchmarkConfig {
     benchmarks: Vec<Benchmark>,
     profiles: Vec<Profile>,
     scenarios: Vec<Scenario>,
-    iterations: Option<usize>,
+    iterations: Option<IterationSpec>,
     is_self_profile: bool,
     bench_rustc: bool,
+    bench_hello_world: bool,
+    tool_benchmarks: Vec<ToolBenchmark>,
+    reuse_target_dirs: bool,
+    sccache: Option<PathBuf>,
+}
+
+/// Iteration counts parsed from `--iterations`: a default count plus
+/// `<glob>=<n>` overrides. The first override whose glob matches a
+/// benchmark's name wins.
+#[derive(Clone, Debug)]
+struct IterationSpec {
+    default: usize,
+    overrides: Vec<(String, usize)>,
+}
+
+impl IterationSpec {
+    /// The same count for every benchmark, without overrides.
+    fn fixed(default: usize) -> IterationSpec {
+        IterationSpec {
+            default,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Parses the values passed to `--iterations`: at most one plain number
+    /// (the default count) and any number of `<glob>=<n>` overrides.
+    fn parse(values: &[String]) -> anyhow::Result<IterationSpec> {
+        let mut default = None;
+        let mut overrides = Vec::new();
+        for value in values {
+            match value.split_once('=') {
+                Some((pattern, count)) => {
+                    let count = count.parse().with_context(|| {
+                        format!("invalid iteration count in `{value}`")
+                    })?;
+                    overrides.push((pattern.to_string(), count));
+                }
+                None => {
+                    let count = value
+                        .parse()
+                        .with_context(|| format!("invalid iteration count `{value}`"))?;
+                    if default.replace(count).is_some() {
+                        anyhow::bail!("at most one plain `--iterations` count may be given");
+                    }
+                }
+            }
+        }
+        Ok(IterationSpec {
+            default: default.unwrap_or(1),
+            overrides,
+        })
+    }
+
+    fn for_benchmark(&self, name: &str) -> usize {
+        self.overrides
+            .iter()
+            .find(|(pattern, _)| glob_matches(pattern, name))
+            .map(|&(_, count)| count)
+            .unwrap_or(self.default)
+    }
+}
+
+/// Matches `name` against a glob pattern where `*` stands for any (possibly
+/// empty) substring. Patterns without a `*` must match the name exactly.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == name;
+    }
+    let segments: Vec<&str> = pattern.split('*').collect();
+    // The parts before the first and after the last `*` are anchored at the
+    // start and end of the name respectively.
+    let (first, rest_segments) = segments.split_first().unwrap();
+    let (last, middle) = rest_segments.split_last().unwrap();
+    if name.len() < first.len() + last.len()
+        || !name.starts_with(first)
+        || !name.ends_with(last)
+    {
+        return false;
+    }
+    // The segments between `*`s just have to appear in order; matching each
+    // at the leftmost possible position never rules out a valid match.
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for segment in middle {
+        match rest.find(segment) {
+            Some(pos) => rest = &rest[pos + segment.len()..],
+            None => return false,
+        }
+    }
+    true
 }
 
 struct RuntimeBenchmarkConfig {
     runtime_suite: BenchmarkSuite,
     filter: BenchmarkFilter,
     iterations: u32,
+    output: BenchmarkOutput,
 }
 
 impl RuntimeBenchmarkConfig {
-    fn new(suite: BenchmarkSuite, filter: BenchmarkFilter, iterations: u32) -> Self {
+    fn new(
+        suite: BenchmarkSuite,
+        filter: BenchmarkFilter,
+        iterations: u32,
+        output: BenchmarkOutput,
+    ) -> Self {
         Self {
             runtime_suite: suite.filter(&filter),
             filter,
             iterations,
+            output,
         }
     }
 }
@@ -174,6 +275,43 @@ fn generate_cachegrind_diffs(
     annotated_diffs
 }
 
+/// Generates per-function diffs of the `cargo llvm-lines` reports produced by
+/// profiling with two toolchains, to make chasing monomorphization bloat
+/// easier. `cargo llvm-lines` only runs for the Debug and Opt profiles and
+/// the Full scenario, so only those combinations are diffed.
+fn generate_llvm_lines_diffs(
+    id1: &str,
+    id2: &str,
+    out_dir: &Path,
+    benchmarks: &[Benchmark],
+    profiles: &[Profile],
+    errors: &mut BenchmarkErrors,
+) -> Vec<PathBuf> {
+    let mut diffs = Vec::new();
+    for benchmark in benchmarks {
+        for &profile in profiles {
+            if !matches!(profile, Profile::Debug | Profile::Opt) {
+                continue;
+            }
+            let filename =
+                |prefix, id| format!("{}-{}-{}-{:?}-Full", prefix, id, benchmark.name, profile);
+            let id_diff = format!("{}-{}", id1, id2);
+            let ll1 = out_dir.join(filename("ll", id1));
+            let ll2 = out_dir.join(filename("ll", id2));
+            let ll_diff = out_dir.join(filename("ll-diff", &id_diff));
+
+            if let Err(e) = llvm_lines_diff(&ll1, &ll2, &ll_diff) {
+                errors.incr();
+                eprintln!("collector error: {:?}", e);
+                continue;
+            }
+
+            diffs.push(ll_diff);
+        }
+    }
+    diffs
+}
+
 #[allow(clippy::too_many_arguments)]
 fn profile_compile(
     toolchain: &Toolchain,
@@ -375,6 +513,19 @@ struct CompileTimeOptions {
     /// The path to the local rustdoc to measure
     #[arg(long)]
     rustdoc: Option<PathBuf>,
+
+    /// Retain prepared benchmark target directories between runs, keyed by a
+    /// hash of the benchmark sources, build flags and toolchain, and reuse
+    /// them instead of rebuilding dependencies when the hash matches
+    #[arg(long = "reuse-target-dirs")]
+    reuse_target_dirs: bool,
+
+    /// Wrap dependency (non-measured) crate compilations with the given
+    /// sccache binary. The measured leaf crate is never cached, so the
+    /// recorded statistics are unaffected, but full-scenario preparation gets
+    /// much cheaper on repeated runs
+    #[arg(long)]
+    sccache: Option<PathBuf>,
 }
 
 #[derive(Debug, clap::Args)]
@@ -383,6 +534,13 @@ struct RuntimeOptions {
     /// found groups will be compiled.
     #[arg(long)]
     group: Option<String>,
+
+    /// Build the runtime benchmark groups with the Cargo features in this comma-separated list
+    /// (e.g. `simd`). Only features declared in a group's `Cargo.toml` are enabled for that group.
+    /// Benchmarks built with features are recorded under a `<name>@<features>` identity, so that
+    /// feature configurations can be compared against each other.
+    #[arg(long, value_delimiter = ',')]
+    features: Vec<String>,
 }
 
 #[derive(Debug, clap::Args)]
@@ -408,6 +566,52 @@ struct BenchRustcOption {
     bench_rustc: bool,
 }
 
+#[derive(Debug, clap::Args)]
+struct BenchHelloWorldOption {
+    /// Run the special `helloworld-e2e` benchmark, which measures the
+    /// end-to-end latency of `cargo new` plus a cold and a warm `cargo build`
+    #[arg(long = "bench-hello-world")]
+    bench_hello_world: bool,
+}
+
+#[derive(Debug, clap::Args)]
+struct ToolConfigOption {
+    /// Run the auxiliary tool benchmarks (rustfmt, clippy) declared in this
+    /// JSON configuration file; results are recorded under the `tool:`
+    /// benchmark namespace
+    #[arg(long = "tool-config")]
+    tool_config: Option<PathBuf>,
+}
+
+impl ToolConfigOption {
+    fn benchmarks(&self) -> anyhow::Result<Vec<ToolBenchmark>> {
+        match &self.tool_config {
+            Some(path) => Ok(ToolConfig::from_file(path)?.tools),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum CargoArg {
+    /// The cargo that ships with the benchmarked artifact
+    Artifact,
+    /// A pinned stock cargo (the rustup nightly one)
+    Stock,
+    /// Benchmark with both cargos
+    Both,
+}
+
+impl CargoArg {
+    fn variants(self) -> Vec<CargoVariant> {
+        match self {
+            CargoArg::Artifact => vec![CargoVariant::Artifact],
+            CargoArg::Stock => vec![CargoVariant::Stock],
+            CargoArg::Both => vec![CargoVariant::Artifact, CargoVariant::Stock],
+        }
+    }
+}
+
 // For each subcommand we list the mandatory arguments in the required
 // order, followed by the options in alphabetical order.
 #[derive(Debug, clap::Subcommand)]
@@ -428,10 +632,19 @@ enum Commands {
         #[command(flatten)]
         db: DbOption,
 
+        /// Print progress events as JSON, one object per line, instead of
+        /// progress bars. Useful for consuming the output in CI.
+        #[arg(long = "log-json", conflicts_with = "quiet")]
+        log_json: bool,
+
         /// Compile runtime benchmarks directly in their crate directory, to make local experiments
         /// faster.
         #[arg(long = "no-isolate")]
         no_isolate: bool,
+
+        /// Suppress progress and per-benchmark output, printing only errors.
+        #[arg(long)]
+        quiet: bool,
     },
 
     /// Profiles a runtime benchmark.
@@ -482,9 +695,53 @@ enum Commands {
         #[command(flatten)]
         bench_rustc: BenchRustcOption,
 
-        /// The number of iterations to do for each benchmark
+        #[command(flatten)]
+        bench_hello_world: BenchHelloWorldOption,
+
+        #[command(flatten)]
+        tool_config: ToolConfigOption,
+
+        /// Experimental: run only benchmarks historically sensitive to the
+        /// compiler areas touched by the given diff. The argument is a path
+        /// to a file with one touched path per line (e.g. the output of
+        /// `git diff --name-only`). If the sensitivity matrix does not cover
+        /// all touched paths, the full suite is run instead.
+        #[arg(long)]
+        affected_by: Option<PathBuf>,
+
+        /// The number of iterations to do for each benchmark. A plain number
+        /// applies to every benchmark; repeated occurrences of the form
+        /// `<glob>=<n>` override the count for benchmarks whose name matches
+        /// the glob, e.g. `--iterations 3 --iterations 'cargo*=1'`.
         #[arg(long, default_value = "1")]
-        iterations: usize,
+        iterations: Vec<String>,
+
+        /// Record only the metrics in this comma-separated list (e.g.
+        /// `instructions,wall-time`), skipping the measurement overhead of the
+        /// rest. By default, all supported metrics are recorded.
+        #[arg(long)]
+        metrics: Option<String>,
+
+        /// Run the benchmark builds inside this Docker/Podman image, with the
+        /// toolchain and working directory mounted in. The image digest is
+        /// recorded with the results, which makes results from different
+        /// machines more comparable and documents environment drift.
+        #[arg(long)]
+        container: Option<String>,
+
+        /// Benchmark only the given comma-separated benchmark categories.
+        /// By default the primary and secondary benchmarks are run; this is
+        /// the only way to run the `stress` benchmarks, which are excluded
+        /// from the default suite.
+        #[arg(long, value_enum, value_delimiter = ',')]
+        category: Vec<Category>,
+
+        /// Run only a curated subset of the benchmark suite. The `quick`
+        /// preset is a roughly ten-benchmark subset whose results predict the
+        /// full-suite summary well, intended for fast local sanity checks.
+        /// The subsets are defined in `collector/benchmark-presets`.
+        #[arg(long, value_enum)]
+        preset: Option<Preset>,
 
         #[command(flatten)]
         self_profile: SelfProfileOption,
@@ -501,17 +758,107 @@ enum Commands {
         #[command(flatten)]
         bench_rustc: BenchRustcOption,
 
+        #[command(flatten)]
+        bench_hello_world: BenchHelloWorldOption,
+
+        #[command(flatten)]
+        tool_config: ToolConfigOption,
+
         #[command(flatten)]
         self_profile: SelfProfileOption,
     },
 
-    /// Benchmarks a published toolchain for perf.rust-lang.org's dashboard
+    /// Benchmarks published toolchains for perf.rust-lang.org's dashboard
     BenchPublished {
-        /// Toolchain (e.g. stable, beta, 1.26.0)
-        toolchain: String,
+        /// Toolchains (e.g. stable, beta, 1.26.0). When several are given,
+        /// the next release's download runs in the background while the
+        /// current one is benchmarked, which speeds up backfilling many
+        /// releases considerably.
+        #[arg(required = true)]
+        toolchains: Vec<String>,
+
+        /// Which cargo to drive the builds with: the toolchain's own cargo, a
+        /// pinned stock cargo (the rustup nightly one), or both. Stock-cargo
+        /// results are recorded under a `-stock-cargo` id suffix, which makes
+        /// it possible to attribute wall-time shifts to cargo rather than
+        /// rustc.
+        #[arg(long, value_enum, default_value = "artifact")]
+        cargo: CargoArg,
+
+        #[command(flatten)]
+        db: DbOption,
+    },
+
+    /// Benchmarks master commits that are missing results in the database,
+    /// newest first. Progress is persisted, so an interrupted backfill can be
+    /// resumed by running the command again.
+    Backfill {
+        #[command(flatten)]
+        db: DbOption,
+
+        /// Only consider master commits merged on or after this day (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        from: chrono::NaiveDate,
+
+        /// Backfill only benchmarks matching a prefix in this comma-separated list
+        #[arg(long)]
+        benchmarks: Option<String>,
+
+        #[command(flatten)]
+        bench_rustc: BenchRustcOption,
+
+        /// Print the missing (commit, benchmark) combinations without
+        /// benchmarking anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// File recording the commits that have already been processed
+        #[arg(long, default_value = "backfill-progress.txt")]
+        progress_file: PathBuf,
+
+        #[command(flatten)]
+        self_profile: SelfProfileOption,
+    },
+
+    /// Benchmarks newly added benchmarks against the most recent stored master
+    /// artifacts, so that their series starts out with history instead of a
+    /// single point
+    BenchNewBenchmarks {
+        /// Include only benchmarks matching a prefix in this comma-separated list
+        #[arg(long)]
+        benchmarks: String,
+
+        /// Number of most recent stored master artifacts to benchmark against
+        #[arg(long, default_value = "10")]
+        artifacts: usize,
+
+        #[command(flatten)]
+        db: DbOption,
+
+        #[command(flatten)]
+        self_profile: SelfProfileOption,
+    },
 
+    /// Re-benchmarks a random subset of recent stored master artifacts and
+    /// records the deltas against their previously stored results. The
+    /// compiler does not change between the two measurements, so the deltas
+    /// are an empirical sample of infrastructure noise. Intended to be run on
+    /// a schedule (e.g. from cron).
+    BenchVariance {
         #[command(flatten)]
         db: DbOption,
+
+        /// Number of most recent stored master artifacts to sample from
+        #[arg(long, default_value = "30")]
+        window: usize,
+
+        /// Number of artifacts to re-benchmark per invocation
+        #[arg(long, default_value = "2")]
+        artifacts: usize,
+
+        /// Re-benchmark only benchmarks matching a prefix in this comma-separated list
+        #[arg(long)]
+        benchmarks: Option<String>,
     },
 
     /// Profiles a local rustc with one of several profilers
@@ -547,6 +894,27 @@ enum Commands {
 
     /// Download a crate into collector/benchmarks.
     Download(DownloadCommand),
+
+    /// Maintenance operations on a results database.
+    Database {
+        #[command(subcommand)]
+        command: DatabaseSubcommand,
+    },
+
+    /// Check every compile benchmark's perf-config.json and on-disk layout
+    /// against the schema the collector expects, without running anything.
+    Validate,
+
+    /// Builds every compile-time benchmark once at minimal settings and runs
+    /// each runtime benchmark for a single iteration, as a fast smoke test of
+    /// the benchmark suite. Results are recorded into a throwaway database.
+    TestBenchmarks {
+        #[command(flatten)]
+        local: LocalOptions,
+
+        #[command(flatten)]
+        runtime: RuntimeOptions,
+    },
 }
 
 #[derive(Debug, clap::Parser)]
@@ -583,8 +951,60 @@ enum DownloadSubcommand {
     },
 }
 
+#[derive(Debug, clap::Parser)]
+enum DatabaseSubcommand {
+    /// Removes a compile benchmark and every result recorded for it
+    /// (measurements, self-profile data and errors) from the database.
+    RemoveBenchmark {
+        /// Name of the benchmark to remove
+        name: String,
+
+        #[command(flatten)]
+        db: DbOption,
+
+        /// Only print what would be removed, without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Drops a commit from the pending benchmark queue by marking its queued
+    /// build as complete. Master commits are re-derived from git on every
+    /// queue refresh; use `blacklist` for those.
+    DropFromQueue {
+        /// Commit sha to drop
+        sha: String,
+
+        #[command(flatten)]
+        db: DbOption,
+    },
+
+    /// Blacklists a known-broken commit (e.g. one that ICEs during
+    /// bootstrap), so that it stops being re-enqueued on every queue refresh.
+    Blacklist {
+        /// Commit sha or artifact tag to blacklist
+        sha: String,
+
+        /// Why the artifact is blacklisted
+        #[arg(long)]
+        reason: Option<String>,
+
+        #[command(flatten)]
+        db: DbOption,
+    },
+
+    /// Removes a commit from the blacklist.
+    Unblacklist {
+        /// Commit sha or artifact tag to remove from the blacklist
+        sha: String,
+
+        #[command(flatten)]
+        db: DbOption,
+    },
+}
+
 fn main_result() -> anyhow::Result<i32> {
     env_logger::init();
+    collector::utils::shutdown::install_signal_handlers();
 
     let args = Cli::parse();
 
@@ -614,7 +1034,9 @@ fn main_result() -> anyhow::Result<i32> {
             runtime,
             iterations,
             db,
+            log_json,
             no_isolate,
+            quiet,
         } => {
             log_db(&db);
             let toolchain = get_local_toolchain_for_runtime_benchmarks(&local, &target_triple)?;
@@ -626,6 +1048,14 @@ fn main_result() -> anyhow::Result<i32> {
                 CargoIsolationMode::Isolated
             };
 
+            let output = if log_json {
+                BenchmarkOutput::Json
+            } else if quiet {
+                BenchmarkOutput::Quiet
+            } else {
+                BenchmarkOutput::Progress
+            };
+
             let mut conn = rt.block_on(pool.connection());
             let artifact_id = ArtifactId::Tag(toolchain.id.clone());
             let runtime_suite = rt.block_on(load_runtime_benchmarks(
@@ -633,8 +1063,10 @@ fn main_result() -> anyhow::Result<i32> {
                 &runtime_benchmark_dir,
                 isolation_mode,
                 runtime.group,
+                RuntimeCompilationOpts::default().features(runtime.features),
                 &toolchain,
                 &artifact_id,
+                output,
             ))?;
 
             let shared = SharedBenchmarkConfig {
@@ -645,6 +1077,7 @@ fn main_result() -> anyhow::Result<i32> {
                 runtime_suite,
                 BenchmarkFilter::new(local.exclude, local.include),
                 iterations,
+                output,
             );
             run_benchmarks(&mut rt, conn, shared, None, Some(config))?;
             Ok(0)
@@ -673,9 +1106,12 @@ fn main_result() -> anyhow::Result<i32> {
                     runtime.group.clone(),
                     // Compile with debuginfo to have filenames and line numbers available in the
                     // generated profiles.
-                    RuntimeCompilationOpts::default().debug_info("1"),
+                    RuntimeCompilationOpts::default()
+                        .debug_info("1")
+                        .features(runtime.features.clone()),
+                    BenchmarkOutput::default(),
                 )?
-                .extract_suite();
+                .extract_suite()?;
                 Ok::<_, anyhow::Error>((toolchain, suite))
             };
 
@@ -744,13 +1180,44 @@ fn main_result() -> anyhow::Result<i32> {
             opts,
             db,
             bench_rustc,
+            bench_hello_world,
+            tool_config,
+            affected_by,
             iterations,
+            metrics,
+            container,
+            category,
+            preset,
             self_profile,
         } => {
             log_db(&db);
+
+            if let Some(image) = &container {
+                if std::env::var_os(utils::container::CONTAINER_DIGEST_VAR).is_none() {
+                    // Re-execute this same invocation inside the container.
+                    // Only paths that exist on disk need mounting; the rustc
+                    // argument can also be a `+`-prefixed toolchain name and
+                    // the database can be a Postgres URL.
+                    let mounts: Vec<&Path> = [&local.rustc, &db.db]
+                        .into_iter()
+                        .map(Path::new)
+                        .chain(local.cargo.as_deref())
+                        .chain(opts.rustdoc.as_deref())
+                        .filter(|path| path.exists())
+                        .collect();
+                    return utils::container::run_in_container(image, &mounts);
+                }
+            }
+
             let profiles = opts.profiles.0;
             let scenarios = opts.scenarios.0;
 
+            if let Some(metrics) = &metrics {
+                // Inherited by the Cargo processes we spawn and, through them,
+                // by `rustc-fake`, which narrows its measurements accordingly.
+                std::env::set_var("RUSTC_PERF_METRICS", metrics);
+            }
+
             let pool = database::Pool::open(&db.db);
 
             let toolchain = get_local_toolchain(
@@ -769,7 +1236,51 @@ fn main_result() -> anyhow::Result<i32> {
                 local.exclude.as_deref(),
                 local.exclude_suffix.as_deref(),
             )?;
-            benchmarks.retain(|b| b.category().is_primary_or_secondary());
+            if category.is_empty() {
+                benchmarks.retain(|b| b.category().is_primary_or_secondary());
+            } else {
+                benchmarks.retain(|b| category.contains(&b.category()));
+            }
+
+            if let Some(preset) = preset {
+                let names = preset.benchmark_names();
+                benchmarks.retain(|b| names.contains(&b.name.0.as_str()));
+            }
+
+            if let Some(diff_file) = &affected_by {
+                let paths: Vec<String> = fs::read_to_string(diff_file)
+                    .with_context(|| {
+                        format!("cannot read touched paths from `{}`", diff_file.display())
+                    })?
+                    .lines()
+                    .map(|line| line.trim().to_string())
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                match collector::sensitivity::select_affected_benchmarks(&paths) {
+                    Some(affected) => {
+                        benchmarks.retain(|b| {
+                            affected
+                                .iter()
+                                .any(|prefix| b.name.0.starts_with(prefix))
+                        });
+                        println!(
+                            "Sensitivity matrix selected {} benchmark(s): {}",
+                            benchmarks.len(),
+                            benchmarks
+                                .iter()
+                                .map(|b| b.name.0.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        );
+                    }
+                    None => {
+                        eprintln!(
+                            "The sensitivity matrix does not cover all touched paths; \
+                             running the full benchmark suite"
+                        );
+                    }
+                }
+            }
 
             let artifact_id = ArtifactId::Tag(toolchain.id.clone());
             let conn = rt.block_on(pool.connection());
@@ -781,9 +1292,13 @@ fn main_result() -> anyhow::Result<i32> {
                 benchmarks,
                 profiles,
                 scenarios,
-                iterations: Some(iterations),
+                iterations: Some(IterationSpec::parse(&iterations)?),
                 is_self_profile: self_profile.self_profile,
                 bench_rustc: bench_rustc.bench_rustc,
+                bench_hello_world: bench_hello_world.bench_hello_world,
+                tool_benchmarks: tool_config.benchmarks()?,
+                reuse_target_dirs: opts.reuse_target_dirs,
+                sccache: opts.sccache,
             };
 
             run_benchmarks(&mut rt, conn, shared, Some(config), None)?;
@@ -794,6 +1309,8 @@ fn main_result() -> anyhow::Result<i32> {
             site_url,
             db,
             bench_rustc,
+            bench_hello_world,
+            tool_config,
             self_profile,
         } => {
             log_db(&db);
@@ -840,8 +1357,10 @@ fn main_result() -> anyhow::Result<i32> {
                     runs,
                 } => {
                     let sha = commit.sha.to_string();
+                    let download_start = chrono::Utc::now();
                     let sysroot = Sysroot::install(sha.clone(), &target_triple)
                         .with_context(|| format!("failed to install sysroot for {:?}", commit))?;
+                    let download_end = chrono::Utc::now();
 
                     let mut benchmarks = get_compile_benchmarks(
                         &compile_benchmark_dir,
@@ -855,27 +1374,44 @@ fn main_result() -> anyhow::Result<i32> {
                     let mut conn = rt.block_on(pool.connection());
                     let toolchain = Toolchain::from_sysroot(&sysroot, sha);
 
+                    // The sysroot was downloaded before the database connection
+                    // existed, so record how long it took now that we have one.
+                    let artifact_row_id = rt.block_on(conn.artifact_id(&artifact_id));
+                    rt.block_on(conn.record_step_timing(
+                        artifact_row_id,
+                        "harness:toolchain-download",
+                        download_start,
+                        download_end,
+                    ));
+
                     let compile_config = CompileBenchmarkConfig {
                         benchmarks,
                         profiles: Profile::all(),
                         scenarios: Scenario::all(),
-                        iterations: runs.map(|v| v as usize),
+                        iterations: runs.map(|v| IterationSpec::fixed(v as usize)),
                         is_self_profile: self_profile.self_profile,
                         bench_rustc: bench_rustc.bench_rustc,
+                        bench_hello_world: bench_hello_world.bench_hello_world,
+                        tool_benchmarks: tool_config.benchmarks()?,
+                        reuse_target_dirs: false,
+                        sccache: None,
                     };
                     let runtime_suite = rt.block_on(load_runtime_benchmarks(
                         conn.as_mut(),
                         &runtime_benchmark_dir,
                         CargoIsolationMode::Isolated,
                         None,
+                        RuntimeCompilationOpts::default(),
                         &toolchain,
                         &artifact_id,
+                        BenchmarkOutput::default(),
                     ))?;
 
                     let runtime_config = RuntimeBenchmarkConfig {
                         runtime_suite,
                         filter: BenchmarkFilter::keep_all(),
                         iterations: DEFAULT_RUNTIME_ITERATIONS,
+                        output: BenchmarkOutput::default(),
                     };
                     let shared = SharedBenchmarkConfig {
                         artifact_id,
@@ -892,6 +1428,68 @@ fn main_result() -> anyhow::Result<i32> {
 
                     client.post(format!("{}/perf/onpush", site_url)).send()?;
 
+                    res?;
+                }
+                NextArtifact::SelfProfile {
+                    commit,
+                    benchmark,
+                    profile,
+                    scenario,
+                } => {
+                    let sha = commit.sha.to_string();
+                    let sysroot = Sysroot::install(sha.clone(), &target_triple)
+                        .with_context(|| format!("failed to install sysroot for {:?}", commit))?;
+
+                    let mut benchmarks = get_compile_benchmarks(
+                        &compile_benchmark_dir,
+                        Some(&benchmark),
+                        None,
+                        None,
+                    )?;
+                    benchmarks.retain(|b| b.name.0 == benchmark);
+                    if benchmarks.is_empty() {
+                        anyhow::bail!("benchmark {benchmark} not found in the suite");
+                    }
+                    let profile: Profile = clap::ValueEnum::from_str(&profile, true)
+                        .map_err(|e| anyhow::anyhow!("unknown profile {profile}: {e}"))?;
+                    let scenario: Scenario = clap::ValueEnum::from_str(&scenario, true)
+                        .map_err(|e| anyhow::anyhow!("unknown scenario {scenario}: {e}"))?;
+
+                    let artifact_id = ArtifactId::Commit(commit);
+                    let mut conn = rt.block_on(pool.connection());
+                    let toolchain = Toolchain::from_sysroot(&sysroot, sha);
+                    let artifact_row_id = rt.block_on(conn.artifact_id(&artifact_id));
+
+                    // Measure directly instead of going through
+                    // `run_benchmarks`: the artifact has already been fully
+                    // benchmarked, so we must not touch its collection steps
+                    // or recorded duration. The results still get their own
+                    // run, so they don't blend into the original ones.
+                    let run_id =
+                        rt.block_on(conn.start_run(artifact_row_id, &collector::collector_name()));
+                    let mut res = Ok(());
+                    for benchmark in &benchmarks {
+                        let mut processor = BenchProcessor::new(
+                            conn.as_mut(),
+                            &benchmark.name,
+                            &artifact_id,
+                            artifact_row_id,
+                            run_id,
+                            true,
+                        );
+                        res = rt
+                            .block_on(with_timeout(benchmark.measure(
+                                &mut processor,
+                                &[profile],
+                                &[scenario],
+                                &toolchain,
+                                Some(1),
+                            )))
+                            .map(|_| ());
+                    }
+
+                    client.post(format!("{}/perf/onpush", site_url)).send()?;
+
                     res?;
                 }
             }
@@ -899,59 +1497,461 @@ fn main_result() -> anyhow::Result<i32> {
             Ok(0)
         }
 
-        Commands::BenchPublished { toolchain, db } => {
+        Commands::BenchPublished {
+            toolchains,
+            cargo,
+            db,
+        } => {
             log_db(&db);
             let pool = database::Pool::open(&db.db);
-            let conn = rt.block_on(pool.connection());
-            let toolchain = create_toolchain_from_published_version(&toolchain, &target_triple)?;
-            bench_published_artifact(conn, &mut rt, toolchain, &benchmark_dirs)?;
+
+            // The next release downloads while the current one is
+            // benchmarked. Only the network transfer overlaps: the
+            // measurements themselves stay strictly sequential, since
+            // running them concurrently would add noise. Each release
+            // builds in its own temporary target dirs (see
+            // `Benchmark::make_temp_dir`), so nothing leaks between
+            // releases.
+            let spawn_download = |toolchain: String, target_triple: String| {
+                std::thread::spawn(move || {
+                    create_toolchain_from_published_version(&toolchain, &target_triple)
+                })
+            };
+            let mut toolchains = toolchains.into_iter();
+            let mut pending = toolchains
+                .next()
+                .map(|t| spawn_download(t, target_triple.clone()));
+            while let Some(handle) = pending {
+                let toolchain = handle.join().expect("toolchain download thread panicked")?;
+                pending = toolchains
+                    .next()
+                    .map(|t| spawn_download(t, target_triple.clone()));
+                for variant in cargo.variants() {
+                    let conn = rt.block_on(pool.connection());
+                    bench_published_artifact(
+                        conn,
+                        &mut rt,
+                        toolchain.clone().with_cargo_variant(variant)?,
+                        &benchmark_dirs,
+                    )?;
+                }
+            }
             Ok(0)
         }
 
-        Commands::ProfileLocal {
-            profiler,
-            local,
-            opts,
-            out_dir,
-            rustc2,
-            jobs,
+        Commands::Backfill {
+            db,
+            from,
+            benchmarks,
+            bench_rustc,
+            dry_run,
+            progress_file,
+            self_profile,
         } => {
-            let jobs = jobs.max(1);
-            if jobs > 1 && !profiler.supports_parallel_execution() {
-                anyhow::bail!(
-                    "Profiler {:?} does not support parallel execution.",
-                    profiler
-                );
-            }
+            log_db(&db);
+            let pool = database::Pool::open(&db.db);
+            let mut conn = rt.block_on(pool.connection());
 
-            let profiles = &opts.profiles.0;
-            let scenarios = &opts.scenarios.0;
+            let from = chrono::DateTime::<chrono::Utc>::from_utc(
+                from.and_hms_opt(0, 0, 0).unwrap(),
+                chrono::Utc,
+            );
+            let mut master_commits = rt
+                .block_on(collector::master_commits())
+                .map_err(|e| anyhow::anyhow!("{:?}", e))
+                .context("getting master commit list")?;
+            // The list is newest-first; recent commits are the most interesting
+            // ones, so that is also our priority order.
+            master_commits.retain(|c| c.time >= from);
+
+            // Shas of commits that a previous (interrupted) backfill already
+            // processed.
+            let processed: HashSet<String> = match fs::read_to_string(&progress_file) {
+                Ok(contents) => contents.lines().map(|l| l.trim().to_string()).collect(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("failed to read progress file {}", progress_file.display())
+                    })
+                }
+            };
 
-            let mut benchmarks = get_compile_benchmarks(
+            let mut requested = get_compile_benchmarks(
                 &compile_benchmark_dir,
-                local.include.as_deref(),
-                local.exclude.as_deref(),
-                local.exclude_suffix.as_deref(),
+                benchmarks.as_deref(),
+                None,
+                None,
             )?;
-            benchmarks.retain(|b| b.category().is_primary_or_secondary());
+            requested.retain(|b| b.category().is_primary_or_secondary());
+
+            let index = rt.block_on(database::Index::load(conn.as_mut()));
+
+            // Map each benchmark to the instructions:u statistic descriptions
+            // recorded for it; a benchmark is considered present for an
+            // artifact if any of them has a value.
+            let mut series_ids = Vec::new();
+            let mut series_benchmarks = Vec::new();
+            for ((benchmark, _, _, metric), id) in index.compile_statistic_descriptions() {
+                if metric.as_str() == "instructions:u" {
+                    series_ids.push(id);
+                    series_benchmarks.push(benchmark.as_str());
+                }
+            }
 
-            let mut errors = BenchmarkErrors::new();
+            for master_commit in master_commits {
+                let sha = master_commit.sha.clone();
+                if processed.contains(&sha) {
+                    continue;
+                }
+                let commit = Commit {
+                    sha: sha.as_str().into(),
+                    date: master_commit.time.into(),
+                    r#type: CommitType::Master,
+                };
 
-            eprintln!("Running with {jobs} job(s)");
-            rayon::ThreadPoolBuilder::new()
-                .num_threads(jobs as usize)
-                .build_global()
-                .unwrap();
+                let benchmarked: HashSet<&str> =
+                    match ArtifactId::Commit(commit.clone()).lookup(&index) {
+                        Some(aid) => {
+                            let values =
+                                rt.block_on(conn.get_pstats(&series_ids, &[Some(aid)]));
+                            series_benchmarks
+                                .iter()
+                                .zip(values)
+                                .filter(|(_, values)| values.iter().any(|v| v.is_some()))
+                                .map(|(benchmark, _)| *benchmark)
+                                .collect()
+                        }
+                        // The artifact was never benchmarked at all.
+                        None => HashSet::new(),
+                    };
+                let missing: Vec<&str> = requested
+                    .iter()
+                    .map(|b| b.name.0.as_str())
+                    .filter(|name| !benchmarked.contains(name))
+                    .collect();
+
+                if missing.is_empty() {
+                    println!("{}: nothing missing", sha);
+                    if dry_run {
+                        continue;
+                    }
+                } else if dry_run {
+                    println!("{}: would benchmark {}", sha, missing.join(", "));
+                    continue;
+                } else {
+                    println!("{}: benchmarking {}", sha, missing.join(", "));
 
-            let mut get_toolchain_and_profile =
-                |rustc: &str, suffix: &str| -> anyhow::Result<String> {
-                    let toolchain = get_local_toolchain(
-                        profiles,
-                        rustc,
-                        opts.rustdoc.as_deref(),
-                        local.cargo.as_deref(),
-                        local.id.as_deref(),
-                        suffix,
+                    let sysroot = Sysroot::install(sha.clone(), &target_triple)
+                        .with_context(|| format!("failed to install sysroot for {}", sha))?;
+                    let toolchain = Toolchain::from_sysroot(&sysroot, sha.clone());
+
+                    let mut benchmarks = get_compile_benchmarks(
+                        &compile_benchmark_dir,
+                        Some(&missing.join(",")),
+                        None,
+                        None,
+                    )?;
+                    benchmarks.retain(|b| b.category().is_primary_or_secondary());
+
+                    let config = CompileBenchmarkConfig {
+                        benchmarks,
+                        profiles: Profile::all(),
+                        scenarios: Scenario::all(),
+                        iterations: None,
+                        is_self_profile: self_profile.self_profile,
+                        bench_rustc: bench_rustc.bench_rustc,
+                        bench_hello_world: false,
+                        tool_benchmarks: Vec::new(),
+                        reuse_target_dirs: false,
+                        sccache: None,
+                    };
+                    let shared = SharedBenchmarkConfig {
+                        artifact_id: ArtifactId::Commit(commit),
+                        toolchain,
+                    };
+
+                    let conn = rt.block_on(pool.connection());
+                    run_benchmarks(&mut rt, conn, shared, Some(config), None)?;
+                }
+
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&progress_file)
+                    .with_context(|| {
+                        format!("failed to open progress file {}", progress_file.display())
+                    })?;
+                writeln!(file, "{}", sha)?;
+            }
+
+            Ok(0)
+        }
+
+        Commands::BenchNewBenchmarks {
+            benchmarks,
+            artifacts,
+            db,
+            self_profile,
+        } => {
+            log_db(&db);
+            let pool = database::Pool::open(&db.db);
+            let mut conn = rt.block_on(pool.connection());
+            let index = rt.block_on(database::Index::load(conn.as_mut()));
+
+            let requested = get_compile_benchmarks(
+                &compile_benchmark_dir,
+                Some(&benchmarks),
+                None,
+                None,
+            )?;
+            if requested.is_empty() {
+                anyhow::bail!("no benchmarks match `{}`", benchmarks);
+            }
+            let requested_names: HashSet<&str> =
+                requested.iter().map(|b| b.name.0.as_str()).collect();
+
+            // The instructions:u statistic descriptions for the requested
+            // benchmarks, used to skip artifacts that already have data.
+            let series_ids: Vec<u32> = index
+                .compile_statistic_descriptions()
+                .filter(|((benchmark, _, _, metric), _)| {
+                    metric.as_str() == "instructions:u"
+                        && requested_names.contains(benchmark.as_str())
+                })
+                .map(|(_, id)| id)
+                .collect();
+
+            let mut commits: Vec<Commit> = index
+                .commits()
+                .into_iter()
+                .filter(|c| c.is_master())
+                .collect();
+            // `commits()` is sorted oldest-first; take the most recent N and
+            // benchmark them newest-first.
+            let commits = commits.split_off(commits.len().saturating_sub(artifacts));
+
+            for commit in commits.into_iter().rev() {
+                let aid = ArtifactId::Commit(commit.clone()).lookup(&index).unwrap();
+                let values = rt.block_on(conn.get_pstats(&series_ids, &[Some(aid)]));
+                if values.iter().any(|values| values.iter().any(|v| v.is_some())) {
+                    println!("{}: already has data, skipping", commit.sha);
+                    continue;
+                }
+
+                println!("{}: benchmarking {}", commit.sha, benchmarks);
+                // Artifacts are only kept on CI for a limited time; skip
+                // commits whose artifacts are no longer available instead of
+                // aborting the whole run.
+                let sysroot = match Sysroot::install(commit.sha.clone(), &target_triple) {
+                    Ok(sysroot) => sysroot,
+                    Err(e) => {
+                        log::warn!("failed to install sysroot for {}: {:?}", commit.sha, e);
+                        continue;
+                    }
+                };
+                let toolchain = Toolchain::from_sysroot(&sysroot, commit.sha.clone());
+
+                let mut benchmarks = get_compile_benchmarks(
+                    &compile_benchmark_dir,
+                    Some(&benchmarks),
+                    None,
+                    None,
+                )?;
+                benchmarks.retain(|b| b.category().is_primary_or_secondary());
+
+                let config = CompileBenchmarkConfig {
+                    benchmarks,
+                    profiles: Profile::all(),
+                    scenarios: Scenario::all(),
+                    iterations: None,
+                    is_self_profile: self_profile.self_profile,
+                    bench_rustc: false,
+                    bench_hello_world: false,
+                    tool_benchmarks: Vec::new(),
+                    reuse_target_dirs: false,
+                    sccache: None,
+                };
+                let shared = SharedBenchmarkConfig {
+                    artifact_id: ArtifactId::Commit(commit),
+                    toolchain,
+                };
+
+                let conn = rt.block_on(pool.connection());
+                run_benchmarks(&mut rt, conn, shared, Some(config), None)?;
+            }
+
+            Ok(0)
+        }
+
+        Commands::BenchVariance {
+            db,
+            window,
+            artifacts,
+            benchmarks,
+        } => {
+            log_db(&db);
+            let pool = database::Pool::open(&db.db);
+            let mut conn = rt.block_on(pool.connection());
+            let index = rt.block_on(database::Index::load(conn.as_mut()));
+
+            let mut suite = get_compile_benchmarks(
+                &compile_benchmark_dir,
+                benchmarks.as_deref(),
+                None,
+                None,
+            )?;
+            suite.retain(|b| b.category().is_primary_or_secondary());
+            let suite_names: HashSet<&str> = suite.iter().map(|b| b.name.0.as_str()).collect();
+
+            // All statistic descriptions of the selected benchmarks; only
+            // the ones actually re-measured below end up with a recorded
+            // delta.
+            let series_ids: Vec<u32> = index
+                .compile_statistic_descriptions()
+                .filter(|((benchmark, _, _, _), _)| suite_names.contains(benchmark.as_str()))
+                .map(|(_, id)| id)
+                .collect();
+
+            let mut commits: Vec<Commit> = index
+                .commits()
+                .into_iter()
+                .filter(|c| c.is_master())
+                .collect();
+            let mut commits = commits.split_off(commits.len().saturating_sub(window));
+
+            // A time-seeded xorshift shuffle is plenty here: we only need
+            // each scheduled invocation to pick a different subset, not
+            // statistical-quality randomness.
+            let mut seed = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .subsec_nanos() as u64
+                | 1;
+            for i in (1..commits.len()).rev() {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                commits.swap(i, (seed % (i as u64 + 1)) as usize);
+            }
+            commits.truncate(artifacts);
+
+            for commit in commits {
+                let aid = ArtifactId::Commit(commit.clone()).lookup(&index).unwrap();
+                let old = rt.block_on(conn.get_pstats(&series_ids, &[Some(aid)]));
+
+                println!("{}: re-benchmarking", commit.sha);
+                // Artifacts are only kept on CI for a limited time; skip
+                // commits whose artifacts are no longer available instead of
+                // aborting the whole run.
+                let sysroot = match Sysroot::install(commit.sha.clone(), &target_triple) {
+                    Ok(sysroot) => sysroot,
+                    Err(e) => {
+                        log::warn!("failed to install sysroot for {}: {:?}", commit.sha, e);
+                        continue;
+                    }
+                };
+                let toolchain = Toolchain::from_sysroot(&sysroot, commit.sha.clone());
+                let artifact_id = ArtifactId::Commit(commit.clone());
+
+                // Measure directly instead of going through `run_benchmarks`:
+                // the artifact has already been fully benchmarked, so we must
+                // not touch its collection steps or recorded duration. A
+                // single Check/Full iteration is cheap enough for a scheduled
+                // job and exercises the same infrastructure as the full
+                // suite.
+                let run_id = rt.block_on(conn.start_run(aid, &collector::collector_name()));
+                for benchmark in &suite {
+                    let mut processor = BenchProcessor::new(
+                        conn.as_mut(),
+                        &benchmark.name,
+                        &artifact_id,
+                        aid,
+                        run_id,
+                        false,
+                    );
+                    let result = rt.block_on(with_timeout(benchmark.measure(
+                        &mut processor,
+                        &[Profile::Check],
+                        &[Scenario::Full],
+                        &toolchain,
+                        Some(1),
+                    )));
+                    if let Err(e) = result {
+                        log::warn!("failed to re-benchmark {}: {:?}", benchmark.name, e);
+                    }
+                }
+
+                let new = rt.block_on(conn.get_pstats_for_run(&series_ids, aid, run_id));
+                let mut deltas = Vec::new();
+                for ((series, old), new) in series_ids.iter().zip(&old).zip(&new) {
+                    if let (Some(old), Some(new)) = (old[0], *new) {
+                        if old != 0.0 {
+                            let delta = (new - old) / old;
+                            rt.block_on(conn.record_rerun_delta(run_id, *series, delta));
+                            deltas.push(delta);
+                        }
+                    }
+                }
+                if deltas.is_empty() {
+                    println!("{}: no comparable results", commit.sha);
+                } else {
+                    let mean = deltas.iter().map(|d| d.abs()).sum::<f64>() / deltas.len() as f64;
+                    println!(
+                        "{}: recorded {} deltas, mean |delta| {:.2}%",
+                        commit.sha,
+                        deltas.len(),
+                        mean * 100.0
+                    );
+                }
+            }
+
+            Ok(0)
+        }
+
+        Commands::ProfileLocal {
+            profiler,
+            local,
+            opts,
+            out_dir,
+            rustc2,
+            jobs,
+        } => {
+            let jobs = jobs.max(1);
+            if jobs > 1 && !profiler.supports_parallel_execution() {
+                anyhow::bail!(
+                    "Profiler {:?} does not support parallel execution.",
+                    profiler
+                );
+            }
+
+            let profiles = &opts.profiles.0;
+            let scenarios = &opts.scenarios.0;
+
+            let mut benchmarks = get_compile_benchmarks(
+                &compile_benchmark_dir,
+                local.include.as_deref(),
+                local.exclude.as_deref(),
+                local.exclude_suffix.as_deref(),
+            )?;
+            benchmarks.retain(|b| b.category().is_primary_or_secondary());
+
+            let mut errors = BenchmarkErrors::new();
+
+            eprintln!("Running with {jobs} job(s)");
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs as usize)
+                .build_global()
+                .unwrap();
+
+            let mut get_toolchain_and_profile =
+                |rustc: &str, suffix: &str| -> anyhow::Result<String> {
+                    let toolchain = get_local_toolchain(
+                        profiles,
+                        rustc,
+                        opts.rustdoc.as_deref(),
+                        local.cargo.as_deref(),
+                        local.id.as_deref(),
+                        suffix,
                         target_triple.clone(),
                     )?;
                     let id = toolchain.id.clone();
@@ -1002,6 +2002,31 @@ fn main_result() -> anyhow::Result<i32> {
                         }
                     }
                 }
+
+                if profiler == Profiler::LlvmLines {
+                    let diffs = generate_llvm_lines_diffs(
+                        &id1,
+                        &id2,
+                        &out_dir,
+                        &benchmarks,
+                        profiles,
+                        &mut errors,
+                    );
+                    match diffs.len().cmp(&1) {
+                        Ordering::Equal => {
+                            let short = out_dir.join("ll-diff-latest");
+                            std::fs::copy(&diffs[0], &short).expect("copy to short path");
+                            eprintln!("Original diff at: {}", diffs[0].to_string_lossy());
+                            eprintln!("Short path: {}", short.to_string_lossy());
+                        }
+                        _ => {
+                            eprintln!("Diffs:");
+                            for diff in diffs {
+                                eprintln!("{}", diff.to_string_lossy());
+                            }
+                        }
+                    }
+                }
             } else {
                 get_toolchain_and_profile(local.rustc.as_str(), "")?;
             }
@@ -1052,6 +2077,185 @@ Make sure to modify `{dir}/perf-config.json` if the category/artifact don't matc
             );
             Ok(0)
         }
+        Commands::Database { command } => match command {
+            DatabaseSubcommand::RemoveBenchmark { name, db, dry_run } => {
+                log_db(&db);
+                let pool = database::Pool::open(&db.db);
+                let mut conn = rt.block_on(pool.connection());
+
+                let counts = rt.block_on(conn.compile_benchmark_row_counts(&name));
+                if counts.iter().all(|(_, count)| *count == 0) {
+                    anyhow::bail!("benchmark `{name}` not found in the database");
+                }
+                println!("rows recorded for benchmark `{name}`:");
+                for (table, count) in &counts {
+                    println!("  {table}: {count}");
+                }
+
+                if dry_run {
+                    println!("dry run: nothing removed");
+                } else {
+                    println!("removing `{name}`...");
+                    let mut tx = rt.block_on(conn.transaction());
+                    rt.block_on(tx.conn().remove_compile_benchmark(&name));
+                    rt.block_on(tx.commit())?;
+                    println!("removed `{name}`");
+                }
+                Ok(0)
+            }
+            DatabaseSubcommand::DropFromQueue { sha, db } => {
+                log_db(&db);
+                let pool = database::Pool::open(&db.db);
+                let conn = rt.block_on(pool.connection());
+                match rt.block_on(conn.mark_complete(&sha)) {
+                    Some(commit) => {
+                        println!("dropped {} (PR #{}) from the queue", commit.sha, commit.pr)
+                    }
+                    None => println!("{sha} is not in the queue"),
+                }
+                Ok(0)
+            }
+            DatabaseSubcommand::Blacklist { sha, reason, db } => {
+                log_db(&db);
+                let pool = database::Pool::open(&db.db);
+                let conn = rt.block_on(pool.connection());
+                rt.block_on(conn.blacklist_artifact(&sha, reason.as_deref()));
+                println!("blacklisted {sha}");
+                Ok(0)
+            }
+            DatabaseSubcommand::Unblacklist { sha, db } => {
+                log_db(&db);
+                let pool = database::Pool::open(&db.db);
+                let conn = rt.block_on(pool.connection());
+                if rt.block_on(conn.unblacklist_artifact(&sha)) {
+                    println!("removed {sha} from the blacklist");
+                } else {
+                    println!("{sha} was not blacklisted");
+                }
+                Ok(0)
+            }
+        },
+        Commands::Validate => {
+            let mut dirs = Vec::new();
+            for entry in std::fs::read_dir(&compile_benchmark_dir)? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    dirs.push((
+                        entry.file_name().to_string_lossy().into_owned(),
+                        entry.path(),
+                    ));
+                }
+            }
+            dirs.sort();
+
+            let mut errors = 0;
+            for (name, path) in dirs {
+                let report = validate_benchmark_dir(&path);
+                for warning in &report.warnings {
+                    println!("warning: {name}: {warning}");
+                }
+                for error in &report.errors {
+                    println!("error: {name}: {error}");
+                }
+                errors += report.errors.len();
+            }
+            if errors > 0 {
+                anyhow::bail!("found {errors} error(s) in benchmark configurations");
+            }
+            println!("All benchmark configurations are valid.");
+            Ok(0)
+        }
+        Commands::TestBenchmarks { local, runtime } => {
+            let toolchain = get_local_toolchain(
+                &[Profile::Check],
+                &local.rustc,
+                None,
+                local.cargo.as_deref(),
+                local.id.as_deref(),
+                "",
+                target_triple,
+            )?;
+
+            let mut benchmarks = get_compile_benchmarks(
+                &compile_benchmark_dir,
+                local.include.as_deref(),
+                local.exclude.as_deref(),
+                local.exclude_suffix.as_deref(),
+            )?;
+            benchmarks.retain(|b| !b.disabled());
+
+            // The point is to exercise the suite, not to keep measurements, so
+            // record into a throwaway database.
+            let db_dir = tempfile::tempdir()?;
+            let pool = Pool::open(db_dir.path().join("results.db").to_str().unwrap());
+            let mut conn = rt.block_on(pool.connection());
+
+            let artifact_id = ArtifactId::Tag(toolchain.id.clone());
+            let artifact_row_id = rt.block_on(conn.artifact_id(&artifact_id));
+            let run_id = rt.block_on(conn.start_run(artifact_row_id, &collector::collector_name()));
+
+            let mut results: Vec<(String, anyhow::Result<()>)> = Vec::new();
+
+            // A single Check/Full build is enough to catch compile benchmarks
+            // that no longer build.
+            for benchmark in &benchmarks {
+                eprintln!("Testing {}", benchmark.name);
+                let mut processor = BenchProcessor::new(
+                    conn.as_mut(),
+                    &benchmark.name,
+                    &artifact_id,
+                    artifact_row_id,
+                    run_id,
+                    false,
+                );
+                let result = rt.block_on(with_timeout(benchmark.measure(
+                    &mut processor,
+                    &[Profile::Check],
+                    &[Scenario::Full],
+                    &toolchain,
+                    Some(1),
+                )));
+                results.push((format!("compile/{}", benchmark.name), result.map(|_| ())));
+            }
+
+            // Runtime benchmark groups that fail to compile count as failures,
+            // too.
+            let BenchmarkSuiteCompilation {
+                suite,
+                failed_to_compile,
+            } = prepare_runtime_benchmark_suite(
+                &toolchain,
+                &runtime_benchmark_dir,
+                CargoIsolationMode::Isolated,
+                runtime.group,
+                RuntimeCompilationOpts::default(),
+                BenchmarkOutput::default(),
+            )?;
+            for (group, error) in failed_to_compile {
+                results.push((format!("runtime/{group}"), Err(anyhow::anyhow!("{error}"))));
+            }
+            for (group, result) in
+                test_runtime_benchmark_suite(&suite, &BenchmarkFilter::keep_all())
+            {
+                results.push((format!("runtime/{group}"), result));
+            }
+
+            let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+            for (name, result) in &results {
+                match result {
+                    Ok(()) => println!("test {name} ... ok"),
+                    Err(e) => println!("test {name} ... FAILED\n    {e:#}"),
+                }
+            }
+            println!(
+                "\ntest result: {}. {} passed; {} failed",
+                if failed == 0 { "ok" } else { "FAILED" },
+                results.len() - failed,
+                failed
+            );
+            BenchmarkErrors(failed).fail_if_nonzero()?;
+            Ok(0)
+        }
     }
 }
 
@@ -1075,8 +2279,10 @@ async fn load_runtime_benchmarks(
     benchmark_dir: &Path,
     isolation_mode: CargoIsolationMode,
     group: Option<String>,
+    opts: RuntimeCompilationOpts,
     toolchain: &Toolchain,
     artifact_id: &ArtifactId,
+    output: BenchmarkOutput,
 ) -> anyhow::Result<BenchmarkSuite> {
     let BenchmarkSuiteCompilation {
         suite,
@@ -1086,10 +2292,30 @@ async fn load_runtime_benchmarks(
         benchmark_dir,
         isolation_mode,
         group,
-        RuntimeCompilationOpts::default(),
+        opts,
+        output,
     )?;
 
-    record_runtime_compilation_errors(conn, artifact_id, failed_to_compile).await;
+    if !failed_to_compile.is_empty() {
+        let mut failed: Vec<_> = failed_to_compile.keys().cloned().collect();
+        failed.sort_unstable();
+        eprintln!(
+            "{} runtime benchmark group(s) failed to compile: {}",
+            failed.len(),
+            failed.join(", ")
+        );
+        record_runtime_compilation_errors(conn, artifact_id, failed_to_compile).await;
+
+        // A single broken group should not abort the rest of the suite (the
+        // failures were recorded as benchmark errors above), but if nothing
+        // compiled at all, there is nothing left to benchmark.
+        if suite.groups.is_empty() {
+            return Err(anyhow::anyhow!(
+                "all runtime benchmark groups failed to compile"
+            ));
+        }
+    }
+
     Ok(suite)
 }
 
@@ -1120,7 +2346,11 @@ async fn init_collection(
     assert!(runtime.is_some() || compile.is_some());
     let mut builder = CollectorStepBuilder::default();
     if let Some(compile) = compile {
-        builder = builder.record_compile_benchmarks(&compile.benchmarks, compile.bench_rustc);
+        builder = builder.record_compile_benchmarks(
+            &compile.benchmarks,
+            compile.bench_rustc,
+            compile.bench_hello_world,
+        );
     }
     if let Some(runtime) = runtime {
         builder = builder.record_runtime_benchmarks(&runtime.runtime_suite);
@@ -1130,6 +2360,81 @@ async fn init_collection(
         .await
 }
 
+/// Assumed peak disk usage of a compile benchmark whose usage has not been
+/// recorded yet.
+const DEFAULT_BENCHMARK_DISK_USAGE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Checks that there is likely enough free disk space for benchmarking before
+/// any time is invested into the run, instead of failing with ENOSPC halfway
+/// through. Benchmarks run one at a time and remove their temporary target
+/// directories afterwards, so the estimate is the largest recorded
+/// per-benchmark usage rather than the sum; the toolchain component sizes are
+/// added on top as a safety margin.
+async fn check_disk_space(
+    conn: &dyn Connection,
+    toolchain: &Toolchain,
+    benchmarks: &[Benchmark],
+) -> anyhow::Result<()> {
+    let tmp_dir = std::env::temp_dir();
+    let available = match utils::fs::available_disk_space(&tmp_dir) {
+        Ok(available) => available,
+        Err(error) => {
+            log::warn!("Cannot determine available disk space: {error:?}");
+            return Ok(());
+        }
+    };
+
+    let historical: HashMap<String, u64> = conn
+        .compile_benchmark_disk_usage()
+        .await
+        .into_iter()
+        .collect();
+    let benchmark_estimate = benchmarks
+        .iter()
+        .map(|benchmark| {
+            historical
+                .get(&benchmark.name.0)
+                .copied()
+                .unwrap_or(DEFAULT_BENCHMARK_DISK_USAGE)
+        })
+        .max()
+        .unwrap_or(0);
+
+    let paths = &toolchain.components;
+    let toolchain_size: u64 = [
+        Some(paths.rustc.as_path()),
+        paths.rustdoc.as_deref(),
+        Some(paths.cargo.as_path()),
+        paths.lib_rustc.as_deref(),
+        paths.lib_std.as_deref(),
+        paths.lib_test.as_deref(),
+        paths.lib_llvm.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|path| fs::metadata(path).ok().map(|m| m.len()))
+    .sum();
+
+    let gib = |bytes: u64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    let required = toolchain_size + benchmark_estimate;
+    if available < required {
+        anyhow::bail!(
+            "Not enough disk space to benchmark: approximately {:.1} GiB needed, \
+             but only {:.1} GiB is available in {}. Free up disk space before \
+             starting the run.",
+            gib(required),
+            gib(available),
+            tmp_dir.display()
+        );
+    }
+    log::debug!(
+        "disk space check passed: ~{:.1} GiB needed, {:.1} GiB available",
+        gib(required),
+        gib(available)
+    );
+    Ok(())
+}
+
 /// Execute all benchmarks specified by the given configurations.
 fn run_benchmarks(
     rt: &mut Runtime,
@@ -1138,11 +2443,30 @@ fn run_benchmarks(
     compile: Option<CompileBenchmarkConfig>,
     runtime: Option<RuntimeBenchmarkConfig>,
 ) -> anyhow::Result<()> {
+    if let Some(compile) = &compile {
+        rt.block_on(check_disk_space(
+            &*connection,
+            &shared.toolchain,
+            &compile.benchmarks,
+        ))?;
+    }
+
     rt.block_on(record_toolchain_sizes(
         connection.as_mut(),
         &shared.artifact_id,
         &shared.toolchain,
     ));
+    rt.block_on(record_toolchain_info(
+        connection.as_mut(),
+        &shared.artifact_id,
+        &shared.toolchain,
+    ));
+    rt.block_on(record_collector_config(
+        connection.as_mut(),
+        &shared.artifact_id,
+        compile.as_ref(),
+        runtime.as_ref(),
+    ));
 
     let collector = rt.block_on(init_collection(
         connection.as_mut(),
@@ -1155,7 +2479,18 @@ fn run_benchmarks(
 
     // Compile benchmarks
     let compile_result = if let Some(compile) = compile {
+        let primary_benchmarks: HashSet<String> = compile
+            .benchmarks
+            .iter()
+            .filter(|b| b.category() == Category::Primary)
+            .map(|b| b.name.to_string())
+            .collect();
         let errors = bench_compile(rt, connection.as_mut(), &shared, compile, &collector);
+        rt.block_on(record_latency_slo(
+            connection.as_mut(),
+            collector.artifact_row_id,
+            &primary_benchmarks,
+        ));
         errors
             .fail_if_nonzero()
             .context("Compile benchmarks failed")
@@ -1171,6 +2506,7 @@ fn run_benchmarks(
             &collector,
             runtime.filter,
             runtime.iterations,
+            runtime.output,
         ))
         .context("Runtime benchmarks failed")
     } else {
@@ -1180,6 +2516,13 @@ fn run_benchmarks(
     let end = start.elapsed();
     rt.block_on(connection.record_duration(collector.artifact_row_id, end));
 
+    if collector::utils::shutdown::requested() {
+        return Err(anyhow::anyhow!(
+            "collection was interrupted by a termination signal; \
+             completed results were recorded, re-run the same command to resume"
+        ));
+    }
+
     compile_result.or(runtime_result)
 }
 
@@ -1192,12 +2535,12 @@ fn bench_published_artifact(
 ) -> anyhow::Result<()> {
     let artifact_id = ArtifactId::Tag(toolchain.id.clone());
 
-    let profiles = if collector::version_supports_doc(&toolchain.id) {
+    let profiles = if collector::version_supports_doc(toolchain.base_id()) {
         Profile::all()
     } else {
         Profile::all_non_doc()
     };
-    let scenarios = if collector::version_supports_incremental(&toolchain.id) {
+    let scenarios = if collector::version_supports_incremental(toolchain.base_id()) {
         Scenario::all()
     } else {
         Scenario::all_non_incr()
@@ -1212,8 +2555,10 @@ fn bench_published_artifact(
         dirs.runtime,
         CargoIsolationMode::Isolated,
         None,
+        RuntimeCompilationOpts::default(),
         &toolchain,
         &artifact_id,
+        BenchmarkOutput::default(),
     ))?;
 
     let shared = SharedBenchmarkConfig {
@@ -1228,21 +2573,26 @@ fn bench_published_artifact(
             benchmarks: compile_benchmarks,
             profiles,
             scenarios,
-            iterations: Some(3),
+            iterations: Some(IterationSpec::fixed(3)),
             is_self_profile: false,
             bench_rustc: false,
+            bench_hello_world: false,
+            tool_benchmarks: Vec::new(),
+            reuse_target_dirs: false,
+            sccache: None,
         }),
         Some(RuntimeBenchmarkConfig::new(
             runtime_suite,
             BenchmarkFilter::keep_all(),
             DEFAULT_RUNTIME_ITERATIONS,
+            BenchmarkOutput::default(),
         )),
     )
 }
 
 const COMPILE_BENCHMARK_TIMEOUT: Duration = Duration::from_secs(60 * 30);
 
-async fn with_timeout<F: Future<Output = anyhow::Result<()>>>(fut: F) -> anyhow::Result<()> {
+async fn with_timeout<T, F: Future<Output = anyhow::Result<T>>>(fut: F) -> anyhow::Result<T> {
     match tokio::time::timeout(COMPILE_BENCHMARK_TIMEOUT, fut).await {
         Ok(res) => res,
         Err(_) => Err(anyhow::anyhow!(
@@ -1257,7 +2607,7 @@ fn bench_compile(
     rt: &mut Runtime,
     conn: &mut dyn Connection,
     shared: &SharedBenchmarkConfig,
-    config: CompileBenchmarkConfig,
+    mut config: CompileBenchmarkConfig,
     collector: &CollectorCtx,
 ) -> BenchmarkErrors {
     let mut errors = BenchmarkErrors::new();
@@ -1273,14 +2623,54 @@ fn bench_compile(
     }
 
     let bench_rustc = config.bench_rustc;
+    let bench_hello_world = config.bench_hello_world;
 
     let start = Instant::now();
 
+    // Benchmarks can declare a minimum supported rustc version in their
+    // perf-config.json. When the benchmarked toolchain is older than that
+    // (e.g. when backfilling a published release), record the benchmark as
+    // deliberately unsupported instead of letting its build fail.
+    for benchmark in &config.benchmarks {
+        if let Some(min_rustc) = benchmark.unsupported_by(shared.toolchain.base_id()) {
+            if !rt.block_on(collector.start_compile_step(conn, &benchmark.name)) {
+                continue;
+            }
+            eprintln!(
+                "skipping {} -- requires rustc >= {}",
+                benchmark.name, min_rustc
+            );
+            rt.block_on(conn.record_error(
+                collector.artifact_row_id,
+                &benchmark.name.0,
+                &format!("unsupported: requires rustc >= {min_rustc}"),
+            ));
+            rt.block_on(collector.end_compile_step(conn, &benchmark.name));
+        }
+    }
+    config
+        .benchmarks
+        .retain(|benchmark| benchmark.unsupported_by(shared.toolchain.base_id()).is_none());
+
+    if config.reuse_target_dirs {
+        for benchmark in &mut config.benchmarks {
+            benchmark.enable_target_dir_reuse();
+        }
+    }
+    if let Some(sccache) = &config.sccache {
+        for benchmark in &mut config.benchmarks {
+            benchmark.set_sccache(sccache.clone());
+        }
+    }
+
     let mut measure_and_record =
         |benchmark_name: &BenchmarkName,
          category: Category,
          print_intro: &dyn Fn(),
          measure: &dyn Fn(&mut BenchProcessor) -> anyhow::Result<()>| {
+            if collector::utils::shutdown::requested() {
+                return;
+            }
             let is_fresh = rt.block_on(collector.start_compile_step(conn, benchmark_name));
             if !is_fresh {
                 eprintln!("skipping {} -- already benchmarked", benchmark_name);
@@ -1300,10 +2690,23 @@ fn bench_compile(
                 benchmark_name,
                 &shared.artifact_id,
                 collector.artifact_row_id,
+                collector.run_id,
                 config.is_self_profile,
             );
             let result = measure(&mut processor);
             if let Err(s) = result {
+                if collector::utils::shutdown::requested() {
+                    // The benchmark was killed by our signal handler. Roll
+                    // back its partial results and leave the step unfinished,
+                    // so that it is re-run when the collection is resumed.
+                    eprintln!(
+                        "terminating; discarding partial results of {}",
+                        benchmark_name
+                    );
+                    rt.block_on(tx.finish())
+                        .expect("rolled back interrupted benchmark");
+                    return;
+                }
                 eprintln!(
                     "collector error: Failed to benchmark '{}', recorded: {:#}",
                     benchmark_name, s
@@ -1331,14 +2734,24 @@ fn bench_compile(
                 )
             },
             &|processor| {
-                rt.block_on(with_timeout(benchmark.measure(
-                    processor,
-                    &config.profiles,
-                    &config.scenarios,
-                    &shared.toolchain,
-                    config.iterations,
-                )))
-                .with_context(|| anyhow::anyhow!("Cannot compile {}", benchmark.name))
+                let disk_usage = rt
+                    .block_on(with_timeout(
+                        benchmark.measure(
+                            processor,
+                            &config.profiles,
+                            &config.scenarios,
+                            &shared.toolchain,
+                            config
+                                .iterations
+                                .as_ref()
+                                .map(|spec| spec.for_benchmark(&benchmark.name.0)),
+                        ),
+                    ))
+                    .with_context(|| anyhow::anyhow!("Cannot compile {}", benchmark.name))?;
+                if disk_usage > 0 {
+                    rt.block_on(processor.record_disk_usage(disk_usage));
+                }
+                Ok(())
             },
         )
     }
@@ -1356,6 +2769,36 @@ fn bench_compile(
         );
     }
 
+    // The special hello-world end-to-end latency benchmark, if requested.
+    if bench_hello_world {
+        measure_and_record(
+            &BenchmarkName("helloworld-e2e".to_string()),
+            Category::Primary,
+            &|| eprintln!("Special benchmark commencing (due to `--bench-hello-world`)"),
+            &|processor| {
+                rt.block_on(with_timeout(
+                    processor.measure_hello_world(&shared.toolchain),
+                ))
+                .context("measure hello-world")
+            },
+        );
+    }
+
+    // The auxiliary tool benchmarks, if configured.
+    for tool_benchmark in &config.tool_benchmarks {
+        measure_and_record(
+            &BenchmarkName(format!("tool:{}", tool_benchmark.name)),
+            Category::Primary,
+            &|| eprintln!("Tool benchmark commencing (due to `--tool-config`)"),
+            &|processor| {
+                rt.block_on(with_timeout(
+                    processor.measure_tool(tool_benchmark, &shared.toolchain),
+                ))
+                .with_context(|| format!("measure tool benchmark {}", tool_benchmark.name))
+            },
+        );
+    }
+
     let end = start.elapsed();
 
     eprintln!(
@@ -1402,6 +2845,199 @@ async fn record_toolchain_sizes(
     record(conn, aid, "libLLVM", paths.lib_llvm.as_deref()).await;
 }
 
+/// Records the version metadata of the given toolchain (the exact rustc
+/// version string, commit hash, LLVM version) and the set of toolchain
+/// components that were present, so that e.g. LLVM-upgrade-caused changes
+/// are self-explanatory when comparing artifacts.
+async fn record_toolchain_info(
+    conn: &mut dyn Connection,
+    artifact_id: &ArtifactId,
+    toolchain: &Toolchain,
+) {
+    let aid = conn.artifact_id(artifact_id).await;
+
+    let mut llvm_version = None;
+    match Command::new(&toolchain.components.rustc)
+        .arg("--version")
+        .arg("--verbose")
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                if let Some(version) = line.strip_prefix("rustc ") {
+                    conn.record_artifact_info(aid, "version", version.trim())
+                        .await;
+                } else if let Some((key, value)) = line.split_once(": ") {
+                    let key = match key {
+                        "commit-hash" | "commit-date" | "release" => key,
+                        "LLVM version" => {
+                            llvm_version = Some(value.trim().to_string());
+                            "llvm-version"
+                        }
+                        _ => continue,
+                    };
+                    conn.record_artifact_info(aid, key, value.trim()).await;
+                }
+            }
+        }
+        _ => eprintln!("failed to query rustc version for {artifact_id}"),
+    }
+
+    // An LLVM upgrade is the usual suspect for step changes that affect many
+    // benchmarks at once, so detect major-version transitions between
+    // consecutive artifacts and record them as annotations, which the site
+    // surfaces alongside the graphs.
+    if let Some(new) = &llvm_version {
+        if let Some(previous) = conn.previous_artifact_info(aid, "llvm-version").await {
+            if previous.split('.').next() != new.split('.').next() {
+                conn.record_annotation(
+                    aid,
+                    "llvm-version-change",
+                    &format!("LLVM version changed from {previous} to {new}"),
+                )
+                .await;
+            }
+        }
+    }
+
+    let paths = &toolchain.components;
+    let mut components = vec!["rustc", "cargo"];
+    for (component, path) in [
+        ("rustdoc", &paths.rustdoc),
+        ("librustc_driver", &paths.lib_rustc),
+        ("libstd", &paths.lib_std),
+        ("libtest", &paths.lib_test),
+        ("libLLVM", &paths.lib_llvm),
+    ] {
+        if path.is_some() {
+            components.push(component);
+        }
+    }
+    conn.record_artifact_info(aid, "components", &components.join(","))
+        .await;
+
+    // When running inside a container started by `--container`, record the
+    // image digest so that the execution environment travels with the
+    // results.
+    if let Ok(digest) = std::env::var(utils::container::CONTAINER_DIGEST_VAR) {
+        conn.record_artifact_info(aid, "container-image", &digest)
+            .await;
+    }
+
+    // The version of the suite the results were gathered with, so that the
+    // compare page can warn when a difference may stem from a suite change
+    // rather than the compiler.
+    let compile_dir = compile_benchmark_dir();
+    let runtime_dir = runtime_benchmark_dir();
+    let suite_version = collector::suite_version(&[compile_dir.as_path(), runtime_dir.as_path()]);
+    conn.record_artifact_info(aid, "suite-version", &suite_version)
+        .await;
+}
+
+/// Records the effective configuration of this run (profiles, scenarios,
+/// iteration counts, the measurement backend) under the `collector-config`
+/// artifact-info key as a JSON object, so that consumers of the results can
+/// see exactly how the data was generated. The status and compare APIs
+/// surface artifact info to their clients.
+async fn record_collector_config(
+    conn: &mut dyn Connection,
+    artifact_id: &ArtifactId,
+    compile: Option<&CompileBenchmarkConfig>,
+    runtime: Option<&RuntimeBenchmarkConfig>,
+) {
+    let aid = conn.artifact_id(artifact_id).await;
+
+    let mut config = serde_json::Map::new();
+    if let Some(compile) = compile {
+        // `None` means each benchmark uses its own default iteration count.
+        let iterations = compile.iterations.as_ref().map(|spec| {
+            serde_json::json!({
+                "default": spec.default,
+                "overrides": spec
+                    .overrides
+                    .iter()
+                    .map(|(pattern, count)| (pattern.clone(), serde_json::Value::from(*count)))
+                    .collect::<serde_json::Map<_, _>>(),
+            })
+        });
+        let measurer = collector::compile::execute::measurer::select_measurer()
+            .map(|m| m.name())
+            .ok();
+        config.insert(
+            "compile".to_string(),
+            serde_json::json!({
+                "benchmarks": compile.benchmarks.len(),
+                "profiles": compile
+                    .profiles
+                    .iter()
+                    .map(|p| format!("{p:?}"))
+                    .collect::<Vec<_>>(),
+                "scenarios": compile
+                    .scenarios
+                    .iter()
+                    .map(|s| format!("{s:?}"))
+                    .collect::<Vec<_>>(),
+                "iterations": iterations,
+                "self-profile": compile.is_self_profile,
+                "measurer": measurer,
+            }),
+        );
+    }
+    if let Some(runtime) = runtime {
+        config.insert(
+            "runtime".to_string(),
+            serde_json::json!({
+                "groups": runtime.runtime_suite.groups.len(),
+                "iterations": runtime.iterations,
+            }),
+        );
+    }
+    conn.record_artifact_info(
+        aid,
+        "collector-config",
+        &serde_json::Value::Object(config).to_string(),
+    )
+    .await;
+}
+
+/// Rebuild latency an edit-compile loop user would consider acceptable: an
+/// incremental `check` rebuild with no changes should finish within this many
+/// seconds.
+const LATENCY_SLO_THRESHOLD_SECS: f64 = 1.0;
+
+/// Records the share of primary benchmarks whose `check` profile
+/// `incr-unchanged` wall-time stays under [`LATENCY_SLO_THRESHOLD_SECS`]
+/// under the `latency-slo` artifact-info key, condensing editing-latency
+/// health into a single number the site can graph over time.
+async fn record_latency_slo(
+    conn: &mut dyn Connection,
+    aid: ArtifactIdNumber,
+    primary_benchmarks: &HashSet<String>,
+) {
+    let stats = conn
+        .artifact_compile_statistics(aid, "wall-time", "check", "incr-unchanged")
+        .await;
+    let mut total = 0u32;
+    let mut met = 0u32;
+    for (benchmark, wall_time) in stats {
+        if !primary_benchmarks.contains(&benchmark) {
+            continue;
+        }
+        total += 1;
+        if wall_time < LATENCY_SLO_THRESHOLD_SECS {
+            met += 1;
+        }
+    }
+    // Runs restricted to secondary benchmarks, or ones that did not measure
+    // wall-time, have no data to judge; don't record a misleading zero.
+    if total == 0 {
+        return;
+    }
+    let share = met as f64 / total as f64;
+    conn.record_artifact_info(aid, "latency-slo", &format!("{share:.4}"))
+        .await;
+}
+
 fn add_perf_config(directory: &Path, category: Category, artifact: ArtifactType) {
     let data = serde_json::json!({
         "category": category,
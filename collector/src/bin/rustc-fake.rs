@@ -18,6 +18,33 @@ fn determinism_env(cmd: &mut Command) {
     cmd.env("RUSTC_FORCE_RUSTC_VERSION", "rustc-perf");
 }
 
+/// The perf events we record by default.
+const DEFAULT_PERF_EVENTS: &str =
+    "instructions:u,cycles:u,task-clock,cpu-clock,faults,context-switches,branch-misses,cache-misses";
+
+/// Returns whether the given metric should be recorded.
+///
+/// All metrics are recorded unless the `RUSTC_PERF_METRICS` environment
+/// variable narrows them down to a comma-separated list (set by the
+/// collector's `--metrics` option). Metric names may be given with or without
+/// a modifier suffix, so `instructions` selects `instructions:u`.
+fn metric_requested(name: &str) -> bool {
+    match env::var("RUSTC_PERF_METRICS") {
+        Ok(metrics) => metrics
+            .split(',')
+            .map(|m| m.trim())
+            .any(|m| m == name || Some(m) == name.split(':').next()),
+        Err(_) => true,
+    }
+}
+
+/// Like [`metric_requested`], but does not default to true when
+/// `RUSTC_PERF_METRICS` is unset. For metrics whose collection perturbs the
+/// measured command itself, which therefore must be opted into explicitly.
+fn metric_explicitly_requested(name: &str) -> bool {
+    env::var("RUSTC_PERF_METRICS").is_ok() && metric_requested(name)
+}
+
 fn run_with_determinism_env(mut cmd: Command) {
     determinism_env(&mut cmd);
     let status = cmd.status().expect("failed to spawn");
@@ -28,7 +55,60 @@ fn run_with_determinism_env(mut cmd: Command) {
     );
 }
 
+/// Runs the command like [`run_with_determinism_env`], but pipes the child's
+/// stderr through this process, recording the elapsed time since `start` at
+/// which the first byte appeared. Under cargo, rustc writes nothing to stderr
+/// until its first diagnostic (or the artifact notification emitted once
+/// metadata is ready), so that instant marks when a user would first see
+/// feedback from a check build. Returns `None` if the child never wrote to
+/// stderr.
+fn run_with_determinism_env_watching_stderr(mut cmd: Command, start: Instant) -> Option<Duration> {
+    use std::io::{Read, Write};
+
+    determinism_env(&mut cmd);
+    cmd.stderr(std::process::Stdio::piped());
+    let mut child = cmd.spawn().expect("failed to spawn");
+    let mut child_stderr = child.stderr.take().expect("piped stderr");
+    let reader = std::thread::spawn(move || {
+        let mut first = None;
+        let mut stderr = std::io::stderr();
+        let mut buf = [0u8; 4096];
+        loop {
+            match child_stderr.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    first.get_or_insert_with(|| start.elapsed());
+                    // Forward the output as if it had not been piped.
+                    let _ = stderr.write_all(&buf[..n]);
+                }
+            }
+        }
+        first
+    });
+    let status = child.wait().expect("failed to wait");
+    assert!(
+        status.success(),
+        "command did not complete successfully: {:?}",
+        cmd
+    );
+    reader.join().expect("stderr reader thread")
+}
+
+/// Returns whether the wrapped invocation is a check build, i.e. emits crate
+/// metadata without generating code.
+fn is_check_build(args: &[OsString]) -> bool {
+    args.iter().any(|arg| {
+        arg.to_str().map_or(false, |arg| {
+            arg.strip_prefix("--emit")
+                .is_some_and(|emit| emit.contains("metadata") && !emit.contains("link"))
+        })
+    })
+}
+
 fn main() {
+    #[cfg(windows)]
+    setup_job_object();
+
     let mut args_os = env::args_os();
     let name = args_os.next().unwrap().into_string().unwrap();
 
@@ -69,27 +149,59 @@ fn main() {
 
         benchlib::process::raise_process_priority();
 
+        // The collector records when it spawned cargo; the elapsed time until
+        // this wrapper runs is what cargo spent on dependency resolution and
+        // fingerprinting before invoking the final rustc. rustc's own
+        // wall-time cannot expose regressions in that phase.
+        let cargo_overhead = env::var("RUSTC_PERF_CARGO_STARTED_AT")
+            .ok()
+            .filter(|_| metric_requested("cargo-overhead"))
+            .and_then(|started| started.parse::<u128>().ok())
+            .and_then(|started| {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()?
+                    .as_nanos();
+                Some(Duration::from_nanos(
+                    u64::try_from(now.checked_sub(started)?).ok()?,
+                ))
+            });
+
         // These strings come from `PerfTool::name()`.
         match wrapper {
             "PerfStat" | "PerfStatSelfProfile" => {
-                let mut cmd = Command::new("perf");
-                let has_perf = cmd.output().is_ok();
-                assert!(has_perf);
-                cmd.arg("stat")
-                    // perf respects this environment variable for e.g., percents in
-                    // the output, but we want standard output on all systems.
-                    // See #753 for more details.
-                    .env("LC_NUMERIC", "C")
-                    .arg("-x;")
-                    .arg("-e")
-                    .arg("instructions:u,cycles:u,task-clock,cpu-clock,faults,context-switches,branch-misses,cache-misses")
-                    .arg("--log-fd")
-                    .arg("1")
-                    .arg("setarch")
-                    .arg(std::env::consts::ARCH)
-                    .arg("-R")
-                    .arg(&tool)
-                    .args(&args);
+                let events = DEFAULT_PERF_EVENTS
+                    .split(',')
+                    .filter(|e| metric_requested(e))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                let mut cmd = if events.is_empty() {
+                    // No perf events were requested, so skip perf entirely.
+                    let mut cmd = Command::new("setarch");
+                    cmd.arg(std::env::consts::ARCH).arg("-R").arg(&tool);
+                    cmd
+                } else {
+                    let mut cmd = Command::new("perf");
+                    let has_perf = cmd.output().is_ok();
+                    assert!(has_perf);
+                    cmd.arg("stat")
+                        // perf respects this environment variable for e.g., percents in
+                        // the output, but we want standard output on all systems.
+                        // See #753 for more details.
+                        .env("LC_NUMERIC", "C")
+                        .arg("-x;")
+                        .arg("-e")
+                        .arg(&events)
+                        .arg("--log-fd")
+                        .arg("1")
+                        .arg("setarch")
+                        .arg(std::env::consts::ARCH)
+                        .arg("-R")
+                        .arg(&tool);
+                    cmd
+                };
+                cmd.args(&args);
 
                 let prof_out_dir = std::env::current_dir().unwrap().join("self-profile-output");
                 if wrapper == "PerfStatSelfProfile" {
@@ -101,11 +213,75 @@ fn main() {
                     let _ = fs::create_dir_all(&prof_out_dir);
                 }
 
+                let mono_stats_dir = std::env::current_dir().unwrap().join("mono-stats-output");
+                let mono_stats =
+                    !actually_rustdoc && mono_stats_requested() && supports_mono_stats(&tool);
+                if mono_stats {
+                    setup_mono_stats(&mut cmd, &mono_stats_dir);
+                }
+
+                // rustdoc can spawn rustc processes (e.g. for `--scrape-examples`),
+                // and getrusage's RUSAGE_CHILDREN max-rss is the peak of the
+                // largest single descendant, not the concurrent total of the
+                // whole tree, so memory regressions in those children can go
+                // unnoticed. Sample the tree's combined resident set while it
+                // runs and report the peak separately. The perf counters above
+                // need no such help: they are inherited across fork, so
+                // instructions:u et al. already cover the whole tree.
+                let tree_rss = if actually_rustdoc && metric_requested("tree-max-rss") {
+                    tree_max_rss::Sampler::start()
+                } else {
+                    None
+                };
+
+                // For check builds, the time until rustc first writes to
+                // stderr (its first diagnostic, or the artifact notification
+                // once metadata is ready) approximates IDE responsiveness
+                // more directly than total check time. Relaying stderr
+                // through a pipe adds overhead inside the timed region, so
+                // the metric has to be requested explicitly.
+                let watch_stderr = !actually_rustdoc
+                    && is_check_build(&args)
+                    && metric_explicitly_requested("time-to-first-diagnostic");
+
                 let start = Instant::now();
-                run_with_determinism_env(cmd);
+                let first_stderr = if watch_stderr {
+                    run_with_determinism_env_watching_stderr(cmd, start)
+                } else {
+                    run_with_determinism_env(cmd);
+                    None
+                };
                 let dur = start.elapsed();
-                print_memory();
-                print_time(dur);
+                if let Some(sampler) = tree_rss {
+                    if let Some(peak) = sampler.finish() {
+                        // Same CSV shape as the `perf stat` lines; kilobytes,
+                        // like max-rss.
+                        println!("{};;tree-max-rss;3;100.00", peak);
+                    }
+                }
+                if metric_requested("max-rss") {
+                    print_memory();
+                }
+                if metric_requested("wall-time") {
+                    print_time(dur);
+                }
+                if let Some(first) = first_stderr {
+                    println!(
+                        "!time-to-first-diagnostic:{}.{:09}",
+                        first.as_secs(),
+                        first.subsec_nanos()
+                    );
+                }
+                if let Some(overhead) = cargo_overhead {
+                    println!(
+                        "!cargo-overhead:{}.{:09}",
+                        overhead.as_secs(),
+                        overhead.subsec_nanos()
+                    );
+                }
+                if mono_stats {
+                    print_mono_stats(&mono_stats_dir);
+                }
                 if wrapper == "PerfStatSelfProfile" {
                     process_self_profile_output(prof_out_dir, &args[..]);
                 }
@@ -159,6 +335,9 @@ fn main() {
                 let status = cmd.status().expect("failed to spawn tracelog");
                 assert!(status.success(), "tracelog did not complete successfully");
 
+                let mono_stats =
+                    !actually_rustdoc && mono_stats_requested() && supports_mono_stats(&tool);
+
                 let mut tool = Command::new(tool);
                 tool.args(&args);
 
@@ -172,10 +351,21 @@ fn main() {
                     let _ = fs::create_dir_all(&prof_out_dir);
                 }
 
+                let mono_stats_dir = std::env::current_dir().unwrap().join("mono-stats-output");
+                if mono_stats {
+                    setup_mono_stats(&mut tool, &mono_stats_dir);
+                }
+
                 let start = Instant::now();
                 run_with_determinism_env(tool);
                 let dur = start.elapsed();
+                if metric_requested("max-rss") {
+                    print_memory();
+                }
                 println!("!wall-time:{}.{:09}", dur.as_secs(), dur.subsec_nanos());
+                if mono_stats {
+                    print_mono_stats(&mono_stats_dir);
+                }
 
                 let xperf = |args: &[&str]| {
                     let mut cmd = Command::new(&xperf);
@@ -195,6 +385,113 @@ fn main() {
                 }
             }
 
+            "CachegrindStat" => {
+                let mut cmd = Command::new("valgrind");
+                let has_valgrind = cmd.output().is_ok();
+                assert!(has_valgrind);
+
+                let log_file = std::env::current_dir().unwrap().join("cgstat-log");
+
+                // With --cache-sim=no and --branch-sim=no, Cachegrind just
+                // collects instruction counts.
+                cmd
+                    // We disable jemalloc's delayed purging to eliminate noise
+                    // when benchmarks are around the 10 second mark.
+                    //
+                    // See https://github.com/rust-lang/rust/pull/77162 for some
+                    // further details.
+                    .env("MALLOC_CONF", "dirty_decay_ms:0,muzzy_decay_ms:0")
+                    .arg("--tool=cachegrind")
+                    .arg("--cache-sim=no")
+                    .arg("--branch-sim=no")
+                    .arg("--cachegrind-out-file=cgstat-out")
+                    .arg(format!("--log-file={}", log_file.to_str().unwrap()))
+                    .arg(&tool)
+                    .args(&args);
+
+                let start = Instant::now();
+                run_with_determinism_env(cmd);
+                let dur = start.elapsed();
+
+                // Report the instruction count in the same CSV format as
+                // `perf stat`.
+                let log = fs::read_to_string(&log_file).expect("failed to read cachegrind log");
+                let instructions = log
+                    .lines()
+                    .find_map(|l| l.split_once("I refs:").map(|(_, v)| v.trim().replace(',', "")))
+                    .expect("no instruction count in cachegrind log");
+                println!("{};;instructions:u;4;100.00", instructions);
+                if metric_requested("max-rss") {
+                    print_memory();
+                }
+                if metric_requested("wall-time") {
+                    print_time(dur);
+                }
+            }
+
+            "KperfStat" => {
+                #[cfg(target_os = "macos")]
+                {
+                    use benchlib::measure::kperf::Kperf;
+
+                    // The kperf counters need root access; fall back to
+                    // wall-time and max-rss only if they cannot be enabled.
+                    let kperf = match Kperf::acquire() {
+                        Ok(kperf) => Some(kperf),
+                        Err(error) => {
+                            eprintln!(
+                                "Could not enable the kperf counters: {:?}. \
+                                 Only wall-time and max-rss will be recorded.",
+                                error
+                            );
+                            None
+                        }
+                    };
+
+                    let mut cmd = Command::new(&tool);
+                    cmd.args(&args);
+
+                    let before = kperf.as_ref().map(|kperf| kperf.read_cpu_counters());
+                    let start = Instant::now();
+                    run_with_determinism_env(cmd);
+                    let dur = start.elapsed();
+                    let after = kperf.as_ref().map(|kperf| kperf.read_cpu_counters());
+
+                    if let (Some(Ok(before)), Some(Ok(after))) = (before, after) {
+                        // The fixed counters are system-wide, so the deltas
+                        // include everything that ran on the machine during
+                        // the compilation, not just the compiler. Keep the
+                        // machine quiet while benchmarking.
+                        let delta = after.delta_since(&before);
+                        if metric_requested("instructions") {
+                            println!("{};;instructions:u;4;100.00", delta.instructions);
+                        }
+                        if metric_requested("cycles") {
+                            println!("{};;cycles;4;100.00", delta.cycles);
+                        }
+                    }
+                    if metric_requested("max-rss") {
+                        print_memory();
+                    }
+                    if metric_requested("wall-time") {
+                        print_time(dur);
+                    }
+                }
+                #[cfg(not(target_os = "macos"))]
+                panic!("The kperf measurer is only available on macOS");
+            }
+
+            "TimeStat" => {
+                let mut cmd = Command::new(&tool);
+                cmd.args(&args);
+
+                let start = Instant::now();
+                run_with_determinism_env(cmd);
+                let dur = start.elapsed();
+                print_memory();
+                print_time(dur);
+            }
+
             "SelfProfile" => {
                 let mut cmd = Command::new(&tool);
                 cmd.arg("-Zself-profile-events=all")
@@ -410,7 +707,17 @@ fn main() {
             }
         }
 
-        let mut cmd = Command::new(&tool);
+        // Dependency crates are not measured, so their compilation may go
+        // through sccache when the collector asks for it; the measured leaf
+        // crate takes the wrapped branches above and is never cached.
+        let mut cmd = match env::var_os("RUSTC_PERF_SCCACHE").filter(|_| !actually_rustdoc) {
+            Some(sccache) => {
+                let mut cmd = Command::new(sccache);
+                cmd.arg(&tool);
+                cmd
+            }
+            None => Command::new(&tool),
+        };
         determinism_env(&mut cmd);
         cmd.args(&args);
         exec(&mut cmd);
@@ -474,6 +781,74 @@ fn process_self_profile_output(prof_out_dir: PathBuf, args: &[OsString]) {
     }
 }
 
+/// Returns whether monomorphization statistics should be gathered. Dumping
+/// them makes the compiler do extra work inside the measured run, so they are
+/// only collected when `--metrics` names them explicitly, never by default.
+fn mono_stats_requested() -> bool {
+    metric_explicitly_requested("mono-items")
+        || metric_explicitly_requested("mono-item-instantiations")
+}
+
+/// Returns whether the benchmarked rustc accepts `-Zdump-mono-stats`. Older
+/// toolchains reject the flag outright, and `bench_published` still measures
+/// them. The probe runs outside of the measured command, so it does not
+/// perturb the collected metrics.
+fn supports_mono_stats(rustc: &OsString) -> bool {
+    Command::new(rustc)
+        .arg("-Zdump-mono-stats-format=json")
+        .arg("--version")
+        .output()
+        .map_or(false, |output| output.status.success())
+}
+
+/// Makes rustc dump per-item monomorphization statistics into `dir`. The dump
+/// is written once at the end of codegen.
+fn setup_mono_stats(cmd: &mut Command, dir: &Path) {
+    let _ = fs::remove_dir_all(dir);
+    let _ = fs::create_dir_all(dir);
+    cmd.arg(&format!("-Zdump-mono-stats={}", dir.to_str().unwrap()));
+    cmd.arg("-Zdump-mono-stats-format=json");
+}
+
+/// Reads the `-Zdump-mono-stats` output and reports the total number of mono
+/// items and the total number of instantiated copies across all of them.
+/// Profiles that skip codegen (e.g. `Check`) produce no dump; nothing is
+/// printed in that case.
+fn print_mono_stats(dir: &Path) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let mut items: u64 = 0;
+    let mut instantiations: u64 = 0;
+    let mut found = false;
+    for entry in entries {
+        let path = entry.expect("read mono stats dir").path();
+        let contents = fs::read_to_string(&path).expect("read mono stats dump");
+        let dump: serde_json::Value = serde_json::from_str(&contents)
+            .unwrap_or_else(|e| panic!("cannot parse {}: {:?}", path.display(), e));
+        let dump = dump.as_array().expect("mono stats dump is an array");
+        found = true;
+        items += dump.len() as u64;
+        instantiations += dump
+            .iter()
+            .map(|item| {
+                item.get("instantiation_count")
+                    .and_then(|v| v.as_u64())
+                    .expect("mono item has an instantiation count")
+            })
+            .sum::<u64>();
+    }
+    if found {
+        if metric_requested("mono-items") {
+            println!("!mono-items:{}", items);
+        }
+        if metric_requested("mono-item-instantiations") {
+            println!("!mono-item-instantiations:{}", instantiations);
+        }
+    }
+}
+
 #[cfg(windows)]
 fn exec(cmd: &mut Command) -> ! {
     let cmd_d = format!("{:?}", cmd);
@@ -519,6 +894,135 @@ fn print_time(dur: Duration) {
     );
 }
 
+/// Samples the combined resident set size of this process's descendants,
+/// tracking the peak. This catches memory used by processes spawned by the
+/// benchmarked tool (e.g. rustc processes spawned by rustdoc), which
+/// getrusage only reports individually, never as a concurrent total.
+#[cfg(unix)]
+mod tree_max_rss {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+
+    pub struct Sampler {
+        stop: Arc<AtomicBool>,
+        peak: Arc<AtomicU64>,
+        handle: JoinHandle<()>,
+    }
+
+    impl Sampler {
+        pub fn start() -> Option<Sampler> {
+            if !std::path::Path::new("/proc").is_dir() {
+                return None;
+            }
+            let stop = Arc::new(AtomicBool::new(false));
+            let peak = Arc::new(AtomicU64::new(0));
+            let handle = {
+                let stop = stop.clone();
+                let peak = peak.clone();
+                let self_pid = std::process::id();
+                std::thread::spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        if let Some(rss) = tree_rss_kb(self_pid) {
+                            peak.fetch_max(rss, Ordering::Relaxed);
+                        }
+                        std::thread::sleep(Duration::from_millis(10));
+                    }
+                })
+            };
+            Some(Sampler { stop, peak, handle })
+        }
+
+        /// Stops sampling and returns the peak tree RSS in kilobytes, if any
+        /// sample was taken.
+        pub fn finish(self) -> Option<u64> {
+            self.stop.store(true, Ordering::Relaxed);
+            let _ = self.handle.join();
+            match self.peak.load(Ordering::Relaxed) {
+                0 => None,
+                peak => Some(peak),
+            }
+        }
+    }
+
+    /// Sums the resident set sizes of all live descendants of `root`, in
+    /// kilobytes. The sum includes the measurement wrappers (perf, setarch),
+    /// whose resident sets are small and constant.
+    fn tree_rss_kb(root: u32) -> Option<u64> {
+        let mut processes = Vec::new();
+        for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+            let pid = match entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+            {
+                Some(pid) => pid,
+                None => continue,
+            };
+            // The process may have exited since the directory was listed.
+            let status = match std::fs::read_to_string(entry.path().join("status")) {
+                Ok(status) => status,
+                Err(_) => continue,
+            };
+            let mut ppid = None;
+            // Kernel threads have no VmRSS line.
+            let mut rss = 0;
+            for line in status.lines() {
+                if let Some(value) = line.strip_prefix("PPid:") {
+                    ppid = value.trim().parse::<u32>().ok();
+                } else if let Some(value) = line.strip_prefix("VmRSS:") {
+                    rss = value
+                        .trim()
+                        .trim_end_matches("kB")
+                        .trim()
+                        .parse::<u64>()
+                        .unwrap_or(0);
+                }
+            }
+            if let Some(ppid) = ppid {
+                processes.push((pid, ppid, rss));
+            }
+        }
+
+        let parent_of: HashMap<u32, u32> = processes
+            .iter()
+            .map(|&(pid, ppid, _)| (pid, ppid))
+            .collect();
+        let mut total = 0;
+        for &(pid, _, rss) in &processes {
+            let mut current = pid;
+            while let Some(&ppid) = parent_of.get(&current) {
+                if ppid == root {
+                    total += rss;
+                    break;
+                }
+                if ppid == 0 || ppid == current {
+                    break;
+                }
+                current = ppid;
+            }
+        }
+        Some(total)
+    }
+}
+
+#[cfg(not(unix))]
+mod tree_max_rss {
+    pub struct Sampler;
+
+    impl Sampler {
+        pub fn start() -> Option<Sampler> {
+            None
+        }
+
+        pub fn finish(self) -> Option<u64> {
+            None
+        }
+    }
+}
+
 fn run_summarize(name: &str, prof_out_dir: &Path, prefix: &str) -> anyhow::Result<String> {
     let mut cmd = Command::new(name);
     cmd.current_dir(prof_out_dir);
@@ -543,4 +1047,50 @@ fn run_summarize(name: &str, prof_out_dir: &Path, prefix: &str) -> anyhow::Resul
 }
 
 #[cfg(windows)]
-fn print_memory() {}
+static JOB_HANDLE: std::sync::atomic::AtomicIsize = std::sync::atomic::AtomicIsize::new(0);
+
+/// Puts this process into a job object. Child processes are placed into the
+/// job as well, which lets `print_memory` report the peak memory usage of the
+/// whole process tree.
+#[cfg(windows)]
+fn setup_job_object() {
+    use std::sync::atomic::Ordering;
+    use windows_sys::Win32::System::JobObjects::{AssignProcessToJobObject, CreateJobObjectW};
+    use windows_sys::Win32::System::Threading::GetCurrentProcess;
+
+    unsafe {
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job != 0 && AssignProcessToJobObject(job, GetCurrentProcess()) != 0 {
+            JOB_HANDLE.store(job, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(windows)]
+fn print_memory() {
+    use std::mem;
+    use std::sync::atomic::Ordering;
+    use windows_sys::Win32::System::JobObjects::{
+        JobObjectExtendedLimitInformation, QueryInformationJobObject,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+    };
+
+    let job = JOB_HANDLE.load(Ordering::SeqCst);
+    if job == 0 {
+        return;
+    }
+    unsafe {
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = mem::zeroed();
+        let r = QueryInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            &mut info as *mut _ as *mut _,
+            mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            std::ptr::null_mut(),
+        );
+        if r != 0 {
+            // Report in kilobytes, matching getrusage's ru_maxrss on Linux.
+            println!("!max-rss:{}", info.PeakJobMemoryUsed / 1024);
+        }
+    }
+}
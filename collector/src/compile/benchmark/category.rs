@@ -7,6 +7,11 @@ pub enum Category {
     Primary,
     Secondary,
     Stable,
+    /// Curated artificial stress tests (e.g. token explosion, deep generics).
+    /// They are not benchmarked by default and have to be requested
+    /// explicitly, so that their extreme behavior does not drown out the
+    /// real-world benchmarks.
+    Stress,
 }
 
 impl Category {
@@ -20,12 +25,13 @@ impl Category {
 
     // Within the DB, `Category` is represented in two fields:
     // - a `supports_stable` bool,
-    // - a `category` which is either "primary" or "secondary".
+    // - a `category` which is either "primary", "secondary" or "stress".
     pub fn db_representation(self) -> (bool, String) {
         match self {
             Category::Primary => (false, "primary".to_string()),
             Category::Secondary => (false, "secondary".to_string()),
             Category::Stable => (true, "primary".to_string()),
+            Category::Stress => (false, "stress".to_string()),
         }
     }
 
@@ -33,6 +39,7 @@ impl Category {
         match text {
             "primary" => Ok(Self::Primary),
             "secondary" => Ok(Self::Secondary),
+            "stress" => Ok(Self::Stress),
             _ => Err(anyhow::anyhow!("Unknown category {text}")),
         }
     }
@@ -44,6 +51,7 @@ impl fmt::Display for Category {
             Category::Primary => f.write_str("primary"),
             Category::Secondary => f.write_str("secondary"),
             Category::Stable => f.write_str("stable"),
+            Category::Stress => f.write_str("stress"),
         }
     }
 }
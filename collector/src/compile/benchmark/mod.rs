@@ -23,6 +23,10 @@ fn default_runs() -> usize {
     3
 }
 
+fn default_weight() -> f64 {
+    1.0
+}
+
 #[derive(
     Debug, Default, PartialEq, Copy, Clone, serde::Serialize, serde::Deserialize, clap::ValueEnum,
 )]
@@ -45,6 +49,7 @@ impl Display for ArtifactType {
 /// This is the internal representation of an individual benchmark's
 /// perf-config.json file.
 #[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BenchmarkConfig {
     cargo_opts: Option<String>,
     cargo_rustc_opts: Option<String>,
@@ -63,6 +68,12 @@ pub struct BenchmarkConfig {
 
     category: Category,
 
+    /// Relative weight of this benchmark in summary averages. Benchmarks
+    /// whose size makes them unrepresentative can be weighted down so that
+    /// they do not dominate the headline summary graph.
+    #[serde(default = "default_weight")]
+    weight: f64,
+
     /// Profiles that are not useful for this benchmark.
     /// They will be ignored during benchmarking.
     #[serde(default)]
@@ -73,6 +84,27 @@ pub struct BenchmarkConfig {
     #[serde(default)]
     excluded_scenarios: HashSet<Scenario>,
 
+    /// The minimum rustc version (e.g. `1.60.0`) able to build this
+    /// benchmark, for benchmarks whose sources need an edition or language
+    /// features that older toolchains lack. When an older published
+    /// toolchain is benchmarked, the benchmark is recorded as unsupported
+    /// instead of failing to build.
+    #[serde(default)]
+    min_rustc: Option<String>,
+
+    /// Metrics for which a higher value is an improvement on this benchmark
+    /// (e.g. a throughput counter), so that the comparison page does not
+    /// report their improvements as regressions.
+    #[serde(default)]
+    higher_is_better_metrics: HashSet<String>,
+
+    /// Extra environment variables to set for every cargo (and thus rustc)
+    /// invocation of this benchmark, e.g. an increased stack size or a
+    /// type-length limit. Declaring them here keeps such workarounds out of
+    /// the benchmark sources and the global environment.
+    #[serde(default)]
+    env: HashMap<String, String>,
+
     artifact: ArtifactType,
 }
 
@@ -88,6 +120,16 @@ impl BenchmarkConfig {
     pub fn iterations(&self) -> usize {
         self.runs
     }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns whether a higher value of the given metric is an improvement
+    /// on this benchmark.
+    pub fn is_higher_better(&self, metric: &str) -> bool {
+        self.higher_is_better_metrics.contains(metric)
+    }
 }
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Hash)]
@@ -104,6 +146,8 @@ pub struct Benchmark {
     pub path: PathBuf,
     pub patches: Vec<Patch>,
     config: BenchmarkConfig,
+    reuse_target_dir: bool,
+    sccache: Option<PathBuf>,
 }
 
 impl Benchmark {
@@ -138,13 +182,48 @@ impl Benchmark {
             path,
             patches,
             config,
+            reuse_target_dir: false,
+            sccache: None,
         })
     }
 
+    /// Enables reuse of prepared target directories between runs of the same
+    /// toolchain (see [`target_dir_cache`]).
+    pub fn enable_target_dir_reuse(&mut self) {
+        self.reuse_target_dir = true;
+    }
+
+    /// Wraps dependency (non-measured) crate compilations with the given
+    /// sccache binary. The measured leaf crate is never cached, so the
+    /// recorded statistics are unaffected.
+    pub fn set_sccache(&mut self, sccache: PathBuf) {
+        self.sccache = Some(sccache);
+    }
+
     pub fn category(&self) -> Category {
         self.config.category
     }
 
+    pub fn disabled(&self) -> bool {
+        self.config.disabled
+    }
+
+    /// Returns the benchmark's declared minimum rustc version if the given
+    /// toolchain version is too old to build it. Only release numbers (e.g.
+    /// `1.53.0`) can be too old; `beta` and `master` builds support
+    /// everything.
+    pub fn unsupported_by(&self, version_str: &str) -> Option<&str> {
+        let min_rustc = self.config.min_rustc.as_deref()?;
+        let version = version_str.parse::<semver::Version>().ok()?;
+        let min_version = min_rustc.parse::<semver::Version>().unwrap_or_else(|e| {
+            panic!(
+                "invalid min_rustc `{}` for benchmark {}: {}",
+                min_rustc, self.name, e
+            )
+        });
+        (version < min_version).then_some(min_rustc)
+    }
+
     #[cfg(windows)]
     fn copy(from: &Path, to: &Path) -> anyhow::Result<()> {
         crate::utils::fs::robocopy(from, to, &[])
@@ -172,6 +251,72 @@ impl Benchmark {
         Ok(tmp_dir)
     }
 
+    /// Computes the cache entry for this benchmark + profile combination
+    /// under the given toolchain. The key hashes everything that influences
+    /// the contents of the prepared directory: the benchmark sources, the
+    /// configured cargo flags and the toolchain itself.
+    fn target_cache_entry(&self, toolchain: &Toolchain, profile: Profile) -> TargetCacheEntry {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        toolchain.id.hash(&mut hasher);
+        // The id of local toolchains is user-provided; mix in the metadata of
+        // the rustc binary so that a rebuilt compiler under the same id does
+        // not reuse stale artifacts.
+        if let Ok(metadata) = std::fs::metadata(&toolchain.components.rustc) {
+            metadata.len().hash(&mut hasher);
+            if let Ok(modified) = metadata.modified() {
+                modified.hash(&mut hasher);
+            }
+        }
+        format!("{:?}", profile).hash(&mut hasher);
+        self.config.cargo_opts.hash(&mut hasher);
+        self.config.cargo_rustc_opts.hash(&mut hasher);
+        self.config.cargo_toml.hash(&mut hasher);
+        crate::hash_directory(&self.path, &mut hasher);
+
+        TargetCacheEntry {
+            dir: target_dir_cache().join(format!("{}-{:?}", self.name, profile)),
+            key: format!("{:016x}", hasher.finish()),
+        }
+    }
+
+    /// Restores a previously prepared directory from the cache into `dest`.
+    /// Returns false when the entry is missing or was built from different
+    /// sources, flags or toolchain (or its population was interrupted, in
+    /// which case the key file was never written); such entries are removed.
+    fn restore_cached_target(entry: &TargetCacheEntry, dest: &Path) -> anyhow::Result<bool> {
+        let recorded = std::fs::read_to_string(entry.dir.join(TARGET_CACHE_KEY_FILE)).ok();
+        if recorded.as_deref() != Some(entry.key.as_str()) {
+            if entry.dir.exists() {
+                std::fs::remove_dir_all(&entry.dir)
+                    .with_context(|| format!("removing stale cache entry {:?}", entry.dir))?;
+            }
+            return Ok(false);
+        }
+        let mut contents = entry.dir.join("contents");
+        contents.push(".");
+        Self::copy(&contents, dest)?;
+        Ok(true)
+    }
+
+    /// Stores a freshly prepared directory into the cache. The key file is
+    /// written last, so that an interrupted copy is never mistaken for a
+    /// valid entry.
+    fn store_cached_target(entry: &TargetCacheEntry, prepared: &Path) -> anyhow::Result<()> {
+        if entry.dir.exists() {
+            std::fs::remove_dir_all(&entry.dir)?;
+        }
+        let contents = entry.dir.join("contents");
+        std::fs::create_dir_all(&contents)?;
+        let mut prepared_dot = prepared.to_path_buf();
+        prepared_dot.push(".");
+        Self::copy(&prepared_dot, &contents)?;
+        std::fs::write(entry.dir.join(TARGET_CACHE_KEY_FILE), &entry.key)?;
+        Ok(())
+    }
+
     fn mk_cargo_process<'a>(
         &'a self,
         toolchain: &'a Toolchain,
@@ -216,10 +361,16 @@ impl Benchmark {
                 .collect(),
             touch_file: self.config.touch_file.clone(),
             jobserver: None,
+            sccache: self.sccache.clone(),
+            extra_env: self.config.env.clone(),
         }
     }
 
     /// Run a specific benchmark under a processor + profiler combination.
+    ///
+    /// Returns the approximate peak disk usage (in bytes) of the temporary
+    /// target directories used by the benchmark, so that future runs can
+    /// estimate how much disk space they will need.
     pub async fn measure(
         &self,
         processor: &mut dyn Processor,
@@ -227,7 +378,7 @@ impl Benchmark {
         scenarios: &[Scenario],
         toolchain: &Toolchain,
         iterations: Option<usize>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<u64> {
         if self.config.disabled {
             eprintln!("Skipping {}: disabled", self.name);
             bail!("disabled benchmark");
@@ -243,7 +394,7 @@ impl Benchmark {
 
         if profiles.is_empty() {
             eprintln!("Skipping {}: no profiles selected", self.name);
-            return Ok(());
+            return Ok(0);
         }
 
         let scenarios: Vec<Scenario> = scenarios
@@ -254,7 +405,7 @@ impl Benchmark {
 
         if scenarios.is_empty() {
             eprintln!("Skipping {}: no scenarios selected", self.name);
-            return Ok(());
+            return Ok(0);
         }
 
         eprintln!("Preparing {}", self.name);
@@ -292,11 +443,26 @@ impl Benchmark {
             for (profile, prep_dir) in &profile_dirs {
                 let server = server.clone();
                 let thread = s.spawn::<_, anyhow::Result<()>>(move || {
+                    let cache_entry = self
+                        .reuse_target_dir
+                        .then(|| self.target_cache_entry(toolchain, *profile));
+                    if let Some(entry) = &cache_entry {
+                        if Self::restore_cached_target(entry, prep_dir.path())? {
+                            eprintln!(
+                                "Reusing cached target directory for {} {:?}",
+                                self.name, profile
+                            );
+                            return Ok(());
+                        }
+                    }
                     wait_for_future(
                         self.mk_cargo_process(toolchain, prep_dir.path(), *profile)
                             .jobserver(server)
                             .run_rustc(false),
                     )?;
+                    if let Some(entry) = &cache_entry {
+                        Self::store_cached_target(entry, prep_dir.path())?;
+                    }
                     Ok(())
                 });
                 threads.push(thread);
@@ -319,6 +485,20 @@ impl Benchmark {
             preparation_start.elapsed().as_secs()
         );
 
+        // The prepared target directories live for the rest of the benchmark,
+        // with one timing directory (a copy of a prepared one plus build
+        // artifacts) on top at a time; together they form the peak disk usage
+        // of the benchmark.
+        let prepared_bytes: u64 = profile_dirs
+            .iter()
+            .map(|(_, dir)| {
+                crate::utils::fs::get_file_count_and_size(dir.path())
+                    .map(|(_, size)| size)
+                    .unwrap_or(0)
+            })
+            .sum();
+        let mut max_timing_bytes: u64 = 0;
+
         for (profile, prep_dir) in profile_dirs {
             eprintln!("Running {}: {:?} + {:?}", self.name, profile, scenarios);
 
@@ -389,14 +569,39 @@ impl Benchmark {
                         }
                     }
                 }
+                if i == 0 {
+                    // Later iterations use directories of roughly the same
+                    // size, so measuring the first one is enough.
+                    let timing_bytes = crate::utils::fs::get_file_count_and_size(cwd)
+                        .map(|(_, size)| size)
+                        .unwrap_or(0);
+                    max_timing_bytes = max_timing_bytes.max(timing_bytes);
+                }
                 drop(ManuallyDrop::into_inner(timing_dir));
             }
         }
 
-        Ok(())
+        Ok(prepared_bytes + max_timing_bytes)
     }
 }
 
+/// Location and expected key of a cached prepared directory for one
+/// (benchmark, profile) combination. The prepared files live in a `contents`
+/// subdirectory; the key is recorded next to it in [`TARGET_CACHE_KEY_FILE`].
+struct TargetCacheEntry {
+    dir: PathBuf,
+    key: String,
+}
+
+const TARGET_CACHE_KEY_FILE: &str = "reuse-key";
+
+/// Directory where prepared benchmark directories (sources plus target
+/// directory) are retained between runs when target directory reuse is
+/// enabled.
+pub fn target_dir_cache() -> PathBuf {
+    PathBuf::from("collector/target-dir-cache")
+}
+
 /// Directory containing compile-time benchmarks.
 /// We measure how long does it take to compile these crates.
 pub fn compile_benchmark_dir() -> PathBuf {
@@ -499,6 +704,98 @@ pub fn get_compile_benchmarks(
     Ok(benchmarks)
 }
 
+/// Result of validating a benchmark directory with [`validate_benchmark_dir`].
+#[derive(Default)]
+pub struct ValidationReport {
+    /// Problems that make the benchmark unusable or silently drop data.
+    pub errors: Vec<String>,
+    /// Problems worth flagging that do not prevent benchmarking.
+    pub warnings: Vec<String>,
+}
+
+/// Checks the on-disk configuration of a single benchmark directory without
+/// building or running anything: perf-config.json has to parse against the
+/// strict schema (unknown keys are rejected), the paths it references have to
+/// exist, and patch files have to follow the `0000-name.patch` naming
+/// convention.
+pub fn validate_benchmark_dir(path: &Path) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    let config_path = path.join("perf-config.json");
+    let config: Option<BenchmarkConfig> = match std::fs::read_to_string(&config_path) {
+        Ok(contents) => match serde_json::from_str(&contents) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                report
+                    .errors
+                    .push(format!("failed to parse perf-config.json: {}", e));
+                None
+            }
+        },
+        Err(_) => {
+            report
+                .errors
+                .push("missing a perf-config.json file".to_string());
+            None
+        }
+    };
+
+    let mut patch_count = 0;
+    match std::fs::read_dir(path) {
+        Ok(entries) => {
+            for entry in entries.flatten() {
+                if entry.path().extension().map_or(false, |ext| ext == "patch") {
+                    patch_count += 1;
+                    let file_name = entry.file_name().to_string_lossy().into_owned();
+                    // The same format that `Patch::new` expects, checked here
+                    // without panicking.
+                    let index_ok = file_name
+                        .split('-')
+                        .next()
+                        .map_or(false, |prefix| prefix.parse::<usize>().is_ok());
+                    if !index_ok {
+                        report.errors.push(format!(
+                            "patch `{}` is not in the `0000-name.patch` format",
+                            file_name
+                        ));
+                    }
+                }
+            }
+        }
+        Err(e) => report
+            .errors
+            .push(format!("failed to list benchmark directory: {}", e)),
+    }
+
+    if let Some(config) = config {
+        let manifest = config.cargo_toml.as_deref().unwrap_or("Cargo.toml");
+        if !path.join(manifest).is_file() {
+            report
+                .errors
+                .push(format!("manifest `{}` does not exist", manifest));
+        }
+        if let Some(touch_file) = &config.touch_file {
+            if !path.join(touch_file).is_file() {
+                report
+                    .errors
+                    .push(format!("touch_file `{}` does not exist", touch_file));
+            }
+        }
+        if config.disabled {
+            report.warnings.push("benchmark is disabled".to_string());
+        }
+        if patch_count == 0 && !config.excluded_scenarios.contains(&Scenario::IncrPatched) {
+            report.warnings.push(
+                "has no patches, so the IncrPatched scenario will not produce any data \
+                 (add a patch or list IncrPatched in excluded_scenarios)"
+                    .to_string(),
+            );
+        }
+    }
+
+    report
+}
+
 /// Helper to verify if a benchmark name matches a given substring, like a prefix or a suffix. The
 /// `predicate` closure will be passed each substring from `substrings` until it returns true, and
 /// in that case the substring's number of uses in the map will be increased.
@@ -534,4 +831,23 @@ mod tests {
         .unwrap();
         assert!(!benchmarks.is_empty());
     }
+
+    #[test]
+    fn validate_compile_benchmarks() {
+        // The in-tree benchmark suite should be free of configuration errors.
+        let root = env!("CARGO_MANIFEST_DIR");
+        for entry in std::fs::read_dir(Path::new(root).join("compile-benchmarks")).unwrap() {
+            let entry = entry.unwrap();
+            if !entry.file_type().unwrap().is_dir() {
+                continue;
+            }
+            let report = super::validate_benchmark_dir(&entry.path());
+            assert!(
+                report.errors.is_empty(),
+                "{}: {:?}",
+                entry.path().display(),
+                report.errors
+            );
+        }
+    }
 }
@@ -2,9 +2,10 @@ use crate::compile::benchmark::profile::Profile;
 use crate::compile::benchmark::scenario::Scenario;
 use crate::compile::benchmark::BenchmarkName;
 use crate::compile::execute;
+use crate::compile::execute::measurer::{self, Measurer};
 use crate::compile::execute::{
-    rustc, DeserializeStatError, PerfTool, ProcessOutputData, Processor, Retry, SelfProfile,
-    SelfProfileFiles, Stats, Upload,
+    hello_world, rustc, tool, DeserializeStatError, PerfTool, ProcessOutputData, Processor, Retry,
+    SelfProfile, SelfProfileFiles, Stats, Upload,
 };
 use crate::toolchain::Toolchain;
 use crate::utils::git::get_rustc_perf_commit;
@@ -13,7 +14,6 @@ use futures::StreamExt;
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
-use std::process::Command;
 use std::{env, process};
 
 // Tools usable with the benchmarking subcommands.
@@ -23,6 +23,9 @@ pub enum Bencher {
     PerfStatSelfProfile,
     XperfStat,
     XperfStatSelfProfile,
+    KperfStat,
+    CachegrindStat,
+    TimeStat,
 }
 
 pub struct BenchProcessor<'a> {
@@ -30,6 +33,8 @@ pub struct BenchProcessor<'a> {
     conn: &'a mut dyn database::Connection,
     artifact: &'a database::ArtifactId,
     artifact_row_id: database::ArtifactIdNumber,
+    run_id: database::RunId,
+    measurer: &'static (dyn Measurer + Sync),
     upload: Option<Upload>,
     is_first_collection: bool,
     is_self_profile: bool,
@@ -42,24 +47,12 @@ impl<'a> BenchProcessor<'a> {
         benchmark: &'a BenchmarkName,
         artifact: &'a database::ArtifactId,
         artifact_row_id: database::ArtifactIdNumber,
+        run_id: database::RunId,
         is_self_profile: bool,
     ) -> Self {
-        // Check we have `perf` or (`xperf.exe` and `tracelog.exe`)  available.
-        if cfg!(unix) {
-            let has_perf = Command::new("perf").output().is_ok();
-            assert!(has_perf);
-        } else {
-            let has_xperf = Command::new(env::var("XPERF").unwrap_or("xperf.exe".to_string()))
-                .output()
-                .is_ok();
-            assert!(has_xperf);
-
-            let has_tracelog =
-                Command::new(env::var("TRACELOG").unwrap_or("tracelog.exe".to_string()))
-                    .output()
-                    .is_ok();
-            assert!(has_tracelog);
-        }
+        // Check the tools of the selected measurement backend are available.
+        let measurer = measurer::select_measurer().unwrap();
+        measurer.check_availability().unwrap();
 
         BenchProcessor {
             upload: None,
@@ -67,12 +60,22 @@ impl<'a> BenchProcessor<'a> {
             benchmark,
             artifact,
             artifact_row_id,
+            run_id,
+            measurer,
             is_first_collection: true,
             is_self_profile,
             tries: 0,
         }
     }
 
+    /// Records the peak disk usage observed while measuring this benchmark,
+    /// so that future runs can estimate how much disk space they will need.
+    pub async fn record_disk_usage(&mut self, bytes: u64) {
+        self.conn
+            .record_compile_benchmark_disk_usage(&self.benchmark.0, bytes)
+            .await;
+    }
+
     async fn insert_stats(
         &mut self,
         scenario: database::Scenario,
@@ -81,7 +84,7 @@ impl<'a> BenchProcessor<'a> {
     ) {
         let version = get_rustc_perf_commit();
 
-        let collection = self.conn.collection_id(&version).await;
+        let collection = self.conn.collection_id(&version, Some(self.run_id)).await;
         let profile = match profile {
             Profile::Check => database::Profile::Check,
             Profile::Debug => database::Profile::Debug,
@@ -107,7 +110,12 @@ impl<'a> BenchProcessor<'a> {
                     .join(self.benchmark.0.as_str())
                     .join(profile.to_string())
                     .join(scenario.to_id());
-                self.upload = Some(Upload::new(prefix, collection, files));
+                self.upload = Some(Upload::new(
+                    prefix,
+                    collection,
+                    self.benchmark.0.as_str(),
+                    files,
+                ));
                 self.conn
                     .record_raw_self_profile(
                         collection,
@@ -160,23 +168,50 @@ impl<'a> BenchProcessor<'a> {
     }
 
     pub async fn measure_rustc(&mut self, toolchain: &Toolchain) -> anyhow::Result<()> {
-        rustc::measure(self.conn, toolchain, self.artifact, self.artifact_row_id).await
+        rustc::measure(
+            self.conn,
+            toolchain,
+            self.artifact,
+            self.artifact_row_id,
+            self.run_id,
+        )
+        .await
+    }
+
+    pub async fn measure_hello_world(&mut self, toolchain: &Toolchain) -> anyhow::Result<()> {
+        hello_world::measure(
+            self.conn,
+            &self.benchmark.0,
+            toolchain,
+            self.artifact_row_id,
+            self.run_id,
+        )
+        .await
+    }
+
+    pub async fn measure_tool(
+        &mut self,
+        tool: &tool::ToolBenchmark,
+        toolchain: &Toolchain,
+    ) -> anyhow::Result<()> {
+        tool::measure(
+            self.conn,
+            &self.benchmark.0,
+            tool,
+            toolchain,
+            self.artifact_row_id,
+            self.run_id,
+        )
+        .await
     }
 }
 
 impl<'a> Processor for BenchProcessor<'a> {
     fn perf_tool(&self) -> PerfTool {
-        if self.is_first_collection && self.is_self_profile {
-            if cfg!(unix) {
-                PerfTool::BenchTool(Bencher::PerfStatSelfProfile)
-            } else {
-                PerfTool::BenchTool(Bencher::XperfStatSelfProfile)
-            }
-        } else if cfg!(unix) {
-            PerfTool::BenchTool(Bencher::PerfStat)
-        } else {
-            PerfTool::BenchTool(Bencher::XperfStat)
-        }
+        PerfTool::BenchTool(
+            self.measurer
+                .bencher(self.is_first_collection && self.is_self_profile),
+        )
     }
 
     fn start_first_collection(&mut self) {
@@ -201,12 +236,26 @@ impl<'a> Processor for BenchProcessor<'a> {
                     if let Some(ref profile) = res.1 {
                         execute::store_artifact_sizes_into_stats(&mut res.0, profile);
                     }
+                    // The end-to-end duration of the run, including harness
+                    // overhead, as opposed to the rustc-only wall-time. Used
+                    // to track how expensive individual suite members are to
+                    // collect.
+                    res.0.insert(
+                        "collector:run-duration".to_string(),
+                        data.duration.as_secs_f64(),
+                    );
                     if let Profile::Doc = data.profile {
                         let doc_dir = data.cwd.join("target/doc");
                         if doc_dir.is_dir() {
                             execute::store_documentation_size_into_stats(&mut res.0, &doc_dir);
                         }
                     }
+                    if data.scenario.is_incr() {
+                        let incr_dir = data.cwd.join("incremental-state");
+                        if incr_dir.is_dir() {
+                            execute::store_incremental_state_size_into_stats(&mut res.0, &incr_dir);
+                        }
+                    }
 
                     let fut = match data.scenario {
                         Scenario::Full => {
@@ -247,10 +296,7 @@ impl<'a> Processor for BenchProcessor<'a> {
                         panic!("failed to collect statistics after 5 tries");
                     }
                 }
-                Err(
-                    e @ (DeserializeStatError::ParseError { .. }
-                    | DeserializeStatError::XperfError(..)),
-                ) => {
+                Err(e @ DeserializeStatError::ParseError { .. }) => {
                     panic!("process_perf_stat_output failed: {:?}", e);
                 }
             }
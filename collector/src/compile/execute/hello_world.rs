@@ -0,0 +1,94 @@
+//! Performance collection for end-to-end "hello world" build latency.
+//!
+//! Unlike the regular compile benchmarks, which measure the rustc processes
+//! spawned by a single cargo invocation, this measures the whole workflow a
+//! new user runs: `cargo new` followed by `cargo build`, including process
+//! startup and cargo's own overhead. First-build latency is a user-facing
+//! number that the rest of the suite does not directly represent.
+
+use crate::toolchain::Toolchain;
+use crate::utils::git::get_rustc_perf_commit;
+use anyhow::Context;
+use database::{ArtifactIdNumber, Profile, RunId, Scenario};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+/// How many times the cold/warm build pair is measured.
+const ITERATIONS: usize = 3;
+
+/// Measure the special hello-world benchmark.
+pub async fn measure(
+    conn: &mut dyn database::Connection,
+    benchmark_name: &str,
+    toolchain: &Toolchain,
+    aid: ArtifactIdNumber,
+    run: RunId,
+) -> anyhow::Result<()> {
+    eprintln!("Running hello-world");
+
+    let version = get_rustc_perf_commit();
+    for _ in 0..ITERATIONS {
+        let (cold, warm) = measure_once(toolchain)?;
+        let collection = conn.collection_id(&version, Some(run)).await;
+        // The initial `cargo new && cargo build` is recorded as the `full`
+        // scenario, the no-op rebuild that follows it as `incr-unchanged`.
+        conn.record_statistic(
+            collection,
+            aid,
+            benchmark_name,
+            Profile::Debug,
+            Scenario::Empty,
+            "wall-time",
+            cold.as_secs_f64(),
+        )
+        .await;
+        conn.record_statistic(
+            collection,
+            aid,
+            benchmark_name,
+            Profile::Debug,
+            Scenario::IncrementalFresh,
+            "wall-time",
+            warm.as_secs_f64(),
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Measures one cold build (`cargo new` plus the first `cargo build`) and one
+/// warm build (a second, no-op `cargo build`) in a fresh temporary directory.
+fn measure_once(toolchain: &Toolchain) -> anyhow::Result<(Duration, Duration)> {
+    let dir = tempfile::tempdir().context("creating temporary directory")?;
+    let crate_dir = dir.path().join("hello-world");
+
+    let cargo_build = |dir: &std::path::Path| -> anyhow::Result<()> {
+        let status = Command::new(&toolchain.components.cargo)
+            .current_dir(dir)
+            .arg("build")
+            .env("RUSTC", &toolchain.components.rustc)
+            .status()
+            .context("spawning cargo build")?;
+        anyhow::ensure!(status.success(), "cargo build failed");
+        Ok(())
+    };
+
+    let start = Instant::now();
+    let status = Command::new(&toolchain.components.cargo)
+        .current_dir(dir.path())
+        .arg("new")
+        .arg("hello-world")
+        .env("RUSTC", &toolchain.components.rustc)
+        .status()
+        .context("spawning cargo new")?;
+    anyhow::ensure!(status.success(), "cargo new failed");
+    cargo_build(&crate_dir)?;
+    let cold = start.elapsed();
+
+    let start = Instant::now();
+    cargo_build(&crate_dir)?;
+    let warm = start.elapsed();
+
+    Ok((cold, warm))
+}
@@ -0,0 +1,176 @@
+use crate::compile::execute::bencher::Bencher;
+use std::env;
+use std::process::Command;
+
+/// A measurement backend used when benchmarking.
+///
+/// A measurer decides which wrapper `rustc-fake` runs the compiler under and
+/// knows how to check that the tools the wrapper needs are available, so that
+/// new OS backends can be added without touching the core runner.
+pub trait Measurer {
+    /// Short name of the backend, used in the `RUSTC_PERF_MEASURER`
+    /// environment variable.
+    fn name(&self) -> &'static str;
+
+    /// Checks that the tools the backend needs are available.
+    fn check_availability(&self) -> anyhow::Result<()>;
+
+    /// The wrapper that `rustc-fake` should run the compiler under. These
+    /// names come from `PerfTool::name()`.
+    fn bencher(&self, self_profile: bool) -> Bencher;
+}
+
+/// Measures using `perf stat` (Linux).
+struct PerfStatMeasurer;
+
+impl Measurer for PerfStatMeasurer {
+    fn name(&self) -> &'static str {
+        "perf-stat"
+    }
+
+    fn check_availability(&self) -> anyhow::Result<()> {
+        if Command::new("perf").output().is_err() {
+            anyhow::bail!("`perf` is not available");
+        }
+        Ok(())
+    }
+
+    fn bencher(&self, self_profile: bool) -> Bencher {
+        if self_profile {
+            Bencher::PerfStatSelfProfile
+        } else {
+            Bencher::PerfStat
+        }
+    }
+}
+
+/// Measures using xperf/tracelog ETW traces (Windows).
+struct XperfStatMeasurer;
+
+impl Measurer for XperfStatMeasurer {
+    fn name(&self) -> &'static str {
+        "xperf"
+    }
+
+    fn check_availability(&self) -> anyhow::Result<()> {
+        let xperf = env::var("XPERF").unwrap_or("xperf.exe".to_string());
+        if Command::new(&xperf).output().is_err() {
+            anyhow::bail!("`{xperf}` is not available");
+        }
+        let tracelog = env::var("TRACELOG").unwrap_or("tracelog.exe".to_string());
+        if Command::new(&tracelog).output().is_err() {
+            anyhow::bail!("`{tracelog}` is not available");
+        }
+        Ok(())
+    }
+
+    fn bencher(&self, self_profile: bool) -> Bencher {
+        if self_profile {
+            Bencher::XperfStatSelfProfile
+        } else {
+            Bencher::XperfStat
+        }
+    }
+}
+
+/// Measures cycles and instructions via the fixed PMU counters of Apple
+/// Silicon, using the private kperf framework (macOS). Accessing the counters
+/// requires running as root; without that, only wall-time and max-rss are
+/// recorded.
+struct KperfMeasurer;
+
+impl Measurer for KperfMeasurer {
+    fn name(&self) -> &'static str {
+        "kperf"
+    }
+
+    fn check_availability(&self) -> anyhow::Result<()> {
+        if !cfg!(target_os = "macos") {
+            anyhow::bail!("kperf is only available on macOS");
+        }
+        Ok(())
+    }
+
+    fn bencher(&self, _self_profile: bool) -> Bencher {
+        Bencher::KperfStat
+    }
+}
+
+/// Measures instruction counts using Cachegrind. Slower than `perf stat`, but
+/// deterministic and usable where perf counters are not (e.g. in many VMs).
+struct CachegrindMeasurer;
+
+impl Measurer for CachegrindMeasurer {
+    fn name(&self) -> &'static str {
+        "cachegrind"
+    }
+
+    fn check_availability(&self) -> anyhow::Result<()> {
+        if Command::new("valgrind").output().is_err() {
+            anyhow::bail!("`valgrind` is not available");
+        }
+        Ok(())
+    }
+
+    fn bencher(&self, _self_profile: bool) -> Bencher {
+        // Self-profiling under Cachegrind would distort the results, so it is
+        // not supported.
+        Bencher::CachegrindStat
+    }
+}
+
+/// Measures wall-time and max-rss only, in the style of `/usr/bin/time`.
+/// A last-resort fallback that needs no external tools at all.
+struct TimeMeasurer;
+
+impl Measurer for TimeMeasurer {
+    fn name(&self) -> &'static str {
+        "time"
+    }
+
+    fn check_availability(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn bencher(&self, _self_profile: bool) -> Bencher {
+        Bencher::TimeStat
+    }
+}
+
+static MEASURERS: &[&(dyn Measurer + Sync)] = &[
+    &PerfStatMeasurer,
+    &XperfStatMeasurer,
+    &KperfMeasurer,
+    &CachegrindMeasurer,
+    &TimeMeasurer,
+];
+
+/// Returns the measurer to benchmark with: the one named by the
+/// `RUSTC_PERF_MEASURER` environment variable if it is set, otherwise the
+/// default backend for the current platform.
+pub fn select_measurer() -> anyhow::Result<&'static (dyn Measurer + Sync)> {
+    if let Ok(name) = env::var("RUSTC_PERF_MEASURER") {
+        return MEASURERS
+            .iter()
+            .find(|m| m.name() == name)
+            .copied()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "unknown measurer `{}`; available measurers: {}",
+                    name,
+                    MEASURERS
+                        .iter()
+                        .map(|m| m.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+    }
+    Ok(if cfg!(target_os = "macos") {
+        &KperfMeasurer
+    } else if cfg!(unix) {
+        &PerfStatMeasurer
+    } else {
+        &XperfStatMeasurer
+    })
+}
@@ -11,9 +11,9 @@ use bencher::Bencher;
 use database::QueryLabel;
 use std::collections::HashMap;
 use std::env;
+use sha2::Digest;
 use std::fs;
 use std::future::Future;
-use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::process::{self, Command};
@@ -22,8 +22,11 @@ use std::time::Duration;
 
 pub mod bencher;
 mod etw_parser;
+mod hello_world;
+pub mod measurer;
 pub mod profiler;
 mod rustc;
+pub mod tool;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PerfTool {
@@ -50,6 +53,9 @@ impl PerfTool {
             | BenchTool(PerfStatSelfProfile)
             | BenchTool(XperfStat)
             | BenchTool(XperfStatSelfProfile)
+            | BenchTool(KperfStat)
+            | BenchTool(CachegrindStat)
+            | BenchTool(TimeStat)
             | ProfileTool(SelfProfile)
             | ProfileTool(PerfRecord)
             | ProfileTool(Oprofile)
@@ -94,6 +100,9 @@ impl PerfTool {
             | BenchTool(PerfStatSelfProfile)
             | BenchTool(XperfStat)
             | BenchTool(XperfStatSelfProfile)
+            | BenchTool(KperfStat)
+            | BenchTool(CachegrindStat)
+            | BenchTool(TimeStat)
             | ProfileTool(SelfProfile)
             | ProfileTool(PerfRecord)
             | ProfileTool(Oprofile)
@@ -126,6 +135,10 @@ pub struct CargoProcess<'a> {
     pub rustc_args: Vec<String>,
     pub touch_file: Option<String>,
     pub jobserver: Option<jobserver::Client>,
+    pub sccache: Option<PathBuf>,
+    /// Extra environment variables declared in the benchmark's
+    /// perf-config.json.
+    pub extra_env: HashMap<String, String>,
 }
 
 impl<'a> CargoProcess<'a> {
@@ -169,6 +182,14 @@ impl<'a> CargoProcess<'a> {
         if let Some(r) = &self.toolchain.components.rustdoc {
             cmd.env("RUSTDOC", &*FAKE_RUSTDOC).env("RUSTDOC_REAL", r);
         }
+        if let Some(sccache) = &self.sccache {
+            // Caches dependency crate compilations only; rustc-fake never
+            // applies it to the measured (wrapped) leaf invocation.
+            cmd.env("RUSTC_PERF_SCCACHE", sccache);
+        }
+        for (key, value) in &self.extra_env {
+            cmd.env(key, value);
+        }
         cmd
     }
 
@@ -307,9 +328,27 @@ impl<'a> CargoProcess<'a> {
                 client.configure(&mut cmd);
             }
 
+            // Lets rustc-fake compute how long cargo spent before invoking
+            // the final rustc (dependency resolution, fingerprinting), so
+            // that cargo-side harness changes are distinguishable from
+            // compiler changes. Only recorded for full scenarios, where
+            // cargo's pre-rustc work is not mixed up with incremental state
+            // handling.
+            if self.processor_etc.as_ref().map(|v| v.1) == Some(Scenario::Full) {
+                cmd.env(
+                    "RUSTC_PERF_CARGO_STARTED_AT",
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .expect("time went backwards")
+                        .as_nanos()
+                        .to_string(),
+                );
+            }
+
             log::debug!("{:?}", cmd);
 
             let cmd = tokio::process::Command::from(cmd);
+            let start = std::time::Instant::now();
             let output = async_command_output(cmd).await?;
             if let Some((ref mut processor, scenario, scenario_str, patch)) = self.processor_etc {
                 let data = ProcessOutputData {
@@ -319,6 +358,7 @@ impl<'a> CargoProcess<'a> {
                     scenario,
                     scenario_str,
                     patch,
+                    duration: start.elapsed(),
                 };
                 match processor.process_output(&data, output).await {
                     Ok(Retry::No) => return Ok(()),
@@ -369,6 +409,9 @@ pub struct ProcessOutputData<'a> {
     scenario: Scenario,
     scenario_str: &'a str,
     patch: Option<&'a Patch>,
+    /// End-to-end wall-clock duration of the run, from spawning the cargo
+    /// process to collecting its output, i.e. including harness overhead.
+    duration: std::time::Duration,
 }
 
 /// Trait used by `Benchmark::measure()` to provide different kinds of
@@ -399,13 +442,27 @@ pub trait Processor {
     }
 }
 
-struct Upload(std::process::Child, tempfile::NamedTempFile);
+/// Compression level for uploaded self-profile archives. The default level
+/// compresses noticeably better than snappy at a similar speed; higher levels
+/// would eat into benchmarking time for little additional gain.
+const SELF_PROFILE_ZSTD_LEVEL: i32 = 3;
+
+struct Upload(Vec<(std::process::Child, tempfile::NamedTempFile)>);
 
 impl Upload {
-    fn new(prefix: PathBuf, collection: database::CollectionId, files: SelfProfileFiles) -> Upload {
+    fn new(
+        prefix: PathBuf,
+        collection: database::CollectionId,
+        benchmark: &str,
+        files: SelfProfileFiles,
+    ) -> Upload {
         // Files are placed at
         //  * self-profile/<artifact id>/<benchmark>/<profile>/<scenario>
         //    /self-profile-<collection-id>.{extension}
+        // except for the deduplicated string tables, which are
+        // content-addressed under
+        //  * self-profile/strings/<benchmark>/<hash>.tar.zst
+        let mut uploads = Vec::new();
         let upload = tempfile::NamedTempFile::new()
             .context("create temporary file")
             .unwrap();
@@ -415,10 +472,13 @@ impl Upload {
                 string_data,
                 events,
             } => {
-                let tarball = snap::write::FrameEncoder::new(Vec::new());
-                let mut builder = tar::Builder::new(tarball);
-                builder.mode(tar::HeaderMode::Deterministic);
-
+                let new_builder = || {
+                    let encoder = zstd::Encoder::new(Vec::new(), SELF_PROFILE_ZSTD_LEVEL)
+                        .expect("zstd encoder");
+                    let mut builder = tar::Builder::new(encoder);
+                    builder.mode(tar::HeaderMode::Deterministic);
+                    builder
+                };
                 let append_file = |builder: &mut tar::Builder<_>,
                                    file: &Path,
                                    name: &str|
@@ -430,63 +490,95 @@ impl Upload {
                     }
                     Ok(())
                 };
+                let finish = |builder: tar::Builder<zstd::Encoder<'_, Vec<u8>>>| -> Vec<u8> {
+                    builder
+                        .into_inner()
+                        .expect("complete tarball")
+                        .finish()
+                        .expect("zstd success")
+                };
 
-                append_file(&mut builder, &string_index, "self-profile.string_index")
+                // The string tables are largely identical from artifact to
+                // artifact for the same benchmark, so they are stored once per
+                // distinct content under a key derived from their hash. The
+                // events archive references that key; identical tables across
+                // artifacts thus occupy storage only once.
+                let mut strings = new_builder();
+                append_file(&mut strings, &string_index, "self-profile.string_index")
                     .expect("append string index");
-                append_file(&mut builder, &string_data, "self-profile.string_data")
+                append_file(&mut strings, &string_data, "self-profile.string_data")
                     .expect("append string data");
+                let strings = finish(strings);
+                let strings_key = format!(
+                    "self-profile/strings/{}/{:x}.tar.zst",
+                    benchmark,
+                    sha2::Sha256::digest(&strings)
+                );
+                let strings_file = tempfile::NamedTempFile::new()
+                    .context("create temporary file")
+                    .unwrap();
+                std::fs::write(strings_file.path(), &strings).expect("wrote strings tarball");
+                uploads.push((spawn_s3_upload(strings_file.path(), &strings_key), strings_file));
+
+                let mut builder = new_builder();
                 append_file(&mut builder, &events, "self-profile.events").expect("append events");
-                builder.finish().expect("complete tarball");
-                std::fs::write(
-                    upload.path(),
-                    builder
-                        .into_inner()
-                        .expect("get")
-                        .into_inner()
-                        .expect("snap success"),
-                )
-                .expect("wrote tarball");
-                format!("self-profile-{}.tar.sz", collection)
+                let mut header = tar::Header::new_gnu();
+                header.set_size(strings_key.len() as u64);
+                header.set_mode(0o644);
+                header.set_cksum();
+                builder
+                    .append_data(
+                        &mut header,
+                        "self-profile.strings_ref",
+                        strings_key.as_bytes(),
+                    )
+                    .expect("append strings ref");
+                std::fs::write(upload.path(), finish(builder)).expect("wrote tarball");
+                format!("self-profile-{}.tar.zst", collection)
             }
             SelfProfileFiles::Eight { file } => {
                 let data = std::fs::read(file).expect("read profile data");
-                let mut data = snap::read::FrameEncoder::new(&data[..]);
-                let mut compressed = Vec::new();
-                data.read_to_end(&mut compressed).expect("compressed");
-                std::fs::write(upload.path(), &compressed).expect("write compressed profile data");
+                let compressed =
+                    zstd::encode_all(&data[..], SELF_PROFILE_ZSTD_LEVEL).expect("compressed");
+                std::fs::write(upload.path(), compressed).expect("write compressed profile data");
 
-                format!("self-profile-{}.mm_profdata.sz", collection)
+                format!("self-profile-{}.mm_profdata.zst", collection)
             }
         };
 
-        let child = Command::new("aws")
-            .arg("s3")
-            .arg("cp")
-            .arg("--storage-class")
-            .arg("INTELLIGENT_TIERING")
-            .arg("--only-show-errors")
-            .arg(upload.path())
-            .arg(&format!(
-                "s3://rustc-perf/{}",
-                &prefix.join(filename).to_str().unwrap()
-            ))
-            .spawn()
-            .expect("spawn aws");
-
-        Upload(child, upload)
+        uploads.push((
+            spawn_s3_upload(upload.path(), prefix.join(filename).to_str().unwrap()),
+            upload,
+        ));
+        Upload(uploads)
     }
 
-    fn wait(mut self) {
+    fn wait(self) {
         let start = std::time::Instant::now();
-        let status = self.0.wait().expect("waiting for child");
-        if !status.success() {
-            panic!("S3 upload failed: {:?}", status);
+        for (mut child, _file) in self.0 {
+            let status = child.wait().expect("waiting for child");
+            if !status.success() {
+                panic!("S3 upload failed: {:?}", status);
+            }
         }
 
         log::trace!("uploaded to S3, additional wait: {:?}", start.elapsed());
     }
 }
 
+fn spawn_s3_upload(file: &Path, key: &str) -> std::process::Child {
+    Command::new("aws")
+        .arg("s3")
+        .arg("cp")
+        .arg("--storage-class")
+        .arg("INTELLIGENT_TIERING")
+        .arg("--only-show-errors")
+        .arg(file)
+        .arg(&format!("s3://rustc-perf/{}", key))
+        .spawn()
+        .expect("spawn aws")
+}
+
 fn store_documentation_size_into_stats(stats: &mut Stats, doc_dir: &Path) {
     match utils::fs::get_file_count_and_size(doc_dir) {
         Ok((count, size)) => {
@@ -501,6 +593,53 @@ fn store_documentation_size_into_stats(stats: &mut Stats, doc_dir: &Path) {
     }
 }
 
+/// Measures the on-disk size of the incremental compilation cache and of the
+/// serialized dep-graph within it. Unlike the self-profile artifact sizes,
+/// these are measured directly from the filesystem, so they are recorded for
+/// every incremental run rather than only when self-profiling is enabled.
+fn store_incremental_state_size_into_stats(stats: &mut Stats, incr_dir: &Path) {
+    match utils::fs::get_file_count_and_size(incr_dir) {
+        Ok((count, size)) => {
+            stats.insert("size:incr_cache_files_count".to_string(), count as f64);
+            stats.insert("size:incr_cache_bytes".to_string(), size as f64);
+        }
+        Err(error) => log::error!(
+            "Cannot get size of incremental state directory {}: {:?}",
+            incr_dir.display(),
+            error
+        ),
+    }
+    match incremental_dep_graph_size(incr_dir) {
+        Ok(size) => {
+            stats.insert("size:incr_dep_graph_bytes".to_string(), size as f64);
+        }
+        Err(error) => log::error!(
+            "Cannot get dep-graph size in {}: {:?}",
+            incr_dir.display(),
+            error
+        ),
+    }
+}
+
+/// Sums the sizes of the serialized dep-graph files (`dep-graph.bin` and
+/// partial `dep-graph.part.bin`) in the incremental state directory.
+fn incremental_dep_graph_size(dir: &Path) -> std::io::Result<u64> {
+    let mut size = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            size += incremental_dep_graph_size(&path)?;
+        } else if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("dep-graph"))
+        {
+            size += path.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
 fn store_artifact_sizes_into_stats(stats: &mut Stats, profile: &SelfProfile) {
     for artifact in profile.artifact_sizes.iter() {
         stats
@@ -515,8 +654,6 @@ enum DeserializeStatError {
     NoOutput(process::Output),
     #[error("could not parse `{}` as a float", .0)]
     ParseError(String, #[source] ::std::num::ParseFloatError),
-    #[error("could not process xperf data")]
-    XperfError(#[from] anyhow::Error),
 }
 
 enum SelfProfileFiles {
@@ -558,14 +695,32 @@ fn process_stat_output(
             continue;
         }
         if let Some(counter_file) = line.strip_prefix("!counters-file:") {
-            let counters = etw_parser::parse_etw_file(counter_file).unwrap();
-
-            stats.insert("cycles".into(), counters.total_cycles as f64);
+            // A machine without hardware counter support (e.g. many VMs)
+            // shouldn't break the whole collection: record the metrics we do
+            // have and let comparisons pair up whichever metrics exist on
+            // both artifacts.
+            match etw_parser::parse_etw_file(counter_file) {
+                Ok(counters) => {
+                    stats.insert("cycles".into(), counters.total_cycles as f64);
+                    stats.insert(
+                        "instructions:u".into(),
+                        counters.instructions_retired as f64,
+                    );
+                    stats.insert("cpu-clock".into(), counters.cpu_clock);
+                }
+                Err(e) => {
+                    log::warn!("failed to parse ETW counters file: {:?}", e);
+                }
+            }
+            continue;
+        }
+        if let Some(stripped) = line.strip_prefix("!max-rss:") {
             stats.insert(
-                "instructions:u".into(),
-                counters.instructions_retired as f64,
+                "max-rss".into(),
+                stripped
+                    .parse()
+                    .map_err(|e| DeserializeStatError::ParseError(stripped.to_string(), e))?,
             );
-            stats.insert("cpu-clock".into(), counters.cpu_clock);
             continue;
         }
         if let Some(stripped) = line.strip_prefix("!wall-time:") {
@@ -577,6 +732,42 @@ fn process_stat_output(
             );
             continue;
         }
+        if let Some(stripped) = line.strip_prefix("!cargo-overhead:") {
+            stats.insert(
+                "cargo-overhead".into(),
+                stripped
+                    .parse()
+                    .map_err(|e| DeserializeStatError::ParseError(stripped.to_string(), e))?,
+            );
+            continue;
+        }
+        if let Some(stripped) = line.strip_prefix("!time-to-first-diagnostic:") {
+            stats.insert(
+                "time-to-first-diagnostic".into(),
+                stripped
+                    .parse()
+                    .map_err(|e| DeserializeStatError::ParseError(stripped.to_string(), e))?,
+            );
+            continue;
+        }
+        if let Some(stripped) = line.strip_prefix("!mono-items:") {
+            stats.insert(
+                "mono-items".into(),
+                stripped
+                    .parse()
+                    .map_err(|e| DeserializeStatError::ParseError(stripped.to_string(), e))?,
+            );
+            continue;
+        }
+        if let Some(stripped) = line.strip_prefix("!mono-item-instantiations:") {
+            stats.insert(
+                "mono-item-instantiations".into(),
+                stripped
+                    .parse()
+                    .map_err(|e| DeserializeStatError::ParseError(stripped.to_string(), e))?,
+            );
+            continue;
+        }
 
         // The rest of the loop body handles processing output from the Linux `perf` tool
         // so on Windows, we just skip it and go to the next line.
@@ -10,7 +10,7 @@
 use crate::toolchain::Toolchain;
 use crate::utils::git::get_rustc_perf_commit;
 use anyhow::Context;
-use database::ArtifactId;
+use database::{ArtifactId, RunId};
 use std::env;
 use std::{collections::HashMap, process::Command};
 use std::{path::Path, time::Duration};
@@ -21,12 +21,13 @@ pub async fn measure(
     toolchain: &Toolchain,
     artifact: &database::ArtifactId,
     aid: database::ArtifactIdNumber,
+    run: RunId,
 ) -> anyhow::Result<()> {
     eprintln!("Running rustc");
 
     checkout(artifact).context("checking out rust-lang/rust")?;
 
-    record(conn, toolchain, artifact, aid).await?;
+    record(conn, toolchain, artifact, aid, run).await?;
 
     Ok(())
 }
@@ -36,6 +37,7 @@ async fn record(
     toolchain: &Toolchain,
     artifact: &database::ArtifactId,
     aid: database::ArtifactIdNumber,
+    run: RunId,
 ) -> anyhow::Result<()> {
     let checkout = Path::new("rust");
     let mut status = Command::new("git")
@@ -142,7 +144,7 @@ async fn record(
     }
 
     let version = get_rustc_perf_commit();
-    let collection = conn.collection_id(&version).await;
+    let collection = conn.collection_id(&version, Some(run)).await;
 
     for (krate, timing) in timing_data {
         conn.record_rustc_crate(collection, aid, krate, timing)
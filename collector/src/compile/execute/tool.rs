@@ -0,0 +1,190 @@
+//! Performance collection for auxiliary toolchain tools.
+//!
+//! Besides rustc and rustdoc, a toolchain ships tools such as rustfmt and
+//! clippy whose performance matters to users but is not covered by the
+//! compile suite. A tool benchmark runs one of those binaries over the
+//! sources of an existing compile benchmark and records the end-to-end
+//! wall-time per artifact, in the style of the hello-world benchmark. The
+//! set of tool benchmarks is declared in a JSON configuration file passed
+//! to the collector via `--tool-config`; results are recorded under the
+//! `tool:` benchmark namespace.
+
+use crate::compile::benchmark::compile_benchmark_dir;
+use crate::toolchain::Toolchain;
+use crate::utils::git::get_rustc_perf_commit;
+use anyhow::Context;
+use database::{ArtifactIdNumber, Profile, RunId, Scenario};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// How many times each tool benchmark is measured.
+const ITERATIONS: usize = 3;
+
+/// The contents of a `--tool-config` file.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolConfig {
+    pub tools: Vec<ToolBenchmark>,
+}
+
+impl ToolConfig {
+    pub fn from_file(path: &Path) -> anyhow::Result<ToolConfig> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read tool config {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("failed to parse tool config {}", path.display()))
+    }
+}
+
+/// A single tool benchmark declaration.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolBenchmark {
+    /// Name under which the results are recorded, without the `tool:`
+    /// namespace prefix (an entry named `rustfmt-serde` is stored as the
+    /// `tool:rustfmt-serde` benchmark).
+    pub name: String,
+    pub tool: Tool,
+    /// Directory name of the compile benchmark whose sources the tool runs
+    /// over.
+    pub benchmark: String,
+}
+
+/// The toolchain binaries that can be benchmarked.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Tool {
+    /// `cargo fmt`, reformatting the benchmark's sources.
+    Rustfmt,
+    /// `cargo clippy --fix`, checking the benchmark and applying
+    /// machine-applicable lint suggestions.
+    ClippyFix,
+}
+
+impl Tool {
+    /// The cargo subcommand binary that drives the tool.
+    fn binary(self) -> &'static str {
+        match self {
+            Tool::Rustfmt => "cargo-fmt",
+            Tool::ClippyFix => "cargo-clippy",
+        }
+    }
+
+    /// Builds the command running the tool from the benchmarked toolchain.
+    /// The tool binaries live next to rustc in the sysroot, but are separate
+    /// dist components, so they may be missing from a toolchain that was
+    /// installed without them.
+    fn command(self, toolchain: &Toolchain) -> anyhow::Result<Command> {
+        let bin_dir = toolchain
+            .components
+            .rustc
+            .parent()
+            .context("rustc path has no parent directory")?;
+        let binary = bin_dir.join(self.binary());
+        anyhow::ensure!(
+            binary.is_file(),
+            "{} not found in {}; was the toolchain installed with the rustfmt/clippy components?",
+            self.binary(),
+            bin_dir.display()
+        );
+        let mut cmd = Command::new(binary);
+        cmd.env("CARGO", &toolchain.components.cargo)
+            .env("RUSTC", &toolchain.components.rustc);
+        match self {
+            Tool::Rustfmt => {
+                cmd.env("RUSTFMT", bin_dir.join("rustfmt"));
+            }
+            Tool::ClippyFix => {
+                cmd.arg("--fix").arg("--allow-dirty").arg("--allow-no-vcs");
+            }
+        }
+        Ok(cmd)
+    }
+}
+
+/// Measure a single tool benchmark.
+pub async fn measure(
+    conn: &mut dyn database::Connection,
+    benchmark_name: &str,
+    tool: &ToolBenchmark,
+    toolchain: &Toolchain,
+    aid: ArtifactIdNumber,
+    run: RunId,
+) -> anyhow::Result<()> {
+    eprintln!("Running {benchmark_name}");
+
+    let source_dir = compile_benchmark_dir().join(&tool.benchmark);
+    anyhow::ensure!(
+        source_dir.is_dir(),
+        "compile benchmark `{}` referenced by tool benchmark `{}` does not exist",
+        tool.benchmark,
+        tool.name
+    );
+
+    let version = get_rustc_perf_commit();
+    for _ in 0..ITERATIONS {
+        let duration = measure_once(tool, toolchain, &source_dir)?;
+        let collection = conn.collection_id(&version, Some(run)).await;
+        conn.record_statistic(
+            collection,
+            aid,
+            benchmark_name,
+            Profile::Check,
+            Scenario::Empty,
+            "wall-time",
+            duration.as_secs_f64(),
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Runs the tool once over a fresh copy of the benchmark's sources. The tools
+/// rewrite the sources they run over, so every iteration gets its own copy;
+/// the copy is excluded from the measured time.
+fn measure_once(
+    tool: &ToolBenchmark,
+    toolchain: &Toolchain,
+    source_dir: &Path,
+) -> anyhow::Result<Duration> {
+    let dir = copy_to_temp_dir(source_dir)?;
+    let mut cmd = tool.tool.command(toolchain)?;
+    cmd.current_dir(dir.path());
+
+    let start = Instant::now();
+    let status = cmd
+        .status()
+        .with_context(|| format!("spawning {}", tool.tool.binary()))?;
+    anyhow::ensure!(
+        status.success(),
+        "{} failed on {}",
+        tool.tool.binary(),
+        tool.benchmark
+    );
+    Ok(start.elapsed())
+}
+
+#[cfg(windows)]
+fn copy_to_temp_dir(from: &Path) -> anyhow::Result<TempDir> {
+    let tmp_dir = TempDir::new()?;
+    crate::utils::fs::robocopy(from, tmp_dir.path(), &[])
+        .with_context(|| format!("copying {} to tmp dir", from.display()))?;
+    Ok(tmp_dir)
+}
+
+#[cfg(unix)]
+fn copy_to_temp_dir(from: &Path) -> anyhow::Result<TempDir> {
+    // Appending `.` means we copy just the contents of `from` into the
+    // temporary directory, rather than `from` itself.
+    let mut from_dot = from.to_path_buf();
+    from_dot.push(".");
+    let tmp_dir = TempDir::new()?;
+    let mut cmd = Command::new("cp");
+    cmd.arg("-pLR").arg(&from_dot).arg(tmp_dir.path());
+    crate::command_output(&mut cmd)
+        .with_context(|| format!("copying {} to tmp dir", from.display()))?;
+    Ok(tmp_dir)
+}
@@ -8,13 +8,16 @@ use std::process::{self, Command};
 pub mod api;
 pub mod codegen;
 pub mod compile;
+pub mod preset;
+pub mod query_attribution;
 pub mod runtime;
+pub mod sensitivity;
 pub mod toolchain;
 pub mod utils;
 
 use crate::compile::benchmark::{Benchmark, BenchmarkName};
 use crate::runtime::{BenchmarkGroup, BenchmarkSuite};
-use database::{ArtifactId, ArtifactIdNumber, Connection};
+use database::{ArtifactId, ArtifactIdNumber, Connection, RunId};
 use process::Stdio;
 use std::time::Duration;
 
@@ -166,11 +169,13 @@ pub fn run_command(cmd: &mut Command) -> anyhow::Result<()> {
 fn run_command_with_output(cmd: &mut Command) -> anyhow::Result<process::Output> {
     use anyhow::Context;
     use utils::read2;
+    utils::shutdown::use_own_process_group(cmd);
     let mut child = cmd
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
         .with_context(|| format!("failed to spawn process for cmd: {:?}", cmd))?;
+    let _guard = utils::shutdown::active_process_guard(&child);
 
     let mut stdout = Vec::new();
     let mut stderr = Vec::new();
@@ -290,12 +295,16 @@ impl CollectorStepBuilder {
         mut self,
         benchmarks: &[Benchmark],
         bench_rustc: bool,
+        bench_hello_world: bool,
     ) -> Self {
         self.steps
             .extend(benchmarks.iter().map(|b| b.name.to_string()));
         if bench_rustc {
             self.steps.push("rustc".to_string());
         }
+        if bench_hello_world {
+            self.steps.push("helloworld-e2e".to_string());
+        }
         self
     }
 
@@ -316,22 +325,79 @@ impl CollectorStepBuilder {
     ) -> CollectorCtx {
         // Make sure there is no observable time when the artifact ID is available
         // but the in-progress steps are not.
-        let artifact_row_id = {
+        let (artifact_row_id, run_id) = {
             let mut tx = conn.transaction().await;
             let artifact_row_id = tx.conn().artifact_id(artifact_id).await;
             tx.conn()
                 .collector_start(artifact_row_id, &self.steps)
                 .await;
+            let run_id = tx
+                .conn()
+                .start_run(artifact_row_id, &collector_name())
+                .await;
             tx.commit().await.unwrap();
-            artifact_row_id
+            (artifact_row_id, run_id)
         };
-        CollectorCtx { artifact_row_id }
+        CollectorCtx {
+            artifact_row_id,
+            run_id,
+        }
+    }
+}
+
+/// The name this collector records its benchmarking runs under, so that
+/// results of the same artifact gathered by different machines can be told
+/// apart. Defaults to `collector` when not configured.
+pub fn collector_name() -> String {
+    std::env::var("RUSTC_PERF_COLLECTOR_NAME").unwrap_or_else(|_| "collector".to_string())
+}
+
+/// A version identifier of the benchmark suite itself: a hash of the
+/// benchmark sources in the given directories, combined with the version of
+/// the harness (the rustc-perf commit the collector was built from).
+/// Recorded with each artifact's results under the `suite-version` artifact
+/// info key, so that the site can warn when artifacts measured with
+/// different suites are compared.
+pub fn suite_version(benchmark_dirs: &[&std::path::Path]) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    utils::git::get_rustc_perf_commit().hash(&mut hasher);
+    for dir in benchmark_dirs {
+        hash_directory(dir, &mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+/// Hashes the names and contents of all files under `dir`, visiting entries
+/// in a deterministic order and skipping build artifacts.
+fn hash_directory(dir: &std::path::Path, hasher: &mut impl std::hash::Hasher) {
+    use std::hash::Hash;
+
+    let mut entries: Vec<_> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries.map(|entry| entry.unwrap().path()).collect(),
+        Err(_) => return,
+    };
+    entries.sort();
+    for path in entries {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if path.is_dir() {
+            if name == "target" {
+                continue;
+            }
+            name.hash(hasher);
+            hash_directory(&path, hasher);
+        } else {
+            name.hash(hasher);
+            std::fs::read(&path).unwrap_or_default().hash(hasher);
+        }
     }
 }
 
 /// Represents an in-progress run for a given artifact.
 pub struct CollectorCtx {
     pub artifact_row_id: ArtifactIdNumber,
+    pub run_id: RunId,
 }
 
 impl CollectorCtx {
@@ -0,0 +1,53 @@
+//! Curated subsets ("presets") of the compile-time benchmark suite.
+//!
+//! A preset is a config-driven list of benchmarks stored in the
+//! `benchmark-presets` directory of the collector crate. The `quick` preset
+//! is a roughly ten-benchmark subset whose historical results predict the
+//! full-suite summary well, intended for fast local sanity checks.
+
+/// A curated subset of the compile-time benchmark suite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Preset {
+    /// A small subset for fast local sanity checks.
+    Quick,
+}
+
+impl Preset {
+    /// The names of the benchmarks in this preset.
+    pub fn benchmark_names(&self) -> Vec<&'static str> {
+        let definition = match self {
+            Preset::Quick => include_str!("../benchmark-presets/quick.txt"),
+        };
+        definition
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Preset;
+    use std::path::Path;
+
+    /// The preset definitions live in a plain text file, so make sure they
+    /// stay in sync with the benchmark suite.
+    #[test]
+    fn quick_preset_is_valid() {
+        let benchmark_dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("compile-benchmarks");
+        let names = Preset::Quick.benchmark_names();
+        assert!(
+            (5..=15).contains(&names.len()),
+            "the quick preset should stay small, found {} benchmarks",
+            names.len()
+        );
+        for name in names {
+            assert!(
+                benchmark_dir.join(name).join("perf-config.json").is_file(),
+                "the quick preset references `{name}`, which is not a benchmark"
+            );
+        }
+    }
+}
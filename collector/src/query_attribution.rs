@@ -0,0 +1,101 @@
+//! Attribution of rustc self-profile queries to compiler components.
+//!
+//! When a self-profile diff shows a query regressing, knowing which part of
+//! the compiler the query provider lives in (and which team owns it) speeds
+//! up routing the report to the right people during triage. The mapping
+//! below is maintained by hand; it matches query labels by prefix and does
+//! not need to be exhaustive — unmapped queries are simply shown without
+//! attribution.
+
+/// The compiler component that owns a query provider, and the team to ping
+/// about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryComponent {
+    /// The crate in `rust-lang/rust` where the provider lives, e.g.
+    /// `rustc_borrowck`.
+    pub component: &'static str,
+    /// The GitHub team that owns the component, e.g. `rust-lang/types`.
+    pub team: &'static str,
+}
+
+const fn component(component: &'static str, team: &'static str) -> QueryComponent {
+    QueryComponent { component, team }
+}
+
+/// Query label prefixes mapped to their owning component. The longest
+/// matching prefix wins, so generic entries like `mir_` can coexist with
+/// more specific ones like `mir_borrowck`.
+const QUERY_COMPONENTS: &[(&str, QueryComponent)] = &[
+    ("hir_", component("rustc_ast_lowering", "rust-lang/compiler")),
+    ("parse_", component("rustc_parse", "rust-lang/compiler")),
+    ("expand_", component("rustc_expand", "rust-lang/compiler")),
+    ("resolve_", component("rustc_resolve", "rust-lang/compiler")),
+    ("late_resolve_", component("rustc_resolve", "rust-lang/compiler")),
+    ("typeck", component("rustc_hir_typeck", "rust-lang/types")),
+    ("type_check_crate", component("rustc_hir_typeck", "rust-lang/types")),
+    ("type_op_", component("rustc_trait_selection", "rust-lang/types")),
+    ("evaluate_obligation", component("rustc_trait_selection", "rust-lang/types")),
+    ("normalize_", component("rustc_trait_selection", "rust-lang/types")),
+    ("dropck_outlives", component("rustc_trait_selection", "rust-lang/types")),
+    ("implied_outlives_bounds", component("rustc_trait_selection", "rust-lang/types")),
+    ("codegen_fulfill_obligation", component("rustc_trait_selection", "rust-lang/types")),
+    ("specialization_graph_of", component("rustc_trait_selection", "rust-lang/types")),
+    ("trait_impls_of", component("rustc_trait_selection", "rust-lang/types")),
+    ("mir_", component("rustc_mir_transform", "rust-lang/compiler")),
+    ("mir_borrowck", component("rustc_borrowck", "rust-lang/types")),
+    ("optimized_mir", component("rustc_mir_transform", "rust-lang/compiler")),
+    ("const_eval", component("rustc_const_eval", "rust-lang/compiler")),
+    ("eval_to_", component("rustc_const_eval", "rust-lang/compiler")),
+    ("lint_", component("rustc_lint", "rust-lang/compiler")),
+    ("privacy_", component("rustc_privacy", "rust-lang/compiler")),
+    ("metadata_", component("rustc_metadata", "rust-lang/compiler")),
+    ("generate_crate_metadata", component("rustc_metadata", "rust-lang/compiler")),
+    (
+        "monomorphization_collector",
+        component("rustc_monomorphize", "rust-lang/compiler"),
+    ),
+    (
+        "collect_and_partition_mono_items",
+        component("rustc_monomorphize", "rust-lang/compiler"),
+    ),
+    ("codegen_", component("rustc_codegen_ssa", "rust-lang/compiler")),
+    ("compile_codegen_unit", component("rustc_codegen_llvm", "rust-lang/compiler")),
+    ("LLVM_", component("rustc_codegen_llvm", "rust-lang/compiler")),
+    ("link_", component("rustc_codegen_ssa", "rust-lang/compiler")),
+    ("incr_comp_", component("rustc_incremental", "rust-lang/wg-incr-comp")),
+    ("copy_all_cgu_workproducts_to_incr_comp_cache_dir", component("rustc_incremental", "rust-lang/wg-incr-comp")),
+];
+
+/// Returns the component owning the query with the given self-profile label,
+/// if the mapping table knows about it.
+pub fn attribute_query(label: &str) -> Option<QueryComponent> {
+    QUERY_COMPONENTS
+        .iter()
+        .filter(|(prefix, _)| label.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, component)| *component)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attribute_query;
+
+    #[test]
+    fn longest_prefix_wins() {
+        // `mir_borrowck` matches both the generic `mir_` entry and its own;
+        // the more specific one must win.
+        assert_eq!(
+            attribute_query("mir_borrowck").unwrap().component,
+            "rustc_borrowck"
+        );
+        assert_eq!(
+            attribute_query("mir_promoted").unwrap().component,
+            "rustc_mir_transform"
+        );
+    }
+
+    #[test]
+    fn unmapped_queries_have_no_attribution() {
+        assert!(attribute_query("some_future_query").is_none());
+    }
+}
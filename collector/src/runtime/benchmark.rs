@@ -1,3 +1,4 @@
+use crate::runtime::progress::{BenchmarkOutput, PhaseProgress};
 use crate::runtime_group_step_name;
 use crate::toolchain::Toolchain;
 use anyhow::Context;
@@ -26,6 +27,9 @@ pub struct BenchmarkGroup {
     pub binary: PathBuf,
     pub name: String,
     pub benchmark_names: Vec<String>,
+    /// Cargo features the group was built with. A non-default feature set
+    /// becomes part of the identity under which results are recorded.
+    pub features: Vec<String>,
 }
 
 /// A collection of benchmark suites gathered from a directory.
@@ -133,15 +137,28 @@ pub struct BenchmarkSuiteCompilation {
 }
 
 impl BenchmarkSuiteCompilation {
-    pub fn extract_suite(self) -> BenchmarkSuite {
-        assert!(self.failed_to_compile.is_empty());
-        self.suite
+    /// Returns the compiled suite, failing if any group failed to compile.
+    pub fn extract_suite(self) -> anyhow::Result<BenchmarkSuite> {
+        if !self.failed_to_compile.is_empty() {
+            let mut groups: Vec<_> = self.failed_to_compile.into_iter().collect();
+            groups.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            let errors = groups
+                .into_iter()
+                .map(|(group, error)| format!("`{group}`: {error}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(anyhow::anyhow!(
+                "Some runtime benchmark group(s) failed to compile:\n{errors}"
+            ));
+        }
+        Ok(self.suite)
     }
 }
 
 #[derive(Default)]
 pub struct RuntimeCompilationOpts {
     debug_info: Option<String>,
+    features: Vec<String>,
 }
 
 impl RuntimeCompilationOpts {
@@ -149,6 +166,11 @@ impl RuntimeCompilationOpts {
         self.debug_info = Some(debug_info.to_string());
         self
     }
+
+    pub fn features(mut self, features: Vec<String>) -> Self {
+        self.features = features;
+        self
+    }
 }
 
 /// Find all runtime benchmark crates in `benchmark_dir` and compile them.
@@ -163,6 +185,7 @@ pub fn prepare_runtime_benchmark_suite(
     isolation_mode: CargoIsolationMode,
     group: Option<String>,
     opts: RuntimeCompilationOpts,
+    output: BenchmarkOutput,
 ) -> anyhow::Result<BenchmarkSuiteCompilation> {
     let benchmark_crates = get_runtime_benchmark_groups(benchmark_dir, group)?;
 
@@ -182,28 +205,33 @@ pub fn prepare_runtime_benchmark_suite(
     };
 
     let group_count = benchmark_crates.len();
-    println!("Compiling {group_count} runtime benchmark group(s)");
+    let progress = output.start_phase("Compiling", group_count as u64);
 
     let mut groups = Vec::new();
     let mut failed_to_compile = HashMap::new();
-    for (index, benchmark_crate) in benchmark_crates.into_iter().enumerate() {
-        println!(
-            "Compiling {:<22} ({}/{group_count})",
-            format!("`{}`", benchmark_crate.name),
-            index + 1
-        );
+    for benchmark_crate in benchmark_crates {
+        progress.start_item(&benchmark_crate.name);
 
         let target_dir = temp_dir.as_ref().map(|d| d.path());
 
-        let result = start_cargo_build(toolchain, &benchmark_crate.path, target_dir, &opts)
-            .with_context(|| {
-                anyhow::anyhow!("Cannot start compilation of {}", benchmark_crate.name)
-            })
-            .and_then(|process| {
-                parse_benchmark_group(process, &benchmark_crate.name).with_context(|| {
-                    anyhow::anyhow!("Cannot compile runtime benchmark {}", benchmark_crate.name)
-                })
-            });
+        // Only enable the requested features that the group actually
+        // declares; feature sets like SIMD variants are usually only
+        // meaningful for a few groups.
+        let features = select_features(&benchmark_crate, &opts.features);
+
+        let result = start_cargo_build(
+            toolchain,
+            &benchmark_crate.path,
+            target_dir,
+            &opts,
+            &features,
+        )
+        .with_context(|| anyhow::anyhow!("Cannot start compilation of {}", benchmark_crate.name))
+        .and_then(|process| {
+            parse_benchmark_group(process, &benchmark_crate.name, features, &progress).with_context(
+                || anyhow::anyhow!("Cannot compile runtime benchmark {}", benchmark_crate.name),
+            )
+        });
         match result {
             Ok(group) => groups.push(group),
             Err(error) => {
@@ -217,7 +245,9 @@ pub fn prepare_runtime_benchmark_suite(
                 );
             }
         }
+        progress.finish_item(&benchmark_crate.name);
     }
+    progress.finish();
 
     groups.sort_unstable_by(|a, b| a.binary.cmp(&b.binary));
     log::debug!("Found binaries: {:?}", groups);
@@ -234,6 +264,46 @@ pub fn prepare_runtime_benchmark_suite(
     })
 }
 
+/// Returns the subset of the requested Cargo features that the given
+/// benchmark group declares in its `Cargo.toml`, warning about the rest.
+fn select_features(benchmark_crate: &BenchmarkGroupCrate, requested: &[String]) -> Vec<String> {
+    if requested.is_empty() {
+        return Vec::new();
+    }
+    let declared = match cargo_metadata::MetadataCommand::new()
+        .manifest_path(benchmark_crate.path.join("Cargo.toml"))
+        .no_deps()
+        .exec()
+    {
+        Ok(metadata) => metadata
+            .packages
+            .iter()
+            .flat_map(|package| package.features.keys().cloned())
+            .collect::<Vec<String>>(),
+        Err(error) => {
+            log::warn!(
+                "Cannot read Cargo metadata of `{}`: {error:?}",
+                benchmark_crate.name
+            );
+            Vec::new()
+        }
+    };
+    requested
+        .iter()
+        .filter(|feature| {
+            let is_declared = declared.iter().any(|declared| &declared == feature);
+            if !is_declared {
+                log::warn!(
+                    "Feature `{feature}` is not declared by runtime benchmark group `{}`",
+                    benchmark_crate.name
+                );
+            }
+            is_declared
+        })
+        .cloned()
+        .collect()
+}
+
 /// Checks if there are no duplicate runtime benchmark names.
 fn check_duplicates(groups: &[BenchmarkGroup]) -> anyhow::Result<()> {
     let mut benchmark_to_group_name: HashMap<&str, &str> = HashMap::new();
@@ -260,6 +330,8 @@ fn check_duplicates(groups: &[BenchmarkGroup]) -> anyhow::Result<()> {
 fn parse_benchmark_group(
     mut cargo_process: Child,
     group_name: &str,
+    features: Vec<String>,
+    progress: &PhaseProgress,
 ) -> anyhow::Result<BenchmarkGroup> {
     let mut group: Option<BenchmarkGroup> = None;
 
@@ -290,17 +362,16 @@ fn parse_benchmark_group(
                             binary: path,
                             name: group_name.to_string(),
                             benchmark_names: benchmarks,
+                            features: features.clone(),
                         });
                     }
                 }
             }
-            Message::TextLine(line) => {
-                println!("{line}")
-            }
+            Message::TextLine(line) => progress.println(&line),
             Message::CompilerMessage(msg) => {
                 let message = msg.message.rendered.unwrap_or(msg.message.message);
                 messages.push_str(&message);
-                print!("{message}");
+                progress.println(message.trim_end());
             }
             _ => {}
         }
@@ -327,6 +398,7 @@ fn start_cargo_build(
     benchmark_dir: &Path,
     target_dir: Option<&Path>,
     opts: &RuntimeCompilationOpts,
+    features: &[String],
 ) -> anyhow::Result<Child> {
     let mut command = Command::new(&toolchain.components.cargo);
     command
@@ -344,6 +416,11 @@ fn start_cargo_build(
         command.env("CARGO_PROFILE_RELEASE_DEBUG", debug_info);
     }
 
+    if !features.is_empty() {
+        command.arg("--features");
+        command.arg(features.join(","));
+    }
+
     if let Some(target_dir) = target_dir {
         command.arg("--target-dir");
         command.arg(target_dir);
@@ -355,11 +432,30 @@ fn start_cargo_build(
     Ok(child)
 }
 
-/// Uses a command from `benchlib` to find the benchmark names from the given
-/// benchmark binary.
+/// Performs the versioned handshake with the given benchmark binary and
+/// returns the benchmark names it defines. Binaries that do not understand
+/// the `version` subcommand or speak a different protocol version are
+/// rejected, so stale binaries from a compilation cache cannot silently
+/// miscommunicate after the benchlib JSON protocol evolves.
 fn gather_benchmarks(binary: &Path) -> anyhow::Result<Vec<String>> {
-    let output = Command::new(binary).arg("list").output()?;
-    Ok(serde_json::from_slice(&output.stdout)?)
+    let output = Command::new(binary).arg("version").output()?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Benchmark binary does not support the `version` handshake; \
+             it was probably compiled against an older benchlib. \
+             Clear the target directory and rebuild it."
+        ));
+    }
+    let info: benchlib::comm::messages::GroupInfo = serde_json::from_slice(&output.stdout)?;
+    if info.protocol_version != benchlib::comm::messages::PROTOCOL_VERSION {
+        return Err(anyhow::anyhow!(
+            "Benchmark binary speaks benchlib protocol version {}, but the collector expects {}. \
+             Clear the target directory and rebuild it.",
+            info.protocol_version,
+            benchlib::comm::messages::PROTOCOL_VERSION
+        ));
+    }
+    Ok(info.benchmarks)
 }
 
 /// Finds all runtime benchmarks (crates) in the given directory.
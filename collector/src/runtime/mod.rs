@@ -1,5 +1,5 @@
 use std::ffi::OsStr;
-use std::io::{BufRead, BufReader, Cursor};
+use std::io::{BufReader, Read};
 use std::path::Path;
 use std::process::{Command, Stdio};
 
@@ -7,21 +7,26 @@ use anyhow::Context;
 use thousands::Separable;
 
 use benchlib::comm::messages::{BenchmarkMessage, BenchmarkResult, BenchmarkStats};
+use benchlib::comm::MessageReader;
 pub use benchmark::{
     get_runtime_benchmark_groups, prepare_runtime_benchmark_suite, runtime_benchmark_dir,
     BenchmarkFilter, BenchmarkGroup, BenchmarkGroupCrate, BenchmarkSuite,
     BenchmarkSuiteCompilation, CargoIsolationMode,
 };
-use database::{ArtifactIdNumber, CollectionId, Connection};
+use database::{ArtifactIdNumber, CollectionId, Connection, RunId};
 
 use crate::utils::git::get_rustc_perf_commit;
-use crate::{run_command_with_output, CollectorCtx};
+use crate::CollectorCtx;
 
 mod benchmark;
 mod profile;
+mod progress;
 
 pub use benchmark::RuntimeCompilationOpts;
 pub use profile::{profile_runtime, RuntimeProfiler};
+pub use progress::BenchmarkOutput;
+
+use progress::PhaseProgress;
 
 pub const DEFAULT_RUNTIME_ITERATIONS: u32 = 5;
 
@@ -35,13 +40,16 @@ pub async fn bench_runtime(
     collector: &CollectorCtx,
     filter: BenchmarkFilter,
     iterations: u32,
+    output: BenchmarkOutput,
 ) -> anyhow::Result<()> {
     let filtered = suite.filtered_benchmark_count(&filter);
-    println!("Executing {} benchmarks\n", filtered);
+    let progress = output.start_phase("Executing", filtered);
 
     let rustc_perf_version = get_rustc_perf_commit();
-    let mut benchmark_index = 0;
     for group in suite.groups {
+        if crate::utils::shutdown::requested() {
+            break;
+        }
         let Some(step_name) = collector.start_runtime_step(conn, &group).await else {
             eprintln!("skipping {} -- already benchmarked", group.name);
             continue;
@@ -52,27 +60,33 @@ pub async fn bench_runtime(
         // Async block is used to easily capture all results, it basically simulates a `try` block.
         // Extracting this into a separate function would be annoying, as there would be many
         // parameters.
+        progress.start_item(&group.name);
         let result = async {
             let messages = execute_runtime_benchmark_binary(&group.binary, &filter, iterations)?;
             for message in messages {
                 let message = message.map_err(|err| {
                     anyhow::anyhow!(
-                        "Cannot parse BenchmarkMessage from benchmark {}: {err:?}",
+                        "Error while reading messages from benchmark {}: {err:?}",
                         group.binary.display()
                     )
                 })?;
                 match message {
-                    BenchmarkMessage::Result(result) => {
-                        benchmark_index += 1;
-                        println!(
-                            "Finished {}/{} ({}/{})",
-                            group.name, result.name, benchmark_index, filtered
-                        );
-
-                        print_stats(&result);
+                    BenchmarkMessage::Result(mut result) => {
+                        progress.println(&format!("Finished {}/{}", group.name, result.name));
+                        print_stats(&result, &progress);
+                        progress.finish_item(&format!("{}/{}", group.name, result.name));
+
+                        // Results gathered with a non-default feature set are
+                        // recorded under a distinct identity, so that feature
+                        // configurations can be compared against each other.
+                        if !group.features.is_empty() {
+                            result.name =
+                                format!("{}@{}", result.name, group.features.join("+"));
+                        }
                         record_stats(
                             tx.conn(),
                             collector.artifact_row_id,
+                            collector.run_id,
                             &rustc_perf_version,
                             result,
                         )
@@ -87,6 +101,17 @@ pub async fn bench_runtime(
         .with_context(|| format!("Failed to execute runtime benchmark group {}", group.name));
 
         if let Err(error) = result {
+            if crate::utils::shutdown::requested() {
+                // The benchmark was killed by our signal handler. Roll back
+                // the partially benchmarked group and leave its step
+                // unfinished, so that it is re-run when the collection is
+                // resumed.
+                eprintln!("terminating; discarding partial results of {}", group.name);
+                tx.finish()
+                    .await
+                    .expect("Cannot roll back interrupted runtime benchmark group");
+                break;
+            }
             eprintln!("collector error: {:#}", error);
             tx.conn()
                 .record_error(
@@ -102,10 +127,33 @@ pub async fn bench_runtime(
             .await
             .expect("Cannot commit runtime benchmark group results");
     }
+    progress.finish();
 
     Ok(())
 }
 
+/// Runs every group in the suite for a single iteration without recording
+/// anything, returning the per-group result. Used by `collector
+/// test_benchmarks` to smoke-test the runtime benchmark suite.
+pub fn test_runtime_benchmark_suite(
+    suite: &BenchmarkSuite,
+    filter: &BenchmarkFilter,
+) -> Vec<(String, anyhow::Result<()>)> {
+    let mut results = Vec::new();
+    for group in &suite.groups {
+        let result = execute_runtime_benchmark_binary(&group.binary, filter, 1)
+            .and_then(|messages| {
+                for message in messages {
+                    message?;
+                }
+                Ok(())
+            })
+            .with_context(|| format!("Failed to execute runtime benchmark group {}", group.name));
+        results.push((group.name.clone(), result));
+    }
+    results
+}
+
 /// Prepares a command for execution, adding some shared flags.
 fn prepare_command<S: AsRef<OsStr>>(binary: S) -> Command {
     // Turn off ASLR
@@ -117,6 +165,7 @@ fn prepare_command<S: AsRef<OsStr>>(binary: S) -> Command {
 
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
+    crate::utils::shutdown::use_own_process_group(&mut command);
     command
 }
 
@@ -124,6 +173,7 @@ fn prepare_command<S: AsRef<OsStr>>(binary: S) -> Command {
 async fn record_stats(
     conn: &dyn Connection,
     artifact_id: ArtifactIdNumber,
+    run: RunId,
     rustc_perf_version: &str,
     result: BenchmarkResult,
 ) {
@@ -148,7 +198,7 @@ async fn record_stats(
     }
 
     for stat in &result.stats {
-        let collection_id = conn.collection_id(rustc_perf_version).await;
+        let collection_id = conn.collection_id(rustc_perf_version, Some(run)).await;
 
         record(
             conn,
@@ -218,19 +268,71 @@ fn execute_runtime_benchmark_binary(
         command.args(["--include", include]);
     }
 
-    let output = run_command_with_output(&mut command)?;
-    if !output.status.success() {
-        return Err(anyhow::anyhow!(
-            "Process finished with exit code {}\n{}",
-            output.status.code().unwrap_or(-1),
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("failed to spawn benchmark binary {}", binary.display()))?;
+    let shutdown_guard = crate::utils::shutdown::active_process_guard(&child);
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+    // Drain stderr on a separate thread so the child cannot block on a full
+    // pipe while we are waiting for its next result on stdout.
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buffer = String::new();
+        let _ = BufReader::new(stderr).read_to_string(&mut buffer);
+        buffer
+    });
 
-    let reader = BufReader::new(Cursor::new(output.stdout));
-    Ok(reader.lines().map(|line| {
-        Ok(line.and_then(|line| Ok(serde_json::from_str::<BenchmarkMessage>(&line)?))?)
-    }))
+    Ok(BenchmarkMessageStream {
+        reader: MessageReader::new(stdout),
+        child,
+        stderr_reader: Some(stderr_reader),
+        finished: false,
+        _shutdown_guard: shutdown_guard,
+    })
+}
+
+/// Streams messages from a running benchmark group binary as they are
+/// produced, instead of buffering the whole run. The exit status is checked
+/// after the last message, so a late crash is still reported while all
+/// previously completed results have already been handed out.
+struct BenchmarkMessageStream {
+    reader: MessageReader<std::process::ChildStdout>,
+    child: std::process::Child,
+    stderr_reader: Option<std::thread::JoinHandle<String>>,
+    finished: bool,
+    _shutdown_guard: crate::utils::shutdown::ActiveProcessGuard,
+}
+
+impl Iterator for BenchmarkMessageStream {
+    type Item = anyhow::Result<BenchmarkMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if let Some(message) = self.reader.next() {
+            return Some(message);
+        }
+        self.finished = true;
+        let status = match self.child.wait() {
+            Ok(status) => status,
+            Err(error) => return Some(Err(error.into())),
+        };
+        if status.success() {
+            None
+        } else {
+            let stderr = self
+                .stderr_reader
+                .take()
+                .and_then(|handle| handle.join().ok())
+                .unwrap_or_default();
+            Some(Err(anyhow::anyhow!(
+                "Process finished with exit code {}\n{}",
+                status.code().unwrap_or(-1),
+                stderr
+            )))
+        }
+    }
 }
 
 fn calculate_mean<I: Iterator<Item = f64> + Clone>(iter: I) -> f64 {
@@ -239,9 +341,10 @@ fn calculate_mean<I: Iterator<Item = f64> + Clone>(iter: I) -> f64 {
     sum / count as f64
 }
 
-fn print_stats(result: &BenchmarkResult) {
+fn print_stats(result: &BenchmarkResult, progress: &PhaseProgress) {
     fn print_metric<F: Fn(&BenchmarkStats) -> Option<u64>>(
         result: &BenchmarkResult,
+        progress: &PhaseProgress,
         name: &str,
         f: F,
     ) {
@@ -260,23 +363,23 @@ fn print_stats(result: &BenchmarkResult) {
             .sqrt();
             let min = result.stats.iter().map(&f).min().unwrap_or(0);
 
-            println!(
+            progress.println(&format!(
                 "{name:>18}: min:{:>16}    mean: {:>16}    stddev: {:>11}",
                 min.separate_with_commas(),
                 (mean as u64).separate_with_commas(),
                 (stddev as u64).separate_with_commas()
-            );
+            ));
         } else {
-            println!("{name:>20}: Not available");
+            progress.println(&format!("{name:>20}: Not available"));
         }
     }
 
-    print_metric(result, "Instructions", |m| m.instructions);
-    print_metric(result, "Cycles", |m| m.cycles);
-    print_metric(result, "Wall time [µs]", |m| {
+    print_metric(result, progress, "Instructions", |m| m.instructions);
+    print_metric(result, progress, "Cycles", |m| m.cycles);
+    print_metric(result, progress, "Wall time [µs]", |m| {
         Some(m.wall_time.as_micros() as u64)
     });
-    print_metric(result, "Branch misses", |m| m.branch_misses);
-    print_metric(result, "Cache misses", |m| m.cache_misses);
-    println!();
+    print_metric(result, progress, "Branch misses", |m| m.branch_misses);
+    print_metric(result, progress, "Cache misses", |m| m.cache_misses);
+    progress.println("");
 }
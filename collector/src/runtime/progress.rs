@@ -0,0 +1,138 @@
+//! Progress reporting for runtime benchmark compilation and execution.
+//!
+//! Interactive runs show an indicatif progress bar per phase; CI runs can opt
+//! into a quiet mode or line-delimited JSON events instead.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use std::cell::Cell;
+
+/// How the runtime benchmark commands report progress.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BenchmarkOutput {
+    /// Interactive progress bars for the compilation and execution phases.
+    #[default]
+    Progress,
+    /// No progress output, only errors and the final summary.
+    Quiet,
+    /// One JSON object per line for every progress event, for CI consumption.
+    Json,
+}
+
+impl BenchmarkOutput {
+    /// Starts reporting a phase (compilation or execution) consisting of
+    /// `total` items.
+    pub fn start_phase(self, phase: &'static str, total: u64) -> PhaseProgress {
+        let bar = match self {
+            BenchmarkOutput::Progress => {
+                let bar = ProgressBar::new(total);
+                bar.set_style(
+                    ProgressStyle::with_template("{msg:<32} [{wide_bar}] {pos}/{len} ({elapsed})")
+                        .unwrap()
+                        .progress_chars("=> "),
+                );
+                bar.set_message(phase);
+                Some(bar)
+            }
+            BenchmarkOutput::Quiet => None,
+            BenchmarkOutput::Json => {
+                print_event(serde_json::json!({
+                    "event": "phase-started",
+                    "phase": phase,
+                    "total": total,
+                }));
+                None
+            }
+        };
+        PhaseProgress {
+            mode: self,
+            phase,
+            total,
+            completed: Cell::new(0),
+            bar,
+        }
+    }
+}
+
+/// Reports the progress of a single phase of a runtime benchmark run.
+pub struct PhaseProgress {
+    mode: BenchmarkOutput,
+    phase: &'static str,
+    total: u64,
+    completed: Cell<u64>,
+    bar: Option<ProgressBar>,
+}
+
+impl PhaseProgress {
+    /// Marks the item (a benchmark group or a single benchmark) that is
+    /// currently being processed.
+    pub fn start_item(&self, name: &str) {
+        match self.mode {
+            BenchmarkOutput::Progress => {
+                if let Some(bar) = &self.bar {
+                    bar.set_message(format!("{} `{name}`", self.phase));
+                }
+            }
+            BenchmarkOutput::Quiet => {}
+            BenchmarkOutput::Json => print_event(serde_json::json!({
+                "event": "item-started",
+                "phase": self.phase,
+                "name": name,
+            })),
+        }
+    }
+
+    /// Records that one item finished.
+    pub fn finish_item(&self, name: &str) {
+        self.completed.set(self.completed.get() + 1);
+        match self.mode {
+            BenchmarkOutput::Progress => {
+                if let Some(bar) = &self.bar {
+                    bar.inc(1);
+                }
+            }
+            BenchmarkOutput::Quiet => {}
+            BenchmarkOutput::Json => print_event(serde_json::json!({
+                "event": "item-finished",
+                "phase": self.phase,
+                "name": name,
+                "completed": self.completed.get(),
+                "total": self.total,
+            })),
+        }
+    }
+
+    /// Prints a line of auxiliary output (compiler diagnostics, per-benchmark
+    /// statistics) without disturbing the progress bar. The line is dropped
+    /// in the quiet and JSON modes.
+    pub fn println(&self, line: &str) {
+        match self.mode {
+            BenchmarkOutput::Progress => match &self.bar {
+                Some(bar) => bar.println(line),
+                None => println!("{line}"),
+            },
+            BenchmarkOutput::Quiet | BenchmarkOutput::Json => {}
+        }
+    }
+
+    /// Finishes the phase.
+    pub fn finish(self) {
+        match self.mode {
+            BenchmarkOutput::Progress => {
+                if let Some(bar) = &self.bar {
+                    bar.finish_and_clear();
+                }
+            }
+            BenchmarkOutput::Quiet => {}
+            BenchmarkOutput::Json => print_event(serde_json::json!({
+                "event": "phase-finished",
+                "phase": self.phase,
+                "completed": self.completed.get(),
+                "total": self.total,
+            })),
+        }
+    }
+}
+
+fn print_event(event: serde_json::Value) {
+    println!("{event}");
+}
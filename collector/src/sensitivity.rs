@@ -0,0 +1,130 @@
+//! Experimental profile-guided benchmark selection.
+//!
+//! Given the set of paths touched by a rustc change, this module consults a
+//! sensitivity matrix — which benchmarks have historically moved when those
+//! parts of the compiler changed — and proposes a reduced benchmark set, so
+//! that narrow changes can be measured without paying for the full suite.
+//!
+//! The matrix is maintained by hand from past triage results. It errs on the
+//! side of running too much: if any touched path is not covered by the
+//! matrix, no reduction is proposed and the full suite runs.
+
+/// Maps a path prefix inside the rust-lang/rust checkout to the benchmarks
+/// (name prefixes) that have been sensitive to changes under it.
+const SENSITIVITY_MATRIX: &[(&str, &[&str])] = &[
+    (
+        "compiler/rustc_trait_selection",
+        &["diesel", "bitmaps", "projection-caching", "wg-grammar"],
+    ),
+    (
+        "compiler/rustc_hir_typeck",
+        &["diesel", "coercions", "many-assoc-items"],
+    ),
+    ("compiler/rustc_borrowck", &["unify-linearly", "wg-grammar"]),
+    (
+        "compiler/rustc_parse",
+        &["tuple-stress", "token-stream-stress", "tt-muncher"],
+    ),
+    (
+        "compiler/rustc_expand",
+        &["derive", "serde_derive", "tt-muncher", "token-stream-stress"],
+    ),
+    (
+        "compiler/rustc_resolve",
+        &["many-assoc-items", "externs", "ucd"],
+    ),
+    (
+        "compiler/rustc_const_eval",
+        &["ctfe-stress", "stm32f4", "match-stress"],
+    ),
+    (
+        "compiler/rustc_mir_transform",
+        &["deep-vector", "inflate", "encoding"],
+    ),
+    (
+        "compiler/rustc_monomorphize",
+        &["deeply-nested-multi", "tuple-stress"],
+    ),
+    (
+        "compiler/rustc_codegen_ssa",
+        &["cranelift-codegen", "regex", "ripgrep", "syn"],
+    ),
+    (
+        "compiler/rustc_codegen_llvm",
+        &["cranelift-codegen", "regex", "ripgrep", "syn"],
+    ),
+    (
+        "compiler/rustc_incremental",
+        &["regression-31157", "style-servo"],
+    ),
+    ("compiler/rustc_metadata", &["externs", "libc", "helloworld"]),
+    ("src/librustdoc", &["cargo", "hyper", "futures"]),
+];
+
+/// Path prefixes whose changes do not affect compile-time benchmarks at all.
+const IRRELEVANT_PREFIXES: &[&str] = &["src/doc", "src/tools", "tests", "RELEASES.md", "triagebot"];
+
+/// Given the paths touched by a rustc change, returns the name prefixes of
+/// benchmarks expected to be affected, or `None` if the matrix does not
+/// cover every touched path and the full suite should run instead.
+pub fn select_affected_benchmarks(paths: &[String]) -> Option<Vec<&'static str>> {
+    let mut benchmarks: Vec<&'static str> = Vec::new();
+    for path in paths {
+        if IRRELEVANT_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            continue;
+        }
+        let row = SENSITIVITY_MATRIX
+            .iter()
+            .find(|(prefix, _)| path.starts_with(prefix));
+        match row {
+            Some((_, sensitive)) => {
+                for benchmark in *sensitive {
+                    if !benchmarks.contains(benchmark) {
+                        benchmarks.push(benchmark);
+                    }
+                }
+            }
+            // A path we know nothing about (e.g. `library/core`) can affect
+            // anything, so don't propose a reduction.
+            None => return None,
+        }
+    }
+    benchmarks.sort_unstable();
+    Some(benchmarks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_affected_benchmarks;
+
+    #[test]
+    fn unknown_path_runs_everything() {
+        let paths = vec![
+            "compiler/rustc_borrowck/src/lib.rs".to_string(),
+            "library/core/src/iter/mod.rs".to_string(),
+        ];
+        assert!(select_affected_benchmarks(&paths).is_none());
+    }
+
+    #[test]
+    fn covered_paths_select_union() {
+        let paths = vec![
+            "compiler/rustc_parse/src/parser/mod.rs".to_string(),
+            "compiler/rustc_expand/src/mbe/macro_rules.rs".to_string(),
+            "src/doc/rustc/book.toml".to_string(),
+        ];
+        let benchmarks = select_affected_benchmarks(&paths).unwrap();
+        assert!(benchmarks.contains(&"tt-muncher"));
+        assert!(benchmarks.contains(&"derive"));
+        assert!(!benchmarks.contains(&"diesel"));
+    }
+
+    #[test]
+    fn irrelevant_paths_select_nothing() {
+        let paths = vec!["src/tools/tidy/src/main.rs".to_string()];
+        assert_eq!(select_affected_benchmarks(&paths), Some(Vec::new()));
+    }
+}
@@ -235,6 +235,67 @@ impl Toolchain {
             triple: sysroot.triple.clone(),
         }
     }
+
+    /// Replaces the toolchain's cargo according to the given variant.
+    ///
+    /// For [`CargoVariant::Stock`] this swaps in the pinned stock cargo and
+    /// appends a suffix to the toolchain id, so that results gathered with the
+    /// two cargos are recorded as separate series.
+    pub fn with_cargo_variant(mut self, variant: CargoVariant) -> anyhow::Result<Toolchain> {
+        if let CargoVariant::Stock = variant {
+            self.components.cargo = nightly_cargo()?;
+            self.id.push_str(variant.id_suffix());
+        }
+        Ok(self)
+    }
+
+    /// The toolchain id without any cargo-variant suffix, suitable for
+    /// version checks.
+    pub fn base_id(&self) -> &str {
+        self.id.trim_end_matches(CargoVariant::Stock.id_suffix())
+    }
+}
+
+/// Which cargo drives the benchmarked builds: the cargo that shipped with the
+/// benchmarked artifact, or a pinned "stock" cargo that is independent of the
+/// artifact. Cargo-side changes occasionally show up as compile-time shifts;
+/// measuring with both cargos makes it possible to attribute them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CargoVariant {
+    Artifact,
+    Stock,
+}
+
+impl CargoVariant {
+    /// Suffix appended to the artifact identifier for this variant.
+    pub fn id_suffix(self) -> &'static str {
+        match self {
+            CargoVariant::Artifact => "",
+            CargoVariant::Stock => "-stock-cargo",
+        }
+    }
+}
+
+/// Returns the path to the pinned "stock" cargo (the nightly cargo installed
+/// via `rustup`).
+pub fn nightly_cargo() -> anyhow::Result<PathBuf> {
+    let output = Command::new("rustup")
+        .args(["which", "cargo", "--toolchain=nightly"])
+        .output()
+        .context("failed to run `rustup which cargo --toolchain=nightly`")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "`rustup which cargo --toolchain=nightly` exited with status {}\nstderr={}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )
+    }
+    let s = String::from_utf8(output.stdout)
+        .context("failed to convert `rustup which cargo --toolchain=nightly` output to utf8")?;
+
+    let cargo = PathBuf::from(s.trim());
+    debug!("found cargo: {:?}", &cargo);
+    Ok(cargo)
 }
 
 #[derive(Debug, Clone, Default)]
@@ -406,23 +467,7 @@ pub fn get_local_toolchain(
             .with_context(|| format!("failed to canonicalize cargo executable {:?}", cargo))?
     } else {
         // Use the nightly cargo from `rustup`.
-        let output = Command::new("rustup")
-            .args(["which", "cargo", "--toolchain=nightly"])
-            .output()
-            .context("failed to run `rustup which cargo --toolchain=nightly`")?;
-        if !output.status.success() {
-            anyhow::bail!(
-                "`rustup which cargo --toolchain=nightly` exited with status {}\nstderr={}",
-                output.status,
-                String::from_utf8_lossy(&output.stderr)
-            )
-        }
-        let s = String::from_utf8(output.stdout)
-            .context("failed to convert `rustup which cargo --toolchain=nightly` output to utf8")?;
-
-        let cargo = PathBuf::from(s.trim());
-        debug!("found cargo: {:?}", &cargo);
-        cargo
+        nightly_cargo()?
     };
 
     let lib_dir = get_lib_dir_from_rustc(&rustc).context("Cannot find libdir for rustc")?;
@@ -0,0 +1,157 @@
+//! Support for running benchmarks inside a pinned container image.
+//!
+//! `bench_local --container <image>` re-executes the collector inside the
+//! image with the toolchain, working directory and results database mounted
+//! in at their original paths, so that the userspace environment (linker,
+//! system libraries, ...) is the same on every contributor machine. The
+//! resolved image digest is recorded with the results, which documents
+//! environment drift between collections.
+
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Environment variable carrying the resolved image digest inside the
+/// container. Its presence also stops the collector from trying to re-enter
+/// a container recursively.
+pub const CONTAINER_DIGEST_VAR: &str = "RUSTC_PERF_CONTAINER_DIGEST";
+
+/// Returns the container runtime to use, preferring Docker and falling back
+/// to Podman.
+fn container_runtime() -> anyhow::Result<&'static str> {
+    for runtime in ["docker", "podman"] {
+        if super::is_installed(runtime) {
+            return Ok(runtime);
+        }
+    }
+    Err(anyhow::anyhow!(
+        "`--container` requires either `docker` or `podman` to be installed"
+    ))
+}
+
+/// Resolves the image to a content digest, pulling the image if it is not
+/// available locally. The digest, not the tag, is what gets recorded with
+/// the results: tags can move.
+fn image_digest(runtime: &str, image: &str) -> anyhow::Result<String> {
+    if let Some(digest) = inspect_digest(runtime, image) {
+        return Ok(digest);
+    }
+    let status = Command::new(runtime)
+        .args(["pull", image])
+        .status()
+        .with_context(|| format!("failed to run `{runtime} pull {image}`"))?;
+    if !status.success() {
+        anyhow::bail!("failed to pull container image `{image}`");
+    }
+    inspect_digest(runtime, image)
+        .ok_or_else(|| anyhow::anyhow!("could not resolve the digest of image `{image}`"))
+}
+
+fn inspect_digest(runtime: &str, image: &str) -> Option<String> {
+    // Prefer the repo digest, which is stable across machines; fall back to
+    // the local image ID for images that were built locally and never pushed.
+    let output = Command::new(runtime)
+        .args([
+            "image",
+            "inspect",
+            "--format",
+            "{{if .RepoDigests}}{{index .RepoDigests 0}}{{else}}{{.Id}}{{end}}",
+            image,
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let digest = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if digest.is_empty() {
+        None
+    } else {
+        Some(digest)
+    }
+}
+
+/// Returns the host directory that has to be mounted into the container for
+/// `path` to be usable there at its original location.
+fn dir_to_mount(path: &Path) -> Option<PathBuf> {
+    let path = path.canonicalize().ok()?;
+    let dir = if path.is_dir() {
+        path
+    } else {
+        let parent = path.parent()?;
+        // Toolchain binaries live in a sysroot's `bin` directory, but rustc
+        // also needs the sibling `lib` directory, so mount the whole sysroot.
+        if parent.file_name().is_some_and(|name| name == "bin") {
+            parent.parent()?.to_path_buf()
+        } else {
+            parent.to_path_buf()
+        }
+    };
+    Some(dir)
+}
+
+/// Removes the `--container <image>` argument from an argument list, so that
+/// the re-executed collector does not try to enter a container again.
+fn strip_container_arg(args: impl Iterator<Item = String>) -> Vec<String> {
+    let mut stripped = Vec::new();
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        if arg == "--container" {
+            args.next();
+        } else if !arg.starts_with("--container=") {
+            stripped.push(arg);
+        }
+    }
+    stripped
+}
+
+/// Re-executes the current collector invocation inside the given container
+/// image and returns its exit code. The paths in `mounts` (along with the
+/// working directory, the collector binary and the Cargo home) are mounted
+/// into the container at their original locations.
+pub fn run_in_container(image: &str, mounts: &[&Path]) -> anyhow::Result<i32> {
+    let runtime = container_runtime()?;
+    let digest = image_digest(runtime, image)?;
+    log::info!("running benchmarks in container image {digest}");
+
+    let cwd = std::env::current_dir()?;
+    let exe = std::env::current_exe()?;
+    let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")));
+
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for path in std::iter::once(cwd.as_path())
+        .chain(std::iter::once(exe.as_path()))
+        .chain(cargo_home.as_deref())
+        .chain(mounts.iter().copied())
+    {
+        if let Some(dir) = dir_to_mount(path) {
+            // Skip directories already covered by another mount.
+            if !dirs.iter().any(|mounted| dir.starts_with(mounted)) {
+                dirs.retain(|mounted| !mounted.starts_with(&dir));
+                dirs.push(dir);
+            }
+        }
+    }
+
+    let mut cmd = Command::new(runtime);
+    // `--privileged` is needed for access to the hardware performance
+    // counters that `perf` measures with.
+    cmd.args(["run", "--rm", "--privileged"]);
+    for dir in &dirs {
+        cmd.arg("-v")
+            .arg(format!("{0}:{0}", dir.display()));
+    }
+    cmd.arg("-w").arg(&cwd);
+    cmd.arg("-e")
+        .arg(format!("{CONTAINER_DIGEST_VAR}={digest}"));
+    cmd.arg(image);
+    cmd.arg(&exe);
+    cmd.args(strip_container_arg(std::env::args().skip(1)));
+
+    let status = cmd
+        .status()
+        .with_context(|| format!("failed to start container runtime `{runtime}`"))?;
+    Ok(status.code().unwrap_or(1))
+}
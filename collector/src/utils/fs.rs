@@ -112,6 +112,28 @@ pub fn get_file_count_and_size(path: &Path) -> std::io::Result<(u64, u64)> {
     Ok((count, size))
 }
 
+/// Returns the number of bytes available to unprivileged processes on the
+/// filesystem containing `path`.
+#[cfg(unix)]
+pub fn available_disk_space(path: &Path) -> std::io::Result<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes())?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(windows)]
+pub fn available_disk_space(_path: &Path) -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "not implemented on Windows",
+    ))
+}
+
 #[cfg(windows)]
 pub fn robocopy(
     from: &std::path::Path,
@@ -0,0 +1,99 @@
+//! Diffing of `cargo llvm-lines` reports.
+//!
+//! The `llvm-lines` profiler stores the per-function IR-size accounting that
+//! `cargo llvm-lines` prints for a single toolchain. When `profile_local` is
+//! given two toolchains, the two reports are combined into a per-function
+//! diff, which is the interesting view when chasing monomorphization bloat:
+//! it shows which functions gained IR lines or instantiated copies between
+//! the toolchains.
+
+use anyhow::Context;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::{fs, io};
+
+/// The number of IR lines and monomorphized copies attributed to a function.
+#[derive(Debug, Default, Copy, Clone)]
+struct FunctionSize {
+    lines: u64,
+    copies: u64,
+}
+
+/// Creates a per-function diff between two `cargo llvm-lines` reports and
+/// writes it to `output`, sorted by the change in IR lines (biggest growth
+/// first).
+pub fn llvm_lines_diff(ll_a: &Path, ll_b: &Path, output: &Path) -> anyhow::Result<()> {
+    let a = parse_llvm_lines(ll_a)
+        .with_context(|| format!("cannot parse llvm-lines report {}", ll_a.display()))?;
+    let b = parse_llvm_lines(ll_b)
+        .with_context(|| format!("cannot parse llvm-lines report {}", ll_b.display()))?;
+
+    let mut functions: Vec<&str> = a.keys().chain(b.keys()).map(|f| f.as_str()).collect();
+    functions.sort_unstable();
+    functions.dedup();
+
+    let mut rows: Vec<(i64, i64, String)> = functions
+        .into_iter()
+        .map(|function| {
+            let old = a.get(function).copied().unwrap_or_default();
+            let new = b.get(function).copied().unwrap_or_default();
+            (
+                new.lines as i64 - old.lines as i64,
+                new.copies as i64 - old.copies as i64,
+                function.to_string(),
+            )
+        })
+        .filter(|(lines, copies, _)| *lines != 0 || *copies != 0)
+        .collect();
+    rows.sort_by(|x, y| y.0.cmp(&x.0).then_with(|| y.1.cmp(&x.1)));
+
+    let total_lines: i64 = rows.iter().map(|(lines, _, _)| lines).sum();
+    let total_copies: i64 = rows.iter().map(|(_, copies, _)| copies).sum();
+
+    let mut writer = io::BufWriter::new(fs::File::create(output)?);
+    writeln!(writer, "{:>12}  {:>12}  Function name", "Lines", "Copies")?;
+    writeln!(writer, "{:>12}  {:>12}  -------------", "-----", "------")?;
+    writeln!(writer, "{:>+12}  {:>+12}  (TOTAL)", total_lines, total_copies)?;
+    for (lines, copies, function) in rows {
+        writeln!(writer, "{:>+12}  {:>+12}  {}", lines, copies, function)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Parses the tabular output of `cargo llvm-lines` into a map from function
+/// name to its IR size. Rows look like:
+///
+/// ```text
+///   Lines          Copies        Function name
+///   -----          ------        -------------
+///   30737 (100%)   1107 (100%)   (TOTAL)
+///    1395 (4.5%)     83 (7.5%)   core::ptr::drop_in_place
+/// ```
+fn parse_llvm_lines(path: &Path) -> anyhow::Result<HashMap<String, FunctionSize>> {
+    let contents = fs::read_to_string(path)?;
+    let mut functions = HashMap::new();
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        let lines = match parts.next().and_then(|v| v.parse::<u64>().ok()) {
+            Some(lines) => lines,
+            // The header, separator and (TOTAL) rows, which do not start
+            // with a number.
+            None => continue,
+        };
+        // Skip the percentage columns, e.g. `(4.5%)`.
+        parts.next();
+        let copies = parts
+            .next()
+            .and_then(|v| v.parse::<u64>().ok())
+            .with_context(|| format!("malformed llvm-lines row: {line:?}"))?;
+        parts.next();
+        let function = parts.collect::<Vec<_>>().join(" ");
+        if function == "(TOTAL)" {
+            continue;
+        }
+        functions.insert(function, FunctionSize { lines, copies });
+    }
+    Ok(functions)
+}
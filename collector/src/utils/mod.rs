@@ -2,10 +2,13 @@ use std::future::Future;
 use std::process::Command;
 
 pub mod cachegrind;
+pub mod container;
 pub mod fs;
 pub mod git;
+pub mod llvm_lines;
 pub mod mangling;
 pub mod read2;
+pub mod shutdown;
 
 pub fn wait_for_future<F: Future<Output = R>, R>(f: F) -> R {
     tokio::runtime::Builder::new_current_thread()
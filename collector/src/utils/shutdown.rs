@@ -0,0 +1,92 @@
+//! Graceful shutdown of the collector on SIGINT/SIGTERM.
+//!
+//! Dying mid-benchmark leaves orphaned rustc processes running, half-written
+//! target directories and stray temporary directories behind. Instead of
+//! exiting abruptly, the signal handler forwards the signal to the process
+//! group of the currently executing benchmark and sets a flag that the
+//! benchmarking loops check between steps. The collector then unwinds
+//! normally: results of completed steps are committed, the interrupted step
+//! stays unfinished (so it is re-run when the collection is resumed) and
+//! temporary directories are removed by their `Drop` impls.
+//!
+//! A second signal aborts the graceful shutdown and terminates the collector
+//! immediately.
+
+use std::process::{Child, Command};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+static TERMINATION_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Process group of the currently executing benchmark process, or 0 if there
+/// is none.
+static ACTIVE_PROCESS_GROUP: AtomicI32 = AtomicI32::new(0);
+
+/// Installs handlers for SIGINT and SIGTERM. Has no effect on non-Unix
+/// platforms.
+pub fn install_signal_handlers() {
+    #[cfg(unix)]
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = handle_signal as extern "C" fn(libc::c_int) as usize;
+        libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut());
+        libc::sigaction(libc::SIGTERM, &action, std::ptr::null_mut());
+    }
+}
+
+/// Everything in here has to be async-signal-safe: atomics, `kill`, `signal`
+/// and `raise` are, most other things are not.
+#[cfg(unix)]
+extern "C" fn handle_signal(signal: libc::c_int) {
+    if TERMINATION_REQUESTED.swap(true, Ordering::SeqCst) {
+        // Second signal: give up on the graceful shutdown.
+        unsafe {
+            libc::signal(signal, libc::SIG_DFL);
+            libc::raise(signal);
+        }
+        return;
+    }
+    let pgid = ACTIVE_PROCESS_GROUP.load(Ordering::SeqCst);
+    if pgid != 0 {
+        // A negative pid targets the whole process group.
+        unsafe {
+            libc::kill(-pgid, signal);
+        }
+    }
+}
+
+/// Returns true once a termination signal has been received.
+pub fn requested() -> bool {
+    TERMINATION_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Puts the command into its own process group (on Unix), so that a
+/// termination signal can be forwarded to the whole tree of processes it
+/// spawns, not just its direct child.
+pub fn use_own_process_group(command: &mut Command) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    #[cfg(not(unix))]
+    let _ = command;
+}
+
+/// Registers `child` as the currently executing benchmark process. While the
+/// returned guard is alive, termination signals are forwarded to the child's
+/// process group. Guards may nest; dropping one restores the previously
+/// registered process.
+pub fn active_process_guard(child: &Child) -> ActiveProcessGuard {
+    let previous = ACTIVE_PROCESS_GROUP.swap(child.id() as i32, Ordering::SeqCst);
+    ActiveProcessGuard { previous }
+}
+
+pub struct ActiveProcessGuard {
+    previous: i32,
+}
+
+impl Drop for ActiveProcessGuard {
+    fn drop(&mut self) {
+        ACTIVE_PROCESS_GROUP.store(self.previous, Ordering::SeqCst);
+    }
+}
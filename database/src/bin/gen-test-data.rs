@@ -0,0 +1,162 @@
+//! Populates a SQLite database with synthetic benchmark results, so that the
+//! site and its API can be developed locally without access to a production
+//! database dump.
+//!
+//! The generated data contains a linear history of master commits, a small set
+//! of compile-time and runtime benchmarks with per-test-case noise, and a few
+//! injected step changes that show up as regressions or improvements on the
+//! compare page and in the graphs.
+
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use database::{ArtifactId, Commit, CommitType, Date, Pool, Profile, Scenario};
+
+/// Number of commits generated when no count is given on the command line.
+const DEFAULT_COMMITS: usize = 50;
+
+const BENCHMARKS: &[(&str, &str)] = &[
+    ("synthetic-lib", "primary"),
+    ("synthetic-bin", "primary"),
+    ("synthetic-proc-macro", "primary"),
+    ("synthetic-stress", "secondary"),
+];
+
+const RUNTIME_BENCHMARKS: &[&str] = &["synthetic-hashmap", "synthetic-regex"];
+
+const PROFILES: &[Profile] = &[Profile::Check, Profile::Debug, Profile::Opt];
+
+const METRICS: &[&str] = &["instructions:u", "cycles:u", "wall-time", "max-rss"];
+
+/// A small deterministic PRNG (xorshift*), so that repeated runs against a
+/// fresh database produce identical data and we don't need to pull in `rand`.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Returns a float uniformly distributed in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn scenarios() -> Vec<Scenario> {
+    vec![
+        Scenario::Empty,
+        Scenario::IncrementalEmpty,
+        Scenario::IncrementalFresh,
+        Scenario::IncrementalPatch("println".into()),
+    ]
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let db = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| panic!("needs sqlite database as 1st argument"));
+    let commits: usize = std::env::args()
+        .nth(2)
+        .map(|n| n.parse().expect("2nd argument must be a commit count"))
+        .unwrap_or(DEFAULT_COMMITS);
+
+    let pool = Pool::open(&db);
+    let conn = pool.connection().await;
+
+    let mut rng = Rng(0x5EED);
+
+    for (benchmark, category) in BENCHMARKS {
+        conn.record_compile_benchmark(benchmark, Some(false), category.to_string())
+            .await;
+    }
+
+    // Baseline value for each test case, drawn once so that every commit's
+    // results are noise around a stable level.
+    let mut base = |metric: &str| -> f64 {
+        let magnitude = match metric {
+            "instructions:u" | "cycles:u" => 1_000_000_000.0,
+            "wall-time" => 1.0,
+            "max-rss" => 100_000.0,
+            _ => unreachable!(),
+        };
+        magnitude * (0.5 + rng.next_f64())
+    };
+    let mut compile_base = Vec::new();
+    for (benchmark, _) in BENCHMARKS {
+        for profile in PROFILES {
+            for scenario in scenarios() {
+                for metric in METRICS {
+                    compile_base.push((benchmark, *profile, scenario, metric, base(metric)));
+                }
+            }
+        }
+    }
+    let mut runtime_base = Vec::new();
+    for benchmark in RUNTIME_BENCHMARKS {
+        for metric in ["instructions:u", "wall-time"] {
+            runtime_base.push((benchmark, metric, base(metric)));
+        }
+    }
+
+    // Commits are spaced eight hours apart, ending roughly now.
+    let start = Utc::now() - chrono::Duration::hours(8 * commits as i64);
+
+    for nth in 0..commits {
+        let sha = format!("{:040x}", rng.next_u64());
+        let date = start + chrono::Duration::hours(8 * nth as i64);
+        let date = Date(Utc.timestamp_opt(date.timestamp(), 0).unwrap());
+        println!("generating commit {}/{}: {}", nth + 1, commits, sha);
+
+        let aid = conn
+            .artifact_id(&ArtifactId::Commit(Commit {
+                sha,
+                date,
+                r#type: CommitType::Master,
+            }))
+            .await;
+        let cid = conn.collection_id("gen-test-data", None).await;
+
+        // Every ~10th commit the first benchmark takes a persistent 5% hit,
+        // producing clear regressions to exercise the compare page with.
+        let regression = if nth > 0 && nth % 10 == 0 { 1.05 } else { 1.0 };
+
+        for (benchmark, profile, scenario, metric, value) in &mut compile_base {
+            if **benchmark == BENCHMARKS[0].0 {
+                *value *= regression;
+            }
+            // ~0.3% multiplicative noise per measurement.
+            let noise = 1.0 + 0.003 * (rng.next_f64() - 0.5);
+            conn.record_statistic(
+                cid,
+                aid,
+                benchmark,
+                *profile,
+                *scenario,
+                metric,
+                *value * noise,
+            )
+            .await;
+        }
+        for (benchmark, metric, value) in &runtime_base {
+            let noise = 1.0 + 0.003 * (rng.next_f64() - 0.5);
+            conn.record_runtime_statistic(cid, aid, benchmark, metric, *value * noise)
+                .await;
+        }
+
+        conn.record_duration(aid, Duration::from_secs(3600)).await;
+    }
+
+    println!(
+        "generated {} commits; point the site at `{}` to browse them",
+        commits, db
+    );
+}
@@ -8,6 +8,7 @@ use std::hash;
 use std::ops::{Add, Sub};
 use std::time::Duration;
 
+pub mod metric;
 pub mod pool;
 
 pub use pool::{Connection, Pool};
@@ -391,6 +392,18 @@ pub struct QueryDatum {
     pub invocation_count: u32,
 }
 
+/// Self-profile data of one query summed over many test cases, so the
+/// counters are wider than in [`QueryDatum`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct QueryAggregate {
+    pub label: QueryLabel,
+    pub self_time: Duration,
+    pub blocked_time: Duration,
+    pub incremental_load_time: Duration,
+    pub number_of_cache_hits: u64,
+    pub invocation_count: u64,
+}
+
 /// A database row ID for an artifact in the artifact table
 #[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct ArtifactIdNumber(pub u32);
@@ -400,7 +413,7 @@ pub struct ArtifactIdNumber(pub u32);
 /// This is a quick way to find what the database id for something.
 /// Essentially duplicates of the various database tables (artifacts,
 /// error_series, pstat_series, etc.) so that we can avoid a network round-trip.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Index {
     /// Id look for a commit
     commits: Indexed<Commit>,
@@ -570,6 +583,31 @@ impl Index {
         conn.load_index().await
     }
 
+    /// Loads the index from an on-disk snapshot previously written by
+    /// [`Index::store_to_disk`].
+    ///
+    /// The snapshot is memory-mapped, so the bytes are paged in lazily by the
+    /// kernel as deserialization walks them instead of being read (and
+    /// buffered) up front. This is much faster than reloading millions of
+    /// statistic description rows from the database, at the cost of the
+    /// snapshot potentially lagging behind it.
+    pub fn load_from_disk(path: &std::path::Path) -> anyhow::Result<Index> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: snapshots are only replaced via the atomic rename in
+        // `store_to_disk`, so the mapped file does not change under us.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(rmp_serde::from_slice(&mmap)?)
+    }
+
+    /// Writes a compact snapshot of the index, atomically replacing any
+    /// previous snapshot at `path`.
+    pub fn store_to_disk(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, rmp_serde::to_vec(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
     pub fn lookup(
         &self,
         label: &DbLabel,
@@ -665,6 +703,27 @@ pub struct Step {
     pub expected: Duration,
 }
 
+/// The start/end timestamps of a single collection step for an artifact, as
+/// recorded in `collector_progress`.
+#[derive(Debug)]
+pub struct StepTimeline {
+    pub step: String,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+}
+
+/// An annotation attached to an artifact, documenting an event (e.g. an LLVM
+/// major-version upgrade) that is a likely explanation for step changes in
+/// the artifact's results.
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    /// Name of the annotated artifact (commit sha or tag).
+    pub artifact: String,
+    /// Machine-readable kind, e.g. `llvm-version-change`.
+    pub kind: String,
+    pub message: String,
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub struct CollectionId(i32);
 
@@ -674,12 +733,88 @@ impl fmt::Display for CollectionId {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct RunId(i32);
+
+impl fmt::Display for RunId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A single benchmarking run of an artifact. An artifact can be benchmarked
+/// more than once (re-runs after environment problems, different collector
+/// machines), and each such run groups the collections it produced, so that
+/// earlier results survive a re-benchmark instead of being silently folded
+/// into the aggregate.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub id: RunId,
+    /// When the run started, with sub-second precision.
+    pub started_at: DateTime<Utc>,
+    /// Name of the collector that performed the run.
+    pub collector: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CompileBenchmark {
     pub name: String,
     pub category: String,
 }
 
+/// An entry of the append-only audit log recording a mutating operation.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub id: u32,
+    pub occurred_at: Date,
+    /// Who performed the operation, as far as it is known (e.g. `api-token`
+    /// for authenticated API calls, a GitHub username for bot commands).
+    pub actor: String,
+    /// Short machine-readable name of the operation, e.g. `purge-artifact`.
+    pub action: String,
+    /// JSON payload describing the operation's parameters.
+    pub payload: String,
+}
+
+/// A benchmark build requested for a PR (a try build or the merged master
+/// commit), as recorded in the `pull_request_build` table.
+#[derive(Debug, Clone)]
+pub struct PrBuild {
+    pub pr: u32,
+    /// Sha of the benchmarked commit; `None` while a try build has been
+    /// queued but not yet attached to a commit.
+    pub sha: Option<String>,
+    pub parent_sha: Option<String>,
+    pub complete: bool,
+    /// When the build was requested.
+    pub requested: Option<Date>,
+}
+
+/// A digest subscription: significant changes on the selected benchmarks are
+/// periodically summarized and delivered to the target.
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub id: u32,
+    /// Delivery mechanism: `email` or `webhook`.
+    pub kind: String,
+    /// Email address or webhook URL, depending on `kind`.
+    pub target: String,
+    /// Benchmark name substring the subscription covers; empty for all.
+    pub benchmark: String,
+}
+
+/// A queued request to re-run a single test case of an already benchmarked
+/// artifact with self-profiling enabled.
+#[derive(Debug, Clone)]
+pub struct SelfProfileRequest {
+    pub id: u32,
+    /// Commit SHA of the artifact to profile.
+    pub artifact: String,
+    pub benchmark: String,
+    pub profile: String,
+    pub scenario: String,
+}
+
 #[derive(Debug)]
 pub struct ArtifactCollection {
     pub duration: Duration,
@@ -0,0 +1,309 @@
+//! Static metadata about the metrics stored in the database.
+//!
+//! The set of metrics is open-ended — collectors are free to record new ones —
+//! so this is a best-effort registry covering the metrics gathered by the
+//! in-tree collector. It lets frontends and scripts display units and
+//! interpret changes without hardcoding metric names.
+
+use serde::Serialize;
+
+/// Whether a lower or a higher value of a metric is an improvement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BetterDirection {
+    Lower,
+    Higher,
+}
+
+/// Metadata describing a single metric.
+pub struct MetricMetadata {
+    /// Name under which the metric's values are stored (e.g. `instructions:u`).
+    pub name: &'static str,
+    /// Unit of the metric's values.
+    pub unit: &'static str,
+    pub better_direction: BetterDirection,
+    pub description: &'static str,
+}
+
+impl MetricMetadata {
+    /// Returns metadata for all metrics known to this registry.
+    pub fn all() -> &'static [MetricMetadata] {
+        METRICS
+    }
+
+    /// Returns metadata for the given metric, if it is known.
+    pub fn for_metric(name: &str) -> Option<&'static MetricMetadata> {
+        METRICS.iter().find(|m| m.name == name)
+    }
+}
+
+/// Returns the factor by which values measured in `from` must be multiplied
+/// to obtain values measured in `to`, if both units are known and
+/// commensurable (e.g. `bytes` → `MiB`, or `nanoseconds` → `milliseconds`).
+pub fn conversion_factor(from: &str, to: &str) -> Option<f64> {
+    let (from_base, from_factor) = unit_base(from)?;
+    let (to_base, to_factor) = unit_base(to)?;
+    if from_base != to_base {
+        return None;
+    }
+    Some(from_factor / to_factor)
+}
+
+/// Maps a unit to its base unit and the factor converting it to that base.
+fn unit_base(unit: &str) -> Option<(&'static str, f64)> {
+    Some(match unit {
+        "bytes" => ("bytes", 1.0),
+        "kilobytes" => ("bytes", 1000.0),
+        "KiB" => ("bytes", 1024.0),
+        "MiB" => ("bytes", 1024.0 * 1024.0),
+        "GiB" => ("bytes", 1024.0 * 1024.0 * 1024.0),
+        "nanoseconds" => ("seconds", 1e-9),
+        "microseconds" => ("seconds", 1e-6),
+        "milliseconds" => ("seconds", 1e-3),
+        "seconds" => ("seconds", 1.0),
+        _ => return None,
+    })
+}
+
+macro_rules! metric {
+    ($name:literal, $unit:literal, $direction:ident, $description:literal) => {
+        MetricMetadata {
+            name: $name,
+            unit: $unit,
+            better_direction: BetterDirection::$direction,
+            description: $description,
+        }
+    };
+}
+
+static METRICS: &[MetricMetadata] = &[
+    metric!(
+        "context-switches",
+        "count",
+        Lower,
+        "Number of context switches during the benchmarked process"
+    ),
+    metric!(
+        "cpu-clock",
+        "milliseconds",
+        Lower,
+        "CPU time used by the benchmarked process"
+    ),
+    metric!(
+        "cpu-clock:u",
+        "milliseconds",
+        Lower,
+        "CPU time used by the benchmarked process in user space"
+    ),
+    metric!(
+        "cycles",
+        "count",
+        Lower,
+        "CPU cycles used by the benchmarked process"
+    ),
+    metric!(
+        "cycles:u",
+        "count",
+        Lower,
+        "CPU cycles used by the benchmarked process in user space"
+    ),
+    metric!(
+        "faults",
+        "count",
+        Lower,
+        "Page faults incurred by the benchmarked process"
+    ),
+    metric!(
+        "faults:u",
+        "count",
+        Lower,
+        "Page faults incurred by the benchmarked process in user space"
+    ),
+    metric!(
+        "instructions:u",
+        "count",
+        Lower,
+        "Instructions executed by the benchmarked process in user space; the \
+        least noisy of the hardware counters"
+    ),
+    metric!(
+        "max-rss",
+        "kilobytes",
+        Lower,
+        "Peak resident set size of the benchmarked process"
+    ),
+    metric!(
+        "tree-max-rss",
+        "kilobytes",
+        Lower,
+        "Peak combined resident set size of the benchmarked process and its \
+        child processes; recorded for rustdoc, which can spawn rustc"
+    ),
+    metric!(
+        "task-clock",
+        "milliseconds",
+        Lower,
+        "CPU time used by the benchmarked task"
+    ),
+    metric!(
+        "task-clock:u",
+        "milliseconds",
+        Lower,
+        "CPU time used by the benchmarked task in user space"
+    ),
+    metric!(
+        "wall-time",
+        "seconds",
+        Lower,
+        "Elapsed wall-clock time"
+    ),
+    metric!(
+        "time-to-first-diagnostic",
+        "seconds",
+        Lower,
+        "Elapsed wall-clock time until rustc first wrote to stderr during a \
+        check build (its first diagnostic, or the artifact notification once \
+        metadata is ready); approximates how long an IDE waits for feedback"
+    ),
+    metric!(
+        "branch-misses",
+        "count",
+        Lower,
+        "Branch mispredictions during the benchmarked process"
+    ),
+    metric!(
+        "cache-misses",
+        "count",
+        Lower,
+        "Cache misses during the benchmarked process"
+    ),
+    metric!(
+        "mono-items",
+        "count",
+        Lower,
+        "Number of distinct monomorphization items produced during codegen"
+    ),
+    metric!(
+        "mono-item-instantiations",
+        "count",
+        Lower,
+        "Total number of instantiated copies across all monomorphization \
+        items; an explosion here often explains a codegen-time regression"
+    ),
+    metric!(
+        "size:codegen_unit_size_estimate",
+        "count",
+        Lower,
+        "Rustc's estimate of codegen unit size, based on MIR count"
+    ),
+    metric!(
+        "size:cgu_instructions",
+        "count",
+        Lower,
+        "LLVM IR instruction count of codegen units, the real size of a CGU"
+    ),
+    metric!(
+        "size:dep_graph",
+        "bytes",
+        Lower,
+        "Size of the incremental compilation dependency graph on disk"
+    ),
+    metric!(
+        "size:linked_artifact",
+        "bytes",
+        Lower,
+        "Size of the final linked artifact"
+    ),
+    metric!(
+        "size:object_file",
+        "bytes",
+        Lower,
+        "Size of the generated object files"
+    ),
+    metric!(
+        "size:query_cache",
+        "bytes",
+        Lower,
+        "Size of the incremental compilation query cache on disk"
+    ),
+    metric!(
+        "size:work_product_index",
+        "bytes",
+        Lower,
+        "Size of the incremental compilation work product index on disk"
+    ),
+    metric!(
+        "size:incr_cache_bytes",
+        "bytes",
+        Lower,
+        "Total on-disk size of the incremental compilation cache directory"
+    ),
+    metric!(
+        "size:incr_cache_files_count",
+        "count",
+        Lower,
+        "Number of files in the incremental compilation cache directory"
+    ),
+    metric!(
+        "size:incr_dep_graph_bytes",
+        "bytes",
+        Lower,
+        "On-disk size of the serialized dep-graph files in the incremental cache"
+    ),
+    metric!(
+        "size:crate_metadata",
+        "bytes",
+        Lower,
+        "Size of the generated crate metadata"
+    ),
+    metric!(
+        "size:dwo_file",
+        "bytes",
+        Lower,
+        "Size of the generated split debug info files"
+    ),
+    metric!(
+        "size:assembly_file",
+        "bytes",
+        Lower,
+        "Size of the generated assembly files"
+    ),
+    metric!(
+        "size:llvm_bitcode",
+        "bytes",
+        Lower,
+        "Size of the generated LLVM bitcode"
+    ),
+    metric!(
+        "size:llvm_ir",
+        "bytes",
+        Lower,
+        "Size of the generated LLVM IR"
+    ),
+    metric!(
+        "size:doc_bytes",
+        "bytes",
+        Lower,
+        "Total size of a generated documentation directory"
+    ),
+    metric!(
+        "size:doc_files_count",
+        "count",
+        Lower,
+        "Number of files inside a generated documentation directory"
+    ),
+    metric!(
+        "cargo-overhead",
+        "seconds",
+        Lower,
+        "Time cargo spent on dependency resolution and fingerprinting before \
+        invoking the final rustc; recorded for full scenarios so cargo-side \
+        harness changes are distinguishable from compiler changes"
+    ),
+    metric!(
+        "collector:run-duration",
+        "seconds",
+        Lower,
+        "End-to-end duration of a benchmark run, including collector overhead"
+    ),
+];
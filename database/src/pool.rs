@@ -1,5 +1,5 @@
 use crate::{ArtifactCollection, ArtifactId, ArtifactIdNumber, CompileBenchmark};
-use crate::{CollectionId, Index, Profile, QueuedCommit, Scenario, Step};
+use crate::{CollectionId, Index, Profile, QueuedCommit, Run, RunId, Scenario, Step, StepTimeline};
 use chrono::{DateTime, Utc};
 use hashbrown::HashMap;
 use std::sync::{Arc, Mutex};
@@ -26,6 +26,14 @@ pub trait Connection: Send + Sync {
     );
     async fn get_compile_benchmarks(&self) -> Vec<CompileBenchmark>;
 
+    /// Records the disk usage (in bytes) observed while benchmarking
+    /// `benchmark`, replacing any previously recorded value. Used to estimate
+    /// how much disk space future runs will need.
+    async fn record_compile_benchmark_disk_usage(&self, benchmark: &str, size_bytes: u64);
+    /// Returns the last recorded disk usage (in bytes) of each compile
+    /// benchmark.
+    async fn compile_benchmark_disk_usage(&self) -> Vec<(String, u64)>;
+
     async fn artifact_by_name(&self, artifact: &str) -> Option<ArtifactId>;
 
     /// This records the duration of a collection run, i.e., collecting all of
@@ -33,9 +41,31 @@ pub trait Connection: Send + Sync {
     async fn record_duration(&self, artifact: ArtifactIdNumber, duration: Duration);
 
     /// One collection corresponds to all gathered metrics for a single iteration of a test case.
-    async fn collection_id(&self, version: &str) -> CollectionId;
+    /// If known, `run` attributes the collection to a benchmarking run of the
+    /// artifact; collections recorded outside of a run (imports, test data)
+    /// pass `None`.
+    async fn collection_id(&self, version: &str, run: Option<RunId>) -> CollectionId;
     async fn artifact_id(&self, artifact: &ArtifactId) -> ArtifactIdNumber;
 
+    /// Starts a new benchmarking run of the given artifact, recording the
+    /// current time and the name of the collector performing it. Statistics
+    /// are attributed to the run via `collection_id`.
+    async fn start_run(&self, aid: ArtifactIdNumber, collector: &str) -> RunId;
+
+    /// Returns all benchmarking runs of the given artifact, ordered from
+    /// oldest to newest; the "latest" run is thus the last entry.
+    async fn runs_for_artifact(&self, aid: ArtifactIdNumber) -> Vec<Run>;
+
+    /// Records the relative difference between a value gathered by a
+    /// re-benchmarking run and the previously stored value of the same
+    /// series for the same artifact. The compiler did not change between
+    /// the two measurements, so these deltas are an empirical sample of
+    /// infrastructure noise.
+    async fn record_rerun_delta(&self, run: RunId, series: u32, delta: f64);
+
+    /// Returns all recorded rerun deltas as (series, delta) pairs.
+    async fn rerun_deltas(&self) -> Vec<(u32, f64)>;
+
     #[allow(clippy::too_many_arguments)]
     async fn record_statistic(
         &self,
@@ -94,6 +124,109 @@ pub trait Connection: Send + Sync {
     /// Returns the sizes of individual components of a single artifact.
     async fn get_artifact_size(&self, aid: ArtifactIdNumber) -> HashMap<String, u64>;
 
+    /// Records a piece of metadata about an artifact, such as the rustc
+    /// version string or the LLVM version it was built with.
+    async fn record_artifact_info(&self, artifact: ArtifactIdNumber, key: &str, value: &str);
+
+    /// Returns all recorded metadata of a single artifact as key/value pairs.
+    async fn get_artifact_info(&self, aid: ArtifactIdNumber) -> HashMap<String, String>;
+
+    /// Returns the value of the given artifact-info key for the most recently
+    /// benchmarked artifact that predates the given one, if any. Used to
+    /// detect transitions (e.g. LLVM upgrades) between consecutive artifacts.
+    async fn previous_artifact_info(&self, aid: ArtifactIdNumber, key: &str) -> Option<String>;
+
+    /// Returns the value of the given artifact-info key for every artifact
+    /// that has one recorded. Used to graph derived per-artifact metrics
+    /// (e.g. the latency SLO share) over time.
+    async fn artifact_info_for_key(&self, key: &str) -> HashMap<ArtifactIdNumber, String>;
+
+    /// Records an annotation for the given artifact. At most one annotation
+    /// of each kind is kept per artifact; recording the same kind again
+    /// overwrites the message.
+    async fn record_annotation(&self, aid: ArtifactIdNumber, kind: &str, message: &str);
+
+    /// Returns all annotations, ordered by artifact date.
+    async fn annotations(&self) -> Vec<crate::Annotation>;
+
+    /// Stores a saved view: an opaque serialized query shared by URL slug.
+    /// Overwrites a previously saved query of the same name.
+    async fn save_query(&self, name: &str, query: &str);
+
+    /// Returns all saved views as (name, serialized query) pairs.
+    async fn saved_queries(&self) -> Vec<(String, String)>;
+
+    /// Returns the saved view of the given name, if any.
+    async fn get_saved_query(&self, name: &str) -> Option<String>;
+
+    /// Adds a digest subscription for the given target.
+    async fn add_subscription(&self, kind: &str, target: &str, benchmark: &str);
+
+    /// Returns all digest subscriptions.
+    async fn subscriptions(&self) -> Vec<crate::Subscription>;
+
+    /// Appends an entry to the audit log. The log records every mutating
+    /// operation with actor, timestamp and a JSON payload; it is append-only
+    /// and never purged.
+    async fn record_audit_event(&self, actor: &str, action: &str, payload: &str);
+
+    /// Returns the most recent audit log entries, newest first.
+    async fn audit_log(&self, limit: u32) -> Vec<crate::AuditEvent>;
+
+    /// Returns a previously computed comparison summary for the given artifact
+    /// pair and metric, if any. The summary is an opaque blob that is
+    /// serialized and deserialized by the site.
+    async fn get_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+    ) -> Option<Vec<u8>>;
+
+    /// Stores a computed comparison summary for the given artifact pair and
+    /// metric, replacing any previously stored summary.
+    async fn record_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+        summary: &[u8],
+    );
+
+    /// Removes all cached comparison summaries involving the given artifact.
+    ///
+    /// This is invoked when an artifact is (re-)benchmarked, since new data
+    /// invalidates any summary computed from the old data.
+    async fn purge_comparison_summaries(&self, aid: ArtifactIdNumber);
+
+    /// Removes cached comparison summaries that were computed more than
+    /// `retention` ago. They are cheap to recompute if an old comparison is
+    /// viewed again.
+    async fn purge_stale_comparison_summaries(&self, retention: Duration);
+
+    /// Marks the artifact as a suspected noise run, i.e. a collection whose
+    /// results were likely skewed by an environment issue (e.g. thermal
+    /// throttling) rather than a change in the compiler.
+    async fn mark_suspected_noise(&self, aid: ArtifactIdNumber);
+
+    /// Returns all artifacts currently marked as suspected noise runs.
+    async fn suspected_noise_artifacts(&self) -> Vec<ArtifactIdNumber>;
+
+    /// Queues a request to re-run a single test case of an already
+    /// benchmarked artifact with self-profiling enabled.
+    async fn queue_self_profile_request(
+        &self,
+        artifact: &str,
+        benchmark: &str,
+        profile: &str,
+        scenario: &str,
+    );
+
+    /// Pops the oldest pending self-profile request, marking it as
+    /// dispatched. A dispatched request is never handed out again; if the
+    /// resulting run fails, the request has to be queued anew.
+    async fn dequeue_self_profile_request(&self) -> Option<crate::SelfProfileRequest>;
+
     /// Returns vector of bootstrap build times for the given artifacts. The kth
     /// element is the minimum build time for the kth artifact in `aids`, across
     /// all collections for the artifact, or none if there is no bootstrap data
@@ -118,11 +251,31 @@ pub trait Connection: Send + Sync {
         pstat_series_row_ids: &[u32],
         artifact_row_id: &[Option<ArtifactIdNumber>],
     ) -> Vec<Vec<Option<f64>>>;
+    /// Like `get_pstats` for a single artifact, but restricted to the
+    /// collections of one benchmarking run. `get_pstats` aggregates over all
+    /// runs of an artifact; combined with `runs_for_artifact` this allows
+    /// selecting a specific run or the latest one instead.
+    async fn get_pstats_for_run(
+        &self,
+        pstat_series_row_ids: &[u32],
+        artifact_row_id: ArtifactIdNumber,
+        run: RunId,
+    ) -> Vec<Option<f64>>;
     async fn get_runtime_pstats(
         &self,
         runtime_pstat_series_row_ids: &[u32],
         artifact_row_id: &[Option<ArtifactIdNumber>],
     ) -> Vec<Vec<Option<f64>>>;
+    /// Returns `(benchmark, value)` pairs for every compile-time test case of
+    /// the given artifact matching the metric, profile and scenario, with the
+    /// value aggregated over collections the same way `get_pstats` does.
+    async fn artifact_compile_statistics(
+        &self,
+        artifact_row_id: ArtifactIdNumber,
+        metric: &str,
+        profile: &str,
+        scenario: &str,
+    ) -> Vec<(String, f64)>;
     async fn get_error(&self, artifact_row_id: ArtifactIdNumber) -> HashMap<String, String>;
 
     async fn queue_pr(
@@ -143,6 +296,40 @@ pub trait Connection: Send + Sync {
     async fn queued_commits(&self) -> Vec<QueuedCommit>;
     async fn mark_complete(&self, sha: &str) -> Option<QueuedCommit>;
 
+    /// Adds an artifact to the blacklist, updating the reason if it is
+    /// already listed. Blacklisted artifacts are excluded from the pending
+    /// benchmark queue, so known-broken commits (e.g. ones that ICE during
+    /// bootstrap) stop being re-enqueued on every queue refresh.
+    async fn blacklist_artifact(&self, name: &str, reason: Option<&str>);
+    /// Removes an artifact from the blacklist. Returns false if it was not
+    /// blacklisted.
+    async fn unblacklist_artifact(&self, name: &str) -> bool;
+    /// Returns all blacklisted artifacts with their reasons, sorted by name.
+    async fn blacklisted_artifacts(&self) -> Vec<(String, Option<String>)>;
+
+    /// Deletes all results gathered for the given artifact and, if it was a
+    /// queued PR build, marks the build as incomplete again so that the
+    /// artifact is re-enqueued for benchmarking.
+    ///
+    /// Used to recover when an environment issue on a collector corrupted a
+    /// run.
+    async fn purge_artifact(&self, aid: &ArtifactId);
+
+    /// Marks an artifact's results as suspect, returning true if it was not
+    /// already marked. Used to re-benchmark an artifact at most once when its
+    /// results fail the ingestion-time sanity checks.
+    async fn mark_artifact_suspect(&self, name: &str) -> bool;
+
+    /// Returns, per table, how many rows record results or errors for the
+    /// given compile benchmark. Used to preview what
+    /// [`Connection::remove_compile_benchmark`] would delete.
+    async fn compile_benchmark_row_counts(&self, benchmark: &str) -> Vec<(String, u32)>;
+
+    /// Deletes the given compile benchmark and every row referencing it:
+    /// measurements, self-profile data and errors. Call this through
+    /// [`Connection::transaction`] so that a partial removal is rolled back.
+    async fn remove_compile_benchmark(&self, benchmark: &str);
+
     // Collector status API
 
     async fn collector_start(&self, aid: ArtifactIdNumber, steps: &[String]);
@@ -156,6 +343,23 @@ pub trait Connection: Send + Sync {
 
     async fn in_progress_steps(&self, aid: &ArtifactId) -> Vec<Step>;
 
+    /// Returns the recorded start/end timestamps of every step for the given
+    /// artifact, ordered by start time.
+    async fn collector_step_timings(&self, aid: ArtifactIdNumber) -> Vec<StepTimeline>;
+
+    /// Records a step that was timed outside of the regular
+    /// `collector_start_step`/`collector_end_step` machinery, such as harness
+    /// work (e.g. downloading the toolchain) that happens before the
+    /// collection proper starts. Completed rows survive `collector_start`,
+    /// which only cleans out unterminated steps.
+    async fn record_step_timing(
+        &self,
+        aid: ArtifactIdNumber,
+        step: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    );
+
     async fn last_artifact_collection(&self) -> Option<ArtifactCollection>;
 
     /// Returns the sha of the parent commit, if available.
@@ -168,6 +372,10 @@ pub trait Connection: Send + Sync {
     /// (Currently only works for try commits)
     async fn pr_of(&self, sha: &str) -> Option<u32>;
 
+    /// Returns all benchmark builds ever requested for the given PR (try
+    /// builds and the merged master commit), most recently requested first.
+    async fn pr_builds(&self, pr: u32) -> Vec<crate::PrBuild>;
+
     /// Returns the collection ids corresponding to the query. Usually just one.
     ///
     /// Currently only supported by postgres (sqlite does not store self-profile
@@ -179,6 +387,14 @@ pub trait Connection: Send + Sync {
         profile: &str,
         cache: &str,
     ) -> Vec<(ArtifactIdNumber, i32)>;
+
+    /// Sums the self-profile query data recorded for the given artifact over
+    /// every benchmark, profile and scenario, per query label. Multiple
+    /// iterations of one test case are averaged before summing.
+    async fn aggregate_self_profile_queries(
+        &self,
+        aid: ArtifactIdNumber,
+    ) -> Vec<crate::QueryAggregate>;
 }
 
 #[async_trait::async_trait]
@@ -197,6 +413,9 @@ pub trait ConnectionManager {
     async fn is_valid(&self, c: &mut Self::Connection) -> bool;
 }
 
+/// The maximum number of simultaneously open connections per pool.
+const POOL_SIZE: usize = 16;
+
 pub struct ConnectionPool<M: ConnectionManager> {
     connections: Arc<Mutex<Vec<M::Connection>>>,
     permits: Arc<Semaphore>,
@@ -239,8 +458,8 @@ where
 {
     fn new(manager: M) -> Self {
         ConnectionPool {
-            connections: Arc::new(Mutex::new(Vec::with_capacity(16))),
-            permits: Arc::new(Semaphore::new(16)),
+            connections: Arc::new(Mutex::new(Vec::with_capacity(POOL_SIZE))),
+            permits: Arc::new(Semaphore::new(POOL_SIZE)),
             manager,
         }
     }
@@ -272,6 +491,24 @@ where
             permit,
         }
     }
+
+    /// Waits for all checked-out connections to be returned and closes the
+    /// pooled ones. Further `get` calls will hang, so this must only be
+    /// called once the pool's users are done (e.g. during shutdown).
+    async fn shutdown(&self) {
+        // Each checked-out connection holds one permit, so holding every
+        // permit means no connection is in use.
+        let _permits = self
+            .permits
+            .clone()
+            .acquire_many_owned(POOL_SIZE as u32)
+            .await
+            .unwrap();
+        self.connections
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
 }
 
 pub enum Pool {
@@ -294,4 +531,13 @@ impl Pool {
             Pool::Sqlite(ConnectionPool::new(sqlite::Sqlite::new(uri.into())))
         }
     }
+
+    /// Waits for in-flight connections to be returned and closes the pool.
+    /// Must only be called once the pool's users are done.
+    pub async fn shutdown(&self) {
+        match self {
+            Pool::Sqlite(p) => p.shutdown().await,
+            Pool::Postgres(p) => p.shutdown().await,
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::pool::{Connection, ConnectionManager, ManagedConnection, Transaction};
 use crate::{
     ArtifactCollection, ArtifactId, ArtifactIdNumber, Benchmark, CollectionId, Commit, CommitType,
-    CompileBenchmark, Date, Index, Profile, QueuedCommit, Scenario,
+    CompileBenchmark, Date, Index, Profile, QueuedCommit, Run, RunId, Scenario,
 };
 use anyhow::Context as _;
 use chrono::{DateTime, TimeZone, Utc};
@@ -254,6 +254,114 @@ static MIGRATIONS: &[&str] = &[
         UNIQUE(aid, component)
     );
     "#,
+    r#"
+    create table comparison_summary(
+        aid_a integer not null references artifact(id) on delete cascade on update cascade,
+        aid_b integer not null references artifact(id) on delete cascade on update cascade,
+        metric text not null,
+        date_computed timestamptz not null,
+        summary bytea not null,
+        PRIMARY KEY(aid_a, aid_b, metric)
+    );
+    "#,
+    r#"
+    create table suspected_noise(
+        aid integer primary key not null references artifact(id) on delete cascade on update cascade,
+        date_detected timestamptz not null
+    );
+    "#,
+    r#"
+    create table self_profile_request(
+        id serial primary key,
+        artifact text not null,
+        benchmark text not null,
+        profile text not null,
+        scenario text not null,
+        requested_at timestamptz not null,
+        dispatched_at timestamptz
+    );
+    "#,
+    r#"
+    create table artifact_info(
+        aid integer not null references artifact(id) on delete cascade on update cascade,
+        key text not null,
+        value text not null,
+        UNIQUE(aid, key)
+    );
+    "#,
+    r#"
+    create table saved_query(
+        name text primary key not null,
+        query text not null,
+        created_at timestamptz not null
+    );
+    "#,
+    r#"
+    create table subscription(
+        id serial primary key,
+        kind text not null,
+        target text not null,
+        benchmark text not null,
+        created_at timestamptz not null
+    );
+    "#,
+    r#"
+    create table audit_log(
+        id serial primary key,
+        occurred_at timestamptz not null,
+        actor text not null,
+        action text not null,
+        payload text not null
+    );
+    "#,
+    r#"
+    create table annotation(
+        id serial primary key,
+        aid integer not null references artifact(id) on delete cascade on update cascade,
+        kind text not null,
+        message text not null,
+        UNIQUE(aid, kind)
+    );
+    "#,
+    r#"
+    create table run(
+        id serial primary key,
+        aid integer not null references artifact(id) on delete cascade on update cascade,
+        started_at timestamptz not null,
+        collector text not null
+    );
+    alter table collection add column run integer references run(id) on delete set null;
+    "#,
+    r#"
+    create table rerun_delta(
+        run integer references run(id) on delete cascade on update cascade,
+        series integer references pstat_series(id) on delete cascade on update cascade,
+        delta double precision not null,
+        PRIMARY KEY(run, series)
+    );
+    "#,
+    r#"
+    create table artifact_blacklist(
+        name text primary key,
+        reason text
+    );
+    "#,
+    // No foreign key to artifact: the marker must survive the artifact being
+    // purged for a re-run.
+    r#"
+    create table suspect_artifact(
+        name text primary key,
+        suspected_at timestamptz not null
+    );
+    "#,
+    // Used by the collector to estimate how much disk space a run will need.
+    r#"
+    create table benchmark_disk_usage(
+        benchmark text primary key,
+        size_bytes bigint not null,
+        recorded_at timestamptz not null
+    );
+    "#,
 ];
 
 #[async_trait::async_trait]
@@ -463,7 +571,7 @@ impl PostgresConnection {
                 insert_self_query_series: conn.prepare("insert into self_profile_query_series (crate, profile, cache, query) VALUES ($1, $2, $3, $4) ON CONFLICT DO NOTHING RETURNING id").await.unwrap(),
                 insert_pstat_series: conn.prepare("insert into pstat_series (crate, profile, cache, statistic) VALUES ($1, $2, $3, $4) ON CONFLICT DO NOTHING RETURNING id").await.unwrap(),
                 select_pstat_series: conn.prepare("select id from pstat_series where crate = $1 and profile = $2 and cache = $3 and statistic = $4").await.unwrap(),
-                collection_id: conn.prepare("insert into collection (perf_commit) VALUES ($1) returning id").await.unwrap(),
+                collection_id: conn.prepare("insert into collection (perf_commit, run) VALUES ($1, $2) returning id").await.unwrap(),
                 record_duration: conn.prepare("
                     insert into artifact_collection_duration (
                         aid,
@@ -688,6 +796,43 @@ where
             .map(|row| row.get::<_, Vec<Option<f64>>>(0))
             .collect()
     }
+    async fn get_pstats_for_run(
+        &self,
+        pstat_series_row_ids: &[u32],
+        artifact_row_id: ArtifactIdNumber,
+        run: RunId,
+    ) -> Vec<Option<f64>> {
+        let pstat_series_row_ids = pstat_series_row_ids
+            .iter()
+            .map(|sid| *sid as i32)
+            .collect::<Vec<_>>();
+        let rows = self
+            .conn()
+            .query(
+                "select pstat.series, min(pstat.value)
+                    from pstat, collection
+                    where pstat.cid = collection.id
+                        and collection.run = $3
+                        and pstat.aid = $2
+                        and pstat.series = any($1::int[])
+                    group by pstat.series",
+                &[
+                    &pstat_series_row_ids,
+                    &(artifact_row_id.0 as i32),
+                    &run.0,
+                ],
+            )
+            .await
+            .unwrap();
+        let by_series: HashMap<i32, f64> = rows
+            .into_iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect();
+        pstat_series_row_ids
+            .iter()
+            .map(|sid| by_series.get(sid).copied())
+            .collect()
+    }
     async fn get_runtime_pstats(
         &self,
         runtime_pstat_series_row_ids: &[u32],
@@ -713,6 +858,32 @@ where
             .map(|row| row.get::<_, Vec<Option<f64>>>(0))
             .collect()
     }
+    async fn artifact_compile_statistics(
+        &self,
+        artifact_row_id: crate::ArtifactIdNumber,
+        metric: &str,
+        profile: &str,
+        scenario: &str,
+    ) -> Vec<(String, f64)> {
+        let rows = self
+            .conn()
+            .query(
+                "select pstat_series.crate, min(pstat.value)
+                    from pstat
+                    join pstat_series on pstat_series.id = pstat.series
+                    where pstat.aid = $1
+                        and pstat_series.statistic = $2
+                        and pstat_series.profile = $3
+                        and pstat_series.cache = $4
+                    group by pstat_series.crate",
+                &[&(artifact_row_id.0 as i32), &metric, &profile, &scenario],
+            )
+            .await
+            .unwrap();
+        rows.into_iter()
+            .map(|row| (row.get::<_, String>(0), row.get::<_, f64>(1)))
+            .collect()
+    }
     async fn get_error(&self, artifact_row_id: crate::ArtifactIdNumber) -> HashMap<String, String> {
         let rows = self
             .conn()
@@ -800,16 +971,178 @@ where
             commit_date: row.get::<_, Option<_>>(6).map(Date),
         })
     }
-    async fn collection_id(&self, version: &str) -> CollectionId {
+    async fn blacklist_artifact(&self, name: &str, reason: Option<&str>) {
+        self.conn()
+            .execute(
+                "insert into artifact_blacklist (name, reason) VALUES ($1, $2)
+                ON CONFLICT (name) DO UPDATE SET reason = EXCLUDED.reason",
+                &[&name, &reason],
+            )
+            .await
+            .unwrap();
+    }
+    async fn unblacklist_artifact(&self, name: &str) -> bool {
+        self.conn()
+            .execute("delete from artifact_blacklist where name = $1", &[&name])
+            .await
+            .unwrap()
+            > 0
+    }
+    async fn blacklisted_artifacts(&self) -> Vec<(String, Option<String>)> {
+        self.conn()
+            .query(
+                "select name, reason from artifact_blacklist order by name",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect()
+    }
+    async fn record_compile_benchmark_disk_usage(&self, benchmark: &str, size_bytes: u64) {
+        self.conn()
+            .execute(
+                "insert into benchmark_disk_usage (benchmark, size_bytes, recorded_at) \
+                VALUES ($1, $2, CURRENT_TIMESTAMP) \
+                ON CONFLICT (benchmark) DO UPDATE SET \
+                size_bytes = EXCLUDED.size_bytes, recorded_at = EXCLUDED.recorded_at",
+                &[&benchmark, &(size_bytes as i64)],
+            )
+            .await
+            .unwrap();
+    }
+    async fn compile_benchmark_disk_usage(&self) -> Vec<(String, u64)> {
+        self.conn()
+            .query(
+                "select benchmark, size_bytes from benchmark_disk_usage",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get(0), row.get::<_, i64>(1) as u64))
+            .collect()
+    }
+    async fn record_audit_event(&self, actor: &str, action: &str, payload: &str) {
+        self.conn()
+            .execute(
+                "insert into audit_log (occurred_at, actor, action, payload) \
+                VALUES (CURRENT_TIMESTAMP, $1, $2, $3)",
+                &[&actor, &action, &payload],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn audit_log(&self, limit: u32) -> Vec<crate::AuditEvent> {
+        self.conn()
+            .query(
+                "select id, occurred_at, actor, action, payload from audit_log
+                order by id desc limit $1",
+                &[&(limit as i64)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::AuditEvent {
+                id: row.get::<_, i32>(0) as u32,
+                occurred_at: Date(row.get(1)),
+                actor: row.get(2),
+                action: row.get(3),
+                payload: row.get(4),
+            })
+            .collect()
+    }
+
+    async fn pr_builds(&self, pr: u32) -> Vec<crate::PrBuild> {
+        self.conn()
+            .query(
+                "select pr, bors_sha, parent_sha, complete, requested from pull_request_build
+                where pr = $1
+                order by requested desc",
+                &[&(pr as i32)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::PrBuild {
+                pr: row.get::<_, i32>(0) as u32,
+                sha: row.get(1),
+                parent_sha: row.get(2),
+                complete: row.get(3),
+                requested: row.get::<_, Option<_>>(4).map(Date),
+            })
+            .collect()
+    }
+    async fn collection_id(&self, version: &str, run: Option<RunId>) -> CollectionId {
         CollectionId(
             self.conn()
-                .query_one(&self.statements().collection_id, &[&version.trim()])
+                .query_one(
+                    &self.statements().collection_id,
+                    &[&version.trim(), &run.map(|r| r.0)],
+                )
                 .await
                 .unwrap()
                 .get(0),
         )
     }
 
+    async fn start_run(&self, aid: ArtifactIdNumber, collector: &str) -> RunId {
+        RunId(
+            self.conn()
+                .query_one(
+                    "insert into run (aid, started_at, collector)
+                        VALUES ($1, CURRENT_TIMESTAMP, $2)
+                        returning id",
+                    &[&(aid.0 as i32), &collector],
+                )
+                .await
+                .unwrap()
+                .get(0),
+        )
+    }
+
+    async fn runs_for_artifact(&self, aid: ArtifactIdNumber) -> Vec<Run> {
+        self.conn()
+            .query(
+                "select id, started_at, collector from run
+                    where aid = $1
+                    order by started_at asc, id asc",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| Run {
+                id: RunId(row.get(0)),
+                started_at: row.get(1),
+                collector: row.get(2),
+            })
+            .collect()
+    }
+
+    async fn record_rerun_delta(&self, run: RunId, series: u32, delta: f64) {
+        self.conn()
+            .execute(
+                "insert into rerun_delta (run, series, delta) VALUES ($1, $2, $3)
+                    ON CONFLICT (run, series) DO UPDATE SET delta = EXCLUDED.delta",
+                &[&run.0, &(series as i32), &delta],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn rerun_deltas(&self) -> Vec<(u32, f64)> {
+        self.conn()
+            .query("select series, delta from rerun_delta", &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get::<_, i32>(0) as u32, row.get(1)))
+            .collect()
+    }
+
     async fn record_statistic(
         &self,
         collection: CollectionId,
@@ -947,6 +1280,281 @@ where
             .collect()
     }
 
+    async fn record_artifact_info(&self, artifact: ArtifactIdNumber, key: &str, value: &str) {
+        self.conn()
+            .execute(
+                "insert into artifact_info (aid, key, value) \
+                VALUES ($1, $2, $3) \
+                ON CONFLICT (aid, key) DO UPDATE SET value = EXCLUDED.value",
+                &[&(artifact.0 as i32), &key, &value],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn get_artifact_info(&self, aid: ArtifactIdNumber) -> HashMap<String, String> {
+        self.conn()
+            .query(
+                "select key, value from artifact_info where aid = $1",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get::<_, String>(0), row.get::<_, String>(1)))
+            .collect()
+    }
+
+    async fn previous_artifact_info(&self, aid: ArtifactIdNumber, key: &str) -> Option<String> {
+        self.conn()
+            .query_opt(
+                "select artifact_info.value from artifact_info, artifact
+                    where artifact_info.aid = artifact.id
+                        and artifact_info.key = $1
+                        and artifact.date is not null
+                        and artifact.date < (select date from artifact where id = $2)
+                    order by artifact.date desc
+                    limit 1",
+                &[&key, &(aid.0 as i32)],
+            )
+            .await
+            .unwrap()
+            .map(|row| row.get(0))
+    }
+
+    async fn artifact_info_for_key(&self, key: &str) -> HashMap<ArtifactIdNumber, String> {
+        self.conn()
+            .query(
+                "select aid, value from artifact_info where key = $1",
+                &[&key],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| {
+                (
+                    ArtifactIdNumber(row.get::<_, i32>(0) as u32),
+                    row.get::<_, String>(1),
+                )
+            })
+            .collect()
+    }
+
+    async fn record_annotation(&self, aid: ArtifactIdNumber, kind: &str, message: &str) {
+        self.conn()
+            .execute(
+                "insert into annotation (aid, kind, message) \
+                VALUES ($1, $2, $3) \
+                ON CONFLICT (aid, kind) DO UPDATE SET message = EXCLUDED.message",
+                &[&(aid.0 as i32), &kind, &message],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn annotations(&self) -> Vec<crate::Annotation> {
+        self.conn()
+            .query(
+                "select artifact.name, annotation.kind, annotation.message
+                    from annotation, artifact
+                    where annotation.aid = artifact.id
+                    order by artifact.date",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::Annotation {
+                artifact: row.get(0),
+                kind: row.get(1),
+                message: row.get(2),
+            })
+            .collect()
+    }
+
+    async fn save_query(&self, name: &str, query: &str) {
+        self.conn()
+            .execute(
+                "insert into saved_query (name, query, created_at) \
+                VALUES ($1, $2, CURRENT_TIMESTAMP) \
+                ON CONFLICT (name) DO UPDATE SET \
+                query = EXCLUDED.query, created_at = EXCLUDED.created_at",
+                &[&name, &query],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn saved_queries(&self) -> Vec<(String, String)> {
+        self.conn()
+            .query("select name, query from saved_query order by name", &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get::<_, String>(0), row.get::<_, String>(1)))
+            .collect()
+    }
+
+    async fn get_saved_query(&self, name: &str) -> Option<String> {
+        self.conn()
+            .query_opt("select query from saved_query where name = $1", &[&name])
+            .await
+            .unwrap()
+            .map(|row| row.get(0))
+    }
+
+    async fn add_subscription(&self, kind: &str, target: &str, benchmark: &str) {
+        self.conn()
+            .execute(
+                "insert into subscription (kind, target, benchmark, created_at) \
+                VALUES ($1, $2, $3, CURRENT_TIMESTAMP)",
+                &[&kind, &target, &benchmark],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn subscriptions(&self) -> Vec<crate::Subscription> {
+        self.conn()
+            .query(
+                "select id, kind, target, benchmark from subscription order by id",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::Subscription {
+                id: row.get::<_, i32>(0) as u32,
+                kind: row.get(1),
+                target: row.get(2),
+                benchmark: row.get(3),
+            })
+            .collect()
+    }
+
+    async fn get_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+    ) -> Option<Vec<u8>> {
+        self.conn()
+            .query_opt(
+                "select summary from comparison_summary \
+                where aid_a = $1 and aid_b = $2 and metric = $3",
+                &[&(aid_a.0 as i32), &(aid_b.0 as i32), &metric],
+            )
+            .await
+            .unwrap()
+            .map(|r| r.get(0))
+    }
+
+    async fn record_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+        summary: &[u8],
+    ) {
+        self.conn()
+            .execute(
+                "insert into comparison_summary (aid_a, aid_b, metric, date_computed, summary) \
+                VALUES ($1, $2, $3, CURRENT_TIMESTAMP, $4) \
+                ON CONFLICT (aid_a, aid_b, metric) DO UPDATE \
+                SET date_computed = EXCLUDED.date_computed, summary = EXCLUDED.summary",
+                &[&(aid_a.0 as i32), &(aid_b.0 as i32), &metric, &summary],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn purge_comparison_summaries(&self, aid: ArtifactIdNumber) {
+        self.conn()
+            .execute(
+                "delete from comparison_summary where aid_a = $1 or aid_b = $1",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn purge_stale_comparison_summaries(&self, retention: Duration) {
+        let cutoff = Utc::now() - chrono::Duration::from_std(retention).unwrap();
+        self.conn()
+            .execute(
+                "delete from comparison_summary where date_computed < $1",
+                &[&cutoff],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn mark_suspected_noise(&self, aid: ArtifactIdNumber) {
+        self.conn()
+            .execute(
+                "insert into suspected_noise (aid, date_detected) \
+                VALUES ($1, CURRENT_TIMESTAMP) \
+                ON CONFLICT DO NOTHING",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn suspected_noise_artifacts(&self) -> Vec<ArtifactIdNumber> {
+        self.conn()
+            .query("select aid from suspected_noise", &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| ArtifactIdNumber(row.get::<_, i32>(0) as u32))
+            .collect()
+    }
+
+    async fn queue_self_profile_request(
+        &self,
+        artifact: &str,
+        benchmark: &str,
+        profile: &str,
+        scenario: &str,
+    ) {
+        self.conn()
+            .execute(
+                "insert into self_profile_request \
+                (artifact, benchmark, profile, scenario, requested_at) \
+                VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP)",
+                &[&artifact, &benchmark, &profile, &scenario],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn dequeue_self_profile_request(&self) -> Option<crate::SelfProfileRequest> {
+        let row = self
+            .conn()
+            .query_opt(
+                "update self_profile_request \
+                set dispatched_at = CURRENT_TIMESTAMP \
+                where id = ( \
+                    select id from self_profile_request \
+                    where dispatched_at is null \
+                    order by requested_at asc, id asc \
+                    limit 1 \
+                ) \
+                returning id, artifact, benchmark, profile, scenario",
+                &[],
+            )
+            .await
+            .unwrap()?;
+        Some(crate::SelfProfileRequest {
+            id: row.get::<_, i32>(0) as u32,
+            artifact: row.get(1),
+            benchmark: row.get(2),
+            profile: row.get(3),
+            scenario: row.get(4),
+        })
+    }
+
     async fn artifact_id(&self, artifact: &ArtifactId) -> ArtifactIdNumber {
         let (name, date, ty) = match artifact {
             ArtifactId::Commit(commit) => (
@@ -1092,7 +1700,97 @@ where
         }
     }
 
+    async fn purge_artifact(&self, aid: &ArtifactId) {
+        let name = match aid {
+            ArtifactId::Commit(commit) => commit.sha.clone(),
+            ArtifactId::Tag(tag) => tag.clone(),
+        };
+        // Every table with per-artifact results references artifact(id) with
+        // `on delete cascade`, so deleting the artifact row removes them all.
+        self.conn()
+            .execute("delete from artifact where name = $1", &[&name])
+            .await
+            .unwrap();
+        // If this was a queued PR build, requeue it for benchmarking.
+        self.conn()
+            .execute(
+                "update pull_request_build set complete = false where bors_sha = $1",
+                &[&name],
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn mark_artifact_suspect(&self, name: &str) -> bool {
+        self.conn()
+            .execute(
+                "insert into suspect_artifact (name, suspected_at) \
+                VALUES ($1, CURRENT_TIMESTAMP) ON CONFLICT DO NOTHING",
+                &[&name],
+            )
+            .await
+            .unwrap()
+            > 0
+    }
+
+    async fn compile_benchmark_row_counts(&self, benchmark: &str) -> Vec<(String, u32)> {
+        let queries = [
+            ("benchmark", "select count(*) from benchmark where name = $1"),
+            (
+                "pstat",
+                "select count(*) from pstat \
+                join pstat_series on pstat.series = pstat_series.id \
+                where pstat_series.crate = $1",
+            ),
+            (
+                "pstat_series",
+                "select count(*) from pstat_series where crate = $1",
+            ),
+            (
+                "self_profile_query",
+                "select count(*) from self_profile_query \
+                join self_profile_query_series on self_profile_query.series = self_profile_query_series.id \
+                where self_profile_query_series.crate = $1",
+            ),
+            (
+                "self_profile_query_series",
+                "select count(*) from self_profile_query_series where crate = $1",
+            ),
+            (
+                "raw_self_profile",
+                "select count(*) from raw_self_profile where crate = $1",
+            ),
+            ("error", "select count(*) from error where benchmark = $1"),
+        ];
+        let mut counts = Vec::with_capacity(queries.len());
+        for (table, query) in queries {
+            let row = self.conn().query_one(query, &[&benchmark]).await.unwrap();
+            counts.push((table.to_string(), row.get::<_, i64>(0) as u32));
+        }
+        counts
+    }
+
+    async fn remove_compile_benchmark(&self, benchmark: &str) {
+        // The error table has no foreign key on its benchmark column, so it
+        // needs an explicit delete.
+        self.conn()
+            .execute("delete from error where benchmark = $1", &[&benchmark])
+            .await
+            .unwrap();
+        // pstat_series, self_profile_query_series and raw_self_profile all
+        // reference benchmark(name) with `on delete cascade`, and the
+        // measurement tables cascade from their series in turn.
+        self.conn()
+            .execute("delete from benchmark where name = $1", &[&benchmark])
+            .await
+            .unwrap();
+    }
+
     async fn collector_start(&self, aid: ArtifactIdNumber, steps: &[String]) {
+        // New results are about to be gathered for this artifact, so any
+        // cached comparison summaries involving it are stale.
+        self.purge_comparison_summaries(aid).await;
+
         // Clean up -- we'll re-insert any missing things in the loop below.
         self.conn()
             .execute(
@@ -1211,6 +1909,41 @@ where
             })
             .collect()
     }
+    async fn collector_step_timings(&self, aid: ArtifactIdNumber) -> Vec<crate::StepTimeline> {
+        self.conn()
+            .query(
+                "select step, start_time, end_time from collector_progress \
+                where aid = $1 order by start_time",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::StepTimeline {
+                step: row.get(0),
+                start: row.get(1),
+                end: row.get(2),
+            })
+            .collect()
+    }
+    async fn record_step_timing(
+        &self,
+        aid: ArtifactIdNumber,
+        step: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) {
+        self.conn()
+            .execute(
+                "insert into collector_progress(aid, step, start_time, end_time) \
+                VALUES ($1, $2, $3, $4) \
+                ON CONFLICT (aid, step) DO UPDATE SET \
+                start_time = excluded.start_time, end_time = excluded.end_time",
+                &[&(aid.0 as i32), &step, &start, &end],
+            )
+            .await
+            .unwrap();
+    }
     async fn last_artifact_collection(&self) -> Option<ArtifactCollection> {
         self.conn()
             .query_opt(
@@ -1295,6 +2028,50 @@ where
             .collect()
     }
 
+    async fn aggregate_self_profile_queries(
+        &self,
+        aid: ArtifactIdNumber,
+    ) -> Vec<crate::QueryAggregate> {
+        self.conn()
+            .query(
+                "select query,
+                        sum(self_time),
+                        sum(blocked_time),
+                        sum(incremental_load_time),
+                        sum(number_of_cache_hits),
+                        sum(invocation_count)
+                    from (
+                        select series.query as query,
+                                avg(sq.self_time)::float8 as self_time,
+                                avg(sq.blocked_time)::float8 as blocked_time,
+                                avg(sq.incremental_load_time)::float8
+                                    as incremental_load_time,
+                                avg(sq.number_of_cache_hits)::float8
+                                    as number_of_cache_hits,
+                                avg(sq.invocation_count)::float8 as invocation_count
+                            from self_profile_query as sq
+                            join self_profile_query_series as series
+                                on series.id = sq.series
+                            where sq.aid = $1
+                            group by sq.series, series.query
+                    ) as per_series
+                    group by query",
+                &[&(aid.0 as i32)],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| crate::QueryAggregate {
+                label: row.get::<_, String>(0).as_str().into(),
+                self_time: Duration::from_nanos(row.get::<_, f64>(1) as u64),
+                blocked_time: Duration::from_nanos(row.get::<_, f64>(2) as u64),
+                incremental_load_time: Duration::from_nanos(row.get::<_, f64>(3) as u64),
+                number_of_cache_hits: row.get::<_, f64>(4) as u64,
+                invocation_count: row.get::<_, f64>(5) as u64,
+            })
+            .collect()
+    }
+
     async fn get_bootstrap(&self, aids: &[ArtifactIdNumber]) -> Vec<Option<Duration>> {
         let mut result = vec![None; aids.len()];
 
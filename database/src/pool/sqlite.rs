@@ -1,7 +1,7 @@
 use crate::pool::{Connection, ConnectionManager, ManagedConnection, Transaction};
 use crate::{
     ArtifactCollection, ArtifactId, Benchmark, CollectionId, Commit, CommitType, CompileBenchmark,
-    Date, Profile,
+    Date, Profile, Run, RunId,
 };
 use crate::{ArtifactIdNumber, Index, QueryDatum, QueuedCommit};
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
@@ -368,6 +368,142 @@ static MIGRATIONS: &[Migration] = &[
         );
     "#,
     ),
+    Migration::new(
+        r#"
+        create table comparison_summary(
+            aid_a integer not null references artifact(id) on delete cascade on update cascade,
+            aid_b integer not null references artifact(id) on delete cascade on update cascade,
+            metric text not null,
+            date_computed integer not null,
+            summary blob not null,
+            PRIMARY KEY(aid_a, aid_b, metric)
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table suspected_noise(
+            aid integer primary key not null references artifact(id) on delete cascade on update cascade,
+            date_detected integer not null
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table self_profile_request(
+            id integer primary key not null,
+            artifact text not null,
+            benchmark text not null,
+            profile text not null,
+            scenario text not null,
+            requested_at integer not null,
+            dispatched_at integer
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table artifact_info(
+            aid integer not null references artifact(id) on delete cascade on update cascade,
+            key text not null,
+            value text not null,
+            UNIQUE(aid, key)
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table saved_query(
+            name text primary key not null,
+            query text not null,
+            created_at integer not null
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table subscription(
+            id integer primary key not null,
+            kind text not null,
+            target text not null,
+            benchmark text not null,
+            created_at integer not null
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table audit_log(
+            id integer primary key not null,
+            occurred_at integer not null,
+            actor text not null,
+            action text not null,
+            payload text not null
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table annotation(
+            id integer primary key not null,
+            aid integer not null references artifact(id) on delete cascade on update cascade,
+            kind text not null,
+            message text not null,
+            UNIQUE(aid, kind)
+        );
+        "#,
+    ),
+    // `started_at` is unix seconds with a fractional part, since runs of the
+    // same artifact can start within the same second.
+    Migration::new(
+        r#"
+        create table run(
+            id integer primary key not null,
+            aid integer not null references artifact(id) on delete cascade on update cascade,
+            started_at real not null,
+            collector text not null
+        );
+        alter table collection add column run integer references run(id) on delete set null;
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table rerun_delta(
+            run integer references run(id) on delete cascade on update cascade,
+            series integer references pstat_series(id) on delete cascade on update cascade,
+            delta double not null,
+            PRIMARY KEY(run, series)
+        );
+        "#,
+    ),
+    Migration::new(
+        r#"
+        create table artifact_blacklist(
+            name text primary key not null,
+            reason text
+        );
+        "#,
+    ),
+    // No foreign key to artifact: the marker must survive the artifact being
+    // purged for a re-run.
+    Migration::new(
+        r#"
+        create table suspect_artifact(
+            name text primary key not null,
+            suspected_at integer not null
+        );
+        "#,
+    ),
+    // Used by the collector to estimate how much disk space a run will need.
+    Migration::new(
+        r#"
+        create table benchmark_disk_usage(
+            benchmark text primary key not null,
+            size_bytes integer not null,
+            recorded_at integer not null
+        );
+        "#,
+    ),
 ];
 
 #[async_trait::async_trait]
@@ -607,11 +743,11 @@ impl Connection for SqliteConnection {
             .execute(params![artifact.0, duration.as_secs() as i64])
             .unwrap();
     }
-    async fn collection_id(&self, version: &str) -> CollectionId {
+    async fn collection_id(&self, version: &str, run: Option<RunId>) -> CollectionId {
         let raw = self.raw_ref();
         raw.execute(
-            "insert into collection (perf_commit) values (?)",
-            params![version],
+            "insert into collection (perf_commit, run) values (?, ?)",
+            params![version, run.map(|r| r.0)],
         )
         .unwrap();
         CollectionId(
@@ -623,6 +759,66 @@ impl Connection for SqliteConnection {
             .unwrap(),
         )
     }
+    async fn start_run(&self, aid: ArtifactIdNumber, collector: &str) -> RunId {
+        let raw = self.raw_ref();
+        let started_at = Utc::now().timestamp_micros() as f64 / 1_000_000.0;
+        raw.execute(
+            "insert into run (aid, started_at, collector) values (?, ?, ?)",
+            params![aid.0, started_at, collector],
+        )
+        .unwrap();
+        RunId(
+            raw.query_row(
+                "select id from run where rowid = last_insert_rowid()",
+                params![],
+                |r| r.get(0),
+            )
+            .unwrap(),
+        )
+    }
+    async fn runs_for_artifact(&self, aid: ArtifactIdNumber) -> Vec<Run> {
+        self.raw_ref()
+            .prepare_cached(
+                "select id, started_at, collector from run
+                    where aid = ?
+                    order by started_at asc, id asc",
+            )
+            .unwrap()
+            .query_map(params![aid.0], |row| {
+                let micros = (row.get::<_, f64>(1)? * 1_000_000.0) as i64;
+                Ok(Run {
+                    id: RunId(row.get(0)?),
+                    started_at: Utc
+                        .timestamp_opt(
+                            micros.div_euclid(1_000_000),
+                            (micros.rem_euclid(1_000_000) * 1_000) as u32,
+                        )
+                        .unwrap(),
+                    collector: row.get(2)?,
+                })
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+    async fn record_rerun_delta(&self, run: RunId, series: u32, delta: f64) {
+        self.raw_ref()
+            .prepare_cached(
+                "insert or replace into rerun_delta (run, series, delta) VALUES (?, ?, ?)",
+            )
+            .unwrap()
+            .execute(params![run.0, series, delta])
+            .unwrap();
+    }
+    async fn rerun_deltas(&self) -> Vec<(u32, f64)> {
+        self.raw_ref()
+            .prepare_cached("select series, delta from rerun_delta")
+            .unwrap()
+            .query_map(params![], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
     async fn artifact_id(&self, artifact: &crate::ArtifactId) -> ArtifactIdNumber {
         let (name, date, ty) = match artifact {
             crate::ArtifactId::Commit(commit) => (
@@ -824,6 +1020,271 @@ impl Connection for SqliteConnection {
             .collect()
     }
 
+    async fn record_artifact_info(&self, artifact: ArtifactIdNumber, key: &str, value: &str) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into artifact_info (aid, key, value)\
+                values (?, ?, ?)",
+                params![&artifact.0, &key, &value],
+            )
+            .unwrap();
+    }
+
+    async fn get_artifact_info(&self, aid: ArtifactIdNumber) -> HashMap<String, String> {
+        self.raw_ref()
+            .prepare("select key, value from artifact_info where aid = ?")
+            .unwrap()
+            .query_map(params![&aid.0], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn previous_artifact_info(&self, aid: ArtifactIdNumber, key: &str) -> Option<String> {
+        self.raw_ref()
+            .prepare(
+                "select artifact_info.value from artifact_info, artifact
+                    where artifact_info.aid = artifact.id
+                        and artifact_info.key = ?
+                        and artifact.date is not null
+                        and artifact.date < (select date from artifact where id = ?)
+                    order by artifact.date desc
+                    limit 1",
+            )
+            .unwrap()
+            .query_row(params![&key, &aid.0], |row| row.get(0))
+            .optional()
+            .unwrap()
+    }
+
+    async fn artifact_info_for_key(&self, key: &str) -> HashMap<ArtifactIdNumber, String> {
+        self.raw_ref()
+            .prepare("select aid, value from artifact_info where key = ?")
+            .unwrap()
+            .query_map(params![&key], |row| {
+                Ok((
+                    ArtifactIdNumber(row.get::<_, i32>(0)? as u32),
+                    row.get::<_, String>(1)?,
+                ))
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn record_annotation(&self, aid: ArtifactIdNumber, kind: &str, message: &str) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into annotation (aid, kind, message)\
+                values (?, ?, ?)",
+                params![&aid.0, &kind, &message],
+            )
+            .unwrap();
+    }
+
+    async fn annotations(&self) -> Vec<crate::Annotation> {
+        self.raw_ref()
+            .prepare(
+                "select artifact.name, annotation.kind, annotation.message
+                    from annotation, artifact
+                    where annotation.aid = artifact.id
+                    order by artifact.date",
+            )
+            .unwrap()
+            .query_map(params![], |row| {
+                Ok(crate::Annotation {
+                    artifact: row.get(0)?,
+                    kind: row.get(1)?,
+                    message: row.get(2)?,
+                })
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn save_query(&self, name: &str, query: &str) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into saved_query (name, query, created_at)\
+                values (?, ?, strftime('%s','now'))",
+                params![&name, &query],
+            )
+            .unwrap();
+    }
+
+    async fn saved_queries(&self) -> Vec<(String, String)> {
+        self.raw_ref()
+            .prepare("select name, query from saved_query order by name")
+            .unwrap()
+            .query_map(params![], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn get_saved_query(&self, name: &str) -> Option<String> {
+        self.raw_ref()
+            .prepare("select query from saved_query where name = ?")
+            .unwrap()
+            .query_row(params![&name], |row| row.get(0))
+            .optional()
+            .unwrap()
+    }
+
+    async fn add_subscription(&self, kind: &str, target: &str, benchmark: &str) {
+        self.raw_ref()
+            .execute(
+                "insert into subscription (kind, target, benchmark, created_at)\
+                values (?, ?, ?, strftime('%s','now'))",
+                params![&kind, &target, &benchmark],
+            )
+            .unwrap();
+    }
+
+    async fn subscriptions(&self) -> Vec<crate::Subscription> {
+        self.raw_ref()
+            .prepare("select id, kind, target, benchmark from subscription order by id")
+            .unwrap()
+            .query_map(params![], |row| {
+                Ok(crate::Subscription {
+                    id: row.get(0)?,
+                    kind: row.get(1)?,
+                    target: row.get(2)?,
+                    benchmark: row.get(3)?,
+                })
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn get_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+    ) -> Option<Vec<u8>> {
+        self.raw_ref()
+            .prepare_cached(
+                "select summary from comparison_summary \
+                where aid_a = ? and aid_b = ? and metric = ?",
+            )
+            .unwrap()
+            .query_row(params![&aid_a.0, &aid_b.0, &metric], |row| row.get(0))
+            .optional()
+            .unwrap()
+    }
+
+    async fn record_comparison_summary(
+        &self,
+        aid_a: ArtifactIdNumber,
+        aid_b: ArtifactIdNumber,
+        metric: &str,
+        summary: &[u8],
+    ) {
+        self.raw_ref()
+            .prepare_cached(
+                "insert or replace into comparison_summary (aid_a, aid_b, metric, date_computed, summary) \
+                VALUES (?, ?, ?, strftime('%s','now'), ?)",
+            )
+            .unwrap()
+            .execute(params![&aid_a.0, &aid_b.0, &metric, &summary])
+            .unwrap();
+    }
+
+    async fn purge_comparison_summaries(&self, aid: ArtifactIdNumber) {
+        self.raw_ref()
+            .execute(
+                "delete from comparison_summary where aid_a = ? or aid_b = ?",
+                params![&aid.0, &aid.0],
+            )
+            .unwrap();
+    }
+
+    async fn purge_stale_comparison_summaries(&self, retention: Duration) {
+        self.raw_ref()
+            .execute(
+                "delete from comparison_summary \
+                where date_computed < strftime('%s','now') - ?",
+                params![&(retention.as_secs() as i64)],
+            )
+            .unwrap();
+    }
+
+    async fn mark_suspected_noise(&self, aid: ArtifactIdNumber) {
+        self.raw_ref()
+            .execute(
+                "insert or ignore into suspected_noise (aid, date_detected) \
+                VALUES (?, strftime('%s','now'))",
+                params![&aid.0],
+            )
+            .unwrap();
+    }
+
+    async fn suspected_noise_artifacts(&self) -> Vec<ArtifactIdNumber> {
+        self.raw_ref()
+            .prepare_cached("select aid from suspected_noise")
+            .unwrap()
+            .query_map(params![], |row| Ok(ArtifactIdNumber(row.get(0)?)))
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn queue_self_profile_request(
+        &self,
+        artifact: &str,
+        benchmark: &str,
+        profile: &str,
+        scenario: &str,
+    ) {
+        self.raw_ref()
+            .execute(
+                "insert into self_profile_request \
+                (artifact, benchmark, profile, scenario, requested_at) \
+                VALUES (?, ?, ?, ?, strftime('%s','now'))",
+                params![&artifact, &benchmark, &profile, &scenario],
+            )
+            .unwrap();
+    }
+
+    async fn dequeue_self_profile_request(&self) -> Option<crate::SelfProfileRequest> {
+        let request = self
+            .raw_ref()
+            .prepare_cached(
+                "select id, artifact, benchmark, profile, scenario \
+                from self_profile_request \
+                where dispatched_at is null \
+                order by requested_at asc, id asc \
+                limit 1",
+            )
+            .unwrap()
+            .query_row(params![], |row| {
+                Ok(crate::SelfProfileRequest {
+                    id: row.get(0)?,
+                    artifact: row.get(1)?,
+                    benchmark: row.get(2)?,
+                    profile: row.get(3)?,
+                    scenario: row.get(4)?,
+                })
+            })
+            .optional()
+            .unwrap()?;
+        self.raw_ref()
+            .execute(
+                "update self_profile_request \
+                set dispatched_at = strftime('%s','now') where id = ?",
+                params![&request.id],
+            )
+            .unwrap();
+        Some(request)
+    }
+
     async fn get_bootstrap(&self, aids: &[ArtifactIdNumber]) -> Vec<Option<Duration>> {
         aids.iter()
             .map(|aid| {
@@ -910,6 +1371,33 @@ impl Connection for SqliteConnection {
             })
             .collect()
     }
+    async fn get_pstats_for_run(
+        &self,
+        series: &[u32],
+        artifact_row_id: ArtifactIdNumber,
+        run: RunId,
+    ) -> Vec<Option<f64>> {
+        let conn = self.raw_ref();
+        let mut query = conn
+            .prepare_cached(
+                "select min(pstat.value) from pstat, collection
+                    where pstat.cid = collection.id
+                        and collection.run = ?
+                        and pstat.series = ?
+                        and pstat.aid = ?;",
+            )
+            .unwrap();
+        series
+            .iter()
+            .map(|sid| {
+                query
+                    .query_row(params![&run.0, &sid, &artifact_row_id.0], |row| row.get(0))
+                    .unwrap_or_else(|e| {
+                        panic!("{:?}: series={:?}, run={:?}", e, sid, run);
+                    })
+            })
+            .collect()
+    }
     async fn get_runtime_pstats(
         &self,
         runtime_pstat_series_row_ids: &[u32],
@@ -942,6 +1430,33 @@ impl Connection for SqliteConnection {
             })
             .collect()
     }
+    async fn artifact_compile_statistics(
+        &self,
+        artifact_row_id: ArtifactIdNumber,
+        metric: &str,
+        profile: &str,
+        scenario: &str,
+    ) -> Vec<(String, f64)> {
+        self.raw_ref()
+            .prepare_cached(
+                "select pstat_series.crate, min(pstat.value)
+                    from pstat
+                    join pstat_series on pstat_series.id = pstat.series
+                    where pstat.aid = ?
+                        and pstat_series.statistic = ?
+                        and pstat_series.profile = ?
+                        and pstat_series.cache = ?
+                    group by pstat_series.crate",
+            )
+            .unwrap()
+            .query_map(
+                params![&artifact_row_id.0, &metric, &profile, &scenario],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?)),
+            )
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
     async fn get_error(&self, aid: crate::ArtifactIdNumber) -> HashMap<String, String> {
         self.raw_ref()
             .prepare_cached("select benchmark, error from error where aid = ?")
@@ -1040,7 +1555,204 @@ impl Connection for SqliteConnection {
             .optional()
             .unwrap()
     }
+    async fn blacklist_artifact(&self, name: &str, reason: Option<&str>) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into artifact_blacklist (name, reason) VALUES (?, ?)",
+                params![&name, &reason],
+            )
+            .unwrap();
+    }
+    async fn unblacklist_artifact(&self, name: &str) -> bool {
+        self.raw_ref()
+            .execute(
+                "delete from artifact_blacklist where name = ?",
+                params![&name],
+            )
+            .unwrap()
+            > 0
+    }
+    async fn blacklisted_artifacts(&self) -> Vec<(String, Option<String>)> {
+        self.raw_ref()
+            .prepare_cached("select name, reason from artifact_blacklist order by name")
+            .unwrap()
+            .query(params![])
+            .unwrap()
+            .mapped(|row| Ok((row.get(0)?, row.get(1)?)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+    async fn record_compile_benchmark_disk_usage(&self, benchmark: &str, size_bytes: u64) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into benchmark_disk_usage (benchmark, size_bytes, recorded_at) \
+                VALUES (?, ?, strftime('%s','now'))",
+                params![&benchmark, &(size_bytes as i64)],
+            )
+            .unwrap();
+    }
+    async fn compile_benchmark_disk_usage(&self) -> Vec<(String, u64)> {
+        self.raw_ref()
+            .prepare_cached("select benchmark, size_bytes from benchmark_disk_usage")
+            .unwrap()
+            .query(params![])
+            .unwrap()
+            .mapped(|row| Ok((row.get(0)?, row.get::<_, i64>(1)? as u64)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+    async fn record_audit_event(&self, actor: &str, action: &str, payload: &str) {
+        self.raw_ref()
+            .execute(
+                "insert into audit_log (occurred_at, actor, action, payload)\
+                values (strftime('%s','now'), ?, ?, ?)",
+                params![&actor, &action, &payload],
+            )
+            .unwrap();
+    }
+
+    async fn audit_log(&self, limit: u32) -> Vec<crate::AuditEvent> {
+        self.raw_ref()
+            .prepare_cached(
+                "select id, occurred_at, actor, action, payload from audit_log
+                order by id desc limit ?",
+            )
+            .unwrap()
+            .query(params![&limit])
+            .unwrap()
+            .mapped(|row| {
+                Ok(crate::AuditEvent {
+                    id: row.get(0)?,
+                    occurred_at: Date(DateTime::from_utc(
+                        NaiveDateTime::from_timestamp_opt(row.get(1)?, 0).unwrap(),
+                        Utc,
+                    )),
+                    actor: row.get(2)?,
+                    action: row.get(3)?,
+                    payload: row.get(4)?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    async fn pr_builds(&self, pr: u32) -> Vec<crate::PrBuild> {
+        self.raw_ref()
+            .prepare_cached(
+                "select pr, bors_sha, parent_sha, complete, requested from pull_request_build
+                where pr = ?
+                order by requested desc",
+            )
+            .unwrap()
+            .query(params![&pr])
+            .unwrap()
+            .mapped(|row| {
+                Ok(crate::PrBuild {
+                    pr: row.get(0).unwrap(),
+                    sha: row.get(1).unwrap(),
+                    parent_sha: row.get(2).unwrap(),
+                    complete: row.get(3).unwrap(),
+                    requested: row.get::<_, Option<i64>>(4).unwrap().map(|timestamp| {
+                        Date(DateTime::from_utc(
+                            NaiveDateTime::from_timestamp_opt(timestamp, 0).unwrap(),
+                            Utc,
+                        ))
+                    }),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+    async fn purge_artifact(&self, aid: &ArtifactId) {
+        let name = match aid {
+            ArtifactId::Commit(commit) => commit.sha.clone(),
+            ArtifactId::Tag(tag) => tag.clone(),
+        };
+        // Every table with per-artifact results references artifact(id) with
+        // `on delete cascade`, so deleting the artifact row removes them all.
+        self.raw_ref()
+            .execute("delete from artifact where name = ?", params![&name])
+            .unwrap();
+        // If this was a queued PR build, requeue it for benchmarking.
+        self.raw_ref()
+            .execute(
+                "update pull_request_build set complete = 0 where bors_sha = ?",
+                params![&name],
+            )
+            .unwrap();
+    }
+
+    async fn mark_artifact_suspect(&self, name: &str) -> bool {
+        self.raw_ref()
+            .execute(
+                "insert or ignore into suspect_artifact (name, suspected_at) \
+                VALUES (?, strftime('%s','now'))",
+                params![&name],
+            )
+            .unwrap()
+            > 0
+    }
+
+    async fn compile_benchmark_row_counts(&self, benchmark: &str) -> Vec<(String, u32)> {
+        let queries = [
+            ("benchmark", "select count(*) from benchmark where name = ?"),
+            (
+                "pstat",
+                "select count(*) from pstat \
+                join pstat_series on pstat.series = pstat_series.id \
+                where pstat_series.crate = ?",
+            ),
+            (
+                "pstat_series",
+                "select count(*) from pstat_series where crate = ?",
+            ),
+            (
+                "self_profile_query",
+                "select count(*) from self_profile_query \
+                join self_profile_query_series on self_profile_query.series = self_profile_query_series.id \
+                where self_profile_query_series.crate = ?",
+            ),
+            (
+                "self_profile_query_series",
+                "select count(*) from self_profile_query_series where crate = ?",
+            ),
+            (
+                "raw_self_profile",
+                "select count(*) from raw_self_profile where crate = ?",
+            ),
+            ("error", "select count(*) from error where benchmark = ?"),
+        ];
+        queries
+            .into_iter()
+            .map(|(table, query)| {
+                let count: u32 = self
+                    .raw_ref()
+                    .query_row(query, params![&benchmark], |row| row.get(0))
+                    .unwrap();
+                (table.to_string(), count)
+            })
+            .collect()
+    }
+
+    async fn remove_compile_benchmark(&self, benchmark: &str) {
+        // The error table has no foreign key on its benchmark column, so it
+        // needs an explicit delete.
+        self.raw_ref()
+            .execute("delete from error where benchmark = ?", params![&benchmark])
+            .unwrap();
+        // pstat_series, self_profile_query_series and raw_self_profile all
+        // reference benchmark(name) with `on delete cascade`, and the
+        // measurement tables cascade from their series in turn.
+        self.raw_ref()
+            .execute("delete from benchmark where name = ?", params![&benchmark])
+            .unwrap();
+    }
+
     async fn collector_start(&self, aid: ArtifactIdNumber, steps: &[String]) {
+        // New results are about to be gathered for this artifact, so any
+        // cached comparison summaries involving it are stale.
+        self.purge_comparison_summaries(aid).await;
+
         // Clean out any leftover unterminated steps.
         self.raw_ref()
             .execute_batch("delete from collector_progress where start is null or end is null;")
@@ -1162,6 +1874,45 @@ impl Connection for SqliteConnection {
             .collect()
     }
 
+    async fn collector_step_timings(&self, aid: ArtifactIdNumber) -> Vec<crate::StepTimeline> {
+        self.raw_ref()
+            .prepare(
+                "select step, start, end from collector_progress \
+                where aid = ? order by start",
+            )
+            .unwrap()
+            .query_map(params![&aid.0], |row| {
+                Ok(crate::StepTimeline {
+                    step: row.get(0)?,
+                    start: row
+                        .get::<_, Option<i64>>(1)?
+                        .map(|t| Utc.timestamp_opt(t, 0).unwrap()),
+                    end: row
+                        .get::<_, Option<i64>>(2)?
+                        .map(|t| Utc.timestamp_opt(t, 0).unwrap()),
+                })
+            })
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    async fn record_step_timing(
+        &self,
+        aid: ArtifactIdNumber,
+        step: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) {
+        self.raw_ref()
+            .execute(
+                "insert or replace into collector_progress(aid, step, start, end) \
+                VALUES (?, ?, ?, ?)",
+                params![&aid.0, &step, &start.timestamp(), &end.timestamp()],
+            )
+            .unwrap();
+    }
+
     async fn last_artifact_collection(&self) -> Option<ArtifactCollection> {
         self.raw_ref()
             .query_row(
@@ -1238,4 +1989,47 @@ impl Connection for SqliteConnection {
             .collect::<Result<_, _>>()
             .unwrap()
     }
+
+    async fn aggregate_self_profile_queries(
+        &self,
+        aid: ArtifactIdNumber,
+    ) -> Vec<crate::QueryAggregate> {
+        self.raw_ref()
+            .prepare_cached(
+                "select query,
+                        sum(self_time),
+                        sum(blocked_time),
+                        sum(incremental_load_time),
+                        sum(number_of_cache_hits),
+                        sum(invocation_count)
+                    from (
+                        select series.query as query,
+                                avg(sq.self_time) as self_time,
+                                avg(sq.blocked_time) as blocked_time,
+                                avg(sq.incremental_load_time) as incremental_load_time,
+                                avg(sq.number_of_cache_hits) as number_of_cache_hits,
+                                avg(sq.invocation_count) as invocation_count
+                            from self_profile_query as sq
+                            join self_profile_query_series as series
+                                on series.id = sq.series
+                            where sq.aid = ?
+                            group by sq.series, series.query
+                    ) as per_series
+                    group by query",
+            )
+            .unwrap()
+            .query_map(params![&aid.0], |row| {
+                Ok(crate::QueryAggregate {
+                    label: row.get::<_, String>(0)?.as_str().into(),
+                    self_time: Duration::from_nanos(row.get::<_, f64>(1)? as u64),
+                    blocked_time: Duration::from_nanos(row.get::<_, f64>(2)? as u64),
+                    incremental_load_time: Duration::from_nanos(row.get::<_, f64>(3)? as u64),
+                    number_of_cache_hits: row.get::<_, f64>(4)? as u64,
+                    invocation_count: row.get::<_, f64>(5)? as u64,
+                })
+            })
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
 }
@@ -0,0 +1,142 @@
+//! A typed client for the perf.rust-lang.org HTTP API.
+//!
+//! This wraps the site's endpoints with typed methods and transparent
+//! retry/backoff, so that triage scripts, bisection tooling and external
+//! users don't have to hand-roll `reqwest` calls against JSON shapes that
+//! change whenever the frontend does.
+//!
+//! The `/api/v1/` endpoints are a stable contract; the remaining wrapped
+//! endpoints (`graphs`, `compare`, `status`) are internal to the site, and
+//! this crate is updated in lockstep when they change.
+
+use std::time::Duration;
+
+use anyhow::Context;
+
+pub mod types;
+
+use types::*;
+
+/// Delay before the first retry; doubled after each subsequent attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+pub struct Client {
+    base_url: String,
+    client: reqwest::Client,
+    max_retries: u32,
+}
+
+impl Client {
+    /// Creates a client for the site at `base_url`, e.g.
+    /// `https://perf.rust-lang.org`.
+    pub fn new(base_url: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            max_retries: 3,
+        }
+    }
+
+    /// Sets how many times a failed request is retried (default: 3).
+    /// Transport errors and 5xx responses are retried with exponential
+    /// backoff; 4xx responses fail immediately.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Returns the artifacts with benchmark results.
+    pub async fn artifacts(&self) -> anyhow::Result<Artifacts> {
+        self.get("/api/v1/artifacts", &()).await
+    }
+
+    /// Returns the benchmarks with recorded results.
+    pub async fn benchmarks(&self) -> anyhow::Result<Benchmarks> {
+        self.get("/api/v1/benchmarks", &()).await
+    }
+
+    /// Returns the recorded metrics together with their metadata.
+    pub async fn metrics(&self) -> anyhow::Result<Metrics> {
+        self.get("/api/v1/metrics", &()).await
+    }
+
+    /// Returns the known metric names and the date of the last loaded run.
+    pub async fn info(&self) -> anyhow::Result<Info> {
+        self.get("/perf/info", &()).await
+    }
+
+    /// Returns graph series for all test cases matching the request.
+    pub async fn graphs(&self, request: &GraphsRequest) -> anyhow::Result<Graphs> {
+        self.get("/perf/graphs", request).await
+    }
+
+    /// Compares the statistics of two artifacts.
+    pub async fn compare(&self, request: &CompareRequest) -> anyhow::Result<Compare> {
+        self.post("/perf/get", request).await
+    }
+
+    /// Returns the current collection status.
+    pub async fn status(&self) -> anyhow::Result<Status> {
+        self.get("/perf/status_page", &()).await
+    }
+
+    async fn get<T, Q>(&self, path: &str, query: &Q) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        Q: serde::Serialize,
+    {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .execute(|| self.client.get(&url).query(query))
+            .await
+            .with_context(|| format!("GET {} failed", url))?;
+        Ok(response.json().await?)
+    }
+
+    async fn post<T, B>(&self, path: &str, body: &B) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .execute(|| self.client.post(&url).json(body))
+            .await
+            .with_context(|| format!("POST {} failed", url))?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends the request built by `build`, retrying transport errors and 5xx
+    /// responses up to `max_retries` times with exponential backoff.
+    async fn execute(
+        &self,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> anyhow::Result<reqwest::Response> {
+        let mut delay = INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            let result = build().send().await;
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(_) => true,
+            };
+            if retryable && attempt < self.max_retries {
+                attempt += 1;
+                log::debug!(
+                    "request failed ({}), retrying in {:?} (attempt {}/{})",
+                    match &result {
+                        Ok(response) => response.status().to_string(),
+                        Err(error) => error.to_string(),
+                    },
+                    delay,
+                    attempt,
+                    self.max_retries
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                continue;
+            }
+            return Ok(result?.error_for_status()?);
+        }
+    }
+}
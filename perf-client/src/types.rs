@@ -0,0 +1,198 @@
+//! Deserialization-side mirrors of the site's API types.
+//!
+//! These structs intentionally declare only the fields the client exposes;
+//! serde ignores any additional fields the site may add, so the client keeps
+//! working when the API grows in a backwards-compatible way.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Response of `/perf/info`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Info {
+    /// Sorted list of known compile metrics.
+    pub compile_metrics: Vec<String>,
+    /// Sorted list of known runtime metrics.
+    pub runtime_metrics: Vec<String>,
+    /// Chronologically last loaded run date.
+    pub as_of: Option<DateTime<Utc>>,
+}
+
+/// A benchmarked artifact, as returned by `/api/v1/artifacts`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactCommit {
+    pub sha: String,
+    /// Seconds since the Unix epoch.
+    pub date: i64,
+    /// `true` for merged master commits, `false` for try builds.
+    pub is_master: bool,
+}
+
+/// Response of `/api/v1/artifacts`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Artifacts {
+    /// All benchmarked commits, oldest first.
+    pub commits: Vec<ArtifactCommit>,
+    /// Published release artifacts (e.g. `1.70.0`).
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompileBenchmark {
+    pub name: String,
+    pub category: String,
+}
+
+/// Response of `/api/v1/benchmarks`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Benchmarks {
+    pub compile: Vec<CompileBenchmark>,
+    /// Names of runtime benchmarks with recorded results.
+    pub runtime: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Metric {
+    pub name: String,
+    pub unit: Option<String>,
+    /// `"lower"` or `"higher"`, depending on which direction is an
+    /// improvement; `None` for unknown metrics.
+    pub better_direction: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Response of `/api/v1/metrics`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Metrics {
+    pub compile_metrics: Vec<Metric>,
+    pub runtime_metrics: Vec<Metric>,
+}
+
+/// Request for `/perf/graphs`.
+///
+/// Bounds are strings in the format the site accepts: a commit SHA, a date
+/// (`YYYY-MM-DD`), or empty for an open bound.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GraphsRequest {
+    pub start: String,
+    pub end: String,
+    pub stat: String,
+    /// One of `raw`, `percentfromfirst` or `percentrelative`.
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Unit to convert the values to, if it differs from the metric's native
+    /// unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Series {
+    /// y-values.
+    pub points: Vec<f32>,
+    /// Indices of interpolated points.
+    pub interpolated_indices: HashSet<u16>,
+}
+
+/// Response of `/perf/graphs`, keyed by benchmark, then profile, then
+/// scenario.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Graphs {
+    /// `(UTC timestamp in seconds, sha)` pairs, oldest first.
+    pub commits: Vec<(i64, String)>,
+    pub benchmarks: HashMap<String, HashMap<String, HashMap<String, Series>>>,
+    /// Shas of commits whose results are suspected to be skewed by an
+    /// environment issue on the collection machine.
+    pub suspected_noise: Vec<String>,
+    /// Unit of the raw values in the series, if known.
+    pub unit: Option<String>,
+}
+
+/// Request body for `/perf/get`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompareRequest {
+    pub start: String,
+    pub end: String,
+    /// Metric to compare, e.g. `instructions:u`.
+    pub stat: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactDescription {
+    pub commit: String,
+    pub date: Option<DateTime<Utc>>,
+    pub pr: Option<u32>,
+    pub r#type: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatComparison {
+    pub is_relevant: bool,
+    pub significance_threshold: f64,
+    pub significance_factor: f64,
+    /// The compared values for the two artifacts.
+    pub statistics: (f64, f64),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompileComparison {
+    pub benchmark: String,
+    pub profile: String,
+    pub scenario: String,
+    pub comparison: StatComparison,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuntimeComparison {
+    pub benchmark: String,
+    pub comparison: StatComparison,
+}
+
+/// Response of `/perf/get`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Compare {
+    /// The artifact preceding `a`, if any.
+    pub prev: Option<String>,
+    pub a: ArtifactDescription,
+    pub b: ArtifactDescription,
+    pub compile_comparisons: Vec<CompileComparison>,
+    pub runtime_comparisons: Vec<RuntimeComparison>,
+    pub new_errors: Vec<(String, String)>,
+    /// The artifact following `b`, if any.
+    pub next: Option<String>,
+    /// Whether `a` is the direct parent of `b`.
+    pub is_contiguous: bool,
+    /// Unit of the compared statistic values, if known.
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusCommit {
+    pub sha: String,
+    pub r#type: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkStatus {
+    pub name: String,
+    pub error: String,
+}
+
+/// Response of `/perf/status_page` (partial; the queue and in-progress
+/// details are not exposed by the client).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Status {
+    pub last_commit: Option<StatusCommit>,
+    /// Benchmarks that errored during the last collection.
+    pub benchmarks: Vec<BenchmarkStatus>,
+    /// Seconds since the epoch of the most recent finished collection, if
+    /// any.
+    pub most_recent_end: Option<i64>,
+}
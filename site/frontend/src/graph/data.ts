@@ -1,4 +1,8 @@
-export type GraphKind = "raw" | "percentfromfirst" | "percentrelative";
+export type GraphKind =
+  | "raw"
+  | "percentfromfirst"
+  | "percentrelative"
+  | "percentrollingmedian";
 
 // Parameters used to filter graph data
 export interface GraphsSelector {
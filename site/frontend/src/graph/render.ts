@@ -335,12 +335,14 @@ export function renderPlots(
         yAxisUnit = "count";
       }
 
-      if (selector.kind == "raw" && benchName == "Summary") {
+      if (selector.kind == "raw" && benchName.startsWith("Summary")) {
         yAxisUnit = "relative";
       } else if (selector.kind == "percentfromfirst") {
         yAxisUnit = "% change from first";
       } else if (selector.kind == "percentrelative") {
         yAxisUnit = "% change from previous";
+      } else if (selector.kind == "percentrollingmedian") {
+        yAxisUnit = "% change from rolling median";
       }
 
       yAxis = yAxisUnit ? `${yAxis} (${yAxisUnit})` : yAxis;
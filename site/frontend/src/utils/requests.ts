@@ -39,7 +39,13 @@ export async function postMsgpack<T>(path: string, body: any): Promise<T> {
     return decode(new Uint8Array(buffer));
   } else {
     const text = await response.text();
-    alert(text);
-    throw new Error(`Invalid response from server: ${text}`);
+    // Errors are reported as `{"error": {"kind": ..., "message": ...}}`;
+    // fall back to the raw body for anything else (e.g. proxy errors).
+    let message = text;
+    try {
+      message = JSON.parse(text)["error"]["message"] ?? text;
+    } catch {}
+    alert(message);
+    throw new Error(`Invalid response from server: ${message}`);
   }
 }
@@ -0,0 +1,168 @@
+//! Alert rules evaluated after each ingestion.
+//!
+//! Rules are configured in `[[alerts]]` sections of `site-config.toml` and
+//! watch a selection of test cases (benchmark/profile/scenario filters) on a
+//! single metric. When the watched selection regresses beyond the configured
+//! threshold for the configured number of consecutive benchmarked master
+//! commits, a webhook is fired with a link to the comparison page. The
+//! payload is a JSON object with a single `text` field, understood by Zulip
+//! and Slack incoming webhooks as well as most generic receivers.
+//!
+//! Rules are re-evaluated on every ingestion, so a rule keeps firing as long
+//! as its condition holds; receivers that want to alert only once are
+//! expected to deduplicate on the message text.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use collector::Bound;
+
+use crate::comparison::{compare, ArtifactComparison, Metric};
+use crate::load::{AlertRule, SiteCtxt};
+
+pub async fn evaluate_alerts(ctxt: &SiteCtxt) {
+    let rules = ctxt.config().alerts.clone();
+    if rules.is_empty() {
+        return;
+    }
+
+    // Benchmarked master commits, oldest first.
+    let master_commits: Vec<String> = ctxt
+        .index
+        .load()
+        .commits()
+        .into_iter()
+        .filter(|c| c.is_master())
+        .map(|c| c.sha)
+        .collect();
+
+    for rule in &rules {
+        if let Err(error) = evaluate_rule(ctxt, rule, &master_commits).await {
+            log::error!("failed to evaluate alert rule `{}`: {error:?}", rule.name);
+        }
+    }
+}
+
+async fn evaluate_rule(
+    ctxt: &SiteCtxt,
+    rule: &AlertRule,
+    master_commits: &[String],
+) -> anyhow::Result<()> {
+    let metric = rule
+        .metric
+        .parse::<Metric>()
+        .map_err(|e| anyhow::anyhow!("unknown metric {}: {e:?}", rule.metric))?;
+    if master_commits.len() < rule.consecutive + 1 {
+        return Ok(());
+    }
+
+    // Compare each of the last `consecutive` commits against the common base
+    // commit right before the window; the rule only fires for test cases
+    // that regressed in every one of those comparisons, which filters out
+    // one-off noise spikes.
+    let base = &master_commits[master_commits.len() - 1 - rule.consecutive];
+    let window = &master_commits[master_commits.len() - rule.consecutive..];
+    let mut per_commit = Vec::with_capacity(window.len());
+    for sha in window {
+        let comparison = compare(
+            Bound::Commit(base.clone()),
+            Bound::Commit(sha.clone()),
+            metric,
+            ctxt,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("comparison failed: {e}"))?
+        .with_context(|| format!("missing comparison data for {base}..{sha}"))?;
+        per_commit.push(regressing_cases(&comparison, rule));
+    }
+
+    let latest = per_commit.last().unwrap();
+    let mut fired: Vec<(&str, f64)> = latest
+        .iter()
+        .filter(|(case, _)| per_commit.iter().all(|cases| cases.contains_key(*case)))
+        .map(|(case, &change)| (case.as_str(), change))
+        .collect();
+    if fired.is_empty() {
+        return Ok(());
+    }
+    fired.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let end = master_commits.last().unwrap();
+    let url = format!(
+        "https://perf.rust-lang.org/compare.html?start={base}&end={end}&stat={}",
+        metric.as_str()
+    );
+    let (worst_case, worst_change) = fired[0];
+    let text = format!(
+        "Alert `{}`: {} test case(s) regressed by more than {}% \
+        over the last {} commit(s); worst: {} ({:+.2}%). {}",
+        rule.name,
+        fired.len(),
+        rule.threshold,
+        rule.consecutive,
+        worst_case,
+        worst_change,
+        url
+    );
+    fire_webhook(&rule.webhook, &text).await
+}
+
+/// Returns the test cases matching the rule's filters whose results
+/// regressed beyond the rule's threshold, mapped to the relative change in
+/// percent.
+fn regressing_cases(comparison: &ArtifactComparison, rule: &AlertRule) -> HashMap<String, f64> {
+    let mut cases = HashMap::new();
+    for c in &comparison.compile_comparisons {
+        if let Some(benchmark) = &rule.benchmark {
+            if c.benchmark().as_str() != benchmark {
+                continue;
+            }
+        }
+        if let Some(profile) = &rule.profile {
+            if c.profile().to_string() != *profile {
+                continue;
+            }
+        }
+        if let Some(scenario) = &rule.scenario {
+            if c.scenario().to_string() != *scenario {
+                continue;
+            }
+        }
+        let change = c.relative_change() * 100.0;
+        if change >= rule.threshold {
+            cases.insert(
+                format!("{}/{}/{}", c.benchmark(), c.profile(), c.scenario()),
+                change,
+            );
+        }
+    }
+    // Runtime benchmarks have no profile or scenario, so they can only match
+    // rules that do not filter on them.
+    if rule.profile.is_none() && rule.scenario.is_none() {
+        for c in &comparison.runtime_comparisons {
+            if let Some(benchmark) = &rule.benchmark {
+                if c.benchmark().as_str() != benchmark {
+                    continue;
+                }
+            }
+            let change = c.relative_change() * 100.0;
+            if change >= rule.threshold {
+                cases.insert(format!("runtime/{}", c.benchmark()), change);
+            }
+        }
+    }
+    cases
+}
+
+pub(crate) async fn fire_webhook(url: &str, text: &str) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+    client
+        .post(url)
+        .json(&serde_json::json!({ "text": text }))
+        .send()
+        .await
+        .context("failed to send webhook")?
+        .error_for_status()
+        .context("webhook returned an error status")?;
+    Ok(())
+}
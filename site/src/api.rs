@@ -4,9 +4,63 @@
 //!
 //! The responses are calculated in the server.rs file.
 
+use serde::Serialize;
+use std::fmt;
 use std::result::Result as StdResult;
 
-pub type ServerResult<T> = StdResult<T, String>;
+pub type ServerResult<T> = StdResult<T, RequestError>;
+
+/// A typed request-handling error.
+///
+/// The server maps each variant onto an HTTP status code and renders it as a
+/// JSON body of the form `{"error": {"kind": "...", "message": "..."}}`, so
+/// API clients can branch on the kind of failure instead of parsing message
+/// text.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum RequestError {
+    /// The request was malformed: an unparseable parameter, an invalid
+    /// selector, a range that is too wide. Maps to 400.
+    BadRequest(String),
+    /// The requested entity (commit, artifact, saved query, profile data)
+    /// does not exist. Maps to 404.
+    NotFound(String),
+    /// An upstream service the site depends on (the GitHub API, raw
+    /// self-profile storage) failed. Maps to 502.
+    Upstream(String),
+    /// The database did not produce a result in time. Maps to 503.
+    DbTimeout(String),
+    /// Any other failure while producing the response. Maps to 500.
+    Internal(String),
+}
+
+impl RequestError {
+    pub fn message(&self) -> &str {
+        match self {
+            RequestError::BadRequest(message)
+            | RequestError::NotFound(message)
+            | RequestError::Upstream(message)
+            | RequestError::DbTimeout(message)
+            | RequestError::Internal(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// Errors produced with `format!` carry no classification; treat them as
+/// internal so they keep the 500 status they had when `ServerResult` carried
+/// plain strings. Paths where the classification matters construct the
+/// variant explicitly.
+impl From<String> for RequestError {
+    fn from(message: String) -> Self {
+        RequestError::Internal(message)
+    }
+}
 
 pub mod info {
     use database::Date;
@@ -25,6 +79,31 @@ pub mod info {
     }
 }
 
+pub mod metrics {
+    use database::metric::BetterDirection;
+    use serde::Serialize;
+
+    /// A single stored metric together with its registry metadata, if any.
+    /// The metadata fields are `None` for metrics that were stored by a
+    /// collector this site does not know about.
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Metric {
+        pub name: String,
+        pub unit: Option<String>,
+        pub better_direction: Option<BetterDirection>,
+        pub description: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Response {
+        /// Sorted list of metrics stored for compile benchmarks
+        pub compile_metrics: Vec<Metric>,
+
+        /// Sorted list of metrics stored for runtime benchmarks
+        pub runtime_metrics: Vec<Metric>,
+    }
+}
+
 pub mod dashboard {
     use serde::{Deserialize, Serialize};
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -59,11 +138,50 @@ pub mod graph {
         pub start: Bound,
         pub end: Bound,
         pub kind: GraphKind,
+        /// Unit to convert the values to (e.g. `MiB`), if it differs from the
+        /// metric's native unit.
+        #[serde(default)]
+        pub unit: Option<String>,
     }
 
     #[derive(Debug, PartialEq, Clone, Serialize)]
     pub struct Response {
         pub series: Series,
+        /// Unit of the raw values in the series, if known.
+        pub unit: Option<String>,
+    }
+}
+
+pub mod sparkline {
+    use collector::Bound;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub start: Bound,
+        pub end: Bound,
+        pub stat: String,
+        pub benchmark: Option<String>,
+        pub scenario: Option<String>,
+        pub profile: Option<String>,
+    }
+
+    /// Range summary of one series. Only measured values are considered:
+    /// commits where the series has no data contribute nothing, instead of
+    /// being interpolated like in the full graph responses.
+    #[derive(Debug, PartialEq, Clone, Serialize)]
+    pub struct Summary {
+        pub first: f64,
+        pub last: f64,
+        pub min: f64,
+        pub max: f64,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Serialize)]
+    pub struct Response {
+        /// Summaries keyed by benchmark, then profile, then scenario.
+        pub summaries: HashMap<String, HashMap<String, HashMap<String, Summary>>>,
     }
 }
 
@@ -81,6 +199,22 @@ pub mod graphs {
         pub benchmark: Option<String>,
         pub scenario: Option<String>,
         pub profile: Option<String>,
+        /// Unit to convert the values to (e.g. `MiB`), if it differs from the
+        /// metric's native unit.
+        #[serde(default)]
+        pub unit: Option<String>,
+        /// Key the returned series by their stable statistic-description IDs
+        /// instead of nested benchmark/profile/scenario string maps. This
+        /// shrinks large responses substantially and lets clients join series
+        /// across endpoints without re-deriving string keys.
+        #[serde(default)]
+        pub series_ids: bool,
+        /// Overlay a second metric in the same response, plotted against its
+        /// own y-axis (e.g. `instructions:u` and `max-rss`). Its series are
+        /// reported in `secondary_benchmarks`/`secondary_series` and both
+        /// axes are described in `axes`.
+        #[serde(default)]
+        pub secondary_stat: Option<String>,
     }
 
     #[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
@@ -92,6 +226,10 @@ pub mod graphs {
         PercentFromFirst,
         // Change from the previous value, useful for looking for noise.
         PercentRelative,
+        // Change from the rolling median of the preceding points; like
+        // PercentRelative, but without amplifying the noise of a single
+        // previous point.
+        PercentRollingMedian,
     }
 
     #[derive(Debug, PartialEq, Clone, Serialize)]
@@ -100,6 +238,21 @@ pub mod graphs {
         pub points: Vec<f32>,
         // The index of interpolated coordinates
         pub interpolated_indices: HashSet<u16>,
+        /// For summary series only: per point, how many of the averaged
+        /// underlying series were interpolated at that commit. A spike whose
+        /// points are mostly interpolated can be discounted as an artifact of
+        /// missing data rather than a real change.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub interpolation_counts: Option<Vec<u16>>,
+    }
+
+    /// Metadata describing one y-axis of an overlay chart.
+    #[derive(Debug, PartialEq, Clone, Serialize)]
+    pub struct Axis {
+        /// Metric plotted against this axis.
+        pub stat: String,
+        /// Unit of the axis values, if known.
+        pub unit: Option<String>,
     }
 
     #[derive(Debug, PartialEq, Clone, Serialize)]
@@ -107,6 +260,348 @@ pub mod graphs {
         // (UTC timestamp in seconds, sha)
         pub commits: Vec<(i64, String)>,
         pub benchmarks: HashMap<String, HashMap<database::Profile, HashMap<String, Series>>>,
+        /// Series keyed by the stable statistic-description ID of their
+        /// (benchmark, profile, scenario, metric) tuple in the database. Only
+        /// filled when the request sets `series_ids`; the summary series have
+        /// no database ID and are always reported in `benchmarks`.
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub series: HashMap<database::StatisticalDescriptionId, Series>,
+        // Shas of commits whose results are suspected to be skewed by an
+        // environment issue on the collection machine (e.g. thermal
+        // throttling), so the frontend can annotate them.
+        pub suspected_noise: Vec<String>,
+        /// Per commit (same order as `commits`), the fraction of the selected
+        /// series with measured data for it, in `0.0..=1.0`. Values below 1.0
+        /// mark partially benchmarked artifacts (e.g. a half-finished run),
+        /// whose remaining points are interpolated.
+        pub completeness: Vec<f32>,
+        /// Relative weights applied to benchmarks when averaging the summary
+        /// series, taken from the benchmarks' configured summary weights.
+        /// Benchmarks not listed have weight 1.0.
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub summary_weights: HashMap<String, f64>,
+        /// Notes about summary series that could not be computed, e.g.
+        /// because the Empty-scenario baseline has no data.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub summary_warnings: Vec<String>,
+        /// Selected series with no measured data anywhere in the range; they
+        /// are omitted from the response instead of being reported as empty.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub warnings: Vec<String>,
+        /// Unit of the raw values in the series (the summary series are
+        /// unitless ratios), if known.
+        pub unit: Option<String>,
+        /// Series of the secondary metric, in the same shape as `benchmarks`.
+        /// Only filled when the request sets `secondary_stat`; the summary
+        /// series are not computed for the secondary metric.
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub secondary_benchmarks:
+            HashMap<String, HashMap<database::Profile, HashMap<String, Series>>>,
+        /// Secondary-metric counterpart of `series`, filled when the request
+        /// sets both `secondary_stat` and `series_ids`.
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub secondary_series: HashMap<database::StatisticalDescriptionId, Series>,
+        /// The y-axes of an overlay chart: axis 0 describes `benchmarks` and
+        /// `series`, axis 1 describes `secondary_benchmarks` and
+        /// `secondary_series`. Empty unless a `secondary_stat` was requested.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub axes: Vec<Axis>,
+    }
+
+    /// Compact variant of [`Response`], negotiated by sending an `Accept`
+    /// header containing `application/msgpack` to the graphs endpoint. It
+    /// carries the same content, but is encoded as msgpack with the commit
+    /// timestamps delta-encoded and the commit shas stored in a flat array,
+    /// which shrinks the full-history default query substantially.
+    #[derive(Debug, PartialEq, Clone, Serialize)]
+    pub struct CompactResponse {
+        /// Seconds since the previous commit; the first entry is an absolute
+        /// UTC timestamp in seconds.
+        pub commit_timestamp_deltas: Vec<i64>,
+        /// Commit shas, in the same order as `commit_timestamp_deltas`.
+        pub commit_shas: Vec<String>,
+        pub benchmarks: HashMap<String, HashMap<database::Profile, HashMap<String, Series>>>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub series: HashMap<database::StatisticalDescriptionId, Series>,
+        pub suspected_noise: Vec<String>,
+        pub completeness: Vec<f32>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub summary_weights: HashMap<String, f64>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub summary_warnings: Vec<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub warnings: Vec<String>,
+        pub unit: Option<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub secondary_benchmarks:
+            HashMap<String, HashMap<database::Profile, HashMap<String, Series>>>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub secondary_series: HashMap<database::StatisticalDescriptionId, Series>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub axes: Vec<Axis>,
+    }
+
+    impl From<&Response> for CompactResponse {
+        fn from(response: &Response) -> Self {
+            let mut previous = 0;
+            let mut commit_timestamp_deltas = Vec::with_capacity(response.commits.len());
+            let mut commit_shas = Vec::with_capacity(response.commits.len());
+            for (timestamp, sha) in &response.commits {
+                commit_timestamp_deltas.push(timestamp - previous);
+                previous = *timestamp;
+                commit_shas.push(sha.clone());
+            }
+            Self {
+                commit_timestamp_deltas,
+                commit_shas,
+                benchmarks: response.benchmarks.clone(),
+                series: response.series.clone(),
+                suspected_noise: response.suspected_noise.clone(),
+                completeness: response.completeness.clone(),
+                summary_weights: response.summary_weights.clone(),
+                summary_warnings: response.summary_warnings.clone(),
+                warnings: response.warnings.clone(),
+                unit: response.unit.clone(),
+                secondary_benchmarks: response.secondary_benchmarks.clone(),
+                secondary_series: response.secondary_series.clone(),
+                axes: response.axes.clone(),
+            }
+        }
+    }
+
+    /// Request for graphs where the x-axis is published release artifacts
+    /// (`1.60.0`, …, plus the latest beta) rather than master commits.
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct ReleaseRequest {
+        pub stat: String,
+        pub kind: GraphKind,
+        pub benchmark: Option<String>,
+        pub scenario: Option<String>,
+        pub profile: Option<String>,
+        /// Unit to convert the values to (e.g. `MiB`), if it differs from the
+        /// metric's native unit.
+        #[serde(default)]
+        pub unit: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Serialize)]
+    pub struct ReleaseResponse {
+        // Release tags, oldest first
+        pub releases: Vec<String>,
+        pub benchmarks: HashMap<String, HashMap<database::Profile, HashMap<String, Series>>>,
+        /// Relative weights applied to benchmarks when averaging the summary
+        /// series; benchmarks not listed have weight 1.0.
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub summary_weights: HashMap<String, f64>,
+        /// Notes about summary series that could not be computed, e.g.
+        /// because the Empty-scenario baseline has no data.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub summary_warnings: Vec<String>,
+        /// Selected series with no measured data for any release; they are
+        /// omitted from the response instead of being reported as empty.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub warnings: Vec<String>,
+        /// Unit of the raw values in the series, if known.
+        pub unit: Option<String>,
+    }
+}
+
+pub mod suite_cost {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Artifact to rank; defaults to the most recently benchmarked commit.
+        pub artifact: Option<String>,
+        /// Maximum number of entries returned.
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// Total duration of all collected test cases, in seconds.
+        pub total_seconds: f64,
+        /// Test cases sorted from most to least expensive.
+        pub test_cases: Vec<TestCaseCost>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct TestCaseCost {
+        pub benchmark: String,
+        pub profile: String,
+        pub scenario: String,
+        /// End-to-end collection time, including harness overhead.
+        pub seconds: f64,
+    }
+}
+
+pub mod selector_query {
+    //! Exposes the internal selector query engine for ad-hoc analytics,
+    //! rather than only the fixed-shape endpoints built on top of it.
+
+    use collector::Bound;
+    use serde::{Deserialize, Serialize};
+
+    /// Selects values along one dimension of a query.
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum SelectorSpec {
+        /// Every value (the default).
+        #[default]
+        All,
+        /// A single value.
+        One(String),
+        /// Any of the given values.
+        Subset(Vec<String>),
+        /// Values matching the given regular expression (anchored at both
+        /// ends).
+        Regex(String),
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Suite {
+        #[default]
+        Compile,
+        Runtime,
+    }
+
+    /// How to aggregate each series over the selected artifact range.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Aggregation {
+        /// No aggregation: return one value per artifact.
+        #[default]
+        Series,
+        Mean,
+        Min,
+        Max,
+        Sum,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Request {
+        pub start: Bound,
+        pub end: Bound,
+        /// The benchmark suite to query; defaults to compile-time benchmarks.
+        #[serde(default)]
+        pub suite: Suite,
+        #[serde(default)]
+        pub benchmark: SelectorSpec,
+        /// Only supported for the compile suite.
+        #[serde(default)]
+        pub profile: SelectorSpec,
+        /// Only supported for the compile suite.
+        #[serde(default)]
+        pub scenario: SelectorSpec,
+        #[serde(default)]
+        pub metric: SelectorSpec,
+        #[serde(default)]
+        pub aggregation: Aggregation,
+    }
+
+    /// The shape of the response body. It is streamed incrementally (one
+    /// chunk per series) rather than serialized in one piece, so that
+    /// deep-history queries do not spike the site's memory usage.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// The selected artifacts (commit shas or tags), oldest first.
+        pub artifact_ids: Vec<String>,
+        pub series: Vec<Series>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Series {
+        pub benchmark: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub profile: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub scenario: Option<String>,
+        pub metric: String,
+        /// The aggregated value; absent with `series` aggregation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub value: Option<f64>,
+        /// One value per artifact; absent unless using `series` aggregation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub points: Option<Vec<Option<f64>>>,
+    }
+}
+
+pub mod suggest {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// The partial or possibly misspelled name to complete.
+        pub q: String,
+        /// Maximum number of suggestions to return (default 10).
+        #[serde(default)]
+        pub limit: Option<usize>,
+    }
+
+    /// Which namespace a suggested value comes from.
+    #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Kind {
+        Benchmark,
+        Metric,
+        Scenario,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Suggestion {
+        pub kind: Kind,
+        pub value: String,
+    }
+
+    /// Matches are ordered best first, across all namespaces.
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Response {
+        pub suggestions: Vec<Suggestion>,
+    }
+}
+
+pub mod range_compare {
+    use collector::Bound;
+    use serde::{Deserialize, Serialize};
+
+    /// Compares the distributions of each test case over two commit ranges
+    /// (e.g. last week vs the week before), which is more robust for
+    /// periodic reporting than comparing two individual commits.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub start_a: Bound,
+        pub end_a: Bound,
+        pub start_b: Bound,
+        pub end_b: Bound,
+        pub stat: String,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct TestCaseComparison {
+        pub benchmark: String,
+        pub profile: String,
+        pub scenario: String,
+        /// Median of the test case's results over the first range.
+        pub median_a: f64,
+        /// Median of the test case's results over the second range.
+        pub median_b: f64,
+        /// Relative change of the medians, in percent.
+        pub change: f64,
+        /// Two-sided p-value of a Mann-Whitney U test between the two
+        /// distributions.
+        pub p_value: f64,
+        /// Whether the distributions differ at the 5% significance level.
+        pub significant: bool,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// Number of master commits with data in the first range.
+        pub commits_a: usize,
+        /// Number of master commits with data in the second range.
+        pub commits_b: usize,
+        pub comparisons: Vec<TestCaseComparison>,
     }
 }
 
@@ -133,6 +628,101 @@ pub mod bootstrap {
     }
 }
 
+pub mod latency_slo {
+    use collector::Bound;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub start: Bound,
+        pub end: Bound,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        // (UTC timestamp, sha)
+        pub commits: Vec<(i64, String)>,
+        /// Per commit, the share (0.0 to 1.0) of primary benchmarks whose
+        /// `check` `incr-unchanged` wall-time met the collector's latency
+        /// SLO, or `None` for artifacts benchmarked before the metric
+        /// existed.
+        pub values: Vec<Option<f64>>,
+    }
+}
+
+pub mod channel_compare {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Metric to compare (e.g. `instructions:u`).
+        pub stat: String,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct TestCase {
+        pub benchmark: String,
+        pub profile: String,
+        pub scenario: String,
+        pub stable: Option<f64>,
+        pub beta: Option<f64>,
+        pub nightly: Option<f64>,
+        /// Percent change from stable to beta, when both have values.
+        pub stable_to_beta: Option<f64>,
+        /// Percent change from beta to nightly, when both have values.
+        pub beta_to_nightly: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// Release tag the `stable` values come from.
+        pub stable: String,
+        /// Release tag the `beta` values come from.
+        pub beta: String,
+        /// Master commit sha the `nightly` values come from.
+        pub nightly: String,
+        pub cases: Vec<TestCase>,
+    }
+}
+
+pub mod release_notes {
+    use hashbrown::HashMap;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Metric the improvements are measured on; defaults to
+        /// `instructions:u`.
+        pub stat: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Improvement {
+        pub benchmark: String,
+        /// Average percent improvement across the benchmark's flagged test
+        /// cases, as a positive number.
+        pub average_improvement: f64,
+        /// Largest single test-case improvement, as a positive number.
+        pub largest_improvement: f64,
+        /// Number of test cases improved by at least the threshold.
+        pub test_cases: usize,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// The older of the two consecutive stable releases compared.
+        pub from: String,
+        /// The newer of the two consecutive stable releases compared.
+        pub to: String,
+        pub metric: String,
+        /// Percent improvement a test case must reach to be flagged.
+        pub threshold: f64,
+        /// Flagged improvements grouped by benchmark category, sorted by
+        /// average improvement within each category.
+        pub categories: HashMap<String, Vec<Improvement>>,
+    }
+}
+
 pub mod comparison {
     use crate::benchmark_metadata::ProfileMetadata;
     use crate::comparison::Metric;
@@ -146,6 +736,11 @@ pub mod comparison {
         pub start: Bound,
         pub end: Bound,
         pub stat: Metric,
+        /// Embed the recent historical series of each significant test case
+        /// in the response, so that the UI can render inline sparklines
+        /// without a follow-up graph request per row.
+        #[serde(default)]
+        pub sparklines: bool,
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -172,6 +767,12 @@ pub mod comparison {
         pub compile_comparisons: Vec<CompileBenchmarkComparison>,
         pub runtime_comparisons: Vec<RuntimeBenchmarkComparison>,
 
+        /// How complete each artifact's compile-time data is, so the UI can
+        /// mark a partially benchmarked artifact instead of leaving holes.
+        pub compile_completeness: Completeness,
+        /// How complete each artifact's runtime data is.
+        pub runtime_completeness: Completeness,
+
         pub new_errors: Vec<(String, String)>,
 
         /// The names for the next artifact after `b`, if any.
@@ -181,6 +782,18 @@ pub mod comparison {
         pub is_contiguous: bool,
 
         pub compile_benchmark_metadata: Vec<CompileBenchmarkMetadata>,
+
+        /// Unit of the raw statistic values being compared, if known.
+        pub unit: Option<String>,
+
+        /// Warnings about the validity of the comparison itself, e.g. when
+        /// the two artifacts were measured with different versions of the
+        /// benchmark suite.
+        pub warnings: Vec<String>,
+
+        /// The master commits the embedded sparkline series span, oldest
+        /// first. Empty unless sparklines were requested.
+        pub sparkline_commits: Vec<String>,
     }
 
     #[derive(Debug, Clone, Serialize)]
@@ -190,6 +803,15 @@ pub mod comparison {
         Try,
     }
 
+    /// How many of the expected test cases (those with data in at least one
+    /// of the compared artifacts) have data in each artifact.
+    #[derive(Debug, Clone, Copy, Serialize)]
+    pub struct Completeness {
+        pub expected: u32,
+        pub present_a: u32,
+        pub present_b: u32,
+    }
+
     #[derive(Debug, Clone, Serialize)]
     pub struct ArtifactDescription {
         pub commit: String,
@@ -199,6 +821,10 @@ pub mod comparison {
         pub bootstrap: HashMap<String, u64>,
         pub bootstrap_total: u64,
         pub component_sizes: HashMap<String, u64>,
+        /// Toolchain metadata recorded at benchmarking time, e.g. the exact
+        /// rustc version string, the LLVM version it was built with, and the
+        /// effective collector configuration (the `collector-config` key).
+        pub info: HashMap<String, String>,
     }
 
     #[derive(Debug, Clone, Serialize)]
@@ -216,6 +842,10 @@ pub mod comparison {
         pub profile: String,
         pub scenario: String,
         pub comparison: StatComparison,
+        /// The values of this test case over the commits in
+        /// `sparkline_commits` (`None` for commits without data). Only
+        /// present for significant test cases when sparklines were requested.
+        pub sparkline: Option<Vec<Option<f64>>>,
     }
 
     /// A serializable wrapper for a comparison between two runtime test results.
@@ -223,6 +853,64 @@ pub mod comparison {
     pub struct RuntimeBenchmarkComparison {
         pub benchmark: String,
         pub comparison: StatComparison,
+        /// The values of this test case over the commits in
+        /// `sparkline_commits`, as for compile-time comparisons.
+        pub sparkline: Option<Vec<Option<f64>>>,
+    }
+}
+
+pub mod relevance {
+    use collector::Bound;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub start: Bound,
+        pub end: Bound,
+    }
+
+    /// The relevance verdict for a pair of artifacts, computed with the same
+    /// logic that is used when posting comparison comments to PRs. This allows
+    /// retroactively asking whether an already benchmarked artifact pair would
+    /// be flagged under the current thresholds.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// Combined direction of the primary and secondary summaries.
+        pub direction: String,
+        /// Whether this comparison deserves attention (e.g. would have been
+        /// marked as "ACTION NEEDED" in a PR comment).
+        pub is_relevant: bool,
+        pub primary: SummaryStats,
+        pub secondary: SummaryStats,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct SummaryStats {
+        pub num_improvements: usize,
+        pub num_regressions: usize,
+        /// Arithmetic mean of all relevant changes as a percent, if there were any.
+        pub mean: Option<f64>,
+        /// The largest relevant change as a percent, if there was any.
+        pub largest_change: Option<f64>,
+    }
+}
+
+pub mod health {
+    use serde::Serialize;
+
+    /// A cheap structured liveness probe for load balancers and uptime
+    /// monitors, as opposed to the human-oriented status page.
+    #[derive(Serialize, Debug)]
+    pub struct Response {
+        /// Round-trip latency of a simple database query, in milliseconds.
+        pub db_latency_ms: f64,
+        /// When the most recently ingested artifact finished collecting, in
+        /// seconds since the epoch; `None` for an empty database.
+        pub last_ingested: Option<i64>,
+        /// Number of artifacts waiting to be benchmarked.
+        pub queue_depth: usize,
+        /// Seconds since the in-memory index was last (re)loaded.
+        pub index_age_seconds: u64,
     }
 }
 
@@ -231,6 +919,7 @@ pub mod status {
     use database::ArtifactId;
     use database::Commit;
     use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
     #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
     pub struct BenchmarkStatus {
@@ -257,6 +946,10 @@ pub mod status {
     #[derive(Serialize, Debug)]
     pub struct Response {
         pub last_commit: Option<Commit>,
+        /// Info the collector recorded for the last commit: toolchain
+        /// versions as well as the effective collector configuration
+        /// (the `collector-config` key). Empty if there is no last commit.
+        pub last_commit_info: HashMap<String, String>,
         pub benchmarks: Vec<BenchmarkStatus>,
         pub missing: Vec<(Commit, MissingReason)>,
         pub current: Option<CurrentState>,
@@ -265,6 +958,55 @@ pub mod status {
     }
 }
 
+pub mod annotations {
+    use serde::Serialize;
+
+    /// An event attached to an artifact (e.g. an LLVM major-version upgrade)
+    /// that is a likely explanation for step changes in the artifact's
+    /// results. Annotations are created automatically during ingestion.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Annotation {
+        /// Commit sha or artifact tag.
+        pub artifact: String,
+        /// Machine-readable kind, e.g. `llvm-version-change`.
+        pub kind: String,
+        pub message: String,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// All annotations, in artifact date order.
+        pub annotations: Vec<Annotation>,
+    }
+}
+
+pub mod step_timeline {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Commit sha or artifact tag (e.g. `1.66.0`).
+        pub artifact: String,
+    }
+
+    /// A single collection step and when it ran, in seconds since the epoch.
+    /// `start`/`end` are missing for steps that have not started/finished.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Step {
+        pub step: String,
+        pub start: Option<i64>,
+        pub end: Option<i64>,
+        /// Seconds the step took, if it completed.
+        pub duration: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        pub artifact: String,
+        pub steps: Vec<Step>,
+    }
+}
+
 pub mod self_profile_raw {
     use serde::{Deserialize, Serialize};
 
@@ -348,6 +1090,9 @@ pub mod self_profile {
     #[derive(Serialize, Deserialize, Clone, Debug)]
     pub struct QueryData {
         pub label: QueryLabel,
+        /// The compiler component owning the query provider and the team to
+        /// ping about it, e.g. `rustc_borrowck (rust-lang/types)`, if known.
+        pub component: Option<String>,
         // Nanoseconds
         pub self_time: u64,
         pub percent_total_time: f32,
@@ -370,6 +1115,9 @@ pub mod self_profile {
     pub struct SelfProfileDelta {
         pub totals: QueryDataDelta,
         pub query_data: Vec<QueryDataDelta>,
+        /// Components owning the queries whose self-time regressed, ordered
+        /// by the size of the regression, for ping routing during triage.
+        pub regressing_components: Vec<String>,
         pub artifact_sizes: Vec<ArtifactSizeDelta>,
     }
 
@@ -388,6 +1136,49 @@ pub mod self_profile {
     }
 }
 
+pub mod self_profile_aggregate {
+    use database::QueryLabel;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub commit: String,
+        pub base_commit: Option<String>,
+    }
+
+    /// Self-profile data of one compiler query summed over every benchmark,
+    /// profile and scenario measured for the artifact.
+    #[derive(Serialize, Clone, Debug)]
+    pub struct QueryData {
+        pub label: QueryLabel,
+        // Nanoseconds
+        pub self_time: u64,
+        pub percent_total_time: f32,
+        pub number_of_cache_hits: u64,
+        pub invocation_count: u64,
+        // Nanoseconds
+        pub blocked_time: u64,
+        // Nanoseconds
+        pub incremental_load_time: u64,
+        /// Change of `self_time` against the base artifact (which counts as
+        /// zero if it did not record this query), in nanoseconds. `None` if
+        /// no base commit was given.
+        pub self_time_delta: Option<i64>,
+    }
+
+    #[derive(Serialize, Clone, Debug)]
+    pub struct Response {
+        // Nanoseconds
+        pub total_self_time: u64,
+        /// Change of `total_self_time` against the base artifact, in
+        /// nanoseconds, if a base commit was given.
+        pub total_self_time_delta: Option<i64>,
+        /// Sorted by the largest regression when diffing against a base
+        /// artifact, by `self_time` otherwise.
+        pub query_data: Vec<QueryData>,
+    }
+}
+
 pub mod github {
     use serde::{Deserialize, Serialize};
 
@@ -466,6 +1257,135 @@ pub mod github {
     pub struct Response;
 }
 
+pub mod purge_artifact {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Commit SHA or release tag whose results should be purged.
+        pub artifact: String,
+    }
+}
+
+pub mod saved_query {
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// A saved view: a named combination of dashboard filters (metric, graph
+    /// kind, benchmark/profile/scenario filters, ...). The query itself is an
+    /// opaque JSON object so the frontend can evolve its format without
+    /// server changes.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct CreateRequest {
+        /// URL slug the view is shared under.
+        pub name: String,
+        pub query: serde_json::Value,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct ListResponse {
+        pub queries: HashMap<String, serde_json::Value>,
+    }
+}
+
+pub mod audit_log {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Maximum number of entries to return (default 100, capped at 1000).
+        #[serde(default)]
+        pub limit: Option<u32>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Event {
+        pub id: u32,
+        /// UTC timestamp in seconds.
+        pub occurred_at: i64,
+        pub actor: String,
+        pub action: String,
+        pub payload: serde_json::Value,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        /// Audit log entries, newest first.
+        pub events: Vec<Event>,
+    }
+}
+
+pub mod pr_history {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        pub pr: u32,
+    }
+
+    /// A single perf run performed for the PR.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Run {
+        /// Sha of the benchmarked commit; missing while a try build is
+        /// queued but not yet attached to a commit.
+        pub sha: Option<String>,
+        pub parent_sha: Option<String>,
+        /// `try` for pre-merge try builds, `master` for the post-merge run,
+        /// `unknown` if the commit has no results (yet).
+        pub kind: String,
+        pub complete: bool,
+        /// UTC timestamp in seconds at which the run was requested.
+        pub requested_at: Option<i64>,
+        /// Overall verdict of the comparison against the parent commit, if
+        /// results are available: `improvement`, `regression`, `mixed` or
+        /// `none`.
+        pub verdict: Option<String>,
+        /// Link to the compare page for this run.
+        pub compare_url: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Response {
+        pub pr: u32,
+        pub runs: Vec<Run>,
+    }
+}
+
+pub mod subscription {
+    use serde::{Deserialize, Serialize};
+
+    /// Request to subscribe a target to the periodic digest of significant
+    /// changes, see the `subscription` table.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Delivery mechanism: `email` or `webhook`.
+        pub kind: String,
+        /// Email address or webhook URL, depending on `kind`.
+        pub target: String,
+        /// Benchmark name substring to subscribe to; all benchmarks if empty.
+        #[serde(default)]
+        pub benchmark: String,
+    }
+}
+
+pub mod self_profile_request {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Request {
+        /// Commit SHA of the already benchmarked artifact to profile.
+        pub commit: String,
+        pub benchmark: String,
+        pub profile: String,
+        pub scenario: String,
+    }
+}
+
 pub mod triage {
     use collector::Bound;
     use serde::{Deserialize, Serialize};
@@ -475,8 +1395,94 @@ pub mod triage {
         pub start: Bound,
         #[serde(default)]
         pub end: Bound,
+        #[serde(default)]
+        pub format: Format,
+    }
+
+    /// Output format of the triage report.
+    #[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Format {
+        /// The triage log format used for the weekly triage report PRs.
+        #[default]
+        Markdown,
+        /// A message ready to be posted to the performance triage Zulip
+        /// stream.
+        Zulip,
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Response(pub String);
 }
+
+/// The public, versioned API surface served under `/api/v1/`.
+///
+/// Unlike the `/perf/` endpoints above, which are internal to the frontend and
+/// change whenever the UI does, the shapes in this module are stable: fields
+/// may be added in a backwards-compatible way, but existing fields are never
+/// removed or renamed. Breaking changes require a new version namespace.
+pub mod v1 {
+    pub mod artifacts {
+        use serde::Serialize;
+        use utoipa::ToSchema;
+
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        pub struct Commit {
+            pub sha: String,
+            /// Seconds since the Unix epoch.
+            pub date: i64,
+            /// `true` for merged master commits, `false` for try builds.
+            pub is_master: bool,
+        }
+
+        /// All artifacts with benchmark results, oldest first.
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        #[schema(as = ArtifactsResponse)]
+        pub struct Response {
+            pub commits: Vec<Commit>,
+            /// Published release artifacts (e.g. `1.70.0`).
+            pub tags: Vec<String>,
+        }
+    }
+
+    pub mod benchmarks {
+        use serde::Serialize;
+        use utoipa::ToSchema;
+
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        pub struct CompileBenchmark {
+            pub name: String,
+            pub category: String,
+        }
+
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        #[schema(as = BenchmarksResponse)]
+        pub struct Response {
+            pub compile: Vec<CompileBenchmark>,
+            /// Names of runtime benchmarks with recorded results.
+            pub runtime: Vec<String>,
+        }
+    }
+
+    pub mod metrics {
+        use serde::Serialize;
+        use utoipa::ToSchema;
+
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        pub struct Metric {
+            pub name: String,
+            pub unit: Option<String>,
+            /// `"lower"` or `"higher"`, depending on which direction is an
+            /// improvement; `None` for unknown metrics.
+            pub better_direction: Option<String>,
+            pub description: Option<String>,
+        }
+
+        #[derive(Debug, Clone, Serialize, ToSchema)]
+        #[schema(as = MetricsResponse)]
+        pub struct Response {
+            pub compile_metrics: Vec<Metric>,
+            pub runtime_metrics: Vec<Metric>,
+        }
+    }
+}
@@ -5,6 +5,16 @@ use crate::db::Point;
 /// It could support non-interpolated iterators too but that's a bit more work
 /// and not currently used anyway.
 pub fn average<I>(iterators: Vec<I>) -> Average<I>
+where
+    I: Iterator,
+    I::Item: Point,
+{
+    weighted_average(iterators.into_iter().map(|it| (it, 1.0)).collect())
+}
+
+/// Like [`average`], but each iterator contributes to the mean in proportion
+/// to its weight. With all weights equal this is the plain arithmetic mean.
+pub fn weighted_average<I>(iterators: Vec<(I, f64)>) -> Average<I>
 where
     I: Iterator,
     I::Item: Point,
@@ -16,7 +26,7 @@ where
 }
 
 pub struct Average<I> {
-    iterators: Vec<I>,
+    iterators: Vec<(I, f64)>,
     is_first: bool,
 }
 
@@ -29,23 +39,25 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut sum = 0.0;
-        let mut count = 0;
+        let mut total_weight = 0.0;
 
         let mut i = 0;
         let mut first = None::<I::Item>;
         let mut removed = false;
         // replace with drain_filter when it stabilizes
         while i != self.iterators.len() {
-            match self.iterators[i].next() {
+            let weight = self.iterators[i].1;
+            match self.iterators[i].0.next() {
                 None => {
                     removed = true;
                     self.iterators.remove(i);
                 }
                 Some(point) => {
-                    count += 1;
-                    sum += point
-                        .value()
-                        .expect("Uninterpolated iterators are not supported");
+                    total_weight += weight;
+                    sum += weight
+                        * point
+                            .value()
+                            .expect("Uninterpolated iterators are not supported");
                     i += 1;
                     if let Some(t) = &mut first {
                         if point.interpolated() {
@@ -71,7 +83,7 @@ where
                 None
             }
             Some(mut t) => {
-                t.set_value(sum / (count as f64));
+                t.set_value(sum / total_weight);
                 Some(t)
             }
         }
@@ -135,6 +147,25 @@ mod tests {
         assert!(average.next().is_none());
     }
 
+    #[test]
+    fn test_weighted_average() {
+        // A weight of 3 counts the series three times; with weights 3 and 1
+        // the means of (0, 100) and (200, 300) become 25 and 225.
+        use super::weighted_average;
+
+        let v = vec![
+            (vec![("a", 0.0), ("b", 200.0)], 3.0),
+            (vec![("a", 100.0), ("b", 300.0)], 1.0),
+        ];
+
+        let iterators: Vec<_> = v.into_iter().map(|(v, w)| (v.into_iter(), w)).collect();
+        let mut average = weighted_average(iterators);
+
+        assert_eq!(average.next().unwrap(), ("a", 25.0));
+        assert_eq!(average.next().unwrap(), ("b", 225.0));
+        assert!(average.next().is_none());
+    }
+
     #[test]
     #[should_panic(expected = "Uninterpolated iterators are not supported")]
     fn test_uninterpolated_iterator() {
@@ -7,7 +7,7 @@ use crate::db::{ArtifactId, Benchmark, Lookup, Profile, Scenario};
 use crate::github;
 use crate::load::SiteCtxt;
 use crate::selector::{
-    self, BenchmarkQuery, CompileBenchmarkQuery, RuntimeBenchmarkQuery, TestCase,
+    self, BenchmarkQuery, CompileBenchmarkQuery, RuntimeBenchmarkQuery, Selector, TestCase,
 };
 
 use collector::compile::benchmark::category::Category;
@@ -19,7 +19,6 @@ use crate::benchmark_metadata::get_compile_benchmarks_metadata;
 use crate::server::comparison::StatComparison;
 use collector::compile::benchmark::ArtifactType;
 use database::{CommitType, CompileBenchmark};
-use serde::de::IntoDeserializer;
 use std::cmp;
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
@@ -27,9 +26,13 @@ use std::fmt::Write;
 use std::hash::Hash;
 use std::iter;
 use std::ops::Deref;
-use std::str::FromStr;
 use std::sync::Arc;
 
+pub use analysis::{
+    deserves_attention_icount, ArtifactComparisonSummary, Direction, HistoricalData, Magnitude,
+    Metric, TestResultComparison,
+};
+
 type BoxedError = Box<dyn Error + Send + Sync>;
 
 pub async fn handle_triage(
@@ -39,21 +42,32 @@ pub async fn handle_triage(
     log::info!("handle_triage({:?})", body);
     let start = body.start;
     let end = body.end;
+    ctxt.check_bound_order(&start, &end)
+        .map_err(api::RequestError::BadRequest)?;
     let master_commits = &ctxt.get_master_commits().commits;
 
     let start_artifact = ctxt
         .artifact_id_for_bound(start.clone(), true)
-        .ok_or(format!("could not find start commit for bound {:?}", start))?;
+        .ok_or_else(|| {
+            api::RequestError::NotFound(format!(
+                "could not find start commit for bound {:?}",
+                start
+            ))
+        })?;
     let end_artifact = ctxt
         .artifact_id_for_bound(end.clone(), false)
-        .ok_or(format!("could not find end commit for bound {:?}", end))?;
+        .ok_or_else(|| {
+            api::RequestError::NotFound(format!("could not find end commit for bound {:?}", end))
+        })?;
     // This gives a better error, but is still not great -- the common case here
     // is that we've had a 422 error and as such had a fork. It's possible we
     // could diagnose that and give a nicer error here telling the user which
     // commit to use.
     let mut next = next_commit(&start_artifact, master_commits)
         .map(|c| Bound::Commit(c.sha.clone()))
-        .ok_or(format!("no next commit for {:?}", start_artifact))?;
+        .ok_or_else(|| {
+            api::RequestError::NotFound(format!("no next commit for {:?}", start_artifact))
+        })?;
 
     let mut report = HashMap::new();
     let mut before = start.clone();
@@ -121,7 +135,12 @@ pub async fn handle_triage(
             None => String::from("**ERROR**: no data found for end bound"),
         };
 
-    let report = generate_report(&start, &end, summary, report, num_comparisons).await;
+    let report = match body.format {
+        api::triage::Format::Markdown => {
+            generate_report(&start, &end, summary, report, num_comparisons).await
+        }
+        api::triage::Format::Zulip => generate_zulip_report(&start, &end, summary, report),
+    };
     Ok(api::triage::Response(report))
 }
 
@@ -130,6 +149,8 @@ pub async fn handle_compare(
     ctxt: &SiteCtxt,
 ) -> api::ServerResult<api::comparison::Response> {
     log::info!("handle_compare({:?})", body);
+    ctxt.check_bound_order(&body.start, &body.end)
+        .map_err(api::RequestError::BadRequest)?;
     let master_commits = &ctxt.get_master_commits().commits;
 
     let end = body.end;
@@ -137,7 +158,12 @@ pub async fn handle_compare(
         compare_given_commits(body.start, end.clone(), body.stat, ctxt, master_commits)
             .await
             .map_err(|e| format!("error comparing commits: {}", e))?
-            .ok_or_else(|| format!("could not find end commit for bound {:?}", end))?;
+            .ok_or_else(|| {
+                api::RequestError::NotFound(format!(
+                    "could not find end commit for bound {:?}",
+                    end
+                ))
+            })?;
 
     let conn = ctxt.conn().await;
     let prev = comparison.prev(master_commits);
@@ -145,6 +171,16 @@ pub async fn handle_compare(
     let is_contiguous = comparison.is_contiguous(&*conn, master_commits).await;
     let compile_benchmark_map = conn.get_compile_benchmarks().await;
 
+    // The recent history of each significant test case, if the client asked
+    // for it to be embedded.
+    let mut sparklines = if body.sparklines {
+        compute_sparklines(ctxt, &comparison, body.stat)
+            .await
+            .map_err(|e| format!("error computing sparklines: {}", e))?
+    } else {
+        Sparklines::default()
+    };
+
     let compile_comparisons = comparison
         .compile_comparisons
         .into_iter()
@@ -152,6 +188,11 @@ pub async fn handle_compare(
             benchmark: comparison.benchmark.to_string(),
             profile: comparison.profile.to_string(),
             scenario: comparison.scenario.to_string(),
+            sparkline: sparklines.compile.remove(&(
+                comparison.benchmark,
+                comparison.profile,
+                comparison.scenario,
+            )),
             comparison: comparison.comparison.into(),
         })
         .collect();
@@ -161,6 +202,7 @@ pub async fn handle_compare(
         .into_iter()
         .map(|comparison| api::comparison::RuntimeBenchmarkComparison {
             benchmark: comparison.benchmark.to_string(),
+            sparkline: sparklines.runtime.remove(&comparison.benchmark),
             comparison: comparison.comparison.into(),
         })
         .collect();
@@ -171,6 +213,23 @@ pub async fn handle_compare(
         .collect::<Vec<_>>();
     new_errors.sort();
 
+    // Results gathered with different versions of the benchmark suite are
+    // not directly comparable: a difference may stem from a change to the
+    // benchmarks or the harness rather than the compiler.
+    let mut warnings = Vec::new();
+    if let (Some(suite_a), Some(suite_b)) = (
+        comparison.a.info.get("suite-version"),
+        comparison.b.info.get("suite-version"),
+    ) {
+        if suite_a != suite_b {
+            warnings.push(format!(
+                "The compared artifacts were measured with different versions \
+                 of the benchmark suite ({suite_a} vs {suite_b}); differences \
+                 may be caused by suite changes rather than the compiler."
+            ));
+        }
+    }
+
     let compile_metadata = get_compile_benchmarks_metadata();
     // Enrich data from the DB with metadata generated by the build script
     let compile_benchmark_metadata = compile_benchmark_map
@@ -196,399 +255,236 @@ pub async fn handle_compare(
         b: comparison.b.into(),
         compile_comparisons,
         runtime_comparisons,
+        compile_completeness: comparison.compile_completeness.into(),
+        runtime_completeness: comparison.runtime_completeness.into(),
         new_errors,
         next,
         is_contiguous,
         compile_benchmark_metadata,
+        unit: database::metric::MetricMetadata::for_metric(body.stat.as_str())
+            .map(|m| m.unit.to_string()),
+        warnings,
+        sparkline_commits: sparklines.commits,
     })
 }
 
-async fn populate_report(
-    comparison: &ArtifactComparison,
-    benchmark_map: &HashMap<Benchmark, Category>,
-    report: &mut HashMap<Direction, Vec<String>>,
-) {
-    let (primary, secondary) = comparison
-        .clone()
-        .summarize_compile_by_category(benchmark_map);
-    // Get the combined direction of the primary and secondary summaries
-    let direction = Direction::join(primary.direction(), secondary.direction());
-    if direction == Direction::None {
-        return;
-    }
-
-    let include_in_triage = deserves_attention_icount(&primary, &secondary);
-
-    if include_in_triage {
-        let entry = report.entry(direction).or_default();
-        entry.push(write_triage_summary(comparison, &primary, &secondary).await);
-    }
-}
-
-/// This enum contains all "known" metrics coming from rustc or profiling tools that we know
-/// (and care) about.
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub enum Metric {
-    #[serde(rename = "context-switches")]
-    ContextSwitches,
-    #[serde(rename = "cpu-clock")]
-    CpuClock,
-    #[serde(rename = "cpu-clock:u")]
-    CpuClockUser,
-    #[serde(rename = "cycles")]
-    Cycles,
-    #[serde(rename = "cycles:u")]
-    CyclesUser,
-    #[serde(rename = "faults")]
-    Faults,
-    #[serde(rename = "faults:u")]
-    FaultsUser,
-    #[serde(rename = "instructions:u")]
-    InstructionsUser,
-    #[serde(rename = "max-rss")]
-    MaxRSS,
-    #[serde(rename = "task-clock")]
-    TaskClock,
-    #[serde(rename = "task-clock:u")]
-    TaskClockUser,
-    #[serde(rename = "wall-time")]
-    WallTime,
-    #[serde(rename = "branch-misses")]
-    BranchMisses,
-    #[serde(rename = "cache-misses")]
-    CacheMisses,
-    /// Rustc guesses the codegen unit size by MIR count.
-    #[serde(rename = "size:codegen_unit_size_estimate")]
-    CodegenUnitSize,
-    /// The codegen unit size by llvm ir count, the real size of a cgu.
-    #[serde(rename = "size:cgu_instructions")]
-    CodegenUnitLlvmIrCount,
-    #[serde(rename = "size:dep_graph")]
-    DepGraphSize,
-    #[serde(rename = "size:linked_artifact")]
-    LinkedArtifactSize,
-    #[serde(rename = "size:object_file")]
-    ObjectFileSize,
-    #[serde(rename = "size:query_cache")]
-    QueryCacheSize,
-    #[serde(rename = "size:work_product_index")]
-    WorkProductIndexSize,
-    #[serde(rename = "size:crate_metadata")]
-    CrateMetadataSize,
-    #[serde(rename = "size:dwo_file")]
-    DwoFileSize,
-    #[serde(rename = "size:assembly_file")]
-    AssemblyFileSize,
-    #[serde(rename = "size:llvm_bitcode")]
-    LlvmBitcodeSize,
-    #[serde(rename = "size:llvm_ir")]
-    LlvmIrSize,
-    /// Total bytes of a generated documentation directory
-    #[serde(rename = "size:doc_bytes")]
-    DocByteSize,
-    /// Number of files inside a generated documentation directory.
-    #[serde(rename = "size:doc_files_count")]
-    DocFilesCount,
+/// How many of the most recent master commits (ending at the `b` artifact)
+/// feed each sparkline embedded in the comparison response.
+const SPARKLINE_COMMITS: usize = 30;
+
+/// The historical mini-series of each significant test case over the last
+/// [`SPARKLINE_COMMITS`] master commits, so the compare page can render
+/// inline sparklines without a follow-up graph request per row.
+#[derive(Default)]
+struct Sparklines {
+    /// The commits the series span, oldest first.
+    commits: Vec<String>,
+    compile: HashMap<(Benchmark, Profile, Scenario), Vec<Option<f64>>>,
+    runtime: HashMap<Benchmark, Vec<Option<f64>>>,
 }
 
-impl FromStr for Metric {
-    type Err = String;
+async fn compute_sparklines(
+    ctxt: &SiteCtxt,
+    comparison: &ArtifactComparison,
+    metric: Metric,
+) -> Result<Sparklines, String> {
+    let mut sparklines = Sparklines::default();
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Metric::deserialize(s.into_deserializer())
-            .map_err(|e: serde::de::value::Error| format!("Unknown metric `{s}`: {e:?}"))
+    let idx = ctxt.index.load();
+    let masters: Vec<_> = idx
+        .commits()
+        .into_iter()
+        .filter(|commit| commit.is_master())
+        .collect();
+    // The series end at the `b` artifact or, for a try build, at the last
+    // master commit benchmarked before it. Release artifacts are too far
+    // apart for their history to make a meaningful sparkline.
+    let end = match &comparison.b.artifact {
+        ArtifactId::Commit(commit) => match masters.iter().position(|c| c.sha == commit.sha) {
+            Some(position) => position + 1,
+            None => masters.partition_point(|c| c.date <= commit.date),
+        },
+        ArtifactId::Tag(_) => 0,
+    };
+    if end == 0 {
+        return Ok(sparklines);
     }
-}
+    let commits = &masters[end.saturating_sub(SPARKLINE_COMMITS)..end];
+    let artifact_ids = Arc::new(
+        commits
+            .iter()
+            .map(|commit| ArtifactId::Commit(commit.clone()))
+            .collect::<Vec<_>>(),
+    );
+    sparklines.commits = commits.iter().map(|commit| commit.sha.clone()).collect();
 
-impl Metric {
-    pub fn as_str(&self) -> &str {
-        match self {
-            Metric::ContextSwitches => "context-switches",
-            Metric::CpuClock => "cpu-clock",
-            Metric::CpuClockUser => "cpu-clock:u",
-            Metric::Cycles => "cycles",
-            Metric::CyclesUser => "cycles:u",
-            Metric::Faults => "faults",
-            Metric::FaultsUser => "faults:u",
-            Metric::InstructionsUser => "instructions:u",
-            Metric::MaxRSS => "max-rss",
-            Metric::TaskClock => "task-clock",
-            Metric::TaskClockUser => "task-clock:u",
-            Metric::WallTime => "wall-time",
-            Metric::BranchMisses => "branch-misses",
-            Metric::CacheMisses => "cache-misses",
-            Metric::CodegenUnitSize => "size:codegen_unit_size_estimate",
-            Metric::CodegenUnitLlvmIrCount => "size:cgu_instructions",
-            Metric::DepGraphSize => "size:dep_graph",
-            Metric::LinkedArtifactSize => "size:linked_artifact",
-            Metric::ObjectFileSize => "size:object_file",
-            Metric::QueryCacheSize => "size:query_cache",
-            Metric::WorkProductIndexSize => "size:work_product_index",
-            Metric::CrateMetadataSize => "size:crate_metadata",
-            Metric::DwoFileSize => "size:dwo_file",
-            Metric::AssemblyFileSize => "size:assembly_file",
-            Metric::LlvmBitcodeSize => "size:llvm_bitcode",
-            Metric::LlvmIrSize => "size:llvm_ir",
-            Metric::DocByteSize => "size:doc_bytes",
-            Metric::DocFilesCount => "size:doc_files_count",
+    let compile_cases: HashSet<_> = comparison
+        .compile_comparisons
+        .iter()
+        .filter(|comparison| comparison.is_relevant())
+        .map(|comparison| {
+            (
+                comparison.benchmark,
+                comparison.profile,
+                comparison.scenario,
+            )
+        })
+        .collect();
+    if !compile_cases.is_empty() {
+        let query = CompileBenchmarkQuery::default()
+            .benchmark(Selector::Subset(
+                compile_cases
+                    .iter()
+                    .map(|&(benchmark, _, _)| benchmark.to_string())
+                    .collect(),
+            ))
+            .metric_name(metric.as_str().into());
+        for response in ctxt.statistic_series(query, artifact_ids.clone()).await? {
+            let case = &response.test_case;
+            let case = (case.benchmark, case.profile, case.scenario);
+            if compile_cases.contains(&case) {
+                sparklines
+                    .compile
+                    .insert(case, response.series.map(|(_, value)| value).collect());
+            }
         }
     }
 
-    /// Determines the magnitude of a percent relative change for a given metric.
-    ///
-    /// Takes into account how noisy the stat is. For example, instruction
-    /// count which is normally not very noisy has smaller thresholds than
-    /// max-rss which can be noisy.
-    fn relative_change_magnitude(&self, change: f64) -> Magnitude {
-        let noise_factor = if self.is_typically_noisy() { 2.0 } else { 1.0 };
-        let change = change / noise_factor;
-        if change < 0.2 {
-            Magnitude::VerySmall
-        } else if change < 1.0 {
-            Magnitude::Small
-        } else if change < 2.0 {
-            Magnitude::Medium
-        } else if change < 5.0 {
-            Magnitude::Large
-        } else {
-            Magnitude::VeryLarge
+    let runtime_cases: HashSet<_> = comparison
+        .runtime_comparisons
+        .iter()
+        .filter(|comparison| comparison.is_relevant())
+        .map(|comparison| comparison.benchmark)
+        .collect();
+    if !runtime_cases.is_empty() {
+        let query = RuntimeBenchmarkQuery::default()
+            .benchmark(Selector::Subset(
+                runtime_cases
+                    .iter()
+                    .map(|benchmark| benchmark.to_string())
+                    .collect(),
+            ))
+            .metric_name(metric.as_str().into());
+        for response in ctxt.statistic_series(query, artifact_ids).await? {
+            let benchmark = response.test_case.benchmark;
+            if runtime_cases.contains(&benchmark) {
+                sparklines
+                    .runtime
+                    .insert(benchmark, response.series.map(|(_, value)| value).collect());
+            }
         }
     }
 
-    /// Currently, we conservatively consider everything except instructions to be noisy.
-    fn is_typically_noisy(&self) -> bool {
-        !matches!(self, Self::InstructionsUser)
-    }
+    Ok(sparklines)
 }
 
-/// A summary of a given comparison
-///
-/// This summary only includes changes that are significant and relevant (as determined by a change's magnitude).
-pub struct ArtifactComparisonSummary {
-    /// Relevant comparisons ordered from most negative to most positive
-    relevant_comparisons: Vec<TestResultComparison>,
-    /// The cached number of comparisons that are improvements
-    num_improvements: usize,
-    /// The cached number of comparisons that are regressions
-    num_regressions: usize,
-}
-
-impl ArtifactComparisonSummary {
-    /// Summarize a collection of `TestResultComparison`
-    pub fn summarize(comparisons: Vec<TestResultComparison>) -> Self {
-        let mut num_improvements = 0;
-        let mut num_regressions = 0;
-
-        let mut relevant_comparisons = comparisons
-            .into_iter()
-            .filter(|c| c.is_relevant())
-            .inspect(|c| {
-                if c.is_improvement() {
-                    num_improvements += 1;
-                } else {
-                    num_regressions += 1
-                }
-            })
-            .collect::<Vec<_>>();
-
-        let cmp = |b1: &TestResultComparison, b2: &TestResultComparison| {
-            b1.relative_change()
-                .partial_cmp(&b2.relative_change())
-                .unwrap_or(cmp::Ordering::Equal)
-        };
-        relevant_comparisons.sort_by(cmp);
-
-        ArtifactComparisonSummary {
-            relevant_comparisons,
-            num_improvements,
-            num_regressions,
-        }
-    }
+/// Like [`handle_compare`], but renders the comparison as the GitHub-flavored
+/// markdown summary table the bot posts on PRs, so external automation (e.g.
+/// project-specific CI) can embed it in its own comments.
+pub async fn handle_compare_markdown(
+    body: api::comparison::Request,
+    ctxt: &SiteCtxt,
+) -> api::ServerResult<String> {
+    log::info!("handle_compare_markdown({:?})", body);
+    ctxt.check_bound_order(&body.start, &body.end)
+        .map_err(api::RequestError::BadRequest)?;
+    let master_commits = &ctxt.get_master_commits().commits;
 
-    /// The direction of the changes
-    pub fn direction(&self) -> Direction {
-        if self.relevant_comparisons.is_empty() {
-            return Direction::None;
-        }
+    let end = body.end;
+    let comparison =
+        compare_given_commits(body.start, end.clone(), body.stat, ctxt, master_commits)
+            .await
+            .map_err(|e| format!("error comparing commits: {}", e))?
+            .ok_or_else(|| {
+                api::RequestError::NotFound(format!(
+                    "could not find end commit for bound {:?}",
+                    end
+                ))
+            })?;
 
-        let (regressions, improvements): (Vec<&TestResultComparison>, _) = self
-            .relevant_comparisons
-            .iter()
-            .partition(|c| c.is_regression());
+    let benchmark_map = ctxt.get_benchmark_category_map().await;
+    let link = compare_link(&comparison.a.artifact, &comparison.b.artifact);
+    let (primary, secondary) = comparison.summarize_compile_by_category(&benchmark_map);
 
-        if regressions.is_empty() {
-            return Direction::Improvement;
-        }
+    let mut result = format!("### [{}]({})\n\n", body.stat.as_str(), link);
+    write_summary_table(&primary, &secondary, true, &mut result);
+    Ok(result)
+}
 
-        if improvements.is_empty() {
-            return Direction::Regression;
-        }
+/// Computes the relevance verdict for a pair of previously benchmarked artifacts,
+/// using the same logic that determines the result posted in PR comments.
+pub async fn handle_relevance(
+    body: api::relevance::Request,
+    ctxt: &SiteCtxt,
+) -> api::ServerResult<api::relevance::Response> {
+    log::info!("handle_relevance({:?})", body);
+    let master_commits = &ctxt.get_master_commits().commits;
 
-        let total_num = self.relevant_comparisons.len();
-        let regressions_ratio = regressions.len() as f64 / total_num as f64;
+    let end = body.end;
+    let comparison = compare_given_commits(
+        body.start,
+        end.clone(),
+        Metric::InstructionsUser,
+        ctxt,
+        master_commits,
+    )
+    .await
+    .map_err(|e| format!("error comparing commits: {}", e))?
+    .ok_or_else(|| {
+        api::RequestError::NotFound(format!("could not find end commit for bound {:?}", end))
+    })?;
 
-        let has_medium_and_above_regressions = regressions
-            .iter()
-            .any(|c| c.magnitude().is_medium_or_above());
-        let has_medium_and_above_improvements = improvements
-            .iter()
-            .any(|c| c.magnitude().is_medium_or_above());
-        match (
-            has_medium_and_above_improvements,
-            has_medium_and_above_regressions,
-        ) {
-            (true, true) => Direction::Mixed,
-            (true, false) => {
-                if regressions_ratio >= 0.15 {
-                    Direction::Mixed
-                } else {
-                    Direction::Improvement
-                }
-            }
-            (false, true) => {
-                if regressions_ratio < 0.85 {
-                    Direction::Mixed
-                } else {
-                    Direction::Regression
-                }
-            }
-            (false, false) => {
-                if (0.1..=0.9).contains(&regressions_ratio) {
-                    Direction::Mixed
-                } else if regressions_ratio <= 0.1 {
-                    Direction::Improvement
-                } else {
-                    Direction::Regression
-                }
-            }
+    let benchmark_map = ctxt.get_benchmark_category_map().await;
+    let (primary, secondary) = comparison.summarize_compile_by_category(&benchmark_map);
+
+    let direction = primary.direction().join(secondary.direction());
+    let is_relevant = deserves_attention_icount(&primary, &secondary)
+        && matches!(direction, Direction::Regression | Direction::Mixed);
+
+    fn summary_stats(summary: &ArtifactComparisonSummary) -> api::relevance::SummaryStats {
+        api::relevance::SummaryStats {
+            num_improvements: summary.num_improvements(),
+            num_regressions: summary.num_regressions(),
+            mean: (!summary.is_empty()).then(|| summary.arithmetic_mean_of_changes()),
+            largest_change: summary
+                .largest_change()
+                .map(|c| c.relative_change() * 100.0),
         }
     }
 
-    /// Arithmetic mean of all improvements as a percent
-    pub fn arithmetic_mean_of_improvements(&self) -> f64 {
-        self.arithmetic_mean(self.improvements())
-    }
-
-    /// Arithmetic mean of all regressions as a percent
-    pub fn arithmetic_mean_of_regressions(&self) -> f64 {
-        self.arithmetic_mean(self.regressions())
-    }
-
-    /// Arithmetic mean of all changes as a percent
-    pub fn arithmetic_mean_of_changes(&self) -> f64 {
-        self.arithmetic_mean(self.relevant_comparisons.iter())
-    }
-
-    pub fn is_empty(&self) -> bool {
-        self.relevant_comparisons.is_empty()
-    }
-
-    fn arithmetic_mean<'a>(
-        &'a self,
-        changes: impl Iterator<Item = &'a TestResultComparison>,
-    ) -> f64 {
-        let mut count = 0;
-        let mut sum = 0.0;
-        for r in changes {
-            sum += r.relative_change();
-            count += 1;
+    Ok(api::relevance::Response {
+        direction: match direction {
+            Direction::None => "none",
+            Direction::Improvement => "improvement",
+            Direction::Regression => "regression",
+            Direction::Mixed => "mixed",
         }
+        .to_string(),
+        is_relevant,
+        primary: summary_stats(&primary),
+        secondary: summary_stats(&secondary),
+    })
+}
 
-        (sum / count as f64) * 100.0
-    }
-
-    fn improvements(&self) -> impl Iterator<Item = &TestResultComparison> {
-        self.relevant_comparisons
-            .iter()
-            .filter(|c| c.is_improvement())
-    }
-
-    fn regressions(&self) -> impl Iterator<Item = &TestResultComparison> {
-        self.relevant_comparisons
-            .iter()
-            .filter(|c| c.is_regression())
-    }
-
-    // This is the most negative result.
-    fn largest_improvement(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons
-            .iter()
-            .find(|s| s.is_improvement())
-    }
-
-    // This is the least negative result.
-    fn smallest_improvement(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons
-            .iter()
-            .rfind(|s| s.is_improvement())
-    }
-
-    // This is the most positive result.
-    fn largest_regression(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons
-            .iter()
-            .rfind(|s| s.is_regression())
-    }
-
-    // This is the least positive result.
-    fn smallest_regression(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons.iter().find(|s| s.is_regression())
-    }
-
-    // This may be an improvement or a regression.
-    fn most_positive_change(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons.last()
-    }
-
-    // This may be an improvement or a regression.
-    fn most_negative_change(&self) -> Option<&TestResultComparison> {
-        self.relevant_comparisons.first()
-    }
-
-    /// The relevance level of the entire comparison
-    pub fn is_relevant(&self) -> bool {
-        !self.is_empty()
-    }
-
-    pub fn num_changes(&self) -> usize {
-        self.relevant_comparisons.len()
+async fn populate_report(
+    comparison: &ArtifactComparison,
+    benchmark_map: &HashMap<Benchmark, Category>,
+    report: &mut HashMap<Direction, Vec<String>>,
+) {
+    let (primary, secondary) = comparison
+        .clone()
+        .summarize_compile_by_category(benchmark_map);
+    // Get the combined direction of the primary and secondary summaries
+    let direction = Direction::join(primary.direction(), secondary.direction());
+    if direction == Direction::None {
+        return;
     }
 
-    pub fn largest_change(&self) -> Option<&TestResultComparison> {
-        if self.num_changes() == 0 {
-            None
-        } else {
-            let most_pos = self.most_positive_change().unwrap();
-            let most_neg = self.most_negative_change().unwrap();
-            let most_pos_abs = most_pos.relative_change().abs();
-            let most_neg_abs = most_neg.relative_change().abs();
-            if most_neg_abs.partial_cmp(&most_pos_abs) == Some(cmp::Ordering::Greater) {
-                Some(most_neg)
-            } else {
-                Some(most_pos)
-            }
-        }
-    }
-}
+    let include_in_triage = deserves_attention_icount(&primary, &secondary);
 
-/// Whether we are confident enough that an artifact comparison represents a real change and thus deserves to be looked at.
-///
-/// For example, this can be used to determine if artifact comparisons with regressions should be labeled with the
-/// `perf-regression` GitHub label or should be shown in the perf triage report.
-pub(crate) fn deserves_attention_icount(
-    primary: &ArtifactComparisonSummary,
-    secondary: &ArtifactComparisonSummary,
-) -> bool {
-    match (primary.largest_change(), secondary.largest_change()) {
-        (Some(c), _) if c.magnitude() >= Magnitude::Medium => true,
-        (_, Some(c)) if c.magnitude() >= Magnitude::Large => true,
-        _ => {
-            // How we determine whether a group of small changes deserves attention is and always will be arbitrary,
-            // but this feels good enough for now. We may choose in the future to become more sophisticated about it.
-            let primary_n = primary.num_changes();
-            let secondary_n = secondary.num_changes();
-            (primary_n * 3 + secondary_n) >= 9
-        }
+    if include_in_triage {
+        let entry = report.entry(direction).or_default();
+        entry.push(write_triage_summary(comparison, &primary, &secondary).await);
     }
 }
 
@@ -626,10 +522,10 @@ pub fn write_summary_table(
     let metric = include_metric
         .then(|| {
             primary
-                .relevant_comparisons
+                .relevant_comparisons()
                 .first()
-                .or(secondary.relevant_comparisons.first())
-                .map(|m| format!("({})", m.metric.as_str()))
+                .or(secondary.relevant_comparisons().first())
+                .map(|m| format!("({})", m.metric().as_str()))
         })
         .flatten()
         .unwrap_or_else(|| "          ".to_string());
@@ -664,16 +560,16 @@ pub fn write_summary_table(
 
     // mean
     columns.push(vec![
-        render_stat(primary.num_regressions, || {
+        render_stat(primary.num_regressions(), || {
             Some(primary.arithmetic_mean_of_regressions())
         }),
-        render_stat(secondary.num_regressions, || {
+        render_stat(secondary.num_regressions(), || {
             Some(secondary.arithmetic_mean_of_regressions())
         }),
-        render_stat(primary.num_improvements, || {
+        render_stat(primary.num_improvements(), || {
             Some(primary.arithmetic_mean_of_improvements())
         }),
-        render_stat(secondary.num_improvements, || {
+        render_stat(secondary.num_improvements(), || {
             Some(secondary.arithmetic_mean_of_improvements())
         }),
         if primary.is_empty() {
@@ -686,31 +582,31 @@ pub fn write_summary_table(
     // range
     let rel_change = |r: Option<&TestResultComparison>| r.unwrap().relative_change() * 100.0;
     columns.push(vec![
-        render_range(primary.num_regressions, || {
+        render_range(primary.num_regressions(), || {
             (
                 rel_change(primary.smallest_regression()),
                 rel_change(primary.largest_regression()),
             )
         }),
-        render_range(secondary.num_regressions, || {
+        render_range(secondary.num_regressions(), || {
             (
                 rel_change(secondary.smallest_regression()),
                 rel_change(secondary.largest_regression()),
             )
         }),
-        render_range(primary.num_improvements, || {
+        render_range(primary.num_improvements(), || {
             (
                 rel_change(primary.largest_improvement()),
                 rel_change(primary.smallest_improvement()),
             )
         }),
-        render_range(secondary.num_improvements, || {
+        render_range(secondary.num_improvements(), || {
             (
                 rel_change(secondary.largest_improvement()),
                 rel_change(secondary.smallest_improvement()),
             )
         }),
-        render_range(primary.num_regressions + primary.num_improvements, || {
+        render_range(primary.num_regressions() + primary.num_improvements(), || {
             (
                 rel_change(primary.most_negative_change()),
                 rel_change(primary.most_positive_change()),
@@ -720,11 +616,11 @@ pub fn write_summary_table(
 
     // count
     columns.push(vec![
-        primary.num_regressions.to_string(),
-        secondary.num_regressions.to_string(),
-        primary.num_improvements.to_string(),
-        secondary.num_improvements.to_string(),
-        (primary.num_regressions + primary.num_improvements).to_string(),
+        primary.num_regressions().to_string(),
+        secondary.num_regressions().to_string(),
+        primary.num_improvements().to_string(),
+        secondary.num_improvements().to_string(),
+        (primary.num_regressions() + primary.num_improvements()).to_string(),
     ]);
 
     // This code attempts to space the table cells evenly so that the data is
@@ -825,10 +721,35 @@ async fn compare_given_commits(
         Some(b) => b,
         None => return Ok(None),
     };
+
+    // Check whether we have already computed (and persisted) this comparison.
+    let cache_key = match (a.lookup(&idx), b.lookup(&idx)) {
+        (Some(aid_a), Some(aid_b)) => Some((aid_a, aid_b)),
+        _ => None,
+    };
+    if let Some((aid_a, aid_b)) = cache_key {
+        let conn = ctxt.conn().await;
+        if let Some(cached) = conn
+            .get_comparison_summary(aid_a, aid_b, metric.as_str())
+            .await
+        {
+            match serde_json::from_slice::<ArtifactComparison>(&cached) {
+                Ok(comparison) => return Ok(Some(comparison)),
+                // A deserialization failure just means the stored format has
+                // changed; fall through and recompute (which overwrites it).
+                Err(e) => log::warn!("failed to deserialize cached comparison: {:?}", e),
+            }
+        }
+    }
+
     let aids = Arc::new(vec![a.clone(), b.clone()]);
 
     // get all crates, cache, and profile combinations for the given metric
-    let compile_comparisons = get_comparison::<CompileTestResultComparison, _, _>(
+    let (compile_comparisons, compile_completeness) = get_comparison::<
+        CompileTestResultComparison,
+        _,
+        _,
+    >(
         ctxt,
         CompileBenchmarkQuery::all_for_metric(metric),
         a.clone(),
@@ -839,13 +760,20 @@ async fn compare_given_commits(
             profile: test_case.profile,
             scenario: test_case.scenario,
             benchmark: test_case.benchmark,
-            comparison,
+            comparison: comparison.with_higher_is_better(higher_is_better(
+                metric,
+                Some(test_case.benchmark.as_str()),
+            )),
         },
     )
     .await?;
 
     // get all crates, cache, and profile combinations for the given metric
-    let runtime_comparisons = get_comparison::<RuntimeTestResultComparison, _, _>(
+    let (runtime_comparisons, runtime_completeness) = get_comparison::<
+        RuntimeTestResultComparison,
+        _,
+        _,
+    >(
         ctxt,
         RuntimeBenchmarkQuery::all_for_metric(metric),
         a.clone(),
@@ -854,7 +782,7 @@ async fn compare_given_commits(
         master_commits,
         |test_case, comparison| RuntimeTestResultComparison {
             benchmark: test_case.benchmark,
-            comparison,
+            comparison: comparison.with_higher_is_better(higher_is_better(metric, None)),
         },
     )
     .await?;
@@ -865,14 +793,176 @@ async fn compare_given_commits(
     for (name, _) in errors_in_a {
         errors_in_b.remove(&name);
     }
+    // Benchmarks deliberately skipped because the toolchain predates their
+    // declared minimum rustc version are recorded with an `unsupported:`
+    // marker; they are not failures.
+    errors_in_b.retain(|_, error| !error.starts_with("unsupported:"));
 
-    Ok(Some(ArtifactComparison {
+    let comparison = ArtifactComparison {
         a: ArtifactDescription::for_artifact(&*conn, a.clone(), master_commits).await,
         b: ArtifactDescription::for_artifact(&*conn, b.clone(), master_commits).await,
         compile_comparisons,
         runtime_comparisons,
+        compile_completeness,
+        runtime_completeness,
         newly_failed_benchmarks: errors_in_b.into_iter().collect(),
-    }))
+    };
+
+    if let Some((aid_a, aid_b)) = cache_key {
+        conn.record_comparison_summary(
+            aid_a,
+            aid_b,
+            metric.as_str(),
+            &serde_json::to_vec(&comparison).unwrap(),
+        )
+        .await;
+    }
+
+    Ok(Some(comparison))
+}
+
+/// Checks recently benchmarked master commits for "noise runs": collections
+/// whose results moved sharply in one direction and reverted on the very next
+/// commit, which is the signature of a transient environment problem (e.g.
+/// thermal throttling on the collection machine) rather than a change in the
+/// compiler. Suspected commits are recorded in the database and surfaced in
+/// graph responses.
+pub async fn detect_noise_runs(ctxt: &SiteCtxt) {
+    if let Err(e) = detect_noise_runs_impl(ctxt).await {
+        log::error!("noise run detection failed: {:?}", e);
+    }
+}
+
+async fn detect_noise_runs_impl(ctxt: &SiteCtxt) -> Result<(), BoxedError> {
+    let already_marked: HashSet<_> = ctxt
+        .conn()
+        .await
+        .suspected_noise_artifacts()
+        .await
+        .into_iter()
+        .collect();
+    let commits: Vec<_> = ctxt
+        .index
+        .load()
+        .commits()
+        .iter()
+        .rev()
+        .take(10)
+        .cloned()
+        .collect();
+    let master_commits = &ctxt.get_master_commits().commits;
+    let idx = ctxt.index.load();
+    for commit in commits {
+        let aid = match ArtifactId::Commit(commit.clone()).lookup(&idx) {
+            Some(aid) => aid,
+            None => continue,
+        };
+        if already_marked.contains(&aid) {
+            continue;
+        }
+        let parent = match master_commits.iter().find(|c| c.sha == commit.sha) {
+            Some(c) => c.parent_sha.clone(),
+            None => continue,
+        };
+        let incoming = match compare_given_commits(
+            Bound::Commit(parent),
+            Bound::Commit(commit.sha.clone()),
+            Metric::InstructionsUser,
+            ctxt,
+            master_commits,
+        )
+        .await?
+        {
+            Some(c) => c,
+            None => continue,
+        };
+        let next = match incoming.next(master_commits) {
+            Some(next) => next,
+            None => continue,
+        };
+        let outgoing = match compare_given_commits(
+            Bound::Commit(commit.sha.clone()),
+            Bound::Commit(next),
+            Metric::InstructionsUser,
+            ctxt,
+            master_commits,
+        )
+        .await?
+        {
+            Some(c) => c,
+            None => continue,
+        };
+        if is_suspected_noise(&incoming, &outgoing) {
+            log::info!("marking {} as a suspected noise run", commit.sha);
+            ctxt.conn().await.mark_suspected_noise(aid).await;
+        }
+    }
+    Ok(())
+}
+
+/// A commit is a suspected noise run if most of its compile-time results
+/// changed significantly in a single direction relative to its parent and then
+/// changed significantly in the opposite direction on the next commit. Genuine
+/// compiler changes are almost never reverted on the immediately following
+/// commit, so this pattern points at the environment instead.
+fn is_suspected_noise(incoming: &ArtifactComparison, outgoing: &ArtifactComparison) -> bool {
+    fn dominant_direction(comparison: &ArtifactComparison) -> Option<Direction> {
+        let total = comparison.compile_comparisons.len();
+        if total == 0 {
+            return None;
+        }
+        let summary = ArtifactComparisonSummary::summarize(
+            comparison
+                .compile_comparisons
+                .iter()
+                .map(|c| c.comparison.clone())
+                .collect(),
+        );
+        if (summary.num_changes() as f64) < total as f64 * 0.5 {
+            return None;
+        }
+        match summary.direction() {
+            d @ (Direction::Improvement | Direction::Regression) => Some(d),
+            Direction::None | Direction::Mixed => None,
+        }
+    }
+
+    match (dominant_direction(incoming), dominant_direction(outgoing)) {
+        (Some(a), Some(b)) => a != b,
+        _ => false,
+    }
+}
+
+/// The overall direction of a comparison's relevant compile-time results,
+/// used as a one-word verdict e.g. in the PR perf history.
+pub fn overall_direction(comparison: &ArtifactComparison) -> Direction {
+    ArtifactComparisonSummary::summarize(
+        comparison
+            .compile_comparisons
+            .iter()
+            .map(|c| c.comparison.clone())
+            .collect(),
+    )
+    .direction()
+}
+
+/// Returns whether a higher value of the given metric counts as an
+/// improvement: either the metric registry marks the metric itself as
+/// higher-is-better (e.g. a throughput counter), or the compile benchmark's
+/// perf-config declares it so for this benchmark.
+fn higher_is_better(metric: Metric, compile_benchmark: Option<&str>) -> bool {
+    use database::metric::{BetterDirection, MetricMetadata};
+
+    if MetricMetadata::for_metric(metric.as_str())
+        .is_some_and(|metadata| metadata.better_direction == BetterDirection::Higher)
+    {
+        return true;
+    }
+    compile_benchmark.is_some_and(|benchmark| {
+        get_compile_benchmarks_metadata()
+            .get(benchmark)
+            .is_some_and(|metadata| metadata.perf_config.is_higher_better(metric.as_str()))
+    })
 }
 
 async fn get_comparison<
@@ -887,7 +977,7 @@ async fn get_comparison<
     metric: Metric,
     master_commits: &[collector::MasterCommit],
     func: F,
-) -> Result<HashSet<Comparison>, BoxedError> {
+) -> Result<(HashSet<Comparison>, DataCompleteness), BoxedError> {
     // `responses` contains series iterators. The first element in the iterator is the data
     // for `a` and the second is the data for `b`
     let mut responses = ctxt.statistic_series(query.clone(), aids).await?;
@@ -895,21 +985,35 @@ async fn get_comparison<
     let statistics_for_a = statistics_from_series(&mut responses);
     let statistics_for_b = statistics_from_series(&mut responses);
 
+    // A test case is expected to have data if either artifact has it; a
+    // shortfall on one side marks that artifact as partially benchmarked.
+    let expected = statistics_for_a
+        .keys()
+        .chain(statistics_for_b.keys())
+        .collect::<HashSet<_>>()
+        .len() as u32;
+    let completeness = DataCompleteness {
+        expected,
+        present_a: statistics_for_a.len() as u32,
+        present_b: statistics_for_b.len() as u32,
+    };
+
     let mut historical_data =
         HistoricalDataMap::<Query>::calculate(ctxt, start_artifact, master_commits, query).await?;
-    Ok(statistics_for_a
+    let comparisons = statistics_for_a
         .into_iter()
         .filter_map(|(test_case, a)| {
             statistics_for_b.get(&test_case).map(|&b| {
-                let comparison = TestResultComparison {
+                let comparison = TestResultComparison::new(
                     metric,
-                    historical_data: historical_data.data.remove(&test_case),
-                    results: (a, b),
-                };
+                    historical_data.data.remove(&test_case),
+                    (a, b),
+                );
                 func(test_case, comparison)
             })
         })
-        .collect())
+        .collect();
+    Ok((comparisons, completeness))
 }
 
 fn previous_commits(
@@ -936,7 +1040,7 @@ fn previous_commits(
 }
 
 /// Detailed description of a specific artifact
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArtifactDescription {
     /// The artifact in question
     pub artifact: ArtifactId,
@@ -946,6 +1050,9 @@ pub struct ArtifactDescription {
     pub bootstrap: HashMap<String, u64>,
     pub bootstrap_total: u64,
     pub component_sizes: HashMap<String, u64>,
+    /// Toolchain metadata (version string, LLVM version, components, ...)
+    /// recorded at benchmarking time.
+    pub info: HashMap<String, String>,
 }
 
 type StatisticsMap<TestCase> = HashMap<TestCase, f64>;
@@ -1001,12 +1108,15 @@ impl ArtifactDescription {
 
         let component_sizes = conn.get_artifact_size(aid).await.into_iter().collect();
 
+        let info = conn.get_artifact_info(aid).await;
+
         Self {
             pr,
             artifact,
             bootstrap,
             bootstrap_total,
             component_sizes,
+            info,
         }
     }
 }
@@ -1031,6 +1141,16 @@ where
     stats
 }
 
+impl From<DataCompleteness> for api::comparison::Completeness {
+    fn from(completeness: DataCompleteness) -> Self {
+        api::comparison::Completeness {
+            expected: completeness.expected,
+            present_a: completeness.present_a,
+            present_b: completeness.present_b,
+        }
+    }
+}
+
 impl From<ArtifactDescription> for api::comparison::ArtifactDescription {
     fn from(data: ArtifactDescription) -> Self {
         api::comparison::ArtifactDescription {
@@ -1054,12 +1174,13 @@ impl From<ArtifactDescription> for api::comparison::ArtifactDescription {
             bootstrap: data.bootstrap,
             bootstrap_total: data.bootstrap_total,
             component_sizes: data.component_sizes,
+            info: data.info,
         }
     }
 }
 
 // A comparison of two artifacts
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ArtifactComparison {
     pub a: ArtifactDescription,
     pub b: ArtifactDescription,
@@ -1067,10 +1188,27 @@ pub struct ArtifactComparison {
     pub compile_comparisons: HashSet<CompileTestResultComparison>,
     /// Runtime test result copmarisons between the two artifacts
     pub runtime_comparisons: HashSet<RuntimeTestResultComparison>,
+    /// How complete each artifact's compile-time data is
+    pub compile_completeness: DataCompleteness,
+    /// How complete each artifact's runtime data is
+    pub runtime_completeness: DataCompleteness,
     /// A map from benchmark name to an error which occured when building `b` but not `a`.
     pub newly_failed_benchmarks: HashMap<String, String>,
 }
 
+/// How complete the benchmark data of the two compared artifacts is, so that
+/// a half-finished run can be told apart from a complete one without
+/// counting holes in the result tables.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DataCompleteness {
+    /// Test cases with data for the metric in at least one of the artifacts
+    pub expected: u32,
+    /// Of those, the number with data in artifact `a`
+    pub present_a: u32,
+    /// Of those, the number with data in artifact `b`
+    pub present_b: u32,
+}
+
 impl ArtifactComparison {
     /// Gets the previous commit before `a`
     pub fn prev(&self, master_commits: &[collector::MasterCommit]) -> Option<String> {
@@ -1111,6 +1249,10 @@ impl ArtifactComparison {
         ) = self
             .compile_comparisons
             .into_iter()
+            // Stress benchmarks are curated pathological cases; they are
+            // benchmarked only on demand and deliberately kept out of both
+            // summaries so they cannot sway relevance determinations.
+            .filter(|s| category_map.get(&s.benchmark()) != Some(&Category::Stress))
             .partition(|s| category_map.get(&s.benchmark()) == Some(&Category::Primary));
         (
             ArtifactComparisonSummary::summarize(
@@ -1170,7 +1312,7 @@ impl<Query: BenchmarkQuery> HistoricalDataMap<Query> {
         }
 
         // Only retain test cases for which we have enough data to calculate variance.
-        historical_data.retain(|_, v| v.data.len() >= Self::NUM_PREVIOUS_COMMITS);
+        historical_data.retain(|_, v| v.len() >= Self::NUM_PREVIOUS_COMMITS);
 
         Ok(Self {
             data: historical_data,
@@ -1178,83 +1320,6 @@ impl<Query: BenchmarkQuery> HistoricalDataMap<Query> {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
-pub struct HistoricalData {
-    data: Vec<f64>,
-}
-
-impl HistoricalData {
-    /// The multiple of the IQR above Q3 that signifies significance
-    const IQR_MULTIPLIER: f64 = 3.0;
-
-    fn push(&mut self, value: f64) {
-        self.data.push(value);
-    }
-
-    /// The percent change of the deltas sorted from smallest delta to largest
-    fn percent_changes(&self) -> Vec<f64> {
-        let mut deltas = self
-            .deltas()
-            .zip(self.data.iter())
-            .map(|(d, &r)| d / r)
-            .collect::<Vec<_>>();
-        deltas.sort_by(|d1, d2| d1.partial_cmp(d2).unwrap_or(cmp::Ordering::Equal));
-        deltas
-    }
-
-    // This is an absolute value indicating the noise barrier for changes on
-    // this benchmark.
-    //
-    // A number line could be divided like this:
-    //
-    // ------o-------o----------
-    //       ^   ^   ^
-    //       |   |   |
-    //       |   |   |
-    //       |   |   ---- +significance_threshold
-    //       |   |
-    //       |   - not significant, includes zero
-    //       |
-    //       ---- -significance_threshold()
-    fn significance_threshold(&self) -> f64 {
-        let (q1, q3) = self.quartiles();
-
-        // Changes that are IQR_MULTIPLIER away from the Q3 are considered
-        // outliers, and we judge those as significant.
-        q3 + (q3 - q1) * Self::IQR_MULTIPLIER
-    }
-
-    // (q1, q3)
-    fn quartiles(&self) -> (f64, f64) {
-        let pcs = self.percent_changes();
-        fn median(data: &[f64]) -> f64 {
-            if data.len() % 2 == 0 {
-                (data[(data.len() - 1) / 2] + data[data.len() / 2]) / 2.0
-            } else {
-                data[data.len() / 2]
-            }
-        }
-
-        let len = pcs.len();
-        let (h1_end, h2_begin) = if len % 2 == 0 {
-            (len / 2 - 2, len / 2 + 1)
-        } else {
-            (len / 2 - 1, len / 2 + 1)
-        };
-        let q1 = median(&pcs[..=h1_end]);
-        let q3 = median(&pcs[h2_begin..]);
-
-        (q1, q3)
-    }
-
-    // Absolute deltas between adjacent results
-    fn deltas(&self) -> impl Iterator<Item = f64> + '_ {
-        self.data
-            .windows(2)
-            .map(|window| (window[0] - window[1]).abs())
-    }
-}
-
 /// Gets the previous commit
 pub fn prev_commit<'a>(
     artifact: &ArtifactId,
@@ -1280,131 +1345,18 @@ pub fn next_commit<'a>(
     }
 }
 
-// A single comparison between two test results
-#[derive(Debug, Clone)]
-pub struct TestResultComparison {
-    metric: Metric,
-    historical_data: Option<HistoricalData>,
-    results: (f64, f64),
-}
-
-impl TestResultComparison {
-    /// The amount of relative change considered significant when
-    /// we cannot determine from historical data
-    const DEFAULT_SIGNIFICANCE_THRESHOLD: f64 = 0.002;
-
-    fn is_regression(&self) -> bool {
-        let (a, b) = self.results;
-        b > a
-    }
-
-    fn is_improvement(&self) -> bool {
-        !self.is_regression()
-    }
-
-    /// Whether the comparison yielded a statistically significant result
-    pub fn is_significant(&self) -> bool {
-        self.relative_change().abs() >= self.significance_threshold()
-    }
-
-    /// Magnitude of change considered significant
-    fn significance_threshold(&self) -> f64 {
-        self.historical_data
-            .as_ref()
-            .map(|d| d.significance_threshold())
-            .unwrap_or(Self::DEFAULT_SIGNIFICANCE_THRESHOLD)
-    }
-
-    /// This is a numeric magnitude of a particular change.
-    fn significance_factor(&self) -> f64 {
-        let change = self.relative_change();
-        let threshold = self.significance_threshold();
-
-        // How many times the threshold this change is.
-        let factor = change.abs() / threshold;
-        if factor.is_finite() {
-            factor
-        } else {
-            0.0
-        }
-    }
-
-    /// Whether the comparison is relevant or not.
-    ///
-    /// Relevance is a function of significance and magnitude.
-    fn is_relevant(&self) -> bool {
-        self.is_significant() && self.magnitude().is_small_or_above()
-    }
-
-    /// The magnitude of the change.
-    ///
-    /// This is the average of the absolute magnitude of the change
-    /// and the amount above the significance threshold.
-    pub fn magnitude(&self) -> Magnitude {
-        let change = self.relative_change().abs();
-
-        // When the significance threshold is very small, magnitude can become VeryLarge even though
-        // the change itself if incredibly small. So we deliberately return a VerySmall magnitude
-        // here to avoid marking such small result as being relevant.
-        if change < 0.0001 {
-            return Magnitude::VerySmall;
-        }
-
-        let threshold = self.significance_threshold();
-        let over_threshold = if change < threshold * 1.5 {
-            Magnitude::VerySmall
-        } else if change < threshold * 3.0 {
-            Magnitude::Small
-        } else if change < threshold * 6.0 {
-            Magnitude::Medium
-        } else if change < threshold * 12.0 {
-            Magnitude::Large
-        } else {
-            Magnitude::VeryLarge
-        };
-        let absolute_magnitude = self.metric.relative_change_magnitude(change * 100.0);
-        fn as_u8(m: Magnitude) -> u8 {
-            match m {
-                Magnitude::VerySmall => 1,
-                Magnitude::Small => 2,
-                Magnitude::Medium => 3,
-                Magnitude::Large => 4,
-                Magnitude::VeryLarge => 5,
-            }
-        }
-        fn from_u8(m: u8) -> Magnitude {
-            match m {
-                1 => Magnitude::VerySmall,
-                2 => Magnitude::Small,
-                3 => Magnitude::Medium,
-                4 => Magnitude::Large,
-                _ => Magnitude::VeryLarge,
-            }
-        }
-
-        // Take the average of the absolute magnitude and the magnitude
-        // above the significance threshold.
-        from_u8((as_u8(over_threshold) + as_u8(absolute_magnitude)) / 2)
-    }
-
-    fn relative_change(&self) -> f64 {
-        let (a, b) = self.results;
-        (b - a) / a
-    }
-}
-
 impl From<TestResultComparison> for StatComparison {
     fn from(comparison: TestResultComparison) -> Self {
         Self {
             is_relevant: comparison.is_relevant(),
             significance_threshold: comparison.significance_threshold(),
             significance_factor: comparison.significance_factor(),
-            statistics: comparison.results,
+            statistics: comparison.results(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompileTestResultComparison {
     benchmark: Benchmark,
     profile: Profile,
@@ -1416,6 +1368,22 @@ impl CompileTestResultComparison {
     pub fn benchmark(&self) -> Benchmark {
         self.benchmark
     }
+
+    pub fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    pub fn scenario(&self) -> Scenario {
+        self.scenario
+    }
+}
+
+impl Deref for CompileTestResultComparison {
+    type Target = TestResultComparison;
+
+    fn deref(&self) -> &Self::Target {
+        &self.comparison
+    }
 }
 
 impl cmp::PartialEq for CompileTestResultComparison {
@@ -1436,12 +1404,18 @@ impl std::hash::Hash for CompileTestResultComparison {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimeTestResultComparison {
     benchmark: Benchmark,
     comparison: TestResultComparison,
 }
 
+impl RuntimeTestResultComparison {
+    pub fn benchmark(&self) -> Benchmark {
+        self.benchmark
+    }
+}
+
 impl Deref for RuntimeTestResultComparison {
     type Target = TestResultComparison;
 
@@ -1464,53 +1438,11 @@ impl std::hash::Hash for RuntimeTestResultComparison {
     }
 }
 
-// The direction of a performance change
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum Direction {
-    None,
-    Improvement,
-    Regression,
-    Mixed,
-}
-
-// The direction of a performance change. Forms a lattice:
-//
-//          Mixed
-//          /    \
-// Improvement  Regression
-//          \    /
-//           None
-//
-impl Direction {
-    // Also known as the "least upper bound".
-    pub fn join(self, other: Self) -> Self {
-        match (self, other) {
-            (Self::None, b) => b,
-            (a, Self::None) => a,
-            (Self::Improvement, Self::Improvement) => Self::Improvement,
-            (Self::Regression, Self::Regression) => Self::Regression,
-            _ => Self::Mixed,
-        }
-    }
-}
-
-/// The relative size of a performance change
-#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Ord, Eq)]
-pub enum Magnitude {
-    VerySmall,
-    Small,
-    Medium,
-    Large,
-    VeryLarge,
-}
-
-impl Magnitude {
-    fn is_small_or_above(&self) -> bool {
-        *self >= Self::Small
-    }
-
-    fn is_medium_or_above(&self) -> bool {
-        *self >= Self::Medium
+fn fmt_bound(bound: &Bound) -> String {
+    match bound {
+        Bound::Commit(s) => s.to_owned(),
+        Bound::Date(s) => s.format("%Y-%m-%d").to_string(),
+        _ => "???".to_owned(),
     }
 }
 
@@ -1521,13 +1453,6 @@ async fn generate_report(
     mut report: HashMap<Direction, Vec<String>>,
     num_comparisons: usize,
 ) -> String {
-    fn fmt_bound(bound: &Bound) -> String {
-        match bound {
-            Bound::Commit(s) => s.to_owned(),
-            Bound::Date(s) => s.format("%Y-%m-%d").to_string(),
-            _ => "???".to_owned(),
-        }
-    }
     let start = fmt_bound(start);
     let end = fmt_bound(end);
     let regressions = report.remove(&Direction::Regression).unwrap_or_default();
@@ -1597,6 +1522,52 @@ TODO: Nags
     )
 }
 
+/// Generates a report in the format posted to the performance triage Zulip
+/// stream: a short header followed by one collapsible section per direction,
+/// with the entries grouped by PR and linking to the relevant comparisons.
+fn generate_zulip_report(
+    start: &Bound,
+    end: &Bound,
+    summary: String,
+    mut report: HashMap<Direction, Vec<String>>,
+) -> String {
+    let start = fmt_bound(start);
+    let end = fmt_bound(end);
+    let regressions = report.remove(&Direction::Regression).unwrap_or_default();
+    let improvements = report.remove(&Direction::Improvement).unwrap_or_default();
+    let mixed = report.remove(&Direction::Mixed).unwrap_or_default();
+
+    let mut result = format!(
+        "**Performance triage**: revision range [{start}..{end}]\
+        (https://perf.rust-lang.org/?start={start}&end={end}&absolute=false&stat=instructions%3Au)\n\n\
+        {summary}\n\n\
+        {} regressions, {} improvements, {} mixed\n\n",
+        regressions.len(),
+        improvements.len(),
+        mixed.len(),
+    );
+    for (label, entries) in [
+        ("regressions", &regressions),
+        ("improvements", &improvements),
+        ("mixed", &mixed),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        // Zulip renders ```spoiler blocks as collapsible sections, which keeps
+        // the message readable even when there are many entries.
+        write!(
+            &mut result,
+            "```spoiler {} {}\n{}\n```\n\n",
+            entries.len(),
+            label,
+            entries.join("\n\n"),
+        )
+        .unwrap();
+    }
+    result
+}
+
 fn compare_link(start: &ArtifactId, end: &ArtifactId) -> String {
     let start = match &start {
         ArtifactId::Tag(a) => a,
@@ -1788,24 +1759,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn parse_metric_instructions() {
-        let metric: Metric = serde_json::from_str(r#""instructions:u""#).unwrap();
-        assert!(matches!(metric, Metric::InstructionsUser));
-    }
-
-    #[test]
-    fn parse_metric_cycles() {
-        let metric: Metric = serde_json::from_str(r#""cycles:u""#).unwrap();
-        assert!(matches!(metric, Metric::CyclesUser));
-    }
-
-    #[test]
-    fn parse_metric_max_rss() {
-        let metric: Metric = serde_json::from_str(r#""max-rss""#).unwrap();
-        assert!(matches!(metric, Metric::MaxRSS));
-    }
-
     // (category, before, after)
     fn check_table(values: Vec<(Category, f64, f64)>, expected: &str) {
         let mut primary_comparisons = Vec::new();
@@ -1817,11 +1770,11 @@ mod tests {
                 &mut secondary_comparisons
             };
 
-            target.push(TestResultComparison {
-                metric: Metric::InstructionsUser,
-                historical_data: None,
-                results: (before, after),
-            });
+            target.push(TestResultComparison::new(
+                Metric::InstructionsUser,
+                None,
+                (before, after),
+            ));
         }
 
         let primary = ArtifactComparisonSummary::summarize(primary_comparisons);
@@ -1,6 +1,6 @@
 use std::fmt;
 
-pub use crate::average::average;
+pub use crate::average::{average, weighted_average};
 pub use database::*;
 
 pub trait Point {
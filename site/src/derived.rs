@@ -0,0 +1,354 @@
+//! Expressions for derived metrics: virtual metrics configured in
+//! `site-config.toml` and computed from stored metrics at query time, e.g.
+//! `cycles:u / instructions:u` or `instructions:u - baseline(instructions:u)`.
+//!
+//! The grammar is `+`, `-`, `*`, `/` with the usual precedence, parentheses,
+//! numeric literals, metric names, and `baseline(metric)`, which evaluates to
+//! the metric's value at the first artifact of the queried range. Since many
+//! metric names contain hyphens (`wall-time`), a `-` that directly joins two
+//! name characters is part of the name; write subtraction with spaces around
+//! the operator.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// The named metric's value at the artifact being evaluated.
+    Metric(String),
+    /// The named metric's value at the first artifact of the queried range.
+    Baseline(String),
+    Literal(f64),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn parse(input: &str) -> Result<Expr, String> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.expr()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(format!("unexpected `{}` after expression", token)),
+        }
+    }
+
+    /// The stored metrics the expression reads.
+    pub fn metrics(&self) -> BTreeSet<String> {
+        let mut metrics = BTreeSet::new();
+        self.collect_metrics(&mut metrics);
+        metrics
+    }
+
+    fn collect_metrics(&self, metrics: &mut BTreeSet<String>) {
+        match self {
+            Expr::Metric(name) | Expr::Baseline(name) => {
+                metrics.insert(name.clone());
+            }
+            Expr::Literal(_) => {}
+            Expr::Binary(_, lhs, rhs) => {
+                lhs.collect_metrics(metrics);
+                rhs.collect_metrics(metrics);
+            }
+        }
+    }
+
+    /// Evaluates the expression for a single artifact. `value` and `baseline`
+    /// look up a stored metric at that artifact and at the first artifact of
+    /// the range respectively; a missing input or a non-finite result (e.g.
+    /// division by zero) yields `None`.
+    pub fn evaluate(
+        &self,
+        value: &dyn Fn(&str) -> Option<f64>,
+        baseline: &dyn Fn(&str) -> Option<f64>,
+    ) -> Option<f64> {
+        let result = match self {
+            Expr::Metric(name) => value(name)?,
+            Expr::Baseline(name) => baseline(name)?,
+            Expr::Literal(literal) => *literal,
+            Expr::Binary(op, lhs, rhs) => {
+                let lhs = lhs.evaluate(value, baseline)?;
+                let rhs = rhs.evaluate(value, baseline)?;
+                match op {
+                    Op::Add => lhs + rhs,
+                    Op::Sub => lhs - rhs,
+                    Op::Mul => lhs * rhs,
+                    Op::Div => lhs / rhs,
+                }
+            }
+        };
+        result.is_finite().then_some(result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Number(f64),
+    Name(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Plus => f.write_str("+"),
+            Token::Minus => f.write_str("-"),
+            Token::Star => f.write_str("*"),
+            Token::Slash => f.write_str("/"),
+            Token::LParen => f.write_str("("),
+            Token::RParen => f.write_str(")"),
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Whether `c` may continue a metric name. Names start with a letter.
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '.' | '#' | '@')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Slash);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                tokens.push(Token::Number(
+                    literal
+                        .parse()
+                        .map_err(|_| format!("invalid number `{}`", literal))?,
+                ));
+            }
+            c if c.is_ascii_alphabetic() => {
+                let start = i;
+                while i < chars.len() {
+                    if is_name_char(chars[i]) {
+                        i += 1;
+                    } else if chars[i] == '-'
+                        && i + 1 < chars.len()
+                        && chars[i + 1].is_ascii_alphanumeric()
+                    {
+                        // A hyphen joining two name characters is part of the
+                        // name (`wall-time`), not a subtraction.
+                        i += 2;
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Name(chars[start..i].iter().collect()));
+            }
+            c => return Err(format!("unexpected character `{}`", c)),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += token.is_some() as usize;
+        token
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => Op::Add,
+                Some(Token::Minus) => Op::Sub,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.factor()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => Op::Mul,
+                Some(Token::Slash) => Op::Div,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.factor()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Literal(n)),
+            Some(Token::Name(name)) if name == "baseline" => {
+                if self.next() != Some(Token::LParen) {
+                    return Err("expected `(` after `baseline`".to_string());
+                }
+                let metric = match self.next() {
+                    Some(Token::Name(metric)) => metric,
+                    other => {
+                        return Err(format!(
+                            "expected a metric name inside `baseline(...)`, found {}",
+                            found(other)
+                        ))
+                    }
+                };
+                if self.next() != Some(Token::RParen) {
+                    return Err("expected `)` after `baseline(metric`".to_string());
+                }
+                Ok(Expr::Baseline(metric))
+            }
+            Some(Token::Name(name)) => Ok(Expr::Metric(name)),
+            Some(Token::LParen) => {
+                let expr = self.expr()?;
+                if self.next() != Some(Token::RParen) {
+                    return Err("unclosed `(`".to_string());
+                }
+                Ok(expr)
+            }
+            other => Err(format!("expected a value, found {}", found(other))),
+        }
+    }
+}
+
+fn found(token: Option<Token>) -> String {
+    match token {
+        Some(token) => format!("`{}`", token),
+        None => "end of expression".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence_and_parens() {
+        let expr = Expr::parse("cycles:u / instructions:u + 1").unwrap();
+        assert_eq!(
+            expr,
+            Expr::Binary(
+                Op::Add,
+                Box::new(Expr::Binary(
+                    Op::Div,
+                    Box::new(Expr::Metric("cycles:u".to_string())),
+                    Box::new(Expr::Metric("instructions:u".to_string())),
+                )),
+                Box::new(Expr::Literal(1.0)),
+            )
+        );
+        let expr = Expr::parse("cycles:u / (instructions:u + 1)").unwrap();
+        assert!(matches!(expr, Expr::Binary(Op::Div, _, _)));
+    }
+
+    #[test]
+    fn hyphens_join_names_unless_spaced() {
+        assert_eq!(
+            Expr::parse("wall-time").unwrap(),
+            Expr::Metric("wall-time".to_string())
+        );
+        assert_eq!(
+            Expr::parse("wall-time - max-rss").unwrap(),
+            Expr::Binary(
+                Op::Sub,
+                Box::new(Expr::Metric("wall-time".to_string())),
+                Box::new(Expr::Metric("max-rss".to_string())),
+            )
+        );
+    }
+
+    #[test]
+    fn baseline_call() {
+        let expr = Expr::parse("instructions:u - baseline(instructions:u)").unwrap();
+        assert_eq!(
+            expr.metrics().into_iter().collect::<Vec<_>>(),
+            vec!["instructions:u".to_string()]
+        );
+        let value = |_: &str| Some(110.0);
+        let baseline = |_: &str| Some(100.0);
+        assert_eq!(expr.evaluate(&value, &baseline), Some(10.0));
+    }
+
+    #[test]
+    fn missing_inputs_and_division_by_zero() {
+        let expr = Expr::parse("cycles:u / instructions:u").unwrap();
+        assert_eq!(expr.evaluate(&|_| None, &|_| None), None);
+        let zero = |name: &str| Some(if name == "instructions:u" { 0.0 } else { 1.0 });
+        assert_eq!(expr.evaluate(&zero, &|_| None), None);
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert!(Expr::parse("").is_err());
+        assert!(Expr::parse("cycles:u +").is_err());
+        assert!(Expr::parse("(cycles:u").is_err());
+        assert!(Expr::parse("baseline(2)").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! Daily digests of significant changes on subscribed benchmarks.
+//!
+//! Subscriptions live in the `subscription` table and pair a delivery target
+//! (an email address or a webhook URL) with a benchmark name selector. The
+//! `send-digests` periodic job walks the master commits benchmarked since the
+//! last run, collects the relevant comparison results — the same data the
+//! compare page renders — and delivers a plain-text summary per subscription.
+//! Email delivery goes through the local `sendmail` binary.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use anyhow::Context;
+use chrono::Utc;
+use collector::Bound;
+
+use crate::comparison::{compare, Metric};
+use crate::load::SiteCtxt;
+
+pub async fn send_digests(ctxt: Arc<SiteCtxt>) -> anyhow::Result<()> {
+    let subscriptions = ctxt.conn().await.subscriptions().await;
+    if subscriptions.is_empty() {
+        return Ok(());
+    }
+
+    // Master commits benchmarked within the last day, oldest first.
+    let now = Utc::now();
+    let commits: Vec<_> = ctxt
+        .index
+        .load()
+        .commits()
+        .into_iter()
+        .filter(|c| {
+            c.is_master() && now.signed_duration_since(c.date.0) < chrono::Duration::days(1)
+        })
+        .collect();
+    if commits.is_empty() {
+        return Ok(());
+    }
+
+    // Relevant changes as (test case, relative change in percent, sha).
+    let master_commits = &ctxt.get_master_commits().commits;
+    let mut changes: Vec<(String, f64, String)> = Vec::new();
+    for commit in &commits {
+        let parent = master_commits
+            .iter()
+            .find(|m| m.sha == commit.sha)
+            .map(|m| m.parent_sha.clone());
+        let parent = match parent {
+            Some(parent) => parent,
+            None => continue,
+        };
+        let comparison = match compare(
+            Bound::Commit(parent),
+            Bound::Commit(commit.sha.clone()),
+            Metric::InstructionsUser,
+            &ctxt,
+        )
+        .await
+        {
+            Ok(Some(comparison)) => comparison,
+            Ok(None) => continue,
+            Err(error) => {
+                log::error!("failed to compare {} for digest: {error}", commit.sha);
+                continue;
+            }
+        };
+        for c in &comparison.compile_comparisons {
+            if c.is_relevant() {
+                changes.push((
+                    format!("{}/{}/{}", c.benchmark(), c.profile(), c.scenario()),
+                    c.relative_change() * 100.0,
+                    commit.sha.clone(),
+                ));
+            }
+        }
+        for c in &comparison.runtime_comparisons {
+            if c.is_relevant() {
+                changes.push((
+                    format!("runtime/{}", c.benchmark()),
+                    c.relative_change() * 100.0,
+                    commit.sha.clone(),
+                ));
+            }
+        }
+    }
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    for subscription in subscriptions {
+        let mut relevant: Vec<_> = changes
+            .iter()
+            .filter(|(case, _, _)| {
+                subscription.benchmark.is_empty() || case.contains(&subscription.benchmark)
+            })
+            .collect();
+        if relevant.is_empty() {
+            continue;
+        }
+        relevant.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+
+        let mut body = String::from(
+            "Significant changes on subscribed benchmarks over the last day \
+            (instructions:u):\n\n",
+        );
+        for (case, change, sha) in relevant.iter().take(50) {
+            writeln!(body, "{case}: {change:+.2}% in {}", &sha[..sha.len().min(10)]).unwrap();
+        }
+        if relevant.len() > 50 {
+            writeln!(body, "... and {} more", relevant.len() - 50).unwrap();
+        }
+        body.push_str("\nFull data: https://perf.rust-lang.org/compare.html\n");
+
+        let result = match subscription.kind.as_str() {
+            "webhook" => crate::alerts::fire_webhook(&subscription.target, &body).await,
+            "email" => send_email(&subscription.target, "rustc-perf daily digest", &body),
+            other => Err(anyhow::anyhow!("unknown subscription kind {other}")),
+        };
+        if let Err(error) = result {
+            log::error!(
+                "failed to deliver digest to {} ({}): {error:?}",
+                subscription.target,
+                subscription.kind
+            );
+        }
+    }
+    Ok(())
+}
+
+fn send_email(to: &str, subject: &str, body: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+    let mut child = std::process::Command::new("sendmail")
+        .arg("-t")
+        .stdin(std::process::Stdio::piped())
+        .spawn()
+        .context("failed to spawn sendmail")?;
+    let message = format!("To: {to}\nSubject: {subject}\n\n{body}");
+    child
+        .stdin
+        .as_mut()
+        .unwrap()
+        .write_all(message.as_bytes())
+        .context("failed to write mail to sendmail")?;
+    let status = child.wait()?;
+    anyhow::ensure!(status.success(), "sendmail exited with {status}");
+    Ok(())
+}
@@ -28,7 +28,7 @@ impl Client {
     /// Create a client from a `SiteCtxt` and a URL
     pub fn from_ctxt(ctxt: &SiteCtxt, repository_url: String) -> Self {
         let token = ctxt
-            .config
+            .config()
             .keys
             .github_api_token
             .clone()
@@ -253,7 +253,7 @@ impl GraphQLClient {
     /// Create a GraphQL client from a `SiteCtxt`.
     pub fn from_ctxt(ctxt: &SiteCtxt) -> Self {
         let token = ctxt
-            .config
+            .config()
             .keys
             .github_api_token
             .clone()
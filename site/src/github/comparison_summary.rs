@@ -7,6 +7,7 @@ use crate::load::SiteCtxt;
 use database::{ArtifactId, QueuedCommit};
 
 use crate::github::{COMMENT_MARK_ROLLUP, COMMENT_MARK_TEMPORARY, RUST_REPO_GITHUB_API_URL};
+use collector::Bound;
 use humansize::BINARY;
 use std::collections::HashSet;
 use std::fmt::Write;
@@ -16,7 +17,7 @@ use std::fmt::Write;
 pub async fn post_finished(ctxt: &SiteCtxt) {
     // If the github token is not configured, do not run this -- we don't want
     // to mark things as complete without posting the comment.
-    if ctxt.config.keys.github_api_token.is_none() {
+    if ctxt.config().keys.github_api_token.is_none() {
         return;
     }
     let conn = ctxt.conn().await;
@@ -55,6 +56,30 @@ pub async fn post_finished(ctxt: &SiteCtxt) {
         .into_iter()
         .filter(|c| known_commits.contains(&c.sha))
     {
+        // Sanity-check the new results before treating them as truth. A run
+        // where most of the suite shifted wildly is almost always a collector
+        // environment problem, so re-benchmark the artifact once instead of
+        // posting a bogus mass-regression comment.
+        if results_look_implausible(ctxt, &queued_commit).await {
+            if conn.mark_artifact_suspect(&queued_commit.sha).await {
+                log::warn!(
+                    "results for {} look implausible; re-benchmarking once",
+                    queued_commit.sha
+                );
+                if let Some(commit) = index
+                    .commits()
+                    .into_iter()
+                    .find(|c| c.sha == queued_commit.sha)
+                {
+                    conn.purge_artifact(&ArtifactId::Commit(commit)).await;
+                }
+                continue;
+            }
+            log::warn!(
+                "results for {} still look implausible after a re-run; keeping them",
+                queued_commit.sha
+            );
+        }
         if let Some(completed) = conn.mark_complete(&queued_commit.sha).await {
             assert_eq!(completed, queued_commit);
 
@@ -67,6 +92,47 @@ pub async fn post_finished(ctxt: &SiteCtxt) {
     }
 }
 
+/// Fraction of compile test cases that must shift wildly before a run is
+/// considered implausible.
+const SUSPECT_CASE_FRACTION: f64 = 0.8;
+/// Relative change beyond which a single result counts as a wild shift.
+const SUSPECT_RELATIVE_CHANGE: f64 = 0.5;
+
+/// Whether the results for the given commit look implausible when compared
+/// with its parent. Most of the compile suite shifting by half or more does
+/// not happen for genuine compiler changes.
+async fn results_look_implausible(ctxt: &SiteCtxt, commit: &QueuedCommit) -> bool {
+    let comparison = match crate::comparison::compare(
+        Bound::Commit(commit.parent_sha.clone()),
+        Bound::Commit(commit.sha.clone()),
+        Metric::InstructionsUser,
+        ctxt,
+    )
+    .await
+    {
+        Ok(Some(comparison)) => comparison,
+        Ok(None) => return false,
+        Err(error) => {
+            log::error!(
+                "cannot sanity-check results for {}: {:?}",
+                commit.sha,
+                error
+            );
+            return false;
+        }
+    };
+    let total = comparison.compile_comparisons.len();
+    if total == 0 {
+        return false;
+    }
+    let wild = comparison
+        .compile_comparisons
+        .iter()
+        .filter(|comparison| comparison.relative_change().abs() > SUSPECT_RELATIVE_CHANGE)
+        .count();
+    wild as f64 > total as f64 * SUSPECT_CASE_FRACTION
+}
+
 /// Posts a comment to GitHub summarizing the comparison of the queued commit with its parent
 ///
 /// `is_master_commit` is used to differentiate messages for try runs and post-merge runs.
@@ -0,0 +1,169 @@
+//! Periodic maintenance jobs run inside the site binary.
+//!
+//! This is a small cron-like subsystem: each job has a name and a default
+//! interval and is driven by a tokio timer for as long as the site is up,
+//! replacing the external cron entries that used to poke ad-hoc endpoints.
+//! Intervals can be overridden (and the subsystem disabled) via the `[jobs]`
+//! section of `site-config.toml`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::api::graphs;
+use crate::comparison::Metric;
+use crate::load::{MasterCommitCache, SiteCtxt};
+
+use collector::Bound;
+
+struct Job {
+    name: &'static str,
+    default_interval: Duration,
+    run: fn(Arc<SiteCtxt>) -> BoxFuture<'static, anyhow::Result<()>>,
+}
+
+fn jobs() -> Vec<Job> {
+    vec![
+        Job {
+            name: "warm-caches",
+            default_interval: Duration::from_secs(30 * 60),
+            run: |ctxt| Box::pin(warm_caches(ctxt)),
+        },
+        Job {
+            name: "precompute-comparisons",
+            default_interval: Duration::from_secs(60 * 60),
+            run: |ctxt| Box::pin(precompute_comparisons(ctxt)),
+        },
+        Job {
+            name: "refresh-noise-model",
+            default_interval: Duration::from_secs(6 * 60 * 60),
+            run: |ctxt| Box::pin(refresh_noise_model(ctxt)),
+        },
+        Job {
+            name: "enforce-retention",
+            default_interval: Duration::from_secs(24 * 60 * 60),
+            run: |ctxt| Box::pin(enforce_retention(ctxt)),
+        },
+        Job {
+            name: "send-digests",
+            default_interval: Duration::from_secs(24 * 60 * 60),
+            run: |ctxt| Box::pin(crate::digest::send_digests(ctxt)),
+        },
+    ]
+}
+
+/// Spawns a background task for each registered job.
+pub fn start(ctxt: Arc<SiteCtxt>) {
+    let config = ctxt.config();
+    let config = &config.jobs;
+    if !config.enabled {
+        log::info!("periodic jobs disabled by site config");
+        return;
+    }
+    for job in jobs() {
+        let interval = config
+            .intervals
+            .get(job.name)
+            .map(|minutes| Duration::from_secs(minutes * 60))
+            .unwrap_or(job.default_interval);
+        let ctxt = ctxt.clone();
+        tokio::task::spawn(async move {
+            let mut timer = tokio::time::interval(interval);
+            // The first tick fires immediately; skip it so that job work does
+            // not compete with site startup.
+            timer.tick().await;
+            loop {
+                timer.tick().await;
+                log::debug!("running periodic job {}", job.name);
+                if let Err(e) = (job.run)(ctxt.clone()).await {
+                    log::error!("periodic job {} failed: {:?}", job.name, e);
+                }
+            }
+        });
+    }
+}
+
+/// Keeps the caches backing the landing page fresh, so that the first request
+/// after a deploy or a quiet period does not pay the cost of filling them.
+async fn warm_caches(ctxt: Arc<SiteCtxt>) -> anyhow::Result<()> {
+    ctxt.master_commits
+        .store(Arc::new(MasterCommitCache::download().await?));
+
+    // Recompute the landing page graphs; the default query result is cached
+    // by `handle_graphs`.
+    ctxt.landing_page.store(Arc::new(None));
+    crate::request_handlers::handle_graphs(
+        graphs::Request {
+            start: Bound::None,
+            end: Bound::None,
+            stat: String::from("instructions:u"),
+            kind: graphs::GraphKind::Raw,
+            benchmark: None,
+            scenario: None,
+            profile: None,
+            unit: None,
+            series_ids: false,
+            secondary_stat: None,
+        },
+        ctxt,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to recompute landing page: {}", e))?;
+    Ok(())
+}
+
+/// Precomputes (and thereby persists) comparisons for recently benchmarked
+/// master commits, so that compare pages and triage reports are served from
+/// the comparison cache rather than computed on demand.
+async fn precompute_comparisons(ctxt: Arc<SiteCtxt>) -> anyhow::Result<()> {
+    let commits: Vec<_> = ctxt
+        .index
+        .load()
+        .commits()
+        .iter()
+        .rev()
+        .take(20)
+        .cloned()
+        .collect();
+    let master_commits = &ctxt.get_master_commits().commits;
+    for commit in commits {
+        let parent = master_commits
+            .iter()
+            .find(|c| c.sha == commit.sha)
+            .map(|c| c.parent_sha.clone());
+        if let Some(parent) = parent {
+            crate::comparison::compare(
+                Bound::Commit(parent),
+                Bound::Commit(commit.sha.clone()),
+                Metric::InstructionsUser,
+                &ctxt,
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("error comparing commits: {}", e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Reloads the statistics index from the database. The noise model used to
+/// determine the significance of comparison results is derived from the index
+/// on demand, so refreshing it keeps significance thresholds tracking recently
+/// gathered data.
+async fn refresh_noise_model(ctxt: Arc<SiteCtxt>) -> anyhow::Result<()> {
+    let mut conn = ctxt.conn().await;
+    let index = crate::db::Index::load(&mut *conn).await;
+    ctxt.index.store(Arc::new(index));
+    Ok(())
+}
+
+/// Deletes cached comparison summaries older than the configured retention
+/// window.
+async fn enforce_retention(ctxt: Arc<SiteCtxt>) -> anyhow::Result<()> {
+    let days = ctxt.config().jobs.comparison_retention_days;
+    ctxt.conn()
+        .await
+        .purge_stale_comparison_summaries(Duration::from_secs(days * 24 * 60 * 60))
+        .await;
+    Ok(())
+}
@@ -12,14 +12,19 @@ pub struct CommandFailed {
 pub mod api;
 pub mod db;
 pub mod github;
+pub mod jobs;
 pub mod load;
 pub mod server;
 
+mod alerts;
 mod average;
 mod benchmark_metadata;
 mod comparison;
+mod derived;
+mod digest;
 mod interpolate;
 mod request_handlers;
 mod resources;
 mod selector;
 mod self_profile;
+mod suggestions;
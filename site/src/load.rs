@@ -93,10 +93,155 @@ pub struct Keys {
     pub github_webhook_secret: Option<String>,
 }
 
+/// Configuration of the periodic jobs run inside the site binary
+///
+/// Loaded from the `[jobs]` section of `site-config.toml`; every field has a
+/// default so the section can be omitted entirely.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct JobsConfig {
+    /// Master switch for the whole subsystem
+    pub enabled: bool,
+    /// Per-job interval overrides, in minutes, keyed by job name
+    pub intervals: HashMap<String, u64>,
+    /// How long cached comparison summaries are retained, in days
+    pub comparison_retention_days: u64,
+}
+
+impl Default for JobsConfig {
+    fn default() -> Self {
+        JobsConfig {
+            enabled: true,
+            intervals: HashMap::new(),
+            comparison_retention_days: 90,
+        }
+    }
+}
+
+/// A single alert rule
+///
+/// Loaded from an `[[alerts]]` section of `site-config.toml`; rules are
+/// evaluated after each ingestion and fire a webhook when a watched
+/// selection of test cases regresses, see [`crate::alerts`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlertRule {
+    /// Rule name, included in the webhook message
+    pub name: String,
+    /// Metric to watch, e.g. `instructions:u`
+    pub metric: String,
+    /// Exact benchmark name to watch; all benchmarks if omitted
+    pub benchmark: Option<String>,
+    /// Profile to watch (e.g. `opt`); all profiles if omitted
+    pub profile: Option<String>,
+    /// Scenario to watch (e.g. `incr-patched: println`); all if omitted
+    pub scenario: Option<String>,
+    /// Minimum relative regression, in percent, for a test case to match
+    pub threshold: f64,
+    /// Number of consecutive benchmarked master commits the regression has
+    /// to persist before the rule fires
+    #[serde(default = "default_consecutive_commits")]
+    pub consecutive: usize,
+    /// Webhook URL to POST the alert to. The payload is a JSON object with a
+    /// single `text` field, which Zulip and Slack incoming webhooks as well
+    /// as most generic receivers understand.
+    pub webhook: String,
+}
+
+fn default_consecutive_commits() -> usize {
+    1
+}
+
+/// A derived (virtual) metric
+///
+/// Loaded from a `[[derived_metrics]]` section of `site-config.toml`. The
+/// metric is not stored in the database; the query layer computes it on the
+/// fly from the stored metrics its expression references, and it shows up
+/// next to real metrics in `/perf/metrics`, graphs, and the selector query
+/// API. (Comparisons still accept only stored metrics, since their request
+/// format names a [`Metric`](crate::comparison::Metric) variant.) See
+/// [`crate::derived`] for the expression syntax.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DerivedMetric {
+    /// Metric name, e.g. `cpi`; must not collide with a stored metric
+    pub name: String,
+    /// Expression over stored metrics, e.g. `cycles:u / instructions:u`
+    pub expression: String,
+}
+
+/// Configuration of request logging
+///
+/// Loaded from the `[logging]` section of `site-config.toml`; every field has
+/// a default so the section can be omitted entirely.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Requests that take longer than this are logged at warn level
+    pub slow_request_threshold_ms: u64,
+    /// Statistic queries that take longer than this are logged at warn level
+    pub slow_query_threshold_ms: u64,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        LoggingConfig {
+            slow_request_threshold_ms: 5000,
+            slow_query_threshold_ms: 2000,
+        }
+    }
+}
+
 /// Site configuration
 #[derive(Debug, Deserialize)]
 pub struct Config {
     pub keys: Keys,
+    /// Periodic job configuration
+    #[serde(default)]
+    pub jobs: JobsConfig,
+    /// Request logging configuration
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    /// Alert rules evaluated after each ingestion
+    #[serde(default)]
+    pub alerts: Vec<AlertRule>,
+    /// Derived metrics computed from stored ones at query time
+    #[serde(default)]
+    pub derived_metrics: Vec<DerivedMetric>,
+    /// Read-only follower mode: serve queries from a (streamed) copy of the
+    /// database and leave ingestion, bot, and other mutating duties to the
+    /// primary instance. Also enabled by the `SITE_FOLLOWER` environment
+    /// variable, so that two instances can share one configuration file.
+    #[serde(default)]
+    pub follower: bool,
+}
+
+impl Config {
+    /// Loads the configuration from `site-config.toml`, falling back to
+    /// environment variables if the file does not exist.
+    pub fn load() -> anyhow::Result<Config> {
+        let mut config = if let Ok(s) = fs::read_to_string("site-config.toml") {
+            toml::from_str(&s)?
+        } else {
+            Config {
+                keys: Keys {
+                    github_api_token: std::env::var("GITHUB_API_TOKEN").ok(),
+                    github_webhook_secret: std::env::var("GITHUB_WEBHOOK_SECRET").ok(),
+                },
+                jobs: JobsConfig::default(),
+                logging: LoggingConfig::default(),
+                alerts: Vec::new(),
+                derived_metrics: Vec::new(),
+                follower: false,
+            }
+        };
+        config.follower |= std::env::var_os("SITE_FOLLOWER").is_some();
+        // Reject broken expressions at startup rather than at query time.
+        for metric in &config.derived_metrics {
+            if let Err(e) = crate::derived::Expr::parse(&metric.expression) {
+                anyhow::bail!("invalid derived metric `{}`: {}", metric.name, e);
+            }
+        }
+        Ok(config)
+    }
 }
 
 #[derive(Debug)]
@@ -118,8 +263,8 @@ impl MasterCommitCache {
 
 /// Site context object that contains global data
 pub struct SiteCtxt {
-    /// Site configuration
-    pub config: Config,
+    /// Site configuration; can be hot-reloaded via `reload_config`
+    config: ArcSwap<Config>,
     /// Cached site landing page
     pub landing_page: ArcSwap<Option<Arc<crate::api::graphs::Response>>>,
     /// Index of various common queries
@@ -128,6 +273,14 @@ pub struct SiteCtxt {
     pub master_commits: Arc<ArcSwap<MasterCommitCache>>, // outer Arc enables mutation in background task
     /// Database connection pool
     pub pool: Pool,
+    /// Path of the on-disk index snapshot (from `SITE_INDEX_CACHE`), if any
+    index_cache: Option<std::path::PathBuf>,
+    /// Whether the initial index came from the snapshot rather than the
+    /// database, in which case it may be stale and should be refreshed in the
+    /// background once the site is serving
+    pub index_loaded_from_snapshot: bool,
+    /// When the in-memory index was last (re)loaded
+    index_refreshed: ArcSwap<Instant>,
 }
 
 impl SiteCtxt {
@@ -148,52 +301,221 @@ impl SiteCtxt {
         crate::selector::range_subset(self.index.load().commits(), range)
     }
 
+    /// Checks that `metric` names a metric the index has data for, so that a
+    /// typo fails fast with the list of valid names instead of producing an
+    /// empty response after a full range scan.
+    /// Whether `name` is a derived metric configured in `site-config.toml`.
+    pub fn is_derived_metric(&self, name: &str) -> bool {
+        self.config()
+            .derived_metrics
+            .iter()
+            .any(|derived| derived.name == name)
+    }
+
+    pub fn check_known_metric(&self, metric: &str) -> Result<(), String> {
+        let index = self.index.load();
+        let mut known = index.compile_metrics();
+        known.extend(index.runtime_metrics());
+        known.extend(
+            self.config()
+                .derived_metrics
+                .iter()
+                .map(|derived| derived.name.clone()),
+        );
+        known.sort();
+        known.dedup();
+        if known.iter().any(|known| known == metric) {
+            return Ok(());
+        }
+        let mut message = format!("unknown metric `{}`", metric);
+        if let Some(suggestion) = crate::suggestions::did_you_mean(metric, known.iter().cloned()) {
+            message.push_str(&format!("; did you mean `{}`?", suggestion));
+        }
+        message.push_str(&format!(" known metrics are: {}", known.join(", ")));
+        Err(message)
+    }
+
+    /// Checks that `benchmark` names a compile benchmark the index has data
+    /// for; see [`check_known_metric`](Self::check_known_metric).
+    pub fn check_known_compile_benchmark(&self, benchmark: &str) -> Result<(), String> {
+        let index = self.index.load();
+        let known: std::collections::BTreeSet<String> = index
+            .compile_statistic_descriptions()
+            .map(|(&(benchmark, ..), _)| benchmark.to_string())
+            .collect();
+        if known.contains(benchmark) {
+            return Ok(());
+        }
+        let mut message = format!("unknown benchmark `{}`", benchmark);
+        if let Some(suggestion) = crate::suggestions::did_you_mean(benchmark, known.iter().cloned())
+        {
+            message.push_str(&format!("; did you mean `{}`?", suggestion));
+        }
+        message.push_str(&format!(
+            " known benchmarks are: {}",
+            known.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+        Err(message)
+    }
+
+    /// Checks that the `start` bound does not come after the `end` bound.
+    /// Bounds that do not resolve to a commit are left for the caller to
+    /// report.
+    pub fn check_bound_order(&self, start: &Bound, end: &Bound) -> Result<(), String> {
+        if let (Some(ArtifactId::Commit(start_commit)), Some(ArtifactId::Commit(end_commit))) = (
+            self.artifact_id_for_bound(start.clone(), true),
+            self.artifact_id_for_bound(end.clone(), false),
+        ) {
+            if start_commit.date > end_commit.date {
+                return Err(format!(
+                    "start bound {:?} resolves to {}, which is later than the end \
+                    bound {:?} ({}); swap the bounds",
+                    start, start_commit.date.0, end, end_commit.date.0
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Initialize `SiteCtxt` from database url
+    ///
+    /// If `SITE_INDEX_CACHE` points at an index snapshot, the site comes up
+    /// serving the (possibly stale) snapshot immediately instead of blocking
+    /// for minutes on the full index load; the caller is expected to
+    /// [`refresh_index`](Self::refresh_index) in the background afterwards.
     pub async fn from_db_url(db_url: &str) -> anyhow::Result<Self> {
         let pool = Pool::open(db_url);
 
-        let mut conn = pool.connection().await;
-        let index = db::Index::load(&mut *conn).await;
-
-        let config = if let Ok(s) = fs::read_to_string("site-config.toml") {
-            toml::from_str(&s)?
-        } else {
-            Config {
-                keys: Keys {
-                    github_api_token: std::env::var("GITHUB_API_TOKEN").ok(),
-                    github_webhook_secret: std::env::var("GITHUB_WEBHOOK_SECRET").ok(),
-                },
+        let index_cache = std::env::var_os("SITE_INDEX_CACHE").map(std::path::PathBuf::from);
+        let snapshot = index_cache.as_deref().and_then(|path| {
+            if !path.exists() {
+                return None;
+            }
+            match db::Index::load_from_disk(path) {
+                Ok(index) => Some(index),
+                Err(e) => {
+                    error!(
+                        "failed to load index snapshot from {}: {:?}",
+                        path.display(),
+                        e
+                    );
+                    None
+                }
+            }
+        });
+        let index_loaded_from_snapshot = snapshot.is_some();
+        let index = match snapshot {
+            Some(index) => index,
+            None => {
+                let mut conn = pool.connection().await;
+                let index = db::Index::load(&mut *conn).await;
+                if let Some(path) = &index_cache {
+                    if let Err(e) = index.store_to_disk(path) {
+                        error!(
+                            "failed to write index snapshot to {}: {:?}",
+                            path.display(),
+                            e
+                        );
+                    }
+                }
+                index
             }
         };
 
+        let config = Config::load()?;
+
         let master_commits = MasterCommitCache::download().await?;
 
         Ok(Self {
-            config,
+            config: ArcSwap::new(Arc::new(config)),
             index: ArcSwap::new(Arc::new(index)),
             master_commits: Arc::new(ArcSwap::new(Arc::new(master_commits))),
             pool,
             landing_page: ArcSwap::new(Arc::new(None)),
+            index_cache,
+            index_loaded_from_snapshot,
+            index_refreshed: ArcSwap::new(Arc::new(Instant::now())),
         })
     }
 
+    /// Reloads the index from the database and rewrites the on-disk snapshot,
+    /// if one is configured.
+    pub async fn refresh_index(&self) {
+        let mut conn = self.conn().await;
+        let index = db::Index::load(&mut *conn).await;
+        if let Some(path) = &self.index_cache {
+            if let Err(e) = index.store_to_disk(path) {
+                error!(
+                    "failed to write index snapshot to {}: {:?}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+        self.index.store(Arc::new(index));
+        self.index_refreshed.store(Arc::new(Instant::now()));
+    }
+
+    /// How long ago the in-memory index was last (re)loaded.
+    pub fn index_age(&self) -> std::time::Duration {
+        self.index_refreshed.load().elapsed()
+    }
+
+    /// Flushes the on-disk index snapshot and closes the database pool.
+    /// Called once during graceful shutdown, after in-flight requests have
+    /// been drained.
+    pub async fn shutdown(&self) {
+        if let Some(path) = &self.index_cache {
+            if let Err(e) = self.index.load().store_to_disk(path) {
+                error!(
+                    "failed to write index snapshot to {}: {:?}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+        self.pool.shutdown().await;
+    }
+
     pub async fn conn(&self) -> Box<dyn database::pool::Connection> {
         self.pool.connection().await
     }
 
+    /// Get the current site configuration.
+    pub fn config(&self) -> Guard<Arc<Config>> {
+        self.config.load()
+    }
+
+    /// Reloads the site configuration from disk (or the environment), without
+    /// restarting the server and dropping the in-memory index.
+    ///
+    /// Triggered by SIGHUP or the `/perf/reload-config` endpoint.
+    pub fn reload_config(&self) -> anyhow::Result<()> {
+        let config = Config::load()?;
+        self.config.store(Arc::new(config));
+        log::info!("site configuration reloaded");
+        Ok(())
+    }
+
     /// Returns the not yet tested commits
     pub async fn missing_commits(&self) -> Vec<(Commit, MissingReason)> {
         let conn = self.conn().await;
-        let (queued_pr_commits, in_progress_artifacts) =
-            futures::join!(conn.queued_commits(), conn.in_progress_artifacts());
+        let (queued_pr_commits, in_progress_artifacts, blacklisted_artifacts) = futures::join!(
+            conn.queued_commits(),
+            conn.in_progress_artifacts(),
+            conn.blacklisted_artifacts()
+        );
         let master_commits = &self.get_master_commits().commits;
 
         let index = self.index.load();
-        let all_commits = index
+        let mut all_commits = index
             .commits()
             .iter()
             .map(|commit| commit.sha.clone())
             .collect::<HashSet<_>>();
+        // Treat blacklisted commits as if they had results, so that
+        // known-broken commits are not re-enqueued on every refresh.
+        all_commits.extend(blacklisted_artifacts.into_iter().map(|(name, _)| name));
 
         calculate_missing(
             master_commits.clone(),
@@ -39,9 +39,50 @@ async fn main() {
                 commits, artifacts,
             );
             eprintln!("View the results in a web browser at 'http://localhost:2346/compare.html'");
+            // When the initial index came from the on-disk snapshot, the site
+            // serves the (possibly stale) snapshot while the authoritative
+            // index is loaded from the database in the background.
+            if res.index_loaded_from_snapshot {
+                let res = res.clone();
+                tokio::task::spawn(async move {
+                    res.refresh_index().await;
+                    eprintln!(
+                        "index refreshed from the database; {} commits",
+                        res.index.load().commits().len()
+                    );
+                });
+            }
+            // A follower only serves read queries; the primary instance owns
+            // the maintenance jobs and the GitHub posting below.
+            let follower = res.config().follower;
+            if follower {
+                eprintln!("Running in read-only follower mode.");
+            }
+            // Start the periodic maintenance jobs now that the context is
+            // fully loaded.
+            if !follower {
+                site::jobs::start(res.clone());
+            }
+            // Reload the site configuration on SIGHUP, without restarting
+            // the server and dropping the in-memory index.
+            #[cfg(unix)]
+            {
+                let res = res.clone();
+                tokio::task::spawn(async move {
+                    use tokio::signal::unix::{signal, SignalKind};
+                    let mut hangups = signal(SignalKind::hangup()).unwrap();
+                    while hangups.recv().await.is_some() {
+                        if let Err(e) = res.reload_config() {
+                            eprintln!("failed to reload site config: {:?}", e);
+                        }
+                    }
+                });
+            }
             // Spawn off a task to post the results of any commit results that we
             // are now aware of.
-            site::github::post_finished(&res).await;
+            if !follower {
+                site::github::post_finished(&res).await;
+            }
         })
     })
     .fuse();
@@ -57,7 +98,9 @@ async fn main() {
     loop {
         futures::select! {
             _s = server => {
-                eprintln!("Server completed unexpectedly.");
+                // The server future completes after a graceful shutdown (or
+                // after a fatal server error, which it logs itself).
+                eprintln!("Server stopped.");
                 return;
             }
             l = fut => {
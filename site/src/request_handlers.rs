@@ -1,30 +1,63 @@
 mod bootstrap;
+mod channel_compare;
 mod dashboard;
 mod github;
 mod graph;
+mod latency_slo;
 mod next_artifact;
+mod pr_history;
+mod range_compare;
+mod release_notes;
+mod selector_query;
 mod self_profile;
+mod sparkline;
 mod status_page;
+mod step_timeline;
+mod suggest;
+mod suite_cost;
+mod v1;
 
 pub use bootstrap::handle_bootstrap;
+pub use channel_compare::handle_channel_compare;
 pub use dashboard::handle_dashboard;
 pub use github::handle_github;
-pub use graph::{handle_graph, handle_graphs};
+pub use graph::{handle_graph, handle_graphs, handle_graphs_releases};
+pub use latency_slo::handle_latency_slo;
 pub use next_artifact::handle_next_artifact;
+pub use pr_history::handle_pr_history;
+pub use range_compare::handle_range_compare;
+pub use release_notes::handle_release_notes;
+pub use selector_query::handle_selector_query;
 pub use self_profile::{
-    handle_self_profile, handle_self_profile_processed_download, handle_self_profile_raw,
-    handle_self_profile_raw_download,
+    handle_self_profile, handle_self_profile_aggregate, handle_self_profile_processed_download,
+    handle_self_profile_raw, handle_self_profile_raw_download,
 };
-pub use status_page::handle_status_page;
+pub use sparkline::handle_sparkline;
+pub use status_page::{handle_health, handle_status_page};
+pub use step_timeline::handle_step_timeline;
+pub use suggest::handle_suggest;
+pub use suite_cost::handle_suite_cost;
+pub use v1::{handle_v1_artifacts, handle_v1_benchmarks, handle_v1_metrics, openapi_json};
 
-use crate::api::{info, ServerResult};
+use crate::api::{info, metrics, ServerResult};
 use crate::load::SiteCtxt;
 
 pub fn handle_info(ctxt: &SiteCtxt) -> info::Response {
+    // Derived metrics are usable wherever stored metrics are, so they show
+    // up in both lists.
+    let derived: Vec<String> = ctxt
+        .config()
+        .derived_metrics
+        .iter()
+        .map(|d| d.name.clone())
+        .collect();
+
     let mut compile_metrics = ctxt.index.load().compile_metrics();
+    compile_metrics.extend(derived.iter().cloned());
     compile_metrics.sort();
 
     let mut runtime_metrics = ctxt.index.load().runtime_metrics();
+    runtime_metrics.extend(derived);
     runtime_metrics.sort();
 
     info::Response {
@@ -34,6 +67,42 @@ pub fn handle_info(ctxt: &SiteCtxt) -> info::Response {
     }
 }
 
+pub fn handle_metric_descriptions(ctxt: &SiteCtxt) -> metrics::Response {
+    fn describe(
+        mut names: Vec<String>,
+        derived: &[crate::load::DerivedMetric],
+    ) -> Vec<metrics::Metric> {
+        names.sort();
+        let mut described: Vec<metrics::Metric> = names
+            .into_iter()
+            .map(|name| {
+                let metadata = database::metric::MetricMetadata::for_metric(&name);
+                metrics::Metric {
+                    unit: metadata.map(|m| m.unit.to_string()),
+                    better_direction: metadata.map(|m| m.better_direction),
+                    description: metadata.map(|m| m.description.to_string()),
+                    name,
+                }
+            })
+            .collect();
+        described.extend(derived.iter().map(|d| metrics::Metric {
+            unit: None,
+            better_direction: None,
+            description: Some(format!("derived: {}", d.expression)),
+            name: d.name.clone(),
+        }));
+        described.sort_by(|a, b| a.name.cmp(&b.name));
+        described
+    }
+
+    let index = ctxt.index.load();
+    let config = ctxt.config();
+    metrics::Response {
+        compile_metrics: describe(index.compile_metrics(), &config.derived_metrics),
+        runtime_metrics: describe(index.runtime_metrics(), &config.derived_metrics),
+    }
+}
+
 pub async fn handle_collected() -> ServerResult<()> {
     Ok(())
 }
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::api::{channel_compare, RequestError, ServerResult};
+use crate::db::ArtifactId;
+use crate::load::SiteCtxt;
+use crate::selector;
+
+use super::dashboard::sorted_release_tags;
+
+/// Compares the latest stable release, the latest beta, and the most recent
+/// benchmarked master commit per compile test case. This powers a "what will
+/// regress when beta ships" report that release triage otherwise assembles by
+/// hand from individual comparisons.
+pub async fn handle_channel_compare(
+    request: channel_compare::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<channel_compare::Response> {
+    log::info!("handle_channel_compare({:?})", request);
+    ctxt.check_known_metric(&request.stat)
+        .map_err(RequestError::BadRequest)?;
+
+    let index = ctxt.index.load();
+    let tags = sorted_release_tags(&index);
+    let stable = tags
+        .iter()
+        .rev()
+        .find(|tag| !tag.starts_with("beta"))
+        .cloned()
+        .ok_or_else(|| RequestError::NotFound("no stable release in the index".to_string()))?;
+    let beta = tags
+        .iter()
+        .rev()
+        .find(|tag| tag.starts_with("beta"))
+        .cloned()
+        .ok_or_else(|| RequestError::NotFound("no beta release in the index".to_string()))?;
+    let nightly = index
+        .commits()
+        .into_iter()
+        .rev()
+        .find(|commit| commit.is_master())
+        .ok_or_else(|| RequestError::NotFound("no master commit in the index".to_string()))?;
+
+    let artifact_ids = Arc::new(vec![
+        ArtifactId::Tag(stable.clone()),
+        ArtifactId::Tag(beta.clone()),
+        ArtifactId::Commit(nightly.clone()),
+    ]);
+    let query =
+        selector::CompileBenchmarkQuery::default().metric_name(request.stat.as_str().into());
+    let responses = ctxt.statistic_series(query, artifact_ids).await?;
+
+    let percent = |from: Option<f64>, to: Option<f64>| match (from, to) {
+        (Some(from), Some(to)) if from != 0.0 => Some((to - from) / from * 100.0),
+        _ => None,
+    };
+
+    let mut cases = Vec::new();
+    for response in responses {
+        let points: Vec<Option<f64>> = response.series.map(|(_, value)| value).collect();
+        let [stable_value, beta_value, nightly_value] = points[..] else {
+            continue;
+        };
+        if stable_value.is_none() && beta_value.is_none() && nightly_value.is_none() {
+            continue;
+        }
+        cases.push(channel_compare::TestCase {
+            benchmark: response.test_case.benchmark.to_string(),
+            profile: response.test_case.profile.to_string(),
+            scenario: response.test_case.scenario.to_string(),
+            stable: stable_value,
+            beta: beta_value,
+            nightly: nightly_value,
+            stable_to_beta: percent(stable_value, beta_value),
+            beta_to_nightly: percent(beta_value, nightly_value),
+        });
+    }
+    cases.sort_by(|a, b| {
+        (&a.benchmark, &a.profile, &a.scenario).cmp(&(&b.benchmark, &b.profile, &b.scenario))
+    });
+
+    Ok(channel_compare::Response {
+        stable,
+        beta,
+        nightly: nightly.sha,
+        cases,
+    })
+}
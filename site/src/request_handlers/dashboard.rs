@@ -9,12 +9,9 @@ use crate::db::{self, ArtifactId, Profile, Scenario};
 use crate::load::SiteCtxt;
 use crate::selector;
 
-pub async fn handle_dashboard(ctxt: Arc<SiteCtxt>) -> ServerResult<dashboard::Response> {
-    let index = ctxt.index.load();
-    if index.artifacts().next().is_none() {
-        return Ok(dashboard::Response::default());
-    }
-
+/// Returns the published release tags (`1.x.y` plus the latest beta) present
+/// in the index, sorted from oldest to newest.
+pub fn sorted_release_tags(index: &db::Index) -> Vec<String> {
     let mut versions = index
         .artifacts()
         .filter(|a| a.starts_with("1.") || a.starts_with("beta"))
@@ -71,10 +68,19 @@ pub async fn handle_dashboard(ctxt: Arc<SiteCtxt>) -> ServerResult<dashboard::Re
         versions.drain(first_beta..last_beta);
     }
 
+    versions.into_iter().map(|v| v.to_string()).collect()
+}
+
+pub async fn handle_dashboard(ctxt: Arc<SiteCtxt>) -> ServerResult<dashboard::Response> {
+    let index = ctxt.index.load();
+    if index.artifacts().next().is_none() {
+        return Ok(dashboard::Response::default());
+    }
+
     let artifact_ids = Arc::new(
-        versions
+        sorted_release_tags(&index)
             .into_iter()
-            .map(|v| ArtifactId::Tag(v.to_string()))
+            .map(ArtifactId::Tag)
             .collect::<Vec<_>>(),
     );
 
@@ -1,4 +1,4 @@
-use crate::api::{github, ServerResult};
+use crate::api::{github, RequestError, ServerResult};
 use crate::github::{
     client, enqueue_shas, parse_homu_comment, rollup_pr_number, unroll_rollup,
     COMMENT_MARK_TEMPORARY, RUST_REPO_GITHUB_API_URL,
@@ -177,19 +177,21 @@ fn build_captures(comment_body: &str) -> impl Iterator<Item = (&str, regex::Capt
         })
 }
 
-pub async fn get_authorized_users() -> Result<Vec<usize>, String> {
+pub async fn get_authorized_users() -> ServerResult<Vec<usize>> {
     let url = format!("{}/permissions/perf.json", ::rust_team_data::v1::BASE_URL);
+    let upstream_error =
+        |err| RequestError::Upstream(format!("failed to fetch authorized users: {}", err));
     let client = reqwest::Client::new();
     client
         .get(&url)
         .send()
         .await
-        .map_err(|err| format!("failed to fetch authorized users: {}", err))?
+        .map_err(upstream_error)?
         .error_for_status()
-        .map_err(|err| format!("failed to fetch authorized users: {}", err))?
+        .map_err(upstream_error)?
         .json::<rust_team_data::v1::Permission>()
         .await
-        .map_err(|err| format!("failed to fetch authorized users: {}", err))
+        .map_err(upstream_error)
         .map(|perms| perms.github_ids)
 }
 
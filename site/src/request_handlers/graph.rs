@@ -1,14 +1,19 @@
+use collector::compile::benchmark::category::Category;
 use collector::Bound;
-use std::collections::HashMap;
+use futures::stream::StreamExt;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::api::graphs::GraphKind;
-use crate::api::{graph, graphs, ServerResult};
-use crate::db::{self, ArtifactId, Profile, Scenario};
+use crate::api::{graph, graphs, RequestError, ServerResult};
+use crate::benchmark_metadata::get_compile_benchmarks_metadata;
+use crate::db::{self, ArtifactId, Lookup, Profile, Scenario};
 use crate::interpolate::IsInterpolated;
 use crate::load::SiteCtxt;
 use crate::selector::{CompileBenchmarkQuery, CompileTestCase, Selector, SeriesResponse};
 
+use super::range_compare::median;
+
 pub async fn handle_graph(
     request: graph::Request,
     ctxt: Arc<SiteCtxt>,
@@ -33,6 +38,9 @@ pub async fn handle_graphs(
             benchmark: None,
             scenario: None,
             profile: None,
+            unit: None,
+            series_ids: false,
+            secondary_stat: None,
         };
 
     if is_default_query {
@@ -51,28 +59,53 @@ pub async fn handle_graphs(
     Ok(resp)
 }
 
+pub async fn handle_graphs_releases(
+    request: graphs::ReleaseRequest,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<graphs::ReleaseResponse> {
+    log::info!("handle_graphs_releases({:?})", request);
+
+    create_release_graphs(request, &ctxt).await
+}
+
 async fn create_graph(
     request: graph::Request,
     ctxt: Arc<SiteCtxt>,
 ) -> ServerResult<graph::Response> {
+    ctxt.check_known_metric(&request.metric)
+        .map_err(RequestError::BadRequest)?;
+    ctxt.check_known_compile_benchmark(&request.benchmark)
+        .map_err(RequestError::BadRequest)?;
+    ctxt.check_bound_order(&request.start, &request.end)
+        .map_err(RequestError::BadRequest)?;
+    let (unit, scale) = resolve_unit(&request.metric, &request.unit)?;
     let artifact_ids = artifact_ids_for_range(&ctxt, request.start, request.end);
     let mut series_iterator = ctxt
         .statistic_series(
             CompileBenchmarkQuery::default()
                 .benchmark(Selector::One(request.benchmark))
-                .profile(Selector::One(request.profile.parse()?))
-                .scenario(Selector::One(request.scenario.parse()?))
-                .metric(Selector::One(request.metric.parse()?)),
+                .profile(Selector::One(
+                    request.profile.parse().map_err(RequestError::BadRequest)?,
+                ))
+                .scenario(Selector::One(
+                    request.scenario.parse().map_err(RequestError::BadRequest)?,
+                ))
+                .metric_name(request.metric.as_str().into()),
             Arc::new(artifact_ids),
         )
         .await?
         .into_iter()
         .map(SeriesResponse::interpolate);
 
-    let result = series_iterator.next().unwrap();
-    let graph_series = graph_series(result.series, request.kind);
+    let result = series_iterator
+        .next()
+        .ok_or_else(|| RequestError::NotFound("no series matches the given selector".into()))?;
+    let graph_series = graph_series(result.series, request.kind, scale).ok_or_else(|| {
+        RequestError::NotFound("the series has no measured data in the requested range".into())
+    })?;
     Ok(graph::Response {
         series: graph_series,
+        unit,
     })
 }
 
@@ -80,12 +113,275 @@ async fn create_graphs(
     request: graphs::Request,
     ctxt: &SiteCtxt,
 ) -> ServerResult<Arc<graphs::Response>> {
+    ctxt.check_known_metric(&request.stat)
+        .map_err(RequestError::BadRequest)?;
+    if let Some(benchmark) = &request.benchmark {
+        ctxt.check_known_compile_benchmark(benchmark)
+            .map_err(RequestError::BadRequest)?;
+    }
+    ctxt.check_bound_order(&request.start, &request.end)
+        .map_err(RequestError::BadRequest)?;
+    let (unit, scale) = resolve_unit(&request.stat, &request.unit)?;
+    // `check_known_metric` above has validated the name; the query layer
+    // works on metric names directly, so derived metrics graph like any
+    // stored one.
+    let metric: db::Metric = request.stat.as_str().into();
     let artifact_ids = Arc::new(master_artifact_ids_for_range(
         ctxt,
         request.start,
         request.end,
     ));
     let mut benchmarks = HashMap::new();
+    let mut series = HashMap::new();
+
+    let create_selector = |filter: &Option<String>| -> Selector<String> {
+        filter
+            .as_ref()
+            .map(|value| Selector::One(value.clone()))
+            .unwrap_or(Selector::All)
+    };
+
+    let benchmark_selector = create_selector(&request.benchmark);
+    let profile_selector = create_selector(&request.profile)
+        .try_map(|v| v.parse::<Profile>())
+        .map_err(RequestError::BadRequest)?;
+    let scenario_selector = create_selector(&request.scenario)
+        .try_map(|v| v.parse::<Scenario>())
+        .map_err(RequestError::BadRequest)?;
+
+    let interpolated_responses: Vec<_> = ctxt
+        .statistic_series(
+            CompileBenchmarkQuery::default()
+                .benchmark(benchmark_selector.clone())
+                .profile(profile_selector.clone())
+                .scenario(scenario_selector.clone())
+                .metric_name(metric),
+            artifact_ids.clone(),
+        )
+        .await?
+        .into_iter()
+        .map(|sr| sr.interpolate().map(|series| series.collect::<Vec<_>>()))
+        .collect();
+
+    let completeness = commit_completeness(artifact_ids.len(), &interpolated_responses);
+
+    let mut summary_weights = HashMap::new();
+    let mut summary_warnings = Vec::new();
+    if request.benchmark.is_none() {
+        for (name, category) in SUMMARY_CATEGORIES {
+            let summary =
+                create_summary(ctxt, &interpolated_responses, request.kind, category).await?;
+            benchmarks.insert(name.to_string(), summary.series);
+            summary_weights.extend(summary.weights);
+            summary_warnings.extend(summary.warnings);
+        }
+    }
+
+    let idx = ctxt.index.load();
+    let mut warnings = Vec::new();
+    collect_graph_series(
+        &idx,
+        interpolated_responses,
+        request.kind,
+        scale,
+        metric,
+        request.series_ids,
+        &mut benchmarks,
+        &mut series,
+        &mut warnings,
+    );
+
+    // The overlay metric reuses the same test case selectors, so its series
+    // line up with the primary ones; only the metric (and thus the y-axis)
+    // differs. The summary series are ratios of the primary metric and are
+    // not recomputed for it.
+    let mut secondary_benchmarks = HashMap::new();
+    let mut secondary_series = HashMap::new();
+    let mut axes = Vec::new();
+    if let Some(secondary_stat) = &request.secondary_stat {
+        ctxt.check_known_metric(secondary_stat)
+            .map_err(RequestError::BadRequest)?;
+        let (secondary_unit, secondary_scale) = resolve_unit(secondary_stat, &None)?;
+        let secondary_metric: db::Metric = secondary_stat.as_str().into();
+        let responses: Vec<_> = ctxt
+            .statistic_series(
+                CompileBenchmarkQuery::default()
+                    .benchmark(benchmark_selector)
+                    .profile(profile_selector)
+                    .scenario(scenario_selector)
+                    .metric_name(secondary_metric),
+                artifact_ids.clone(),
+            )
+            .await?
+            .into_iter()
+            .map(|sr| sr.interpolate().map(|series| series.collect::<Vec<_>>()))
+            .collect();
+        collect_graph_series(
+            &idx,
+            responses,
+            request.kind,
+            secondary_scale,
+            secondary_metric,
+            request.series_ids,
+            &mut secondary_benchmarks,
+            &mut secondary_series,
+            &mut warnings,
+        );
+        axes.push(graphs::Axis {
+            stat: request.stat.clone(),
+            unit: unit.clone(),
+        });
+        axes.push(graphs::Axis {
+            stat: secondary_stat.clone(),
+            unit: secondary_unit,
+        });
+    }
+
+    let suspected: HashSet<_> = ctxt
+        .conn()
+        .await
+        .suspected_noise_artifacts()
+        .await
+        .into_iter()
+        .collect();
+
+    let mut commits = Vec::new();
+    let mut suspected_noise = Vec::new();
+    for c in Arc::try_unwrap(artifact_ids).unwrap() {
+        if let Some(aid) = c.lookup(&idx) {
+            if suspected.contains(&aid) {
+                if let ArtifactId::Commit(c) = &c {
+                    suspected_noise.push(c.sha.clone());
+                }
+            }
+        }
+        match c {
+            ArtifactId::Commit(c) => commits.push((c.date.0.timestamp(), c.sha)),
+            ArtifactId::Tag(_) => unreachable!(),
+        }
+    }
+
+    Ok(Arc::new(graphs::Response {
+        commits,
+        benchmarks,
+        series,
+        suspected_noise,
+        completeness,
+        summary_weights,
+        summary_warnings,
+        warnings,
+        unit,
+        secondary_benchmarks,
+        secondary_series,
+        axes,
+    }))
+}
+
+/// Converts fetched series either into the nested
+/// benchmark/profile/scenario map of the graphs response or, when
+/// `series_ids` is set, into the flat map keyed by statistic-description IDs.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn collect_graph_series(
+    idx: &db::Index,
+    responses: Vec<SeriesResponse<CompileTestCase, Vec<((ArtifactId, Option<f64>), IsInterpolated)>>>,
+    kind: GraphKind,
+    scale: f64,
+    metric: db::Metric,
+    series_ids: bool,
+    benchmarks: &mut HashMap<String, HashMap<Profile, HashMap<String, graphs::Series>>>,
+    series: &mut HashMap<db::StatisticalDescriptionId, graphs::Series>,
+    warnings: &mut Vec<String>,
+) {
+    for response in responses {
+        let test_case = response.test_case;
+        let graph_series = match graph_series(response.series.into_iter(), kind, scale) {
+            Some(graph_series) => graph_series,
+            None => {
+                warnings.push(format!(
+                    "no measured {} data for {} {} {} in the requested range",
+                    metric, test_case.benchmark, test_case.profile, test_case.scenario
+                ));
+                continue;
+            }
+        };
+
+        if series_ids {
+            let label = db::DbLabel::StatisticDescription {
+                benchmark: test_case.benchmark,
+                profile: test_case.profile,
+                scenario: test_case.scenario,
+                metric,
+            };
+            // The series was just fetched, so its description is in the index.
+            if let Some(id) = label.lookup(idx) {
+                series.insert(id, graph_series);
+            }
+            continue;
+        }
+
+        let benchmark = test_case.benchmark.to_string();
+        let profile = test_case.profile;
+        let scenario = test_case.scenario.to_string();
+
+        benchmarks
+            .entry(benchmark)
+            .or_insert_with(HashMap::new)
+            .entry(profile)
+            .or_insert_with(HashMap::new)
+            .insert(scenario, graph_series);
+    }
+}
+
+/// Computes, per artifact, the fraction of the fetched series with measured
+/// (non-interpolated) data, so that the frontend can render partially
+/// benchmarked artifacts distinctly from complete ones.
+fn commit_completeness(
+    artifact_count: usize,
+    responses: &[SeriesResponse<CompileTestCase, Vec<((ArtifactId, Option<f64>), IsInterpolated)>>],
+) -> Vec<f32> {
+    let mut with_data = vec![0u32; artifact_count];
+    for response in responses {
+        for (idx, (_, is_interpolated)) in response.series.iter().enumerate() {
+            if !is_interpolated.as_bool() {
+                with_data[idx] += 1;
+            }
+        }
+    }
+    with_data
+        .into_iter()
+        .map(|count| {
+            if responses.is_empty() {
+                0.0
+            } else {
+                count as f32 / responses.len() as f32
+            }
+        })
+        .collect()
+}
+
+/// Creates graphs where the x-axis is the published release artifacts
+/// (stable versions plus the latest beta) rather than master commits, to
+/// power "performance across releases" charts.
+async fn create_release_graphs(
+    request: graphs::ReleaseRequest,
+    ctxt: &SiteCtxt,
+) -> ServerResult<graphs::ReleaseResponse> {
+    ctxt.check_known_metric(&request.stat)
+        .map_err(RequestError::BadRequest)?;
+    if let Some(benchmark) = &request.benchmark {
+        ctxt.check_known_compile_benchmark(benchmark)
+            .map_err(RequestError::BadRequest)?;
+    }
+    let (unit, scale) = resolve_unit(&request.stat, &request.unit)?;
+    let releases = super::dashboard::sorted_release_tags(&ctxt.index.load());
+    let artifact_ids = Arc::new(
+        releases
+            .iter()
+            .cloned()
+            .map(ArtifactId::Tag)
+            .collect::<Vec<_>>(),
+    );
+    let mut benchmarks = HashMap::new();
 
     let create_selector = |filter: &Option<String>| -> Selector<String> {
         filter
@@ -95,9 +391,12 @@ async fn create_graphs(
     };
 
     let benchmark_selector = create_selector(&request.benchmark);
-    let profile_selector = create_selector(&request.profile).try_map(|v| v.parse::<Profile>())?;
-    let scenario_selector =
-        create_selector(&request.scenario).try_map(|v| v.parse::<Scenario>())?;
+    let profile_selector = create_selector(&request.profile)
+        .try_map(|v| v.parse::<Profile>())
+        .map_err(RequestError::BadRequest)?;
+    let scenario_selector = create_selector(&request.scenario)
+        .try_map(|v| v.parse::<Scenario>())
+        .map_err(RequestError::BadRequest)?;
 
     let interpolated_responses: Vec<_> = ctxt
         .statistic_series(
@@ -105,24 +404,41 @@ async fn create_graphs(
                 .benchmark(benchmark_selector)
                 .profile(profile_selector)
                 .scenario(scenario_selector)
-                .metric(Selector::One(request.stat.parse()?)),
-            artifact_ids.clone(),
+                .metric_name(request.stat.as_str().into()),
+            artifact_ids,
         )
         .await?
         .into_iter()
         .map(|sr| sr.interpolate().map(|series| series.collect::<Vec<_>>()))
         .collect();
 
+    let mut summary_weights = HashMap::new();
+    let mut summary_warnings = Vec::new();
     if request.benchmark.is_none() {
-        let summary_benchmark = create_summary(ctxt, &interpolated_responses, request.kind)?;
-        benchmarks.insert("Summary".to_string(), summary_benchmark);
+        for (name, category) in SUMMARY_CATEGORIES {
+            let summary =
+                create_summary(ctxt, &interpolated_responses, request.kind, category).await?;
+            benchmarks.insert(name.to_string(), summary.series);
+            summary_weights.extend(summary.weights);
+            summary_warnings.extend(summary.warnings);
+        }
     }
 
+    let mut warnings = Vec::new();
     for response in interpolated_responses {
         let benchmark = response.test_case.benchmark.to_string();
         let profile = response.test_case.profile;
         let scenario = response.test_case.scenario.to_string();
-        let graph_series = graph_series(response.series.into_iter(), request.kind);
+        let graph_series = match graph_series(response.series.into_iter(), request.kind, scale) {
+            Some(graph_series) => graph_series,
+            None => {
+                warnings.push(format!(
+                    "no measured {} data for {benchmark} {profile} {scenario}",
+                    request.stat
+                ));
+                continue;
+            }
+        };
 
         benchmarks
             .entry(benchmark)
@@ -132,19 +448,33 @@ async fn create_graphs(
             .insert(scenario, graph_series);
     }
 
-    Ok(Arc::new(graphs::Response {
-        commits: Arc::try_unwrap(artifact_ids)
-            .unwrap()
-            .into_iter()
-            .map(|c| match c {
-                ArtifactId::Commit(c) => (c.date.0.timestamp(), c.sha),
-                ArtifactId::Tag(_) => unreachable!(),
-            })
-            .collect(),
+    Ok(graphs::ReleaseResponse {
+        releases,
         benchmarks,
-    }))
+        summary_weights,
+        summary_warnings,
+        warnings,
+        unit,
+    })
+}
+
+/// A summary "benchmark" produced by [`create_summary`].
+struct SummaryBenchmark {
+    series: HashMap<Profile, HashMap<String, graphs::Series>>,
+    /// Summary weights differing from the default of 1.0.
+    weights: HashMap<String, f64>,
+    /// Human-readable notes about summary series that could not be computed,
+    /// e.g. because the Empty-scenario baseline has no data.
+    warnings: Vec<String>,
 }
 
+/// The summary series reported by the graph endpoints, split by benchmark
+/// category the same way the compare page splits its results.
+const SUMMARY_CATEGORIES: [(&str, Category); 2] = [
+    ("Summary-primary", Category::Primary),
+    ("Summary-secondary", Category::Secondary),
+];
+
 /// Returns artifact IDs for the given range.
 /// Inside of the range (not at the start/end), only master commits are kept.
 fn artifact_ids_for_range(ctxt: &SiteCtxt, start: Bound, end: Bound) -> Vec<ArtifactId> {
@@ -159,7 +489,11 @@ fn artifact_ids_for_range(ctxt: &SiteCtxt, start: Bound, end: Bound) -> Vec<Arti
 }
 
 /// Returns master commit artifact IDs for the given range.
-fn master_artifact_ids_for_range(ctxt: &SiteCtxt, start: Bound, end: Bound) -> Vec<ArtifactId> {
+pub(super) fn master_artifact_ids_for_range(
+    ctxt: &SiteCtxt,
+    start: Bound,
+    end: Bound,
+) -> Vec<ArtifactId> {
     ctxt.data_range(start..=end)
         .into_iter()
         .filter(|commit| commit.is_master())
@@ -167,92 +501,266 @@ fn master_artifact_ids_for_range(ctxt: &SiteCtxt, start: Bound, end: Bound) -> V
         .collect()
 }
 
+/// Maximum number of summary series computed simultaneously. Each average is
+/// a CPU-bound pass over every fetched series, so the combinations are
+/// spread over the blocking thread pool, bounded so a single landing-page
+/// request does not monopolize it.
+const SUMMARY_CONCURRENCY: usize = 4;
+
 #[allow(clippy::type_complexity)]
-/// Creates a summary "benchmark" that averages the results of all other
-/// test cases per profile type
-fn create_summary(
+/// Creates a summary "benchmark" that averages the results of the test cases
+/// in the given benchmark category per profile type, mirroring the
+/// primary/secondary split of the compare page. The average is weighted by
+/// the benchmarks' configured summary weights; the weights that differ from
+/// the default of 1.0 are also returned, so responses can echo the weighting
+/// scheme.
+///
+/// The per-(scenario, profile) averages are independent of each other, so
+/// they are computed concurrently, bounded by [`SUMMARY_CONCURRENCY`].
+async fn create_summary(
     ctxt: &SiteCtxt,
     interpolated_responses: &[SeriesResponse<
         CompileTestCase,
         Vec<((ArtifactId, Option<f64>), IsInterpolated)>,
     >],
     graph_kind: GraphKind,
-) -> ServerResult<HashMap<Profile, HashMap<String, graphs::Series>>> {
-    let mut baselines = HashMap::new();
-    let mut summary_benchmark = HashMap::new();
+    category: Category,
+) -> ServerResult<SummaryBenchmark> {
+    let metadata = get_compile_benchmarks_metadata();
+    let weight_of = |test_case: &CompileTestCase| {
+        metadata
+            .get(test_case.benchmark.as_str())
+            .map_or(1.0, |m| m.perf_config.weight())
+    };
+    // Matches the compare page's split: stable benchmarks count as primary,
+    // benchmarks without metadata as secondary, and stress benchmarks are
+    // never summarized.
+    let in_summary = |test_case: &CompileTestCase| {
+        let benchmark_category = metadata
+            .get(test_case.benchmark.as_str())
+            .map(|m| m.perf_config.category());
+        match category {
+            Category::Primary => matches!(
+                benchmark_category,
+                Some(Category::Primary) | Some(Category::Stable)
+            ),
+            _ => !matches!(
+                benchmark_category,
+                Some(Category::Primary) | Some(Category::Stable) | Some(Category::Stress)
+            ),
+        }
+    };
+    let mut summary_weights = HashMap::new();
+    for response in interpolated_responses {
+        if !in_summary(&response.test_case) {
+            continue;
+        }
+        let weight = weight_of(&response.test_case);
+        if weight != 1.0 {
+            summary_weights.insert(response.test_case.benchmark.to_string(), weight);
+        }
+    }
+
     let summary_query_cases = iproduct!(
         ctxt.summary_scenarios(),
         vec![Profile::Check, Profile::Debug, Profile::Opt, Profile::Doc]
     );
-    for (scenario, profile) in summary_query_cases {
-        let baseline = match baselines.entry((profile, scenario)) {
-            std::collections::hash_map::Entry::Occupied(o) => *o.get(),
-            std::collections::hash_map::Entry::Vacant(v) => {
-                let baseline_responses = interpolated_responses
-                    .iter()
-                    .filter(|sr| {
-                        let p = sr.test_case.profile;
-                        let s = sr.test_case.scenario;
-                        p == profile && s == Scenario::Empty
-                    })
-                    .map(|sr| sr.series.iter().cloned())
-                    .collect();
-
-                let value = db::average(baseline_responses)
-                    .next()
-                    .map_or(0.0, |((_c, d), _interpolated)| d.expect("interpolated"));
-                *v.insert(value)
-            }
+    let tasks = summary_query_cases.map(|(scenario, profile)| {
+        // Clone out the series each combination averages over, so the
+        // computation can move to the blocking pool without borrowing the
+        // responses. The averaging clones every point anyway.
+        let select = |wanted: Scenario| {
+            interpolated_responses
+                .iter()
+                .filter(|sr| {
+                    let p = sr.test_case.profile;
+                    let s = sr.test_case.scenario;
+                    p == profile && s == wanted && in_summary(&sr.test_case)
+                })
+                .map(|sr| (sr.series.clone(), weight_of(&sr.test_case)))
+                .collect()
         };
+        let baseline_responses = select(Scenario::Empty);
+        let case_responses = select(scenario);
+
+        move || {
+            (
+                profile,
+                scenario,
+                summarize_query_case(
+                    baseline_responses,
+                    case_responses,
+                    graph_kind,
+                    category,
+                    profile,
+                    scenario,
+                ),
+            )
+        }
+    });
+
+    let results: Vec<_> = futures::stream::iter(tasks)
+        .map(|task| async move {
+            tokio::task::spawn_blocking(task)
+                .await
+                .expect("summary computation panicked")
+        })
+        .buffered(SUMMARY_CONCURRENCY)
+        .collect()
+        .await;
 
-        let summary_case_responses = interpolated_responses
-            .iter()
-            .filter(|sr| {
-                let p = sr.test_case.profile;
-                let s = sr.test_case.scenario;
-                p == profile && s == scenario
-            })
-            .map(|sr| sr.series.iter().cloned())
-            .collect();
+    let mut summary_benchmark = HashMap::new();
+    let mut warnings = Vec::new();
+    for (profile, scenario, outcome) in results {
+        match outcome {
+            Ok(graph_series) => {
+                summary_benchmark
+                    .entry(profile)
+                    .or_insert_with(HashMap::new)
+                    .insert(scenario.to_string(), graph_series);
+            }
+            Err(warning) => warnings.push(warning),
+        }
+    }
+    Ok(SummaryBenchmark {
+        series: summary_benchmark,
+        weights: summary_weights,
+        warnings,
+    })
+}
 
-        let avg_vs_baseline = db::average(summary_case_responses)
-            .map(|((c, d), i)| ((c, Some(d.expect("interpolated") / baseline)), i));
+#[allow(clippy::type_complexity)]
+/// Computes a single summary series: the weighted average of the given
+/// test-case series, as a ratio against the Empty-scenario baseline average.
+/// Returns a warning message instead when the baseline or the averaged data
+/// is unusable.
+fn summarize_query_case(
+    baseline_responses: Vec<(Vec<((ArtifactId, Option<f64>), IsInterpolated)>, f64)>,
+    case_responses: Vec<(Vec<((ArtifactId, Option<f64>), IsInterpolated)>, f64)>,
+    graph_kind: GraphKind,
+    category: Category,
+    profile: Profile,
+    scenario: Scenario,
+) -> Result<graphs::Series, String> {
+    let baseline_responses = baseline_responses
+        .into_iter()
+        .map(|(series, weight)| (series.into_iter(), weight))
+        .collect();
+    // Without a usable baseline the ratios below would be nonsensical
+    // (divisions by zero or missing data), so flag the series instead of
+    // producing it.
+    let baseline = db::weighted_average(baseline_responses)
+        .next()
+        .and_then(|((_c, d), _interpolated)| d)
+        .filter(|baseline| baseline.is_finite() && *baseline != 0.0)
+        .ok_or_else(|| {
+            format!(
+                "no Empty-scenario baseline data for {category} {profile} benchmarks; \
+                 skipped the {scenario} summary series"
+            )
+        })?;
+
+    // Count, per commit, how many of the averaged series had their point
+    // interpolated, so summary spikes driven mostly by missing data can be
+    // discounted.
+    let point_count = case_responses.first().map_or(0, |(series, _)| series.len());
+    let mut interpolation_counts = vec![0u16; point_count];
+    for (series, _) in &case_responses {
+        for (idx, (_, is_interpolated)) in series.iter().enumerate() {
+            if is_interpolated.as_bool() {
+                interpolation_counts[idx] += 1;
+            }
+        }
+    }
 
-        let graph_series = graph_series(avg_vs_baseline, graph_kind);
+    let summary_case_responses = case_responses
+        .into_iter()
+        .map(|(series, weight)| (series.into_iter(), weight))
+        .collect();
 
-        summary_benchmark
-            .entry(profile)
-            .or_insert_with(HashMap::new)
-            .insert(scenario.to_string(), graph_series);
+    let avg_vs_baseline = db::weighted_average(summary_case_responses)
+        .map(|((c, d), i)| ((c, d.map(|d| d / baseline)), i));
+
+    // The summary series are ratios against the baseline, so they are
+    // unitless and never scaled.
+    let mut graph_series = graph_series(avg_vs_baseline, graph_kind, 1.0).ok_or_else(|| {
+        format!(
+            "no measured data for {category} {profile} benchmarks; \
+             skipped the {scenario} summary series"
+        )
+    })?;
+    graph_series.interpolation_counts = Some(interpolation_counts);
+    Ok(graph_series)
+}
+
+/// Resolves the unit the response values should be reported in: the metric's
+/// native unit from the registry, converted to the requested unit if one is
+/// given. Returns the unit name and the factor to apply to raw values.
+fn resolve_unit(metric: &str, requested: &Option<String>) -> ServerResult<(Option<String>, f64)> {
+    let native = db::metric::MetricMetadata::for_metric(metric).map(|m| m.unit);
+    match (native, requested) {
+        (Some(native), Some(requested)) => {
+            let factor = db::metric::conversion_factor(native, requested).ok_or_else(|| {
+                RequestError::BadRequest(format!(
+                    "cannot convert `{native}` (unit of `{metric}`) to `{requested}`"
+                ))
+            })?;
+            Ok((Some(requested.clone()), factor))
+        }
+        (native, None) => Ok((native.map(String::from), 1.0)),
+        (None, Some(requested)) => Err(RequestError::BadRequest(format!(
+            "cannot convert `{metric}` to `{requested}`: its unit is not known"
+        ))),
     }
-    Ok(summary_benchmark)
 }
 
+/// Number of preceding points the rolling-median graph kind compares each
+/// point against. Large enough to absorb single-commit noise spikes, small
+/// enough to follow genuine level shifts quickly.
+const ROLLING_MEDIAN_WINDOW: usize = 10;
+
+/// Returns `None` when the series has no measured data anywhere in the
+/// range: interpolation only fills gaps between measured values, so a series
+/// that is entirely empty stays empty and cannot be plotted.
 fn graph_series(
     points: impl Iterator<Item = ((ArtifactId, Option<f64>), IsInterpolated)>,
     kind: GraphKind,
-) -> graphs::Series {
+    scale: f64,
+) -> Option<graphs::Series> {
     let mut graph_series = graphs::Series {
         points: Vec::new(),
         interpolated_indices: Default::default(),
+        interpolation_counts: None,
     };
 
     let mut first = None;
     let mut prev = None;
+    let mut window: Vec<f64> = Vec::with_capacity(ROLLING_MEDIAN_WINDOW);
 
     for (idx, ((_aid, point), is_interpolated)) in points.enumerate() {
-        let point = point.expect("interpolated point still produced an empty value");
+        let point = point? * scale;
         first = Some(first.unwrap_or(point));
         let first = first.unwrap();
         let percent_first = (point - first) / first * 100.0;
         let previous_point = prev.unwrap_or(point);
         let percent_prev = (point - previous_point) / previous_point * 100.0;
         prev = Some(point);
+        let rolling_baseline = if window.is_empty() {
+            point
+        } else {
+            median(&window)
+        };
+        let percent_rolling = (point - rolling_baseline) / rolling_baseline * 100.0;
+        if window.len() == ROLLING_MEDIAN_WINDOW {
+            window.remove(0);
+        }
+        window.push(point);
 
         let value = match kind {
             GraphKind::Raw => point,
             GraphKind::PercentRelative => percent_prev,
             GraphKind::PercentFromFirst => percent_first,
+            GraphKind::PercentRollingMedian => percent_rolling,
         } as f32;
 
         graph_series.points.push(value);
@@ -262,5 +770,5 @@ fn graph_series(
         }
     }
 
-    graph_series
+    Some(graph_series)
 }
@@ -0,0 +1,45 @@
+use futures::stream::{FuturesOrdered, StreamExt};
+
+use crate::api::{latency_slo, RequestError, ServerResult};
+use crate::db::ArtifactId;
+use crate::load::SiteCtxt;
+
+pub async fn handle_latency_slo(
+    body: latency_slo::Request,
+    ctxt: &SiteCtxt,
+) -> ServerResult<latency_slo::Response> {
+    log::info!("handle_latency_slo({:?})", body);
+    ctxt.check_bound_order(&body.start, &body.end)
+        .map_err(RequestError::BadRequest)?;
+    let range = ctxt.data_range(body.start.clone()..=body.end.clone());
+    let commits: Vec<ArtifactId> = range
+        .into_iter()
+        .filter(|c| c.is_master())
+        .map(|c| c.into())
+        .collect();
+
+    let conn = ctxt.conn().await;
+    let ids = commits
+        .iter()
+        .map(|c| conn.artifact_id(c))
+        .collect::<FuturesOrdered<_>>()
+        .collect::<Vec<_>>()
+        .await;
+
+    let shares = conn.artifact_info_for_key("latency-slo").await;
+    let values = ids
+        .iter()
+        .map(|aid| shares.get(aid).and_then(|share| share.parse::<f64>().ok()))
+        .collect();
+
+    Ok(latency_slo::Response {
+        commits: commits
+            .into_iter()
+            .map(|v| match v {
+                ArtifactId::Commit(c) => (c.date.0.timestamp(), c.sha),
+                ArtifactId::Tag(_) => todo!(),
+            })
+            .collect(),
+        values,
+    })
+}
@@ -4,6 +4,50 @@ use collector::api::next_artifact;
 use std::sync::Arc;
 
 pub async fn handle_next_artifact(ctxt: Arc<SiteCtxt>) -> next_artifact::Response {
+    // Self-profile requests target already benchmarked artifacts and are
+    // cheap (a single test case), so serve them before anything else.
+    loop {
+        let request = {
+            let conn = ctxt.conn().await;
+            conn.dequeue_self_profile_request().await
+        };
+        let request = match request {
+            Some(request) => request,
+            None => break,
+        };
+        let commit = ctxt
+            .index
+            .load()
+            .commits()
+            .into_iter()
+            .find(|c| c.sha == request.artifact.as_str());
+        match commit {
+            Some(commit) => {
+                log::debug!(
+                    "next_artifact: self-profile {}/{}/{} @ {}",
+                    request.benchmark,
+                    request.profile,
+                    request.scenario,
+                    commit.sha
+                );
+                return next_artifact::Response {
+                    artifact: Some(next_artifact::NextArtifact::SelfProfile {
+                        commit,
+                        benchmark: request.benchmark,
+                        profile: request.profile,
+                        scenario: request.scenario,
+                    }),
+                };
+            }
+            // The artifact may have been purged since the request was
+            // queued; drop the request and try the next one.
+            None => log::error!(
+                "dropping self-profile request for unknown artifact {}",
+                request.artifact
+            ),
+        }
+    }
+
     // Prefer benchmarking released artifacts first
     match ctxt.missing_published_artifacts().await {
         Ok(next_artifact) => {
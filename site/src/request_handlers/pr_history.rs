@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use collector::Bound;
+
+use crate::api::{pr_history, ServerResult};
+use crate::comparison::{self, Direction, Metric};
+use crate::load::SiteCtxt;
+
+/// Returns all perf runs ever performed for a PR (try builds and the
+/// post-merge master run), with a one-word verdict and a compare link per
+/// run, so the full perf history of a change is visible in one place.
+pub async fn handle_pr_history(
+    request: pr_history::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<pr_history::Response> {
+    log::info!("handle_pr_history({})", request.pr);
+
+    let builds = ctxt.conn().await.pr_builds(request.pr).await;
+    let commits = ctxt.index.load().commits();
+
+    let mut runs = Vec::with_capacity(builds.len());
+    for build in builds {
+        let commit = build
+            .sha
+            .as_ref()
+            .and_then(|sha| commits.iter().find(|c| c.sha == *sha));
+        let kind = match commit {
+            Some(c) if c.is_master() => "master",
+            Some(_) => "try",
+            None => "unknown",
+        };
+
+        let verdict = match (&build.sha, &build.parent_sha) {
+            (Some(sha), Some(parent)) if build.complete && commit.is_some() => {
+                match comparison::compare(
+                    Bound::Commit(parent.clone()),
+                    Bound::Commit(sha.clone()),
+                    Metric::InstructionsUser,
+                    &ctxt,
+                )
+                .await
+                {
+                    Ok(Some(comparison)) => {
+                        Some(match comparison::overall_direction(&comparison) {
+                            Direction::None => "none",
+                            Direction::Improvement => "improvement",
+                            Direction::Regression => "regression",
+                            Direction::Mixed => "mixed",
+                        })
+                    }
+                    Ok(None) => None,
+                    Err(error) => {
+                        log::error!("failed to compare {parent}..{sha}: {error}");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let compare_url = match (&build.sha, &build.parent_sha) {
+            (Some(sha), Some(parent)) => Some(format!(
+                "https://perf.rust-lang.org/compare.html?start={parent}&end={sha}"
+            )),
+            _ => None,
+        };
+
+        runs.push(pr_history::Run {
+            sha: build.sha,
+            parent_sha: build.parent_sha,
+            kind: kind.to_string(),
+            complete: build.complete,
+            requested_at: build.requested.map(|d| d.0.timestamp()),
+            verdict: verdict.map(str::to_string),
+            compare_url,
+        });
+    }
+
+    Ok(pr_history::Response {
+        pr: request.pr,
+        runs,
+    })
+}
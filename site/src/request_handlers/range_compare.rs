@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use collector::Bound;
+
+use crate::api::{range_compare, RequestError, ServerResult};
+use crate::db::ArtifactId;
+use crate::load::SiteCtxt;
+use crate::selector::{CompileBenchmarkQuery, CompileTestCase, Selector};
+
+/// Aggregates each test case over two commit ranges and reports the change
+/// of the medians together with a Mann-Whitney U significance test, so that
+/// range-level drift can be told apart from noise without relying on any two
+/// individual commits.
+pub async fn handle_range_compare(
+    request: range_compare::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<range_compare::Response> {
+    log::info!("handle_range_compare({:?})", request);
+
+    let metric = request.stat.parse().map_err(RequestError::BadRequest)?;
+    let samples_a = collect_samples(&ctxt, request.start_a, request.end_a, metric).await?;
+    let samples_b = collect_samples(&ctxt, request.start_b, request.end_b, metric).await?;
+
+    let mut comparisons = Vec::new();
+    for (test_case, a) in &samples_a.samples {
+        let b = match samples_b.samples.get(test_case) {
+            Some(b) => b,
+            None => continue,
+        };
+        if a.is_empty() || b.is_empty() {
+            continue;
+        }
+        let median_a = median(a);
+        let median_b = median(b);
+        let p_value = mann_whitney_p(a, b);
+        comparisons.push(range_compare::TestCaseComparison {
+            benchmark: test_case.benchmark.to_string(),
+            profile: test_case.profile.to_string(),
+            scenario: test_case.scenario.to_string(),
+            median_a,
+            median_b,
+            change: (median_b - median_a) / median_a * 100.0,
+            p_value,
+            significant: p_value < 0.05,
+        });
+    }
+    comparisons.sort_by(|c1, c2| {
+        c2.change
+            .abs()
+            .partial_cmp(&c1.change.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(range_compare::Response {
+        commits_a: samples_a.commits,
+        commits_b: samples_b.commits,
+        comparisons,
+    })
+}
+
+struct RangeSamples {
+    /// Number of master commits in the range.
+    commits: usize,
+    samples: HashMap<CompileTestCase, Vec<f64>>,
+}
+
+/// Collects, per test case, the measured values of the metric over the
+/// master commits in the given range.
+async fn collect_samples(
+    ctxt: &SiteCtxt,
+    start: Bound,
+    end: Bound,
+    metric: crate::comparison::Metric,
+) -> ServerResult<RangeSamples> {
+    let artifact_ids: Vec<ArtifactId> = ctxt
+        .data_range(start..=end)
+        .into_iter()
+        .filter(|commit| commit.is_master())
+        .map(|commit| commit.into())
+        .collect();
+    if artifact_ids.is_empty() {
+        return Err(RequestError::BadRequest("empty commit range".to_string()));
+    }
+    let commits = artifact_ids.len();
+
+    let responses = ctxt
+        .statistic_series(
+            CompileBenchmarkQuery::default().metric(Selector::One(metric)),
+            Arc::new(artifact_ids),
+        )
+        .await?;
+
+    let mut samples = HashMap::new();
+    for response in responses {
+        let values: Vec<f64> = response
+            .series
+            .filter_map(|(_aid, value)| value)
+            .collect();
+        samples.insert(response.test_case, values);
+    }
+    Ok(RangeSamples { commits, samples })
+}
+
+pub(super) fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Two-sided p-value of the Mann-Whitney U test, using the normal
+/// approximation with tie correction. The approximation is accurate enough
+/// for the sample sizes involved here (a handful of commits per range); the
+/// returned value saturates at 1.0.
+fn mann_whitney_p(a: &[f64], b: &[f64]) -> f64 {
+    let n_a = a.len() as f64;
+    let n_b = b.len() as f64;
+    let n = n_a + n_b;
+
+    // Rank the pooled samples, assigning tied values their average rank.
+    let mut pooled: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&v| (v, true))
+        .chain(b.iter().map(|&v| (v, false)))
+        .collect();
+    pooled.sort_by(|(v1, _), (v2, _)| v1.partial_cmp(v2).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut rank_sum_a = 0.0;
+    let mut tie_correction = 0.0;
+    let mut i = 0;
+    while i < pooled.len() {
+        let mut j = i;
+        while j < pooled.len() && pooled[j].0 == pooled[i].0 {
+            j += 1;
+        }
+        // Ranks are 1-based; every element of the tie group gets the average
+        // of the ranks the group covers.
+        let rank = (i + 1 + j) as f64 / 2.0;
+        let ties = (j - i) as f64;
+        tie_correction += ties * ties * ties - ties;
+        for &(_, from_a) in &pooled[i..j] {
+            if from_a {
+                rank_sum_a += rank;
+            }
+        }
+        i = j;
+    }
+
+    let u_a = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+    let mean = n_a * n_b / 2.0;
+    let variance = n_a * n_b / 12.0 * ((n + 1.0) - tie_correction / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        // All values identical; the distributions certainly do not differ.
+        return 1.0;
+    }
+    // Continuity correction.
+    let z = ((u_a - mean).abs() - 0.5).max(0.0) / variance.sqrt();
+    (erfc(z / std::f64::consts::SQRT_2)).min(1.0)
+}
+
+/// Complementary error function, via the Abramowitz and Stegun 7.1.26
+/// polynomial approximation (max error below 1.5e-7, plenty for a
+/// significance threshold).
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let result = poly * (-x * x).exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mann_whitney_p, median};
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(&[4.0, 1.0, 2.0, 3.0]), 2.5);
+    }
+
+    #[test]
+    fn test_mann_whitney() {
+        // Identical distributions are not significant.
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(mann_whitney_p(&a, &a) > 0.9);
+
+        // Clearly separated distributions are.
+        let b = [10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0];
+        let c = [20.0, 21.0, 22.0, 23.0, 24.0, 25.0, 26.0, 27.0];
+        assert!(mann_whitney_p(&b, &c) < 0.05);
+    }
+}
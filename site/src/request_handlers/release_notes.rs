@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+
+use crate::api::{release_notes, RequestError, ServerResult};
+use crate::db::{ArtifactId, Benchmark};
+use crate::load::SiteCtxt;
+use crate::selector;
+
+use super::dashboard::sorted_release_tags;
+
+/// Percent improvement a test case must reach between two consecutive stable
+/// releases to be worth a release-notes mention.
+const IMPROVEMENT_THRESHOLD_PERCENT: f64 = 1.0;
+
+/// Flags sustained improvements between the two most recent consecutive
+/// stable releases, summarized per benchmark category. An improvement is
+/// "sustained" when the most recent benchmarked master commit still shows it,
+/// so that wins that have since been lost again do not end up in release
+/// notes. This replaces a list that the release-notes process previously
+/// assembled by hand from individual comparisons.
+pub async fn handle_release_notes(
+    request: release_notes::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<release_notes::Response> {
+    log::info!("handle_release_notes({:?})", request);
+    let metric = request.stat.unwrap_or_else(|| "instructions:u".to_string());
+    ctxt.check_known_metric(&metric)
+        .map_err(RequestError::BadRequest)?;
+
+    let index = ctxt.index.load();
+    let tags = sorted_release_tags(&index);
+    let stables: Vec<&String> = tags.iter().filter(|tag| !tag.starts_with("beta")).collect();
+    let [.., from, to] = stables[..] else {
+        return Err(RequestError::NotFound(
+            "fewer than two stable releases in the index".to_string(),
+        ));
+    };
+    let (from, to) = (from.clone(), to.clone());
+    let nightly = index.commits().into_iter().rev().find(|c| c.is_master());
+
+    let mut artifact_ids = vec![ArtifactId::Tag(from.clone()), ArtifactId::Tag(to.clone())];
+    if let Some(nightly) = &nightly {
+        artifact_ids.push(ArtifactId::Commit(nightly.clone()));
+    }
+    let query = selector::CompileBenchmarkQuery::default().metric_name(metric.as_str().into());
+    let responses = ctxt.statistic_series(query, Arc::new(artifact_ids)).await?;
+
+    // Percent improvements of flagged test cases, per benchmark.
+    let mut by_benchmark: HashMap<Benchmark, Vec<f64>> = HashMap::new();
+    for response in responses {
+        let points: Vec<Option<f64>> = response.series.map(|(_, value)| value).collect();
+        let (Some(from_value), Some(to_value)) = (points[0], points[1]) else {
+            continue;
+        };
+        if from_value == 0.0 {
+            continue;
+        }
+        let improvement = (from_value - to_value) / from_value * 100.0;
+        if improvement < IMPROVEMENT_THRESHOLD_PERCENT {
+            continue;
+        }
+        // A data gap on the nightly side should not drop the win, so only
+        // cases where nightly data shows the improvement lost are rejected.
+        if let Some(Some(nightly_value)) = points.get(2) {
+            if (from_value - nightly_value) / from_value * 100.0 < IMPROVEMENT_THRESHOLD_PERCENT {
+                continue;
+            }
+        }
+        by_benchmark
+            .entry(response.test_case.benchmark)
+            .or_default()
+            .push(improvement);
+    }
+
+    let category_map = ctxt.get_benchmark_category_map().await;
+    let mut categories: HashMap<String, Vec<release_notes::Improvement>> = HashMap::new();
+    for (benchmark, improvements) in by_benchmark {
+        let Some(category) = category_map.get(&benchmark) else {
+            continue;
+        };
+        let average = improvements.iter().sum::<f64>() / improvements.len() as f64;
+        let largest = improvements.iter().copied().fold(f64::MIN, f64::max);
+        categories
+            .entry(category.to_string())
+            .or_default()
+            .push(release_notes::Improvement {
+                benchmark: benchmark.to_string(),
+                average_improvement: average,
+                largest_improvement: largest,
+                test_cases: improvements.len(),
+            });
+    }
+    for improvements in categories.values_mut() {
+        improvements.sort_by(|a, b| {
+            b.average_improvement
+                .partial_cmp(&a.average_improvement)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    Ok(release_notes::Response {
+        from,
+        to,
+        metric,
+        threshold: IMPROVEMENT_THRESHOLD_PERCENT,
+        categories,
+    })
+}
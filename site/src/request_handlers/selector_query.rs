@@ -0,0 +1,382 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use headers::{ContentType, Header};
+use http::StatusCode;
+use regex::Regex;
+
+use crate::api::selector_query::{Aggregation, Request, SelectorSpec, Series, Suite};
+use crate::api::{RequestError, ServerResult};
+use crate::db::{self, ArtifactId, Profile, Scenario};
+use crate::load::SiteCtxt;
+use crate::selector::{CompileBenchmarkQuery, RuntimeBenchmarkQuery, Selector};
+
+/// The most artifacts a single query may cover.
+const MAX_ARTIFACTS: usize = 1000;
+/// The most series a single query may return.
+const MAX_SERIES: usize = 5000;
+/// The most metrics a single query may fan out over.
+const MAX_METRICS: usize = 10;
+/// The longest accepted regular expression.
+const MAX_REGEX_LEN: usize = 256;
+
+/// A validated query, with regexes already expanded into concrete subsets.
+struct QueryPlan {
+    artifact_ids: Arc<Vec<ArtifactId>>,
+    suite: SuiteQuery,
+    metrics: Vec<db::Metric>,
+    aggregation: Aggregation,
+}
+
+enum SuiteQuery {
+    Compile {
+        benchmark: Selector<String>,
+        profile: Selector<Profile>,
+        scenario: Selector<Scenario>,
+    },
+    Runtime {
+        benchmark: Selector<String>,
+    },
+}
+
+/// Answers an ad-hoc selector query.
+///
+/// The response is streamed: each series is serialized and sent as it is
+/// produced, so that deep-history queries returning thousands of series do
+/// not have to be accumulated in memory before the first byte is sent. If a
+/// database error occurs mid-stream, the body is aborted and the client sees
+/// a truncated response.
+pub async fn handle_selector_query(
+    request: Request,
+    ctxt: Arc<SiteCtxt>,
+) -> http::Response<hyper::Body> {
+    log::info!("handle_selector_query({:?})", request);
+
+    let plan = match plan_query(request, &ctxt) {
+        Ok(plan) => plan,
+        Err(e) => return crate::server::error_response(e),
+    };
+
+    let (sender, body) = hyper::Body::channel();
+    let mut response = http::Response::new(body);
+    let mut header = vec![];
+    ContentType::json().encode(&mut header);
+    response
+        .headers_mut()
+        .insert(hyper::header::CONTENT_TYPE, header.pop().unwrap());
+    *response.status_mut() = StatusCode::OK;
+    tokio::spawn(stream_response(ctxt, plan, sender));
+    response
+}
+
+fn plan_query(request: Request, ctxt: &SiteCtxt) -> ServerResult<QueryPlan> {
+    let artifact_ids: Vec<ArtifactId> = ctxt
+        .data_range(request.start.clone()..=request.end.clone())
+        .into_iter()
+        .map(|commit| commit.into())
+        .collect();
+    if artifact_ids.is_empty() {
+        return Err(RequestError::BadRequest(
+            "no artifacts in the selected range".to_string(),
+        ));
+    }
+    if artifact_ids.len() > MAX_ARTIFACTS {
+        return Err(RequestError::BadRequest(format!(
+            "the selected range covers {} artifacts, at most {MAX_ARTIFACTS} \
+            are allowed; narrow the start/end bounds",
+            artifact_ids.len()
+        )));
+    }
+
+    let index = ctxt.index.load();
+    let (suite, metrics) = match request.suite {
+        Suite::Compile => {
+            let benchmarks: BTreeSet<String> = index
+                .compile_statistic_descriptions()
+                .map(|(&(benchmark, ..), _)| benchmark.to_string())
+                .collect();
+            let profiles: BTreeSet<String> = index
+                .compile_statistic_descriptions()
+                .map(|(&(_, profile, ..), _)| profile.to_string())
+                .collect();
+            let scenarios: BTreeSet<String> = index
+                .compile_statistic_descriptions()
+                .map(|(&(_, _, scenario, _), _)| scenario.to_string())
+                .collect();
+
+            let suite = SuiteQuery::Compile {
+                benchmark: resolve_spec(&request.benchmark, &benchmarks)?,
+                profile: resolve_spec(&request.profile, &profiles)?
+                    .try_map(|v| v.parse::<Profile>())
+                    .map_err(RequestError::BadRequest)?,
+                scenario: resolve_spec(&request.scenario, &scenarios)?
+                    .try_map(|v| v.parse::<Scenario>())
+                    .map_err(RequestError::BadRequest)?,
+            };
+            let metrics = resolve_metrics(ctxt, &request.metric, index.compile_metrics())?;
+            (suite, metrics)
+        }
+        Suite::Runtime => {
+            if request.profile != SelectorSpec::All || request.scenario != SelectorSpec::All {
+                return Err(RequestError::BadRequest(
+                    "the profile and scenario selectors are only supported for the \
+                    compile suite"
+                        .to_string(),
+                ));
+            }
+            let benchmarks: BTreeSet<String> = index
+                .runtime_statistic_descriptions()
+                .map(|(&(benchmark, _), _)| benchmark.to_string())
+                .collect();
+            let suite = SuiteQuery::Runtime {
+                benchmark: resolve_spec(&request.benchmark, &benchmarks)?,
+            };
+            let metrics = resolve_metrics(ctxt, &request.metric, index.runtime_metrics())?;
+            (suite, metrics)
+        }
+    };
+
+    Ok(QueryPlan {
+        artifact_ids: Arc::new(artifact_ids),
+        suite,
+        metrics,
+        aggregation: request.aggregation,
+    })
+}
+
+/// Executes the query metric by metric, writing the response body as the
+/// series are produced. Only one metric's worth of series is held in memory
+/// at a time.
+async fn stream_response(ctxt: Arc<SiteCtxt>, plan: QueryPlan, mut sender: hyper::body::Sender) {
+    let artifact_ids = serde_json::to_string(
+        &plan
+            .artifact_ids
+            .iter()
+            .map(|aid| match aid {
+                ArtifactId::Commit(commit) => commit.sha.clone(),
+                ArtifactId::Tag(tag) => tag.clone(),
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    if !send_chunk(
+        &mut sender,
+        format!("{{\"artifact_ids\":{artifact_ids},\"series\":["),
+    )
+    .await
+    {
+        return;
+    }
+
+    let mut sent = 0usize;
+    for &metric in &plan.metrics {
+        let responses = match &plan.suite {
+            SuiteQuery::Compile {
+                benchmark,
+                profile,
+                scenario,
+            } => {
+                ctxt.statistic_series(
+                    CompileBenchmarkQuery::default()
+                        .benchmark(benchmark.clone())
+                        .profile(profile.clone())
+                        .scenario(scenario.clone())
+                        .metric_name(metric),
+                    plan.artifact_ids.clone(),
+                )
+                .await
+                .map(|responses| {
+                    responses
+                        .into_iter()
+                        .map(|response| {
+                            let points = response.series.map(|(_, point)| point).collect();
+                            make_series(
+                                response.test_case.benchmark.to_string(),
+                                Some(response.test_case.profile.to_string()),
+                                Some(response.test_case.scenario.to_string()),
+                                metric,
+                                points,
+                                plan.aggregation,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }
+            SuiteQuery::Runtime { benchmark } => {
+                ctxt.statistic_series(
+                    RuntimeBenchmarkQuery::default()
+                        .benchmark(benchmark.clone())
+                        .metric_name(metric),
+                    plan.artifact_ids.clone(),
+                )
+                .await
+                .map(|responses| {
+                    responses
+                        .into_iter()
+                        .map(|response| {
+                            let points = response.series.map(|(_, point)| point).collect();
+                            make_series(
+                                response.test_case.benchmark.to_string(),
+                                None,
+                                None,
+                                metric,
+                                points,
+                                plan.aggregation,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }
+        };
+        let responses = match responses {
+            Ok(responses) => responses,
+            Err(e) => {
+                log::error!("selector query failed mid-stream: {e}");
+                sender.abort();
+                return;
+            }
+        };
+        for series in responses {
+            if sent == MAX_SERIES {
+                log::warn!("selector query returned more than {MAX_SERIES} series; truncating");
+                sender.abort();
+                return;
+            }
+            let mut chunk = if sent == 0 {
+                String::new()
+            } else {
+                ",".to_string()
+            };
+            chunk.push_str(&serde_json::to_string(&series).unwrap());
+            if !send_chunk(&mut sender, chunk).await {
+                return;
+            }
+            sent += 1;
+        }
+    }
+
+    let _ = sender.send_data(bytes::Bytes::from_static(b"]}")).await;
+}
+
+async fn send_chunk(sender: &mut hyper::body::Sender, chunk: String) -> bool {
+    if let Err(e) = sender.send_data(bytes::Bytes::from(chunk)).await {
+        // The client went away; nothing to abort.
+        log::trace!("failed to send response chunk: {e:?}");
+        return false;
+    }
+    true
+}
+
+fn make_series(
+    benchmark: String,
+    profile: Option<String>,
+    scenario: Option<String>,
+    metric: db::Metric,
+    points: Vec<Option<f64>>,
+    aggregation: Aggregation,
+) -> Series {
+    let (value, points) = match aggregation {
+        Aggregation::Series => (None, Some(points)),
+        _ => (aggregate(&points, aggregation), None),
+    };
+    Series {
+        benchmark,
+        profile,
+        scenario,
+        metric: metric.as_str().to_string(),
+        value,
+        points,
+    }
+}
+
+/// Aggregates a series over the artifact range, skipping missing points.
+/// Returns `None` if no point has data.
+fn aggregate(points: &[Option<f64>], aggregation: Aggregation) -> Option<f64> {
+    let values: Vec<f64> = points.iter().flatten().copied().collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some(match aggregation {
+        Aggregation::Series => unreachable!(),
+        Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+        Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+        Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        Aggregation::Sum => values.iter().sum(),
+    })
+}
+
+/// Turns a selector spec into a `Selector`, expanding regexes against the
+/// set of values known to the index. A regex matching nothing yields an
+/// empty subset (and thus no series) rather than an error.
+fn resolve_spec(spec: &SelectorSpec, known: &BTreeSet<String>) -> ServerResult<Selector<String>> {
+    Ok(match spec {
+        SelectorSpec::All => Selector::All,
+        SelectorSpec::One(value) => Selector::One(value.clone()),
+        SelectorSpec::Subset(values) => Selector::Subset(values.clone()),
+        SelectorSpec::Regex(pattern) => {
+            let regex = compile_regex(pattern)?;
+            Selector::Subset(
+                known
+                    .iter()
+                    .filter(|value| regex.is_match(value))
+                    .cloned()
+                    .collect(),
+            )
+        }
+    })
+}
+
+/// Resolves the metric spec to concrete metric names, since a series in the
+/// response must be labeled with its metric. The query layer works on metric
+/// names directly, so everything the index (or, for derived metrics, the
+/// site configuration) knows about is fair game; explicitly named metrics
+/// must be known.
+fn resolve_metrics(
+    ctxt: &SiteCtxt,
+    spec: &SelectorSpec,
+    mut known: Vec<String>,
+) -> ServerResult<Vec<db::Metric>> {
+    known.extend(
+        ctxt.config()
+            .derived_metrics
+            .iter()
+            .map(|derived| derived.name.clone()),
+    );
+    let resolve_one = |name: &String| -> ServerResult<db::Metric> {
+        ctxt.check_known_metric(name)
+            .map_err(RequestError::BadRequest)?;
+        Ok(name.as_str().into())
+    };
+    let metrics: Vec<db::Metric> = match spec {
+        SelectorSpec::All => known.iter().map(|name| name.as_str().into()).collect(),
+        SelectorSpec::One(name) => vec![resolve_one(name)?],
+        SelectorSpec::Subset(names) => names.iter().map(resolve_one).collect::<Result<_, _>>()?,
+        SelectorSpec::Regex(pattern) => {
+            let regex = compile_regex(pattern)?;
+            known
+                .iter()
+                .filter(|name| regex.is_match(name))
+                .map(|name| name.as_str().into())
+                .collect()
+        }
+    };
+    if metrics.len() > MAX_METRICS {
+        return Err(RequestError::BadRequest(format!(
+            "the query selects {} metrics, at most {MAX_METRICS} are allowed",
+            metrics.len()
+        )));
+    }
+    Ok(metrics)
+}
+
+fn compile_regex(pattern: &str) -> ServerResult<Regex> {
+    if pattern.len() > MAX_REGEX_LEN {
+        return Err(RequestError::BadRequest(format!(
+            "regex longer than {MAX_REGEX_LEN} bytes is not allowed"
+        )));
+    }
+    // Anchor the pattern so that e.g. `serde.*` does not also match
+    // `foo-serde`.
+    Regex::new(&format!("^(?:{pattern})$"))
+        .map_err(|e| RequestError::BadRequest(format!("invalid regex: {e}")))
+}
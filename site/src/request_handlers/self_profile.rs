@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::Read;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -9,9 +9,12 @@ use headers::{ContentType, Header};
 use hyper::StatusCode;
 
 use crate::api::self_profile::{ArtifactSize, ArtifactSizeDelta};
-use crate::api::{self_profile, self_profile_processed, self_profile_raw, ServerResult};
+use crate::api::{
+    self_profile, self_profile_aggregate, self_profile_processed, self_profile_raw, RequestError,
+    ServerResult,
+};
 use crate::comparison::Metric;
-use crate::db::ArtifactId;
+use crate::db::{ArtifactId, Lookup};
 use crate::load::SiteCtxt;
 use crate::selector::{self};
 use crate::self_profile::{
@@ -74,11 +77,7 @@ pub async fn handle_self_profile_processed_download(
                     return resp;
                 }
             },
-            Err(e) => {
-                let mut resp = Response::new(e.into());
-                *resp.status_mut() = StatusCode::BAD_REQUEST;
-                return resp;
-            }
+            Err(e) => return crate::server::error_response(e),
         }
     } else {
         None
@@ -104,11 +103,7 @@ pub async fn handle_self_profile_processed_download(
                 return resp;
             }
         },
-        Err(e) => {
-            let mut resp = Response::new(e.into());
-            *resp.status_mut() = StatusCode::BAD_REQUEST;
-            return resp;
-        }
+        Err(e) => return crate::server::error_response(e),
     };
 
     log::trace!("got data in {:?}", start.elapsed());
@@ -154,6 +149,13 @@ pub async fn handle_self_profile_processed_download(
     builder.body(hyper::Body::from(output.data)).unwrap()
 }
 
+/// Formats the owning compiler component and team of a query for display,
+/// e.g. `rustc_borrowck (rust-lang/types)`.
+fn query_component(label: &str) -> Option<String> {
+    collector::query_attribution::attribute_query(label)
+        .map(|owner| format!("{} ({})", owner.component, owner.team))
+}
+
 fn get_self_profile_data(
     cpu_clock: Option<f64>,
     profile: &analyzeme::AnalysisResults,
@@ -165,6 +167,7 @@ fn get_self_profile_data(
         .iter()
         .map(|qd| self_profile::QueryData {
             label: qd.label.as_str().into(),
+            component: query_component(qd.label.as_str()),
             self_time: qd.self_time.as_nanos() as u64,
             percent_total_time: ((qd.self_time.as_secs_f64() / total_time.as_secs_f64()) * 100.0)
                 as f32,
@@ -178,6 +181,7 @@ fn get_self_profile_data(
 
     let totals = self_profile::QueryData {
         label: "Totals".into(),
+        component: None,
         self_time: total_time.as_nanos() as u64,
         // TODO: check against wall-time from perf stats
         percent_total_time: cpu_clock
@@ -249,6 +253,7 @@ fn add_uninvoked_base_profile_queries(
         if !profile_queries.contains(qd.label.as_str()) {
             let uninvoked_query_data = self_profile::QueryData {
                 label: qd.label,
+                component: qd.component.clone(),
                 self_time: 0,
                 percent_total_time: 0.0,
                 number_of_cache_misses: 0,
@@ -309,6 +314,23 @@ fn get_self_profile_delta(
         }
     }
 
+    // Aggregate the positive self-time deltas by owning component, so that a
+    // regression can be routed to the right team at a glance.
+    let mut regressed: HashMap<&str, i64> = HashMap::new();
+    for (qd, delta) in profile.query_data.iter().zip(&query_data) {
+        if delta.self_time > 0 {
+            if let Some(component) = &qd.component {
+                *regressed.entry(component).or_default() += delta.self_time;
+            }
+        }
+    }
+    let mut regressing_components: Vec<_> = regressed.into_iter().collect();
+    regressing_components.sort_by_key(|&(_, self_time)| std::cmp::Reverse(self_time));
+    let regressing_components = regressing_components
+        .into_iter()
+        .map(|(component, _)| component.to_string())
+        .collect();
+
     let first = &profiling_data.artifact_sizes[..];
     let base = base_profiling_data
         .map(|s| &s.artifact_sizes[..])
@@ -324,6 +346,7 @@ fn get_self_profile_delta(
     Some(self_profile::SelfProfileDelta {
         totals,
         query_data,
+        regressing_components,
         artifact_sizes,
     })
 }
@@ -395,11 +418,7 @@ pub async fn handle_self_profile_raw_download(
     log::info!("handle_self_profile_raw_download({:?})", body);
     let url = match handle_self_profile_raw(body, ctxt).await {
         Ok(v) => v.url,
-        Err(e) => {
-            let mut resp = http::Response::new(e.into());
-            *resp.status_mut() = StatusCode::BAD_REQUEST;
-            return resp;
-        }
+        Err(e) => return crate::server::error_response(e),
     };
     log::trace!("downloading {}", url);
 
@@ -506,14 +525,17 @@ pub async fn handle_self_profile_raw(
     let (aid, first_cid) = aids_and_cids
         .first()
         .copied()
-        .ok_or_else(|| format!("No results for {}", body.commit))?;
+        .ok_or_else(|| RequestError::NotFound(format!("No results for {}", body.commit)))?;
 
     let cid = match body.cid {
         Some(cid) => {
             if aids_and_cids.iter().any(|(_, v)| *v == cid) {
                 cid
             } else {
-                return Err(format!("{} is not a collection ID at this artifact", cid));
+                return Err(RequestError::NotFound(format!(
+                    "{} is not a collection ID at this artifact",
+                    cid
+                )));
             }
         }
         _ => first_cid,
@@ -533,9 +555,17 @@ pub async fn handle_self_profile_raw(
         cid,
     );
 
-    return match fetch(&cids, cid, format!("{}.mm_profdata.sz", url_prefix)).await {
+    // Newer collectors upload zstd-compressed archives; fall back to the
+    // legacy snappy extension for older artifacts.
+    return match fetch(&cids, cid, format!("{}.mm_profdata.zst", url_prefix)).await {
         Ok(fetched) => Ok(fetched),
-        Err(new_error) => Err(format!("mm_profdata download failed: {:?}", new_error,)),
+        Err(_) => match fetch(&cids, cid, format!("{}.mm_profdata.sz", url_prefix)).await {
+            Ok(fetched) => Ok(fetched),
+            Err(new_error) => Err(RequestError::Upstream(format!(
+                "mm_profdata download failed: {:?}",
+                new_error,
+            ))),
+        },
     };
 
     async fn fetch(
@@ -547,12 +577,12 @@ pub async fn handle_self_profile_raw(
             .head(&url)
             .send()
             .await
-            .map_err(|e| format!("fetching artifact: {:?}", e))?;
+            .map_err(|e| RequestError::Upstream(format!("fetching artifact: {:?}", e)))?;
         if !resp.status().is_success() {
-            return Err(format!(
+            return Err(RequestError::Upstream(format!(
                 "Artifact did not resolve successfully: {:?} received",
                 resp.status()
-            ));
+            )));
         }
 
         Ok(self_profile_raw::Response {
@@ -593,7 +623,7 @@ pub async fn handle_self_profile(
     let find_aid = |commit: &str| {
         index
             .artifact_id_for_commit(commit)
-            .ok_or(format!("could not find artifact {}", commit))
+            .ok_or_else(|| RequestError::NotFound(format!("could not find artifact {}", commit)))
     };
 
     let mut commits = vec![find_aid(&body.commit)?];
@@ -618,7 +648,11 @@ pub async fn handle_self_profile(
                     extract_profiling_data(d)
                         .map_err(|e| format!("error extracting self profiling data: {}", e))?,
                 ),
-                Err(e) => return Err(format!("could not fetch raw profile data: {e:?}")),
+                Err(e) => {
+                    return Err(RequestError::Upstream(format!(
+                        "could not fetch raw profile data: {e:?}"
+                    )))
+                }
             };
         }
     }
@@ -648,3 +682,91 @@ pub async fn handle_self_profile(
         profile,
     })
 }
+
+/// Sums self-profile query times across the entire benchmark suite for one
+/// artifact, optionally diffed against a base artifact, answering "which
+/// compiler query got slower overall" rather than per benchmark. The sums
+/// are computed from the per-test-case query data stored in the database,
+/// so no raw profiles need to be fetched.
+pub async fn handle_self_profile_aggregate(
+    body: self_profile_aggregate::Request,
+    ctxt: &SiteCtxt,
+) -> ServerResult<self_profile_aggregate::Response> {
+    log::info!("handle_self_profile_aggregate({:?})", body);
+    let index = ctxt.index.load();
+    let find_aid = |commit: &str| {
+        index
+            .artifact_id_for_commit(commit)
+            .ok_or_else(|| RequestError::NotFound(format!("could not find artifact {}", commit)))
+    };
+    let aid = find_aid(&body.commit)?.lookup(&index).unwrap();
+
+    let conn = ctxt.conn().await;
+    let aggregate = conn.aggregate_self_profile_queries(aid).await;
+    if aggregate.is_empty() {
+        return Err(RequestError::NotFound(format!(
+            "no self-profile data recorded for artifact {}",
+            body.commit
+        )));
+    }
+
+    let base = match &body.base_commit {
+        Some(base_commit) => {
+            let base_aid = find_aid(base_commit)?.lookup(&index).unwrap();
+            Some(
+                conn.aggregate_self_profile_queries(base_aid)
+                    .await
+                    .into_iter()
+                    .map(|query| (query.label, query.self_time))
+                    .collect::<HashMap<_, _>>(),
+            )
+        }
+        None => None,
+    };
+
+    let total_self_time: u64 = aggregate
+        .iter()
+        .map(|query| query.self_time.as_nanos() as u64)
+        .sum();
+    let total_self_time_delta = base.as_ref().map(|base| {
+        total_self_time as i64
+            - base
+                .values()
+                .map(|time| time.as_nanos() as i64)
+                .sum::<i64>()
+    });
+
+    let mut query_data: Vec<_> = aggregate
+        .into_iter()
+        .map(|query| {
+            let self_time = query.self_time.as_nanos() as u64;
+            self_profile_aggregate::QueryData {
+                label: query.label,
+                self_time,
+                percent_total_time: self_time as f32 / total_self_time as f32 * 100.0,
+                number_of_cache_hits: query.number_of_cache_hits,
+                invocation_count: query.invocation_count,
+                blocked_time: query.blocked_time.as_nanos() as u64,
+                incremental_load_time: query.incremental_load_time.as_nanos() as u64,
+                self_time_delta: base.as_ref().map(|base| {
+                    self_time as i64
+                        - base
+                            .get(&query.label)
+                            .map_or(0, |time| time.as_nanos() as i64)
+                }),
+            }
+        })
+        .collect();
+    // The most interesting queries first: the largest regression when
+    // diffing, the most expensive query otherwise.
+    match base {
+        Some(_) => query_data.sort_by_key(|qd| std::cmp::Reverse(qd.self_time_delta.unwrap())),
+        None => query_data.sort_by_key(|qd| std::cmp::Reverse(qd.self_time)),
+    }
+
+    Ok(self_profile_aggregate::Response {
+        total_self_time,
+        total_self_time_delta,
+        query_data,
+    })
+}
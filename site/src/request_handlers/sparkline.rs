@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::{sparkline, RequestError, ServerResult};
+use crate::db::{Profile, Scenario};
+use crate::load::SiteCtxt;
+use crate::selector::{CompileBenchmarkQuery, Selector};
+
+use super::graph::master_artifact_ids_for_range;
+
+/// Returns only the first, last, minimum and maximum measured value per
+/// selected series for a range, with no per-point data. Overview pages can
+/// render thousands of sparkline summaries from this without paying for the
+/// full graph payload.
+pub async fn handle_sparkline(
+    request: sparkline::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<sparkline::Response> {
+    log::info!("handle_sparkline({:?})", request);
+    ctxt.check_known_metric(&request.stat)
+        .map_err(RequestError::BadRequest)?;
+    if let Some(benchmark) = &request.benchmark {
+        ctxt.check_known_compile_benchmark(benchmark)
+            .map_err(RequestError::BadRequest)?;
+    }
+    ctxt.check_bound_order(&request.start, &request.end)
+        .map_err(RequestError::BadRequest)?;
+
+    let create_selector = |filter: &Option<String>| -> Selector<String> {
+        filter
+            .as_ref()
+            .map(|value| Selector::One(value.clone()))
+            .unwrap_or(Selector::All)
+    };
+    let query = CompileBenchmarkQuery::default()
+        .benchmark(create_selector(&request.benchmark))
+        .profile(
+            create_selector(&request.profile)
+                .try_map(|v| v.parse::<Profile>())
+                .map_err(RequestError::BadRequest)?,
+        )
+        .scenario(
+            create_selector(&request.scenario)
+                .try_map(|v| v.parse::<Scenario>())
+                .map_err(RequestError::BadRequest)?,
+        )
+        .metric_name(request.stat.as_str().into());
+
+    let artifact_ids = Arc::new(master_artifact_ids_for_range(
+        &ctxt,
+        request.start,
+        request.end,
+    ));
+    let responses = ctxt.statistic_series(query, artifact_ids).await?;
+
+    let mut summaries: HashMap<String, HashMap<String, HashMap<String, sparkline::Summary>>> =
+        HashMap::new();
+    for response in responses {
+        let mut summary: Option<sparkline::Summary> = None;
+        for value in response.series.filter_map(|(_, value)| value) {
+            let summary = summary.get_or_insert(sparkline::Summary {
+                first: value,
+                last: value,
+                min: value,
+                max: value,
+            });
+            summary.last = value;
+            summary.min = summary.min.min(value);
+            summary.max = summary.max.max(value);
+        }
+        // Series with no measured value in the range are omitted entirely.
+        if let Some(summary) = summary {
+            summaries
+                .entry(response.test_case.benchmark.to_string())
+                .or_default()
+                .entry(response.test_case.profile.to_string())
+                .or_default()
+                .insert(response.test_case.scenario.to_string(), summary);
+        }
+    }
+
+    Ok(sparkline::Response { summaries })
+}
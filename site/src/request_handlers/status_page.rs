@@ -1,7 +1,8 @@
 use std::str;
 use std::sync::Arc;
+use std::time::Instant;
 
-use crate::api::status;
+use crate::api::{health, status};
 use crate::db::{ArtifactId, Lookup};
 use crate::load::SiteCtxt;
 
@@ -33,9 +34,9 @@ pub async fn handle_status_page(ctxt: Arc<SiteCtxt>) -> status::Response {
         None
     };
 
-    let errors = if let Some(last) = &last_commit {
-        conn.get_error(ArtifactId::from(last.clone()).lookup(&idx).unwrap())
-            .await
+    let (errors, last_commit_info) = if let Some(last) = &last_commit {
+        let aid = ArtifactId::from(last.clone()).lookup(&idx).unwrap();
+        (conn.get_error(aid).await, conn.get_artifact_info(aid).await)
     } else {
         Default::default()
     };
@@ -49,6 +50,7 @@ pub async fn handle_status_page(ctxt: Arc<SiteCtxt>) -> status::Response {
 
     status::Response {
         last_commit,
+        last_commit_info,
         benchmarks: benchmark_state,
         missing,
         current,
@@ -59,6 +61,22 @@ pub async fn handle_status_page(ctxt: Arc<SiteCtxt>) -> status::Response {
     }
 }
 
+pub async fn handle_health(ctxt: Arc<SiteCtxt>) -> health::Response {
+    let conn = ctxt.conn().await;
+    // The query doubles as both the latency probe and the source of the
+    // last-ingestion timestamp.
+    let start = Instant::now();
+    let last_collection = conn.last_artifact_collection().await;
+    let db_latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    health::Response {
+        db_latency_ms,
+        last_ingested: last_collection.map(|d| d.end_time.timestamp()),
+        queue_depth: ctxt.missing_commits().await.len(),
+        index_age_seconds: ctxt.index_age().as_secs(),
+    }
+}
+
 fn prettify_log(log: &str) -> Option<String> {
     let mut lines = log.lines();
     let first = lines.next()?;
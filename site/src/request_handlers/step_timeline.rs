@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::api::{step_timeline, RequestError, ServerResult};
+use crate::load::SiteCtxt;
+
+/// Returns the start/end timestamps of every collection step recorded for an
+/// artifact, so operators can see where the collection time went and track
+/// harness-side slowdowns (e.g. toolchain downloads) over time.
+pub async fn handle_step_timeline(
+    request: step_timeline::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<step_timeline::Response> {
+    log::info!("handle_step_timeline({})", request.artifact);
+
+    let conn = ctxt.conn().await;
+    let artifact_id = conn
+        .artifact_by_name(&request.artifact)
+        .await
+        .ok_or_else(|| RequestError::NotFound(format!("unknown artifact {}", request.artifact)))?;
+    let aid = conn.artifact_id(&artifact_id).await;
+
+    let steps = conn
+        .collector_step_timings(aid)
+        .await
+        .into_iter()
+        .map(|timing| {
+            let start = timing.start.map(|t| t.timestamp());
+            let end = timing.end.map(|t| t.timestamp());
+            step_timeline::Step {
+                step: timing.step,
+                duration: start.zip(end).map(|(s, e)| e - s),
+                start,
+                end,
+            }
+        })
+        .collect();
+
+    Ok(step_timeline::Response {
+        artifact: request.artifact,
+        steps,
+    })
+}
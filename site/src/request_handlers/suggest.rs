@@ -0,0 +1,67 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::api::suggest;
+use crate::load::SiteCtxt;
+use crate::suggestions::match_score;
+
+/// Suggestions returned when the request does not ask for a specific number.
+const DEFAULT_LIMIT: usize = 10;
+/// Upper bound on the number of suggestions a single request may ask for.
+const MAX_LIMIT: usize = 100;
+
+pub async fn handle_suggest(request: suggest::Request, ctxt: Arc<SiteCtxt>) -> suggest::Response {
+    let index = ctxt.index.load();
+
+    let mut benchmarks = BTreeSet::new();
+    let mut scenarios = BTreeSet::new();
+    for (&(benchmark, _, scenario, _), _) in index.compile_statistic_descriptions() {
+        benchmarks.insert(benchmark.to_string());
+        scenarios.insert(scenario.to_string());
+    }
+    for (&(benchmark, _), _) in index.runtime_statistic_descriptions() {
+        benchmarks.insert(benchmark.to_string());
+    }
+    let mut metrics = index.compile_metrics();
+    metrics.extend(index.runtime_metrics());
+    metrics.extend(
+        ctxt.config()
+            .derived_metrics
+            .iter()
+            .map(|derived| derived.name.clone()),
+    );
+    metrics.sort();
+    metrics.dedup();
+
+    let candidates = benchmarks
+        .into_iter()
+        .map(|value| (suggest::Kind::Benchmark, value))
+        .chain(
+            metrics
+                .into_iter()
+                .map(|value| (suggest::Kind::Metric, value)),
+        )
+        .chain(
+            scenarios
+                .into_iter()
+                .map(|value| (suggest::Kind::Scenario, value)),
+        );
+
+    let mut matches: Vec<(u32, suggest::Suggestion)> = candidates
+        .filter_map(|(kind, value)| {
+            match_score(&request.q, &value)
+                .map(|score| (score, suggest::Suggestion { kind, value }))
+        })
+        .collect();
+    matches.sort_by(|(score_a, a), (score_b, b)| {
+        (score_a, a.value.len(), &a.value).cmp(&(score_b, b.value.len(), &b.value))
+    });
+    matches.truncate(request.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT));
+
+    suggest::Response {
+        suggestions: matches
+            .into_iter()
+            .map(|(_, suggestion)| suggestion)
+            .collect(),
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::api::{suite_cost, RequestError, ServerResult};
+use crate::comparison::Metric;
+use crate::db::ArtifactId;
+use crate::load::SiteCtxt;
+use crate::selector::{CompileBenchmarkQuery, Selector};
+
+/// Ranks the members of the benchmark suite by how long they took to collect
+/// for a single artifact, to inform decisions about trimming or splitting the
+/// suite.
+pub async fn handle_suite_cost(
+    request: suite_cost::Request,
+    ctxt: Arc<SiteCtxt>,
+) -> ServerResult<suite_cost::Response> {
+    log::info!("handle_suite_cost({:?})", request);
+
+    let artifact_id = match &request.artifact {
+        Some(name) => ctxt
+            .conn()
+            .await
+            .artifact_by_name(name)
+            .await
+            .ok_or_else(|| RequestError::NotFound(format!("unknown artifact {}", name)))?,
+        None => ctxt
+            .index
+            .load()
+            .commits()
+            .last()
+            .cloned()
+            .map(ArtifactId::Commit)
+            .ok_or_else(|| "no benchmarked commits".to_string())?,
+    };
+
+    let responses = ctxt
+        .statistic_series(
+            CompileBenchmarkQuery::default().metric(Selector::One(Metric::CollectorRunDuration)),
+            Arc::new(vec![artifact_id]),
+        )
+        .await?;
+
+    let mut test_cases: Vec<_> = responses
+        .into_iter()
+        .filter_map(|sr| {
+            let seconds = sr.series.into_iter().next().and_then(|(_, value)| value)?;
+            Some(suite_cost::TestCaseCost {
+                benchmark: sr.test_case.benchmark.to_string(),
+                profile: sr.test_case.profile.to_string(),
+                scenario: sr.test_case.scenario.to_string(),
+                seconds,
+            })
+        })
+        .collect();
+    test_cases.sort_by(|a, b| b.seconds.partial_cmp(&a.seconds).unwrap());
+    let total_seconds = test_cases.iter().map(|case| case.seconds).sum();
+    if let Some(limit) = request.limit {
+        test_cases.truncate(limit);
+    }
+
+    Ok(suite_cost::Response {
+        total_seconds,
+        test_cases,
+    })
+}
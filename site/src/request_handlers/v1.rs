@@ -0,0 +1,126 @@
+//! Handlers for the public `/api/v1/` endpoints.
+//!
+//! These intentionally do not reuse the response types of the internal
+//! `/perf/` handlers: the `v1` shapes are a stability contract, and sharing
+//! structs would couple them to frontend-driven changes.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::api::v1;
+use crate::load::SiteCtxt;
+
+use database::metric::{BetterDirection, MetricMetadata};
+use utoipa::OpenApi;
+
+/// The OpenAPI document describing the `/api/v1/` endpoints, generated from
+/// the annotated types in `crate::api::v1` and served at `/api/openapi.json`.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "rustc-perf",
+        description = "Public read-only API of the Rust compiler performance tracking site."
+    ),
+    paths(handle_v1_artifacts, handle_v1_benchmarks, handle_v1_metrics),
+    components(schemas(
+        v1::artifacts::Commit,
+        v1::artifacts::Response,
+        v1::benchmarks::CompileBenchmark,
+        v1::benchmarks::Response,
+        v1::metrics::Metric,
+        v1::metrics::Response,
+    ))
+)]
+struct ApiDoc;
+
+pub fn openapi_json() -> String {
+    ApiDoc::openapi()
+        .to_json()
+        .expect("failed to serialize OpenAPI document")
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts",
+    responses((status = 200, description = "All benchmarked artifacts", body = v1::artifacts::Response))
+)]
+pub fn handle_v1_artifacts(ctxt: &SiteCtxt) -> v1::artifacts::Response {
+    let index = ctxt.index.load();
+    let commits = index
+        .commits()
+        .into_iter()
+        .map(|commit| v1::artifacts::Commit {
+            is_master: commit.is_master(),
+            date: commit.date.0.timestamp(),
+            sha: commit.sha,
+        })
+        .collect();
+    let mut tags: Vec<String> = index.artifacts().map(String::from).collect();
+    tags.sort();
+    v1::artifacts::Response { commits, tags }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/benchmarks",
+    responses((status = 200, description = "All benchmarks with recorded results", body = v1::benchmarks::Response))
+)]
+pub async fn handle_v1_benchmarks(ctxt: Arc<SiteCtxt>) -> v1::benchmarks::Response {
+    let conn = ctxt.conn().await;
+    let mut compile: Vec<_> = conn
+        .get_compile_benchmarks()
+        .await
+        .into_iter()
+        .map(|benchmark| v1::benchmarks::CompileBenchmark {
+            name: benchmark.name,
+            category: benchmark.category,
+        })
+        .collect();
+    compile.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let index = ctxt.index.load();
+    let mut runtime: Vec<String> = index
+        .runtime_statistic_descriptions()
+        .map(|(&(benchmark, _), _)| benchmark.to_string())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    runtime.sort();
+
+    v1::benchmarks::Response { compile, runtime }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/metrics",
+    responses((status = 200, description = "All recorded metrics and their metadata", body = v1::metrics::Response))
+)]
+pub fn handle_v1_metrics(ctxt: &SiteCtxt) -> v1::metrics::Response {
+    fn describe(mut names: Vec<String>) -> Vec<v1::metrics::Metric> {
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                let metadata = MetricMetadata::for_metric(&name);
+                v1::metrics::Metric {
+                    unit: metadata.map(|m| m.unit.to_string()),
+                    better_direction: metadata.map(|m| {
+                        match m.better_direction {
+                            BetterDirection::Lower => "lower",
+                            BetterDirection::Higher => "higher",
+                        }
+                        .to_string()
+                    }),
+                    description: metadata.map(|m| m.description.to_string()),
+                    name,
+                }
+            })
+            .collect()
+    }
+
+    let index = ctxt.index.load();
+    v1::metrics::Response {
+        compile_metrics: describe(index.compile_metrics()),
+        runtime_metrics: describe(index.runtime_metrics()),
+    }
+}
@@ -30,11 +30,13 @@ use database::{Benchmark, Commit, Connection, Index, Lookup};
 
 use crate::comparison::Metric;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 use std::time::Instant;
+use tracing::Instrument;
 
 /// Finds the most appropriate `ArtifactId` for a given bound.
 ///
@@ -190,6 +192,12 @@ pub trait BenchmarkQuery: Debug + Clone {
         index: &Index,
         artifact_ids: Arc<Vec<ArtifactId>>,
     ) -> Result<Vec<SeriesResponse<Self::TestCase, StatisticSeries>>, String>;
+
+    /// The metric dimension of the query, used to expand derived metrics.
+    fn metric_selector(&self) -> &Selector<database::Metric>;
+
+    /// A copy of the query asking for the given stored metric instead.
+    fn with_metric(&self, metric: database::Metric) -> Self;
 }
 
 // Compile benchmarks querying
@@ -222,6 +230,13 @@ impl CompileBenchmarkQuery {
         self
     }
 
+    /// Like [`Self::metric`], but takes the metric by name: derived metrics
+    /// are configured at runtime and have no [`Metric`] variant.
+    pub fn metric_name(mut self, metric: database::Metric) -> Self {
+        self.metric = Selector::One(metric);
+        self
+    }
+
     pub fn all_for_metric(metric: Metric) -> Self {
         Self {
             benchmark: Selector::All,
@@ -313,6 +328,17 @@ impl BenchmarkQuery for CompileBenchmarkQuery {
             })
             .collect::<Vec<_>>())
     }
+
+    fn metric_selector(&self) -> &Selector<database::Metric> {
+        &self.metric
+    }
+
+    fn with_metric(&self, metric: database::Metric) -> Self {
+        Self {
+            metric: Selector::One(metric),
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -332,6 +358,23 @@ pub struct RuntimeBenchmarkQuery {
 }
 
 impl RuntimeBenchmarkQuery {
+    pub fn benchmark(mut self, selector: Selector<String>) -> Self {
+        self.benchmark = selector;
+        self
+    }
+
+    pub fn metric(mut self, selector: Selector<Metric>) -> Self {
+        self.metric = selector.map(|v| v.as_str().into());
+        self
+    }
+
+    /// Like [`Self::metric`], but takes the metric by name: derived metrics
+    /// are configured at runtime and have no [`Metric`] variant.
+    pub fn metric_name(mut self, metric: database::Metric) -> Self {
+        self.metric = Selector::One(metric);
+        self
+    }
+
     pub fn all_for_metric(metric: Metric) -> Self {
         Self {
             benchmark: Selector::All,
@@ -389,6 +432,17 @@ impl BenchmarkQuery for RuntimeBenchmarkQuery {
             })
             .collect::<Vec<_>>())
     }
+
+    fn metric_selector(&self) -> &Selector<database::Metric> {
+        &self.metric
+    }
+
+    fn with_metric(&self, metric: database::Metric) -> Self {
+        Self {
+            metric: Selector::One(metric),
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -420,20 +474,107 @@ impl StatisticSeries {
         query: Q,
     ) -> Result<Vec<SeriesResponse<Q::TestCase, Self>>, String> {
         let dumped = format!("{:?}", query);
+        let span = tracing::debug_span!("statistic_query", query = %dumped);
+
+        async {
+            let index = ctxt.index.load();
+            let mut conn = ctxt.conn().await;
+
+            let start = Instant::now();
+            let mut result = query
+                .execute(conn.as_mut(), &index, artifact_ids.clone())
+                .await?;
+            result.extend(derived_series(ctxt, conn.as_mut(), &index, &query, artifact_ids).await?);
+            let elapsed = start.elapsed();
+            log::trace!("{:?}: run {} from {}", elapsed, result.len(), dumped);
+            let slow_threshold = std::time::Duration::from_millis(
+                ctxt.config().logging.slow_query_threshold_ms,
+            );
+            if elapsed > slow_threshold {
+                log::warn!(
+                    "slow statistic query: {:?} returning {} series from {}",
+                    elapsed,
+                    result.len(),
+                    dumped
+                );
+            }
+            Ok(result)
+        }
+        .instrument(span)
+        .await
+    }
+}
 
-        let index = ctxt.index.load();
-        let mut conn = ctxt.conn().await;
+/// Materializes the derived metrics (see [`crate::derived`]) named by the
+/// query's metric selector: for each one, the stored metrics its expression
+/// references are queried and combined point by point, per test case.
+///
+/// Derived metrics only participate when asked for by name; `Selector::All`
+/// still means "all stored metrics", since computing every configured
+/// expression as a side effect of a broad query would be surprising.
+async fn derived_series<Q: BenchmarkQuery>(
+    ctxt: &SiteCtxt,
+    conn: &mut dyn Connection,
+    index: &Index,
+    query: &Q,
+    artifact_ids: Arc<Vec<ArtifactId>>,
+) -> Result<Vec<SeriesResponse<Q::TestCase, StatisticSeries>>, String> {
+    let definitions: Vec<_> = ctxt
+        .config()
+        .derived_metrics
+        .iter()
+        .filter(|definition| {
+            !matches!(query.metric_selector(), Selector::All)
+                && query
+                    .metric_selector()
+                    .matches::<database::Metric>(definition.name.as_str().into())
+        })
+        .cloned()
+        .collect();
+
+    let mut result = Vec::new();
+    for definition in definitions {
+        let expr = crate::derived::Expr::parse(&definition.expression)
+            .map_err(|e| format!("derived metric `{}`: {}", definition.name, e))?;
+
+        let mut inputs_by_test_case: HashMap<Q::TestCase, HashMap<String, Vec<Option<f64>>>> =
+            HashMap::new();
+        for metric in expr.metrics() {
+            let responses = query
+                .with_metric(metric.as_str().into())
+                .execute(conn, index, artifact_ids.clone())
+                .await?;
+            for response in responses {
+                inputs_by_test_case
+                    .entry(response.test_case)
+                    .or_default()
+                    .insert(metric.clone(), response.series.points.collect());
+            }
+        }
 
-        let start = Instant::now();
-        let result = query.execute(conn.as_mut(), &index, artifact_ids).await?;
-        log::trace!(
-            "{:?}: run {} from {}",
-            start.elapsed(),
-            result.len(),
-            dumped
-        );
-        Ok(result)
+        let mut test_cases: Vec<_> = inputs_by_test_case.into_iter().collect();
+        test_cases.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        for (test_case, inputs) in test_cases {
+            let points: Vec<Option<f64>> = (0..artifact_ids.len())
+                .map(|idx| {
+                    expr.evaluate(
+                        &|metric| inputs.get(metric).and_then(|points| points[idx]),
+                        &|metric| inputs.get(metric).and_then(|points| points[0]),
+                    )
+                })
+                .collect();
+            if points.iter().any(|point| point.is_some()) {
+                result.push(SeriesResponse {
+                    series: StatisticSeries {
+                        artifact_ids: ArtifactIdIter::new(artifact_ids.clone()),
+                        points: points.into_iter(),
+                    },
+                    test_case,
+                });
+            }
+        }
     }
+    Ok(result)
 }
 
 impl Iterator for StatisticSeries {
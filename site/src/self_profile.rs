@@ -75,13 +75,21 @@ pub(crate) async fn fetch_raw_self_profile_data(
     scenario: database::Scenario,
     cid: i32,
 ) -> anyhow::Result<Vec<u8>> {
-    let url =
-        format!(
-        "https://perf-data.rust-lang.org/self-profile/{}/{}/{}/{}/self-profile-{}.mm_profdata.sz",
-        aid.0, benchmark, profile, scenario.to_id(), cid,
+    let url_prefix = format!(
+        "https://perf-data.rust-lang.org/self-profile/{}/{}/{}/{}/self-profile-{}.mm_profdata",
+        aid.0,
+        benchmark,
+        profile,
+        scenario.to_id(),
+        cid,
     );
 
-    get_self_profile_raw_data(&url).await
+    // Newer collectors upload zstd-compressed archives; fall back to the
+    // legacy snappy extension for older artifacts.
+    match get_self_profile_raw_data(&format!("{}.zst", url_prefix)).await {
+        Ok(data) => Ok(data),
+        Err(_) => get_self_profile_raw_data(&format!("{}.sz", url_prefix)).await,
+    }
 }
 
 /// Fetch self profile data at the given URL
@@ -117,7 +125,18 @@ pub(crate) async fn get_self_profile_raw_data(url: &str) -> anyhow::Result<Vec<u
     extract(&compressed)
 }
 
+/// Magic number at the start of a zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
 fn extract(compressed: &[u8]) -> anyhow::Result<Vec<u8>> {
+    // Newer archives are compressed with zstd, older ones with snappy;
+    // dispatch on the magic number so that both keep working transparently.
+    if compressed.starts_with(&ZSTD_MAGIC) {
+        return match zstd::decode_all(compressed) {
+            Ok(data) => Ok(data),
+            Err(e) => anyhow::bail!("could not decode: {:?}", e),
+        };
+    }
     let mut data = Vec::new();
     match snap::read::FrameDecoder::new(compressed.reader()).read_to_end(&mut data) {
         Ok(v) => v,
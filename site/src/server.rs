@@ -6,19 +6,20 @@ use std::path::Path;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{fmt, str};
 
 use futures::{future::FutureExt, stream::StreamExt};
 use headers::{Authorization, CacheControl, ContentType, ETag, Header, HeaderMapExt, IfNoneMatch};
 use http::header::CACHE_CONTROL;
 use hyper::StatusCode;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use parking_lot::{Mutex, RwLock};
 use ring::hmac;
 use rmp_serde;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use tracing::Instrument;
 use uuid::Uuid;
 
 pub use crate::api::{
@@ -26,7 +27,7 @@ pub use crate::api::{
     status, triage, ServerResult,
 };
 use crate::db::{self, ArtifactId};
-use crate::load::{Config, SiteCtxt};
+use crate::load::{Config, LoggingConfig, SiteCtxt};
 use crate::request_handlers;
 use crate::resources::ResourceResolver;
 
@@ -146,7 +147,7 @@ impl Server {
         F: FnOnce(Arc<SiteCtxt>) -> R,
         R: std::future::Future<Output = Result<S, E>> + Send,
         S: Serialize,
-        E: Into<Vec<u8>>,
+        E: Into<api::RequestError>,
     {
         check_http_method!(*req.method(), http::Method::GET);
         let ctxt = self.ctxt.clone();
@@ -160,12 +161,7 @@ impl Server {
                 let body = serde_json::to_vec(&result).unwrap();
                 maybe_compressed_response(response, body, compression)
             }
-            Err(err) => http::Response::builder()
-                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                .header_typed(ContentType::text_utf8())
-                .header_typed(CacheControl::new().with_no_cache().with_no_store())
-                .body(hyper::Body::from(err.into()))
-                .unwrap(),
+            Err(err) => error_response(err.into()),
         };
         Ok(response)
     }
@@ -181,7 +177,7 @@ impl Server {
                 &mut Some(auth).into_iter(),
             )
             .unwrap();
-            return auth.0.token() == *ctxt.config.keys.github_webhook_secret.as_ref().unwrap();
+            return auth.0.token() == *ctxt.config().keys.github_webhook_secret.as_ref().unwrap();
         }
 
         false
@@ -275,10 +271,8 @@ impl Server {
 
         let ctxt: Arc<SiteCtxt> = self.ctxt.read().as_ref().unwrap().clone();
         let _updating = self.updating.release_on_drop(channel);
-        let mut conn = ctxt.conn().await;
-        let index = db::Index::load(&mut *conn).await;
-        eprintln!("index has {} commits", index.commits().len());
-        ctxt.index.store(Arc::new(index));
+        ctxt.refresh_index().await;
+        eprintln!("index has {} commits", ctxt.index.load().commits().len());
 
         // Refresh the landing page
         ctxt.landing_page.store(Arc::new(None));
@@ -286,6 +280,11 @@ impl Server {
         // Spawn off a task to post the results of any commit results that we
         // are now aware of.
         tokio::spawn(async move {
+            // Check the freshly ingested results for suspected noise runs
+            // before posting, so that graphs are annotated as soon as the
+            // data is visible.
+            crate::comparison::detect_noise_runs(&ctxt).await;
+            crate::alerts::evaluate_alerts(&ctxt).await;
             crate::github::post_finished(&ctxt).await;
         });
 
@@ -342,6 +341,35 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
         return Ok(response);
     }
 
+    // A read-only follower serves queries from a (streamed) copy of the
+    // database; ingestion, bot, and other mutating duties stay with the
+    // primary instance.
+    let follower = server
+        .ctxt
+        .read()
+        .as_ref()
+        .map_or(false, |ctxt| ctxt.config().follower);
+    if follower {
+        let primary_only = matches!(
+            path,
+            "/perf/onpush"
+                | "/perf/github-hook"
+                | "/perf/next_artifact"
+                | "/perf/collected"
+                | "/perf/purge-artifact"
+                | "/perf/subscribe"
+                | "/perf/self-profile-request"
+        ) || (path == "/perf/saved-query" && *req.method() == http::Method::POST);
+        if primary_only {
+            return Ok(http::Response::builder()
+                .status(StatusCode::SERVICE_UNAVAILABLE)
+                .body(hyper::Body::from(
+                    "this is a read-only follower instance; use the primary",
+                ))
+                .unwrap());
+        }
+    }
+
     macro_rules! check {
         ($e:expr) => {
             match $e {
@@ -352,7 +380,107 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
     }
 
     match path {
+        // The public, versioned API. See `crate::api::v1` for its stability
+        // guarantees.
+        "/api/v1/artifacts" => {
+            return server.handle_get(&req, request_handlers::handle_v1_artifacts)
+        }
+        "/api/v1/benchmarks" => {
+            return server
+                .handle_get_async(&req, request_handlers::handle_v1_benchmarks)
+                .await;
+        }
+        "/api/v1/metrics" => return server.handle_get(&req, request_handlers::handle_v1_metrics),
+        "/api/openapi.json" => {
+            check_http_method!(*req.method(), http::Method::GET);
+            return Ok(http::Response::builder()
+                .header_typed(ContentType::json())
+                .body(hyper::Body::from(request_handlers::openapi_json()))
+                .unwrap());
+        }
         "/perf/info" => return server.handle_get(&req, request_handlers::handle_info),
+        "/perf/metric-descriptions" => {
+            return server.handle_get(&req, request_handlers::handle_metric_descriptions)
+        }
+        "/perf/pr-history" => {
+            let input: api::pr_history::Request = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| {
+                    request_handlers::handle_pr_history(input, c)
+                })
+                .await;
+        }
+        "/perf/annotations" => {
+            return server
+                .handle_get_async(&req, |ctxt| async move {
+                    let annotations = ctxt.conn().await.annotations().await;
+                    api::annotations::Response {
+                        annotations: annotations
+                            .into_iter()
+                            .map(|a| api::annotations::Annotation {
+                                artifact: a.artifact,
+                                kind: a.kind,
+                                message: a.message,
+                            })
+                            .collect(),
+                    }
+                })
+                .await;
+        }
+        "/perf/range-compare" => {
+            let input: api::range_compare::Request = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| {
+                    request_handlers::handle_range_compare(input, c)
+                })
+                .await;
+        }
+        "/perf/step-timeline" => {
+            let input: api::step_timeline::Request = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| {
+                    request_handlers::handle_step_timeline(input, c)
+                })
+                .await;
+        }
+        "/perf/suggest" => {
+            let input: api::suggest::Request = check!(parse_query_string(req.uri()));
+            return server
+                .handle_get_async(&req, |c| request_handlers::handle_suggest(input, c))
+                .await;
+        }
+        "/perf/saved-queries" => {
+            return server
+                .handle_get_async(&req, |ctxt| async move {
+                    let queries = ctxt.conn().await.saved_queries().await;
+                    api::saved_query::ListResponse {
+                        queries: queries
+                            .into_iter()
+                            .map(|(name, query)| {
+                                let query = serde_json::from_str(&query)
+                                    .unwrap_or(serde_json::Value::Null);
+                                (name, query)
+                            })
+                            .collect(),
+                    }
+                })
+                .await;
+        }
+        "/perf/saved-query" if *req.method() == http::Method::GET => {
+            let input: api::saved_query::Request = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |ctxt| async move {
+                    match ctxt.conn().await.get_saved_query(&input.name).await {
+                        Some(query) => Ok(serde_json::from_str::<serde_json::Value>(&query)
+                            .unwrap_or(serde_json::Value::Null)),
+                        None => Err(api::RequestError::NotFound(format!(
+                            "unknown saved query {}",
+                            input.name
+                        ))),
+                    }
+                })
+                .await;
+        }
         "/perf/dashboard" => {
             return server
                 .handle_get_async(&req, request_handlers::handle_dashboard)
@@ -363,6 +491,11 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
                 .handle_get_async(&req, request_handlers::handle_status_page)
                 .await;
         }
+        "/perf/health" => {
+            return server
+                .handle_get_async(&req, request_handlers::handle_health)
+                .await;
+        }
         "/perf/next_artifact" => {
             return server
                 .handle_get_async(&req, request_handlers::handle_next_artifact)
@@ -375,6 +508,14 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
                 crate::comparison::handle_triage(input, &ctxt).await,
             ));
         }
+        "/perf/relevance" => {
+            let query = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| async move {
+                    crate::comparison::handle_relevance(query, &c).await
+                })
+                .await;
+        }
         "/perf/graph" => {
             let query = check!(parse_query_string(req.uri()));
             return server
@@ -385,12 +526,53 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
         }
         "/perf/graphs" => {
             let query = check!(parse_query_string(req.uri()));
+            // Clients can negotiate the compact msgpack encoding, which is
+            // much smaller for the full-history default query.
+            let accepts_msgpack = req
+                .headers()
+                .get(hyper::header::ACCEPT)
+                .and_then(|accept| accept.to_str().ok())
+                .map_or(false, |accept| accept.contains("application/msgpack"));
+            if accepts_msgpack {
+                check_http_method!(*req.method(), http::Method::GET);
+                let ctxt: Arc<SiteCtxt> = server.ctxt.read().as_ref().unwrap().clone();
+                let response = match request_handlers::handle_graphs(query, ctxt).await {
+                    Ok(resp) => {
+                        let compact = graphs::CompactResponse::from(&*resp);
+                        let response = http::Response::builder()
+                            .header_typed(ContentType::from(
+                                "application/msgpack".parse::<mime::Mime>().unwrap(),
+                            ))
+                            .header_typed(CacheControl::new().with_no_cache().with_no_store());
+                        let body = rmp_serde::to_vec_named(&compact).unwrap();
+                        maybe_compressed_response(response, body, &compression)
+                    }
+                    Err(err) => error_response(err),
+                };
+                return Ok(response);
+            }
             return server
                 .handle_fallible_get_async(&req, &compression, |c| {
                     request_handlers::handle_graphs(query, c)
                 })
                 .await;
         }
+        "/perf/graphs-releases" => {
+            let query = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| {
+                    request_handlers::handle_graphs_releases(query, c)
+                })
+                .await;
+        }
+        "/perf/suite-cost" => {
+            let query = check!(parse_query_string(req.uri()));
+            return server
+                .handle_fallible_get_async(&req, &compression, |c| {
+                    request_handlers::handle_suite_cost(query, c)
+                })
+                .await;
+        }
         "/perf/metrics" => {
             return Ok(server.handle_metrics(req).await);
         }
@@ -429,10 +611,35 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
     }
 
     match path {
-        "/perf/get" => Ok(to_response(
-            crate::comparison::handle_compare(check!(parse_body(&body)), &ctxt).await,
-            &compression,
-        )),
+        "/perf/get" => {
+            // `?format=markdown` renders the comparison as the same markdown
+            // summary table the GitHub bot posts, so external automation can
+            // embed it in its own comments.
+            let markdown = req.uri.query().map_or(false, |q| {
+                q.split('&').any(|part| part == "format=markdown")
+            });
+            if markdown {
+                Ok(
+                    match crate::comparison::handle_compare_markdown(
+                        check!(parse_body(&body)),
+                        &ctxt,
+                    )
+                    .await
+                    {
+                        Ok(text) => http::Response::builder()
+                            .header_typed(ContentType::text_utf8())
+                            .body(hyper::Body::from(text))
+                            .unwrap(),
+                        Err(err) => error_response(err),
+                    },
+                )
+            } else {
+                Ok(to_response(
+                    crate::comparison::handle_compare(check!(parse_body(&body)), &ctxt).await,
+                    &compression,
+                ))
+            }
+        }
         "/perf/collected" => {
             if !server.check_auth(&req) {
                 return Ok(http::Response::builder()
@@ -445,8 +652,180 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
                 &compression,
             ))
         }
+        "/perf/audit-log" => {
+            check_http_method!(*req.method, http::Method::GET);
+            if !server.check_auth(&req) {
+                return Ok(http::Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .body(hyper::Body::empty())
+                    .unwrap());
+            }
+            let input: api::audit_log::Request = check!(parse_query_string(&req.uri));
+            let conn = ctxt.conn().await;
+            let events = conn.audit_log(input.limit.unwrap_or(100).min(1000)).await;
+            let response = api::audit_log::Response {
+                events: events
+                    .into_iter()
+                    .map(|event| api::audit_log::Event {
+                        id: event.id,
+                        occurred_at: event.occurred_at.0.timestamp(),
+                        actor: event.actor,
+                        action: event.action,
+                        payload: serde_json::from_str(&event.payload)
+                            .unwrap_or(serde_json::Value::Null),
+                    })
+                    .collect(),
+            };
+            Ok(to_response(Ok(response), &compression))
+        }
+        "/perf/subscribe" => {
+            let input: api::subscription::Request = check!(parse_body(&body));
+            let valid = match input.kind.as_str() {
+                "email" => input.target.contains('@'),
+                "webhook" => {
+                    input.target.starts_with("http://") || input.target.starts_with("https://")
+                }
+                _ => false,
+            };
+            if !valid {
+                return Ok(to_response::<()>(
+                    Err(api::RequestError::BadRequest(format!(
+                        "invalid subscription: kind must be `email` or `webhook` \
+                        with a matching target, got {} {:?}",
+                        input.kind, input.target
+                    ))),
+                    &compression,
+                ));
+            }
+            let conn = ctxt.conn().await;
+            conn.record_audit_event(
+                "anonymous",
+                "subscribe",
+                &serde_json::to_string(&input).unwrap(),
+            )
+            .await;
+            conn.add_subscription(&input.kind, &input.target, &input.benchmark)
+                .await;
+            Ok(to_response(Ok(()), &compression))
+        }
+        "/perf/saved-query" => {
+            let input: api::saved_query::CreateRequest = check!(parse_body(&body));
+            let valid_slug = !input.name.is_empty()
+                && input.name.len() <= 64
+                && input
+                    .name
+                    .bytes()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-' || c == b'_');
+            if !valid_slug {
+                return Ok(to_response::<()>(
+                    Err(api::RequestError::BadRequest(format!(
+                        "invalid saved query name {:?}: \
+                        expected up to 64 lowercase letters, digits, `-` or `_`",
+                        input.name
+                    ))),
+                    &compression,
+                ));
+            }
+            let query = serde_json::to_string(&input.query).unwrap();
+            let conn = ctxt.conn().await;
+            conn.record_audit_event(
+                "anonymous",
+                "save-query",
+                &serde_json::to_string(&input).unwrap(),
+            )
+            .await;
+            conn.save_query(&input.name, &query).await;
+            Ok(to_response(Ok(()), &compression))
+        }
+        "/perf/self-profile-request" => {
+            if !server.check_auth(&req) {
+                return Ok(http::Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .body(hyper::Body::empty())
+                    .unwrap());
+            }
+            let input: api::self_profile_request::Request = check!(parse_body(&body));
+            let known_commit = ctxt
+                .index
+                .load()
+                .commits()
+                .iter()
+                .any(|c| c.sha == input.commit.as_str());
+            if !known_commit {
+                return Ok(to_response::<()>(
+                    Err(api::RequestError::NotFound(format!(
+                        "unknown commit {}",
+                        input.commit
+                    ))),
+                    &compression,
+                ));
+            }
+            let conn = ctxt.conn().await;
+            conn.record_audit_event(
+                "api-token",
+                "self-profile-request",
+                &serde_json::to_string(&input).unwrap(),
+            )
+            .await;
+            conn.queue_self_profile_request(
+                &input.commit,
+                &input.benchmark,
+                &input.profile,
+                &input.scenario,
+            )
+            .await;
+            Ok(to_response(Ok(()), &compression))
+        }
+        "/perf/purge-artifact" => {
+            if !server.check_auth(&req) {
+                return Ok(http::Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .body(hyper::Body::empty())
+                    .unwrap());
+            }
+            let input: api::purge_artifact::Request = check!(parse_body(&body));
+            let mut conn = ctxt.conn().await;
+            let aid = match conn.artifact_by_name(&input.artifact).await {
+                Some(aid) => aid,
+                None => {
+                    return Ok(to_response::<()>(
+                        Err(api::RequestError::NotFound(format!(
+                            "unknown artifact {}",
+                            input.artifact
+                        ))),
+                        &compression,
+                    ));
+                }
+            };
+            conn.record_audit_event(
+                "api-token",
+                "purge-artifact",
+                &serde_json::to_string(&input).unwrap(),
+            )
+            .await;
+            conn.purge_artifact(&aid).await;
+            // Refresh the index so that the purged artifact no longer shows up
+            // and, if it was a master commit, becomes eligible for
+            // re-benchmarking.
+            ctxt.refresh_index().await;
+            ctxt.landing_page.store(Arc::new(None));
+            Ok(to_response(Ok(()), &compression))
+        }
+        "/perf/reload-config" => {
+            if !server.check_auth(&req) {
+                return Ok(http::Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .body(hyper::Body::empty())
+                    .unwrap());
+            }
+            Ok(to_response(
+                ctxt.reload_config()
+                    .map_err(|e| format!("failed to reload config: {:?}", e)),
+                &compression,
+            ))
+        }
         "/perf/github-hook" => {
-            if !verify_gh(&ctxt.config, &req, &body) {
+            if !verify_gh(&ctxt.config(), &req, &body) {
                 return Ok(http::Response::builder()
                     .status(StatusCode::UNAUTHORIZED)
                     .body(hyper::Body::empty())
@@ -474,6 +853,12 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
                     .unwrap()),
             }
         }
+        "/perf/selector-query" => {
+            // The response is streamed as the series are produced, so it is
+            // never compressed.
+            let input = check!(parse_body(&body));
+            Ok(request_handlers::handle_selector_query(input, ctxt.clone()).await)
+        }
         "/perf/self-profile" => Ok(to_response(
             request_handlers::handle_self_profile(check!(parse_body(&body)), &ctxt).await,
             &compression,
@@ -482,6 +867,26 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
             request_handlers::handle_self_profile_raw(check!(parse_body(&body)), &ctxt).await,
             &compression,
         )),
+        "/perf/self-profile-aggregate" => Ok(to_response(
+            request_handlers::handle_self_profile_aggregate(check!(parse_body(&body)), &ctxt).await,
+            &compression,
+        )),
+        "/perf/latency-slo" => Ok(to_response(
+            request_handlers::handle_latency_slo(check!(parse_body(&body)), &ctxt).await,
+            &compression,
+        )),
+        "/perf/channel-compare" => Ok(to_response(
+            request_handlers::handle_channel_compare(check!(parse_body(&body)), ctxt.clone()).await,
+            &compression,
+        )),
+        "/perf/release-notes" => Ok(to_response(
+            request_handlers::handle_release_notes(check!(parse_body(&body)), ctxt.clone()).await,
+            &compression,
+        )),
+        "/perf/sparkline" => Ok(to_response(
+            request_handlers::handle_sparkline(check!(parse_body(&body)), ctxt.clone()).await,
+            &compression,
+        )),
         "/perf/bootstrap" => Ok(
             match request_handlers::handle_bootstrap(check!(parse_body(&body)), &ctxt).await {
                 Ok(result) => {
@@ -495,12 +900,7 @@ async fn serve_req(server: Server, req: Request) -> Result<Response, ServerError
                     let body = serde_json::to_vec(&result).unwrap();
                     response.body(hyper::Body::from(body)).unwrap()
                 }
-                Err(err) => http::Response::builder()
-                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .header_typed(ContentType::text_utf8())
-                    .header_typed(CacheControl::new().with_no_cache().with_no_store())
-                    .body(hyper::Body::from(err))
-                    .unwrap(),
+                Err(err) => error_response(err),
             },
         ),
         _ => Ok(http::Response::builder()
@@ -668,15 +1068,30 @@ where
             let body = rmp_serde::to_vec_named(&result).unwrap();
             maybe_compressed_response(response, body, compression)
         }
-        Err(err) => http::Response::builder()
-            .status(StatusCode::INTERNAL_SERVER_ERROR)
-            .header_typed(ContentType::text_utf8())
-            .header_typed(CacheControl::new().with_no_cache().with_no_store())
-            .body(hyper::Body::from(err))
-            .unwrap(),
+        Err(err) => error_response(err),
     }
 }
 
+/// Renders a request error as its HTTP status code with a JSON body of the
+/// form `{"error": {"kind": "...", "message": "..."}}`.
+pub(crate) fn error_response(err: api::RequestError) -> Response {
+    let status = match &err {
+        api::RequestError::BadRequest(_) => StatusCode::BAD_REQUEST,
+        api::RequestError::NotFound(_) => StatusCode::NOT_FOUND,
+        api::RequestError::Upstream(_) => StatusCode::BAD_GATEWAY,
+        api::RequestError::DbTimeout(_) => StatusCode::SERVICE_UNAVAILABLE,
+        api::RequestError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    http::Response::builder()
+        .status(status)
+        .header_typed(ContentType::json())
+        .header_typed(CacheControl::new().with_no_cache().with_no_store())
+        .body(hyper::Body::from(
+            serde_json::to_string(&serde_json::json!({ "error": err })).unwrap(),
+        ))
+        .unwrap()
+}
+
 fn maybe_compressed_response(
     response: http::response::Builder,
     body: Vec<u8>,
@@ -707,43 +1122,120 @@ fn to_triage_response(result: ServerResult<api::triage::Response>) -> Response {
             let response = http::Response::builder().header_typed(ContentType::text());
             response.body(hyper::Body::from(result.0)).unwrap()
         }
-        Err(err) => http::Response::builder()
-            .status(StatusCode::INTERNAL_SERVER_ERROR)
-            .header_typed(ContentType::text_utf8())
-            .body(hyper::Body::from(err))
-            .unwrap(),
+        Err(err) => error_response(err),
     }
 }
 
 async fn run_server(ctxt: Arc<RwLock<Option<Arc<SiteCtxt>>>>, addr: SocketAddr) {
     let server = Server::new(ctxt);
+    let server_ctxt = server.ctxt.clone();
     let svc = hyper::service::make_service_fn(move |_conn| {
         let ctx = server.clone();
         async move {
             Ok::<_, hyper::Error>(hyper::service::service_fn(move |req| {
                 let start = std::time::Instant::now();
+                // Identifies this request in logs and in the `x-request-id`
+                // response header, so that a slow or failed call can be
+                // correlated with the server-side spans it produced.
+                let request_id = Uuid::new_v4();
                 let desc = format!("{} {}", req.method(), req.uri());
+                let slow_threshold = ctx
+                    .ctxt
+                    .read()
+                    .as_ref()
+                    .map(|ctxt| ctxt.config().logging.slow_request_threshold_ms)
+                    .unwrap_or_else(|| LoggingConfig::default().slow_request_threshold_ms);
+                let span = tracing::info_span!("request", %request_id);
                 serve_req(ctx.clone(), req)
+                    .instrument(span)
                     .inspect(move |r| {
                         let dur = start.elapsed();
-                        info!("{}: {:?} {:?}", desc, r.as_ref().map(|r| r.status()), dur)
+                        info!(
+                            "{} [{}]: {:?} {:?}",
+                            desc,
+                            request_id,
+                            r.as_ref().map(|r| r.status()),
+                            dur
+                        );
+                        if dur > Duration::from_millis(slow_threshold) {
+                            warn!("slow request {} [{}]: took {:?}", desc, request_id, dur);
+                        }
                     })
-                    .map(|mut r| {
+                    .map(move |mut r| {
                         if let Ok(r) = &mut r {
                             r.headers_mut().insert(
                                 hyper::header::ACCESS_CONTROL_ALLOW_ORIGIN,
                                 hyper::header::HeaderValue::from_static("*"),
                             );
+                            r.headers_mut().insert(
+                                "x-request-id",
+                                hyper::header::HeaderValue::from_str(
+                                    &request_id.to_string(),
+                                )
+                                .unwrap(),
+                            );
                         }
                         r
                     })
             }))
         }
     });
-    let server = hyper::server::Server::bind(&addr).serve(svc);
-    if let Err(e) = server.await {
-        eprintln!("server error: {:?}", e);
+    let (shutdown_started_tx, shutdown_started_rx) = tokio::sync::oneshot::channel::<()>();
+    let graceful = hyper::server::Server::bind(&addr)
+        .serve(svc)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            info!("shutdown signal received; draining in-flight requests");
+            let _ = shutdown_started_tx.send(());
+        });
+    tokio::pin!(graceful);
+    tokio::select! {
+        result = &mut graceful => {
+            // The server stopped without a shutdown signal, e.g. because the
+            // listening socket failed.
+            if let Err(e) = result {
+                eprintln!("server error: {:?}", e);
+            }
+            return;
+        }
+        _ = shutdown_started_rx => {
+            // The listener is closed; give in-flight requests a bounded
+            // amount of time to finish.
+            match tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, &mut graceful).await {
+                Ok(Ok(())) => info!("in-flight requests drained"),
+                Ok(Err(e)) => eprintln!("server error during shutdown: {:?}", e),
+                Err(_) => warn!("shutdown grace period elapsed; aborting remaining requests"),
+            }
+        }
+    }
+
+    // Flush caches and close the database pools so that a deploy cannot
+    // interrupt an in-progress write.
+    let ctxt = server_ctxt.read().clone();
+    if let Some(ctxt) = ctxt {
+        ctxt.shutdown().await;
+    }
+    info!("shutdown complete");
+}
+
+/// How long in-flight requests are given to finish after a shutdown signal
+/// before the server exits anyway.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// Resolves when the process is asked to shut down: SIGTERM from a deploy,
+/// or Ctrl+C when running locally.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut sigterm = signal(SignalKind::terminate()).unwrap();
+        tokio::select! {
+            _ = sigterm.recv() => {}
+            _ = tokio::signal::ctrl_c() => {}
+        }
     }
+    #[cfg(not(unix))]
+    tokio::signal::ctrl_c().await.unwrap();
 }
 
 pub async fn start(ctxt: Arc<RwLock<Option<Arc<SiteCtxt>>>>, port: u16) {
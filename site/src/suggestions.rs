@@ -0,0 +1,90 @@
+//! Prefix/fuzzy matching over known names (benchmarks, metrics, scenarios),
+//! backing the `/perf/suggest` endpoint and "did you mean" error messages.
+
+/// How well a candidate matches a query; lower is better. Exact matches rank
+/// before prefix matches, prefix before substring, substring before fuzzy.
+/// All matching is case-insensitive.
+pub fn match_score(query: &str, candidate: &str) -> Option<u32> {
+    let query = query.to_ascii_lowercase();
+    let candidate = candidate.to_ascii_lowercase();
+    if candidate == query {
+        return Some(0);
+    }
+    if candidate.starts_with(&query) {
+        return Some(1);
+    }
+    if candidate.contains(&query) {
+        return Some(2);
+    }
+    // A typo is usually a few characters away; anything further apart is
+    // noise rather than a suggestion.
+    let distance = edit_distance(&query, &candidate);
+    let max_distance = (query.chars().count() / 3).max(2) as u32;
+    if distance <= max_distance {
+        return Some(3 + distance);
+    }
+    None
+}
+
+/// The single best fuzzy match for `query`, if there is a convincing one.
+/// Ties break towards shorter and then lexicographically smaller candidates.
+pub fn did_you_mean<I>(query: &str, candidates: I) -> Option<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    candidates
+        .into_iter()
+        .filter_map(|candidate| match_score(query, &candidate).map(|score| (score, candidate)))
+        .min_by(|(score_a, a), (score_b, b)| (score_a, a.len(), a).cmp(&(score_b, b.len(), b)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`, by characters.
+fn edit_distance(a: &str, b: &str) -> u32 {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<u32> = (0..=b.len() as u32).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous_diagonal = row[0];
+        row[0] = i as u32 + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            let value = (previous_diagonal + cost)
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            previous_diagonal = row[j + 1];
+            row[j + 1] = value;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_ranks_before_fuzzy() {
+        assert_eq!(match_score("instructions:u", "instructions:u"), Some(0));
+        assert_eq!(match_score("instr", "instructions:u"), Some(1));
+        assert_eq!(match_score("structions", "instructions:u"), Some(2));
+        assert!(match_score("instructons:u", "instructions:u") > Some(2));
+    }
+
+    #[test]
+    fn did_you_mean_rejects_distant_names() {
+        let candidates = vec!["instructions:u".to_string(), "cycles:u".to_string()];
+        assert_eq!(
+            did_you_mean("cylces:u", candidates.clone()),
+            Some("cycles:u".to_string())
+        );
+        assert_eq!(did_you_mean("zzzzzzzz", candidates), None);
+    }
+
+    #[test]
+    fn edit_distance_counts_insertions_and_substitutions() {
+        assert_eq!(edit_distance("abc", "abc"), 0);
+        assert_eq!(edit_distance("abc", "abd"), 1);
+        assert_eq!(edit_distance("abc", "abcd"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}